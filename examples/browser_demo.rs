@@ -31,8 +31,21 @@ async fn main() -> Result<()> {
         .await
         .context("Failed to create logging client")?;
 
-    // Run all workflows
-    let result = run_all_workflows(&client).await;
+    // Run all workflows, but bail out to a graceful drain if Ctrl-C/SIGTERM
+    // arrives mid-run instead of leaving the server process orphaned for
+    // `ServerHandle`'s `Drop` impl to force-kill.
+    let result = tokio::select! {
+        result = run_all_workflows(&client) => result,
+        () = common::wait_for_shutdown_signal() => {
+            info!("🛑 Shutdown signal received, draining in-flight requests...");
+            server
+                .graceful_drain(std::time::Duration::from_secs(10), client.in_flight_receiver())
+                .await?;
+            client.flush().await?;
+            conn.close().await?;
+            return Ok(());
+        }
+    };
 
     // Always close connection
     conn.close().await?;
@@ -9,11 +9,12 @@ use kodegen_mcp_client::{
     create_streamable_client,
 };
 use rmcp::model::CallToolResult;
+use sha2::Digest as _;
 use std::path::{Path, PathBuf};
 use std::sync::{Mutex as StdMutex, OnceLock};
 use tokio::io::{AsyncWriteExt, BufWriter};
 use tokio::process::{Child, Command};
-use tokio::sync::{Mutex, watch};
+use tokio::sync::{Mutex, mpsc, oneshot, watch};
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -136,6 +137,79 @@ impl ServerHandle {
         }
         Ok(())
     }
+
+    /// Wait for `in_flight` (see [`LoggingClient::in_flight_receiver`]) to
+    /// reach zero, up to `timeout`, before calling [`Self::shutdown`] - so a
+    /// SIGTERM doesn't land mid-tool-call the way an immediate
+    /// [`Self::shutdown`] can. Shuts down anyway once `timeout` elapses,
+    /// logging how many requests were still in flight.
+    pub async fn graceful_drain(
+        &mut self,
+        timeout: std::time::Duration,
+        mut in_flight: watch::Receiver<usize>,
+    ) -> Result<()> {
+        let deadline = std::time::Instant::now() + timeout;
+        eprintln!(
+            "⏳ Draining in-flight requests before shutdown (up to {:?})...",
+            timeout
+        );
+
+        while *in_flight.borrow() > 0 {
+            let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+            if remaining.is_zero() {
+                eprintln!(
+                    "⚠️  Drain timeout reached with {} request(s) still in flight, shutting down anyway",
+                    *in_flight.borrow()
+                );
+                break;
+            }
+            if tokio::time::timeout(remaining, in_flight.changed())
+                .await
+                .is_err()
+            {
+                eprintln!(
+                    "⚠️  Drain timeout reached with {} request(s) still in flight, shutting down anyway",
+                    *in_flight.borrow()
+                );
+                break;
+            }
+        }
+
+        if *in_flight.borrow() == 0 {
+            eprintln!("✅ All in-flight requests drained");
+        }
+
+        self.shutdown().await
+    }
+}
+
+/// Resolve once SIGINT (Ctrl-C) or, on Unix, SIGTERM is received. Pairs with
+/// [`ServerHandle::graceful_drain`]: a caller `tokio::select!`s this against
+/// its normal workload, and on signal drains in-flight requests, flushes its
+/// [`LoggingClient`]'s JSONL log, and shuts down explicitly - instead of
+/// leaving an orphaned `kodegen-browser` process for [`ServerHandle`]'s
+/// `Drop` impl to force-kill.
+pub async fn wait_for_shutdown_signal() {
+    #[cfg(unix)]
+    {
+        let mut sigterm = match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+        {
+            Ok(signal) => signal,
+            Err(e) => {
+                eprintln!("⚠️  Failed to install SIGTERM handler: {e}");
+                let _ = tokio::signal::ctrl_c().await;
+                return;
+            }
+        };
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => {}
+            _ = sigterm.recv() => {}
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = tokio::signal::ctrl_c().await;
+    }
 }
 
 impl Drop for ServerHandle {
@@ -147,125 +221,207 @@ impl Drop for ServerHandle {
     }
 }
 
-/// Kill processes on specified port (gracefully with fallback)
+/// Process-discovery abstraction behind [`cleanup_port`] - a Unix impl
+/// (`lsof`/`ps`/`kill`) and a Windows impl (`netstat`/`tasklist`/`taskkill`)
+/// share the same poll-every-500ms-for-3s graceful-then-force escalation in
+/// [`reap_port`], so both platforms behave identically instead of Windows
+/// falling back to a manual-cleanup warning.
+#[async_trait::async_trait]
+trait PortReaper {
+    /// PIDs of every process currently listening on `port`.
+    async fn find_pids(&self, port: u16) -> Result<Vec<u32>>;
+    /// Best-effort process name for `pid`, used for the kodegen/cargo safety
+    /// check - `None` if the process can't be identified (treated as safe
+    /// to skip, same as the original Unix-only logic did).
+    async fn process_name(&self, pid: u32) -> Option<String>;
+    /// Ask `pid` to exit - gracefully (SIGTERM / `taskkill /PID /T`) or
+    /// forcefully (SIGKILL / `taskkill /PID /T /F`) depending on `graceful`.
+    async fn terminate(&self, pid: u32, graceful: bool) -> Result<()>;
+    /// Whether `pid` still exists.
+    async fn is_alive(&self, pid: u32) -> bool;
+}
+
 #[cfg(unix)]
-pub async fn cleanup_port(port: u16) -> Result<()> {
-    use std::time::Duration;
-    
-    eprintln!("🧹 Checking for processes on port {port}...");
+struct UnixPortReaper;
 
-    // Step 1: Find PIDs on port using lsof
-    let output = Command::new("lsof")
-        .args(["-ti", &format!(":{port}")])
-        .output()
-        .await
-        .context("Failed to run lsof")?;
+#[cfg(unix)]
+#[async_trait::async_trait]
+impl PortReaper for UnixPortReaper {
+    async fn find_pids(&self, port: u16) -> Result<Vec<u32>> {
+        let output = Command::new("lsof")
+            .args(["-ti", &format!(":{port}")])
+            .output()
+            .await
+            .context("Failed to run lsof")?;
 
-    if !output.status.success() || output.stdout.is_empty() {
-        eprintln!("   No processes found on port {port}");
-        return Ok(());
+        if !output.status.success() || output.stdout.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|s| s.trim().parse::<u32>().ok())
+            .collect())
     }
 
-    // Step 2: Parse and validate PIDs
-    let pids_string = String::from_utf8_lossy(&output.stdout);
-    let pids: Vec<&str> = pids_string
-        .lines()
-        .map(|s| s.trim())
-        .filter(|s| !s.is_empty())
-        .collect();
+    async fn process_name(&self, pid: u32) -> Option<String> {
+        let output = Command::new("ps")
+            .args(["-p", &pid.to_string(), "-o", "comm="])
+            .output()
+            .await
+            .ok()?;
+        let name = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        (!name.is_empty()).then_some(name)
+    }
 
-    if pids.is_empty() {
-        return Ok(());
+    async fn terminate(&self, pid: u32, graceful: bool) -> Result<()> {
+        let signal = if graceful { "-TERM" } else { "-9" };
+        Command::new("kill")
+            .args([signal, &pid.to_string()])
+            .status()
+            .await
+            .context("Failed to send signal")?;
+        Ok(())
     }
 
-    // Step 3: Gracefully shutdown each process
-    for pid_str in pids {
-        // Validate PID is numeric
-        if pid_str.parse::<u32>().is_err() {
-            eprintln!("   ⚠️  Invalid PID: {pid_str}, skipping");
-            continue;
+    async fn is_alive(&self, pid: u32) -> bool {
+        Command::new("kill")
+            .args(["-0", &pid.to_string()])
+            .status()
+            .await
+            .map(|s| s.success())
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(windows)]
+struct WindowsPortReaper;
+
+#[cfg(windows)]
+#[async_trait::async_trait]
+impl PortReaper for WindowsPortReaper {
+    async fn find_pids(&self, port: u16) -> Result<Vec<u32>> {
+        let output = Command::new("netstat")
+            .args(["-ano"])
+            .output()
+            .await
+            .context("Failed to run netstat")?;
+
+        let needle = format!(":{port} ");
+        let mut pids: Vec<u32> = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter(|line| line.contains(&needle) && line.to_uppercase().contains("LISTENING"))
+            .filter_map(|line| line.split_whitespace().last())
+            .filter_map(|pid_str| pid_str.parse::<u32>().ok())
+            .collect();
+        pids.sort_unstable();
+        pids.dedup();
+        Ok(pids)
+    }
+
+    async fn process_name(&self, pid: u32) -> Option<String> {
+        let output = Command::new("tasklist")
+            .args(["/FI", &format!("PID eq {pid}"), "/FO", "CSV", "/NH"])
+            .output()
+            .await
+            .ok()?;
+        let text = String::from_utf8_lossy(&output.stdout);
+        let name = text.split(',').next()?.trim_matches('"').to_string();
+        (!name.is_empty()).then_some(name)
+    }
+
+    async fn terminate(&self, pid: u32, graceful: bool) -> Result<()> {
+        let mut cmd = Command::new("taskkill");
+        cmd.args(["/PID", &pid.to_string(), "/T"]);
+        if !graceful {
+            cmd.arg("/F");
         }
+        cmd.status().await.context("Failed to run taskkill")?;
+        Ok(())
+    }
 
-        // Optional safety check: Verify process name looks like kodegen/cargo
-        // This prevents accidentally killing unrelated processes
-        let proc_check = Command::new("ps")
-            .args(["-p", pid_str, "-o", "comm="])
+    async fn is_alive(&self, pid: u32) -> bool {
+        let Ok(output) = Command::new("tasklist")
+            .args(["/FI", &format!("PID eq {pid}"), "/NH"])
             .output()
-            .await;
-        
-        if let Ok(proc_output) = proc_check {
-            let proc_name = String::from_utf8_lossy(&proc_output.stdout);
-            let proc_name_trimmed = proc_name.trim();
-            
-            // Allow kodegen binaries and cargo (for development)
-            if !proc_name_trimmed.contains("kodegen") 
-                && !proc_name_trimmed.contains("cargo")
-                && !proc_name_trimmed.is_empty() 
-            {
-                eprintln!(
-                    "   ⚠️  Process {pid_str} ({proc_name_trimmed}) doesn't look like kodegen, skipping"
-                );
-                continue;
-            }
+            .await
+        else {
+            return false;
+        };
+        String::from_utf8_lossy(&output.stdout).contains(&pid.to_string())
+    }
+}
+
+/// Shared escalation logic for [`cleanup_port`]: graceful terminate, poll
+/// every 500ms for 3s, then force-kill - same timing the original Unix-only
+/// implementation used, plus the kodegen/cargo name safety check.
+async fn reap_port(reaper: &dyn PortReaper, port: u16) -> Result<()> {
+    eprintln!("🧹 Checking for processes on port {port}...");
+
+    let pids = reaper.find_pids(port).await?;
+    if pids.is_empty() {
+        eprintln!("   No processes found on port {port}");
+        return Ok(());
+    }
+
+    for pid in pids {
+        // Safety check: only kill processes that look like kodegen/cargo,
+        // so a stale unrelated listener on the port isn't touched.
+        if let Some(name) = reaper.process_name(pid).await
+            && !name.contains("kodegen")
+            && !name.contains("cargo")
+        {
+            eprintln!("   ⚠️  Process {pid} ({name}) doesn't look like kodegen, skipping");
+            continue;
         }
 
-        eprintln!("   Sending SIGTERM to PID {pid_str}...");
-        
-        // Step 3a: Try graceful shutdown first (SIGTERM = signal 15)
-        let term_result = Command::new("kill")
-            .args(["-TERM", pid_str])
-            .status()
-            .await;
-            
-        if let Err(e) = term_result {
-            eprintln!("   ⚠️  Failed to send SIGTERM to {pid_str}: {e}");
+        eprintln!("   Sending graceful terminate to PID {pid}...");
+        if let Err(e) = reaper.terminate(pid, true).await {
+            eprintln!("   ⚠️  Failed to terminate {pid}: {e}");
             continue;
         }
 
-        // Step 3b: Wait up to 3 seconds for graceful exit
-        // Poll every 500ms to check if process has exited
         let mut exited = false;
         for attempt in 0..6 {
-            tokio::time::sleep(Duration::from_millis(500)).await;
-            
-            // Check if process still exists using kill -0
-            // Signal 0 doesn't actually send a signal, just checks if PID exists
-            let check = Command::new("kill")
-                .args(["-0", pid_str])
-                .status()
-                .await;
-                
-            if check.map(|s| !s.success()).unwrap_or(true) {
-                eprintln!("   ✅ Process {pid_str} exited gracefully after {}ms", (attempt + 1) * 500);
+            tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+            if !reaper.is_alive(pid).await {
+                eprintln!(
+                    "   ✅ Process {pid} exited gracefully after {}ms",
+                    (attempt + 1) * 500
+                );
                 exited = true;
                 break;
             }
         }
 
-        // Step 3c: Force kill if still alive after grace period
         if !exited {
-            eprintln!("   ⚠️  Process {pid_str} didn't exit gracefully, sending SIGKILL...");
-            match Command::new("kill").args(["-9", pid_str]).status().await {
-                Ok(status) if status.success() => {
-                    eprintln!("   💀 Process {pid_str} killed with SIGKILL");
-                }
-                Ok(status) => {
-                    eprintln!("   ⚠️  SIGKILL failed with exit code: {:?}", status.code());
-                }
-                Err(e) => {
-                    eprintln!("   ⚠️  Failed to send SIGKILL to {pid_str}: {e}");
-                }
+            eprintln!("   ⚠️  Process {pid} didn't exit gracefully, force-killing...");
+            match reaper.terminate(pid, false).await {
+                Ok(()) => eprintln!("   💀 Process {pid} force-killed"),
+                Err(e) => eprintln!("   ⚠️  Failed to force-kill {pid}: {e}"),
             }
         }
     }
 
-    // Step 4: Brief delay to ensure port is released by OS
-    tokio::time::sleep(Duration::from_millis(100)).await;
-    
+    // Brief delay to ensure the port is released by the OS
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
     Ok(())
 }
 
-#[cfg(not(unix))]
+/// Kill processes listening on `port` (gracefully, with a force-kill
+/// fallback) via the platform's [`PortReaper`].
+#[cfg(unix)]
+pub async fn cleanup_port(port: u16) -> Result<()> {
+    reap_port(&UnixPortReaper, port).await
+}
+
+#[cfg(windows)]
+pub async fn cleanup_port(port: u16) -> Result<()> {
+    reap_port(&WindowsPortReaper, port).await
+}
+
+#[cfg(not(any(unix, windows)))]
 pub async fn cleanup_port(port: u16) -> Result<()> {
     eprintln!("⚠️  Port cleanup not implemented for this platform");
     eprintln!("   Please manually stop any process on port {port}");
@@ -307,9 +463,11 @@ fn classify_connection_error(error: &anyhow::Error) -> String {
     } else if error_str.contains("init") 
         || error_str.contains("initialization") {
         "init_error".to_string()
-    } else if error_str.contains("protocol") 
+    } else if error_str.contains("protocol")
         || error_str.contains("mcp") {
         "protocol_error".to_string()
+    } else if error_str.contains("not ready") || error_str.contains("readiness") {
+        "not_ready".to_string()
     } else {
         // Fallback: use first word of error or "unknown"
         error_str
@@ -320,17 +478,50 @@ fn classify_connection_error(error: &anyhow::Error) -> String {
     }
 }
 
+/// Post-connect readiness gate for [`connect_with_retry`] - a successful
+/// transport connect only proves the MCP handshake completed, not that the
+/// backend behind it (Chromium launch, profile load) is usable yet, so the
+/// first real tool call can still fail.
+#[derive(Debug, Clone)]
+pub enum ReadinessCheck {
+    /// Transport connect success is sufficient - the original behavior.
+    None,
+    /// Call `name` with `args` and only return once it succeeds, retrying
+    /// through the same loop (and `classify_connection_error`'s
+    /// `not_ready` category) as a transport-level failure would.
+    ToolCall {
+        name: String,
+        args: serde_json::Value,
+    },
+}
+
+/// Readiness check used by every `kodegen-browser` instance this module
+/// spawns - a no-op navigate to `about:blank` proves Chromium actually
+/// launched and can drive a page, not just that the MCP transport is up.
+fn browser_ready_check() -> ReadinessCheck {
+    ReadinessCheck::ToolCall {
+        name: kodegen_config::BROWSER_NAVIGATE.to_string(),
+        args: serde_json::json!({ "url": "about:blank" }),
+    }
+}
+
 /// Connect to HTTP server with retry
+///
+/// Returns the client, the connection, and the `X_KODEGEN_CONNECTION_ID`
+/// generated for this attempt - [`ServerManager`] keys its pool on this id.
+/// `readiness` additionally gates on a real tool call succeeding once the
+/// transport connects - see [`ReadinessCheck`].
 pub async fn connect_with_retry(
     url: &str,
     total_timeout: std::time::Duration,
     retry_interval: std::time::Duration,
     mut server_child: Option<&mut Child>,
-) -> Result<(KodegenClient, KodegenConnection)> {
+    readiness: ReadinessCheck,
+) -> Result<(KodegenClient, KodegenConnection, String)> {
     let start = std::time::Instant::now();
     let mut attempt = 0;
     let mut last_progress_log = start;
-    
+
     // Track last error type to detect state transitions
     let mut last_error_type: Option<String> = None;
 
@@ -389,13 +580,53 @@ pub async fn connect_with_retry(
         }
 
         match create_streamable_client(url, headers.clone()).await {
-            Ok(result) => {
+            Ok((client, connection)) => {
+                if let ReadinessCheck::ToolCall { name, args } = &readiness
+                    && let Err(e) = client.call_tool(name, args.clone()).await
+                {
+                    let error = anyhow::anyhow!(
+                        "Readiness tool call '{name}' failed (server not ready yet): {e}"
+                    );
+                    let error_type = classify_connection_error(&error);
+
+                    if last_error_type.as_ref() != Some(&error_type) {
+                        eprintln!(
+                            "   ⚠️  Connection error ({}): {}",
+                            error_type.replace('_', " "),
+                            error
+                        );
+                        last_error_type = Some(error_type.clone());
+                    }
+
+                    if start.elapsed() >= total_timeout {
+                        return Err(error.context(format!(
+                            "Connection timeout after {} attempts over {:?}. Last error type: {}",
+                            attempt,
+                            start.elapsed(),
+                            error_type
+                        )));
+                    }
+
+                    if last_progress_log.elapsed() >= std::time::Duration::from_secs(10) {
+                        eprintln!(
+                            "   Still waiting for server... ({:?} elapsed, {} attempts, current error: {})",
+                            start.elapsed(),
+                            attempt,
+                            error_type.replace('_', " ")
+                        );
+                        last_progress_log = std::time::Instant::now();
+                    }
+
+                    tokio::time::sleep(retry_interval).await;
+                    continue;
+                }
+
                 eprintln!(
                     "✅ Connected to HTTP server in {:?} (attempt {})",
                     start.elapsed(),
                     attempt
                 );
-                return Ok(result);
+                return Ok((client, connection, connection_id));
             }
             Err(e) => {
                 let error: anyhow::Error = e.into();
@@ -449,15 +680,14 @@ pub async fn connect_with_retry(
     }
 }
 
-/// Connect to local browser HTTP server
-pub async fn connect_to_local_http_server() -> Result<(KodegenConnection, ServerHandle)> {
+/// Compile the `kodegen-browser` binary once, returning its path - shared by
+/// every instance [`connect_to_local_http_server`] or [`ServerManager`]
+/// spawns, so a pool of N servers only pays the build cost once.
+async fn build_browser_binary() -> Result<PathBuf> {
     let workspace_root = find_workspace_root().context("Failed to find workspace root")?;
-    
-    // ═══════════════════════════════════════════════════════════════════════════
-    // PHASE 1: BUILD - Compile the binary explicitly
-    // ═══════════════════════════════════════════════════════════════════════════
+
     eprintln!("🔨 Building {} (this may take 60-90s on first compile, 10-30s incremental)...", BINARY_NAME);
-    
+
     let build_status = Command::new("cargo")
         .current_dir(workspace_root)
         .args([
@@ -469,7 +699,7 @@ pub async fn connect_to_local_http_server() -> Result<(KodegenConnection, Server
         .status()  // Wait for build to complete, returns exit status
         .await
         .context("Failed to execute cargo build")?;
-    
+
     if !build_status.success() {
         anyhow::bail!(
             "cargo build failed with exit code: {:?}\n\
@@ -480,16 +710,11 @@ pub async fn connect_to_local_http_server() -> Result<(KodegenConnection, Server
             BINARY_NAME
         );
     }
-    
+
     eprintln!("✅ Build complete");
-    
-    // ═══════════════════════════════════════════════════════════════════════════
-    // PHASE 2: RUN - Execute the pre-built binary directly
-    // ═══════════════════════════════════════════════════════════════════════════
-    
-    // Construct binary path: workspace_root/target/debug/kodegen-browser
+
     let binary_path = workspace_root.join("target").join("debug").join(BINARY_NAME);
-    
+
     if !binary_path.exists() {
         anyhow::bail!(
             "Binary not found at expected path: {}\n\
@@ -497,28 +722,40 @@ pub async fn connect_to_local_http_server() -> Result<(KodegenConnection, Server
             binary_path.display()
         );
     }
-    
+
+    Ok(binary_path)
+}
+
+/// Spawn `binary_path` listening on `port` and wait for it to accept
+/// connections - the per-instance half of what [`connect_to_local_http_server`]
+/// used to do inline, factored out so [`ServerManager::spawn`] can run it
+/// once per pooled instance on a distinct port.
+async fn spawn_server_on_port(
+    binary_path: &Path,
+    port: u16,
+    readiness: ReadinessCheck,
+) -> Result<(KodegenClient, KodegenConnection, ServerHandle, String)> {
     // Clean up any stale processes on the port
-    cleanup_port(HTTP_PORT).await.ok();
-    
-    eprintln!("🚀 Starting {} HTTP server on port {}...", BINARY_NAME, HTTP_PORT);
-    
+    cleanup_port(port).await.ok();
+
+    eprintln!("🚀 Starting {} HTTP server on port {}...", BINARY_NAME, port);
+
     // Build command to run binary directly (no cargo overhead)
-    let mut cmd = Command::new(&binary_path);
-    cmd.args(["--http", &format!("127.0.0.1:{}", HTTP_PORT)]);
-    
+    let mut cmd = Command::new(binary_path);
+    cmd.args(["--http", &format!("127.0.0.1:{}", port)]);
+
     // Pass through GITHUB_TOKEN if set
     if let Ok(token) = std::env::var("GITHUB_TOKEN") {
         cmd.env("GITHUB_TOKEN", token);
     }
-    
+
     cmd.stdout(std::process::Stdio::piped());
     cmd.stderr(std::process::Stdio::piped());
-    
+
     let mut child = cmd
         .spawn()
         .context("Failed to spawn HTTP server process")?;
-    
+
     // Forward stdout with [SERVER] prefix
     if let Some(stdout) = child.stdout.take() {
         tokio::spawn(async move {
@@ -526,11 +763,11 @@ pub async fn connect_to_local_http_server() -> Result<(KodegenConnection, Server
             let reader = BufReader::new(stdout);
             let mut lines = reader.lines();
             while let Ok(Some(line)) = lines.next_line().await {
-                eprintln!("[SERVER] {}", line);
+                eprintln!("[SERVER:{port}] {}", line);
             }
         });
     }
-    
+
     // Forward stderr with [SERVER] prefix
     if let Some(stderr) = child.stderr.take() {
         tokio::spawn(async move {
@@ -538,18 +775,20 @@ pub async fn connect_to_local_http_server() -> Result<(KodegenConnection, Server
             let reader = BufReader::new(stderr);
             let mut lines = reader.lines();
             while let Ok(Some(line)) = lines.next_line().await {
-                eprintln!("[SERVER] {}", line);
+                eprintln!("[SERVER:{port}] {}", line);
             }
         });
     }
-    
+
     // Server should start in 2-5 seconds (no compilation), so 30s timeout is generous
     eprintln!("⏳ Waiting for server to be ready (should be <5 seconds)...");
-    let (_client, connection) = connect_with_retry(
-        HTTP_URL,
+    let url: &str = &format!("http://127.0.0.1:{port}/mcp");
+    let (client, connection, connection_id) = connect_with_retry(
+        url,
         std::time::Duration::from_secs(30),    // Reduced from 180s
         std::time::Duration::from_millis(200), // Faster retry interval
         Some(&mut child),  // Monitor child during retry
+        readiness,
     )
     .await
     .context(
@@ -557,14 +796,113 @@ pub async fn connect_to_local_http_server() -> Result<(KodegenConnection, Server
          Server started but failed to respond on port.\n\
          Check server logs for startup errors."
     )?;
-    
+
     let server_handle = ServerHandle::new(child);
-    
-    Ok((connection, server_handle))
+
+    Ok((client, connection, server_handle, connection_id))
+}
+
+/// Connect to local browser HTTP server
+pub async fn connect_to_local_http_server() -> Result<(KodegenConnection, ServerHandle)> {
+    let binary_path = build_browser_binary().await?;
+    let (_client, connection, handle, _connection_id) =
+        spawn_server_on_port(&binary_path, HTTP_PORT, browser_ready_check()).await?;
+    Ok((connection, handle))
+}
+
+/// One `kodegen-browser` instance owned by a [`ServerManager`] pool.
+struct PooledServer {
+    client: Arc<KodegenClient>,
+    _connection: KodegenConnection,
+    connection_id: String,
+    handle: ServerHandle,
+    last_used: std::time::Instant,
+}
+
+/// A connection checked out of a [`ServerManager`] pool - the client to
+/// issue calls through, and the `X_KODEGEN_CONNECTION_ID` it's bound to for
+/// any request headers that need to echo it back.
+pub struct PooledConnection {
+    pub client: Arc<KodegenClient>,
+    pub connection_id: String,
+}
+
+/// Pool of N `kodegen-browser` instances on distinct ports, each with its
+/// own [`ServerHandle`]/[`KodegenConnection`] - so examples and tests can
+/// run parallel browser sessions without the single-instance path's port
+/// collisions. [`Self::checkout`] hands out the least-recently-used pooled
+/// connection, same reuse strategy a connection-pooling DB client uses.
+pub struct ServerManager {
+    servers: Mutex<Vec<PooledServer>>,
+}
+
+impl ServerManager {
+    /// Build the binary once, then spawn `count` instances on consecutive
+    /// ports starting at [`HTTP_PORT`], each via [`spawn_server_on_port`].
+    pub async fn spawn(count: usize) -> Result<Self> {
+        anyhow::ensure!(count > 0, "ServerManager::spawn requires count > 0");
+
+        let binary_path = build_browser_binary().await?;
+        let mut servers = Vec::with_capacity(count);
+
+        for i in 0..count {
+            let port = HTTP_PORT + i as u16;
+            let (client, connection, handle, connection_id) =
+                spawn_server_on_port(&binary_path, port, browser_ready_check()).await?;
+            servers.push(PooledServer {
+                client: Arc::new(client),
+                _connection: connection,
+                connection_id,
+                handle,
+                last_used: std::time::Instant::now(),
+            });
+        }
+
+        Ok(Self {
+            servers: Mutex::new(servers),
+        })
+    }
+
+    /// Hand out the least-recently-used pooled connection, marking it as
+    /// just-used so a future checkout rotates to the next-least-recent one.
+    pub async fn checkout(&self) -> Result<PooledConnection> {
+        let mut servers = self.servers.lock().await;
+        let server = servers
+            .iter_mut()
+            .min_by_key(|s| s.last_used)
+            .context("ServerManager pool is empty")?;
+
+        server.last_used = std::time::Instant::now();
+
+        Ok(PooledConnection {
+            client: Arc::clone(&server.client),
+            connection_id: server.connection_id.clone(),
+        })
+    }
+
+    /// Gracefully shut down every pooled instance concurrently (SIGTERM,
+    /// same as [`ServerHandle::shutdown`]), returning once all have exited
+    /// or been force-killed after their individual timeouts.
+    pub async fn shutdown_all(&self) -> Result<()> {
+        let servers = std::mem::take(&mut *self.servers.lock().await);
+
+        let mut join_set = tokio::task::JoinSet::new();
+        for mut server in servers {
+            join_set.spawn(async move { server.handle.shutdown().await });
+        }
+
+        while let Some(result) = join_set.join_next().await {
+            if let Err(e) = result.context("shutdown task panicked")? {
+                eprintln!("⚠️  Error shutting down pooled server: {e}");
+            }
+        }
+
+        Ok(())
+    }
 }
 
 /// JSONL log entry
-#[derive(Debug, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct LogEntry {
     timestamp: String,
     tool: String,
@@ -574,106 +912,1104 @@ pub struct LogEntry {
     result: LogResult,
 }
 
-#[derive(Debug, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(tag = "status", rename_all = "lowercase")]
 pub enum LogResult {
     Success { response: serde_json::Value },
     Error { error: String },
 }
 
-/// Logging wrapper for KodegenClient
-pub struct LoggingClient {
-    inner: KodegenClient,
-    log_file: Arc<Mutex<BufWriter<tokio::fs::File>>>,
-    shutdown_tx: watch::Sender<bool>,
+/// Whether a [`LoggingClient`] records a golden log or only passes calls
+/// through - see [`LoggingClient::with_mode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RecordMode {
+    /// Write every `call_tool` invocation to the JSONL log - the existing
+    /// behavior, and the default.
+    #[default]
+    Record,
+    /// Skip writing log entries entirely. [`ReplayClient::replay`] does not
+    /// use this mode: it calls its freshly spawned server through a plain
+    /// `KodegenClient`.
+    Replay,
 }
 
-impl LoggingClient {
-    pub async fn new(client: KodegenClient, log_path: impl AsRef<Path>) -> Result<Self> {
-        // Create log directory if needed
-        if let Some(parent) = log_path.as_ref().parent() {
-            tokio::fs::create_dir_all(parent)
-                .await
-                .context("Failed to create log directory")?;
-        }
-
-        // Open log file with BufWriter (8KB buffer)
-        let file = tokio::fs::OpenOptions::new()
-            .create(true)
-            .write(true)
-            .truncate(true)
-            .open(log_path)
-            .await
-            .context("Failed to open log file")?;
+/// Result of a coordinated [`LoggingClient::flush`] - how much the
+/// background task actually persisted, so a caller can confirm durability
+/// before a critical operation rather than just hoping the flush landed.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FlushReport {
+    pub entries_flushed: usize,
+    pub bytes_flushed: usize,
+    /// Total entries discarded by [`OverflowPolicy`] so far - cumulative
+    /// since the sink was created, not just since this flush.
+    pub dropped_entries: usize,
+}
 
-        let log_file = Arc::new(Mutex::new(BufWriter::new(file)));
+/// Overflow behavior for [`FileLogSink`]'s bounded entry queue once the
+/// background writer task falls behind - see
+/// [`LoggingClientConfig::overflow_policy`]. Dropped entries are counted in
+/// [`FlushReport::dropped_entries`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    /// Wait for room rather than drop anything - `call_tool` stalls on
+    /// logging under a sustained flood, but no entry is lost.
+    Block,
+    /// Discard the oldest queued message to make room for the new one.
+    DropOldest,
+    /// Discard the new entry, keeping what's already queued.
+    #[default]
+    DropNewest,
+}
 
-        // Create shutdown channel
-        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+/// Message sent to [`FileLogSink`]'s background writer task, the sole
+/// owner of the log file - both a logged entry and a coordinated flush
+/// request go through the same queue so a flush request is answered only
+/// after every entry queued ahead of it has actually been written.
+enum LogMsg {
+    Entry(LogEntry),
+    FlushRequest(oneshot::Sender<FlushReport>),
+}
 
-        // Spawn background flusher task
-        Self::spawn_background_flusher(Arc::clone(&log_file), shutdown_rx);
+/// Bounded FIFO queue feeding [`FileLogSink`]'s background writer task,
+/// applying [`OverflowPolicy`] once full. Hand-rolled rather than
+/// `tokio::sync::mpsc` because `OverflowPolicy::DropOldest` needs to evict
+/// from the front on push, which `mpsc::Sender` has no way to do -
+/// eviction picks whatever message is oldest regardless of variant, so a
+/// rare in-flight `FlushRequest` could in principle be the one dropped.
+struct LogQueue {
+    inner: tokio::sync::Mutex<std::collections::VecDeque<LogMsg>>,
+    capacity: usize,
+    policy: OverflowPolicy,
+    item_ready: tokio::sync::Notify,
+    space_available: tokio::sync::Notify,
+    closed: std::sync::atomic::AtomicBool,
+    dropped: std::sync::atomic::AtomicUsize,
+}
 
-        Ok(Self {
-            inner: client,
-            log_file,
-            shutdown_tx,
-        })
+impl LogQueue {
+    fn new(capacity: usize, policy: OverflowPolicy) -> Self {
+        Self {
+            inner: tokio::sync::Mutex::new(std::collections::VecDeque::with_capacity(capacity)),
+            capacity,
+            policy,
+            item_ready: tokio::sync::Notify::new(),
+            space_available: tokio::sync::Notify::new(),
+            closed: std::sync::atomic::AtomicBool::new(false),
+            dropped: std::sync::atomic::AtomicUsize::new(0),
+        }
     }
 
-    /// Spawn background task that periodically flushes buffered writes
-    fn spawn_background_flusher(
-        log_file: Arc<Mutex<BufWriter<tokio::fs::File>>>,
-        mut shutdown_rx: watch::Receiver<bool>,
-    ) {
-        tokio::spawn(async move {
-            // Flush interval: 100ms (balances responsiveness vs. I/O efficiency)
-            // Note: edit_log.rs and usage_tracker.rs use 5s, but browser operations
-            // are more latency-sensitive and 100ms is still 10x better than per-entry
-            let mut interval = tokio::time::interval(std::time::Duration::from_millis(100));
-            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
-
-            loop {
-                tokio::select! {
-                    // Periodic flush
-                    _ = interval.tick() => {
-                        // Use try_lock to avoid blocking if write is in progress
-                        if let Ok(mut guard) = log_file.try_lock() {
-                            // Ignore flush errors - this is best-effort async I/O
-                            let _ = guard.flush().await;
-                        }
-                        // If lock is held, skip this flush - will catch it next tick
-                    }
-
-                    // Shutdown signal received
-                    _ = shutdown_rx.changed() => {
-                        if *shutdown_rx.borrow() {
-                            // Final flush before shutdown
-                            let mut guard = log_file.lock().await;
-                            let _ = guard.flush().await;
-                            break;
-                        }
-                    }
+    async fn push(&self, msg: LogMsg) {
+        loop {
+            let mut guard = self.inner.lock().await;
+            if guard.len() < self.capacity {
+                guard.push_back(msg);
+                drop(guard);
+                self.item_ready.notify_one();
+                return;
+            }
+            match self.policy {
+                OverflowPolicy::Block => {
+                    drop(guard);
+                    self.space_available.notified().await;
+                    // Loop and retry - another pusher may have taken the
+                    // slot that just freed up.
+                }
+                OverflowPolicy::DropOldest => {
+                    guard.pop_front();
+                    guard.push_back(msg);
+                    drop(guard);
+                    self.item_ready.notify_one();
+                    self.dropped
+                        .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                    return;
+                }
+                OverflowPolicy::DropNewest => {
+                    drop(guard);
+                    self.dropped
+                        .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                    return;
                 }
             }
-        });
+        }
     }
 
-    pub async fn call_tool(
-        &self,
-        name: &str,
-        arguments: serde_json::Value,
-    ) -> Result<CallToolResult, kodegen_mcp_client::ClientError> {
-        let start = tokio::time::Instant::now();
-        let result = self.inner.call_tool(name, arguments.clone()).await;
-        let duration = start.elapsed();
+    /// Dequeue the oldest message, or `None` once [`Self::close`] has been
+    /// called and every queued message has been drained.
+    async fn pop(&self) -> Option<LogMsg> {
+        loop {
+            let mut guard = self.inner.lock().await;
+            if let Some(msg) = guard.pop_front() {
+                drop(guard);
+                self.space_available.notify_one();
+                return Some(msg);
+            }
+            if self.closed.load(std::sync::atomic::Ordering::SeqCst) {
+                return None;
+            }
+            drop(guard);
+            self.item_ready.notified().await;
+        }
+    }
 
-        self.log_call(name, arguments, &result, duration).await;
-        result
+    /// Stop accepting the idea of more room ever being needed and wake any
+    /// `pop`/`push` waiters so they can observe the closed queue and exit
+    /// rather than waiting forever.
+    fn close(&self) {
+        self.closed
+            .store(true, std::sync::atomic::Ordering::SeqCst);
+        self.item_ready.notify_waiters();
+        self.space_available.notify_waiters();
     }
 
-    async fn log_call(
-        &self,
+    fn dropped_count(&self) -> usize {
+        self.dropped.load(std::sync::atomic::Ordering::SeqCst)
+    }
+}
+
+/// Tunables for [`LoggingClient`] construction - a plain struct of
+/// independent, composable toggles plus [`Default`], same shape as
+/// `ResearchOptions`, since these (flush timing, record/replay mode, ...)
+/// stack rather than being mutually exclusive alternate backends.
+#[derive(Debug, Clone)]
+pub struct LoggingClientConfig {
+    pub mode: RecordMode,
+    /// Flush once this long has passed with no new writes.
+    pub idle_flush: std::time::Duration,
+    /// Flush once this long has passed since the oldest unflushed write,
+    /// regardless of idleness - bounds worst-case durability lag under a
+    /// sustained burst of writes that never goes idle.
+    pub max_flush_latency: std::time::Duration,
+    /// Maintain a running SHA-256 over the log and persist it to a
+    /// `<logfile>.sha256` sidecar on every flush, so [`LoggingClient::verify`]
+    /// can later detect truncation/corruption. Off by default so the hot
+    /// path stays free of hashing when integrity checking isn't needed.
+    pub checksum_enabled: bool,
+    /// Rotate the active log file once it crosses a size or age threshold.
+    /// `None` (the default) means the log grows unbounded, same as before
+    /// this field existed.
+    pub rotation: Option<RotationPolicy>,
+    /// How many entries the bounded queue between `log_entry` and the
+    /// background writer task can hold before `overflow_policy` kicks in.
+    pub channel_capacity: usize,
+    /// What happens to a new entry once `channel_capacity` is reached -
+    /// see [`OverflowPolicy`]. Defaults to dropping the new entry rather
+    /// than blocking the hot tool-call path or silently discarding
+    /// already-queued history.
+    pub overflow_policy: OverflowPolicy,
+}
+
+impl Default for LoggingClientConfig {
+    fn default() -> Self {
+        Self {
+            mode: RecordMode::Record,
+            idle_flush: std::time::Duration::from_secs(5),
+            max_flush_latency: std::time::Duration::from_secs(1),
+            checksum_enabled: false,
+            rotation: None,
+            channel_capacity: 1024,
+            overflow_policy: OverflowPolicy::default(),
+        }
+    }
+}
+
+/// Size/age thresholds that trigger [`LoggingClient`] to archive the active
+/// log and start a fresh one - see [`LoggingClientConfig::rotation`].
+#[derive(Debug, Clone)]
+pub struct RotationPolicy {
+    /// Rotate once the active file has this many bytes written to it.
+    pub max_bytes: u64,
+    /// Rotate once this long has passed since the active file was opened,
+    /// regardless of size.
+    pub max_age: std::time::Duration,
+    /// Gzip-compress the archived file to `<name>.gz` instead of leaving it
+    /// as plain JSONL.
+    pub compress: bool,
+    /// Keep only the newest this-many archives for the log, deleting older
+    /// ones after each rotation.
+    pub retain: usize,
+}
+
+/// Running checksum state for a [`LogWriterState`] - kept inside the same
+/// mutex as the writer (rather than a second lock) since every write that
+/// touches one touches the other.
+struct ChecksumState {
+    digest: sha2::Sha256,
+    total_bytes: u64,
+    sidecar_path: PathBuf,
+}
+
+/// Everything the background flusher task owns and serializes access to:
+/// the buffered writer, plus an optional running checksum fed the same
+/// bytes before they reach the `BufWriter`, and rotation bookkeeping for the
+/// active file.
+struct LogWriterState {
+    file: BufWriter<tokio::fs::File>,
+    checksum: Option<ChecksumState>,
+    /// Path of the currently active log file - re-opened at this same path
+    /// after each rotation, so it's kept here rather than threaded through
+    /// every call site that might trigger one.
+    log_path: PathBuf,
+    rotation: Option<RotationPolicy>,
+    bytes_since_rotation: u64,
+    rotation_started_at: std::time::Instant,
+}
+
+/// Outcome of [`LoggingClient::verify`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VerifyReport {
+    /// No `.sha256` sidecar exists - checksumming wasn't enabled when this
+    /// log was recorded.
+    NoSidecar,
+    /// The log's content up to the recorded length still matches.
+    Ok { bytes_verified: u64 },
+    /// The log is shorter than the sidecar recorded - it was truncated.
+    Truncated {
+        recorded_bytes: u64,
+        actual_bytes: u64,
+    },
+    /// The log is at least as long as recorded, but the digest over that
+    /// length doesn't match - the recorded bytes were modified in place.
+    Mismatch { recorded_bytes: u64 },
+}
+
+/// Shared idle/cap flush timing state between [`LoggingClient`] and its
+/// background flusher task. Times are tracked as milliseconds elapsed since
+/// `epoch` rather than storing `Instant`s directly, since `Instant` isn't
+/// atomic-friendly but a `u64` millis offset from a fixed origin is.
+struct FlushTiming {
+    epoch: std::time::Instant,
+    dirty: std::sync::atomic::AtomicBool,
+    last_write_ms: std::sync::atomic::AtomicU64,
+    first_dirty_ms: std::sync::atomic::AtomicU64,
+    idle_flush_ms: u64,
+    max_flush_latency_ms: u64,
+}
+
+impl FlushTiming {
+    fn new(idle_flush: std::time::Duration, max_flush_latency: std::time::Duration) -> Self {
+        Self {
+            epoch: std::time::Instant::now(),
+            dirty: std::sync::atomic::AtomicBool::new(false),
+            last_write_ms: std::sync::atomic::AtomicU64::new(0),
+            first_dirty_ms: std::sync::atomic::AtomicU64::new(0),
+            idle_flush_ms: idle_flush.as_millis() as u64,
+            max_flush_latency_ms: max_flush_latency.as_millis() as u64,
+        }
+    }
+
+    fn now_ms(&self) -> u64 {
+        self.epoch.elapsed().as_millis() as u64
+    }
+
+    /// Record a write, marking the buffer dirty and stamping `first_dirty_ms`
+    /// if it wasn't already.
+    fn mark_written(&self) {
+        let now = self.now_ms();
+        if !self.dirty.swap(true, std::sync::atomic::Ordering::SeqCst) {
+            self.first_dirty_ms
+                .store(now, std::sync::atomic::Ordering::SeqCst);
+        }
+        self.last_write_ms
+            .store(now, std::sync::atomic::Ordering::SeqCst);
+    }
+
+    /// Whether the idle threshold or the max-latency cap has been crossed
+    /// since the last flush cleared `dirty`.
+    fn should_flush(&self) -> bool {
+        if !self.dirty.load(std::sync::atomic::Ordering::SeqCst) {
+            return false;
+        }
+        let now = self.now_ms();
+        let idle_elapsed =
+            now.saturating_sub(self.last_write_ms.load(std::sync::atomic::Ordering::SeqCst));
+        let latency_elapsed =
+            now.saturating_sub(self.first_dirty_ms.load(std::sync::atomic::Ordering::SeqCst));
+        idle_elapsed >= self.idle_flush_ms || latency_elapsed >= self.max_flush_latency_ms
+    }
+
+    fn clear_dirty(&self) {
+        self.dirty.store(false, std::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+/// Destination for a completed [`LogEntry`] - [`FileLogSink`] (the local
+/// JSONL writer) is always active; [`LoggingClient::with_sinks`] lets a
+/// caller fan entries out to additional sinks like [`RemoteBatchSink`] on
+/// top of it. `record` has no `Result` - a sink is responsible for handling
+/// and reporting its own failures, since `log_entry`'s callers (`call_tool`)
+/// can't do anything useful with a logging error.
+#[async_trait::async_trait]
+trait LogSink: Send + Sync {
+    async fn record(&self, entry: &LogEntry);
+}
+
+/// The local JSONL file sink - owns the writer, checksum, rotation, and
+/// background writer machinery that used to live directly on
+/// `LoggingClient`. Always present; see [`LogSink`] for pluggable
+/// additions. The writer task is the sole owner of `LogWriterState` - it
+/// reads every message straight off `queue` rather than racing another
+/// call site for a mutex, so `LogWriterState` needs no lock of its own.
+struct FileLogSink {
+    queue: Arc<LogQueue>,
+    shutdown_tx: watch::Sender<bool>,
+}
+
+impl FileLogSink {
+    async fn new(log_path: impl AsRef<Path>, config: &LoggingClientConfig) -> Result<Self> {
+        // Create log directory if needed
+        if let Some(parent) = log_path.as_ref().parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .context("Failed to create log directory")?;
+        }
+
+        let log_path_owned = log_path.as_ref().to_path_buf();
+        let sidecar_path = PathBuf::from(format!("{}.sha256", log_path_owned.display()));
+
+        // Open log file with BufWriter (8KB buffer)
+        let file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(&log_path_owned)
+            .await
+            .context("Failed to open log file")?;
+
+        let checksum = config.checksum_enabled.then(|| ChecksumState {
+            digest: sha2::Sha256::new(),
+            total_bytes: 0,
+            sidecar_path,
+        });
+
+        let state = LogWriterState {
+            file: BufWriter::new(file),
+            checksum,
+            log_path: log_path_owned,
+            rotation: config.rotation.clone(),
+            bytes_since_rotation: 0,
+            rotation_started_at: std::time::Instant::now(),
+        };
+
+        // Create shutdown channel
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+
+        let queue = Arc::new(LogQueue::new(config.channel_capacity, config.overflow_policy));
+
+        let flush_timing = FlushTiming::new(config.idle_flush, config.max_flush_latency);
+
+        // Spawn background writer task - the sole owner of `state` from
+        // here on.
+        Self::spawn_writer(state, Arc::clone(&queue), shutdown_rx, flush_timing);
+
+        Ok(Self { queue, shutdown_tx })
+    }
+
+    /// Flush `state` and report a [`FlushReport`] for what was just
+    /// persisted, including entries/bytes written since the caller's last
+    /// flush and the queue's cumulative drop count - shared by the
+    /// idle/cap-triggered tick and a coordinated
+    /// [`LogMsg::FlushRequest`] so both paths report consistently.
+    async fn do_flush(
+        state: &mut LogWriterState,
+        entries_since_flush: &mut usize,
+        bytes_since_flush: &mut usize,
+        flush_timing: &FlushTiming,
+        queue: &LogQueue,
+    ) -> FlushReport {
+        let _ = state.file.flush().await;
+        // Snapshot the digest via `.clone()` rather than `.finalize()` so
+        // the real running accumulator keeps accumulating future writes -
+        // `sha2::Sha256`'s internal state is `Clone` specifically to support
+        // peeking a digest mid-stream like this.
+        let sidecar_write = state.checksum.as_ref().map(|checksum| {
+            (
+                format!("{:x}", checksum.digest.clone().finalize()),
+                checksum.total_bytes,
+                checksum.sidecar_path.clone(),
+            )
+        });
+
+        if let Some((digest_hex, total_bytes, sidecar_path)) = sidecar_write {
+            let tmp_path = sidecar_path.with_extension("sha256.tmp");
+            let contents = format!("{digest_hex} {total_bytes}\n");
+            if let Err(e) = tokio::fs::write(&tmp_path, contents).await {
+                eprintln!("⚠️  Failed to write checksum sidecar: {e}");
+            } else if let Err(e) = tokio::fs::rename(&tmp_path, &sidecar_path).await {
+                eprintln!("⚠️  Failed to commit checksum sidecar: {e}");
+            }
+        }
+
+        flush_timing.clear_dirty();
+        FlushReport {
+            entries_flushed: std::mem::take(entries_since_flush),
+            bytes_flushed: std::mem::take(bytes_since_flush),
+            dropped_entries: queue.dropped_count(),
+        }
+    }
+
+    /// Archive the active file (renamed to a timestamped name, optionally
+    /// gzip-compressed per [`RotationPolicy::compress`]), prune archives
+    /// beyond [`RotationPolicy::retain`], and reopen a fresh file at the
+    /// same path. The writer task is `state`'s sole owner, so no entry
+    /// written concurrently can straddle the old and new file.
+    async fn rotate(state: &mut LogWriterState, policy: &RotationPolicy) -> Result<()> {
+        state
+            .file
+            .flush()
+            .await
+            .context("Failed to flush log before rotation")?;
+
+        // Fixed-width and zero-padded, so archive names for the same log
+        // sort chronologically as plain strings - `prune_archives` relies
+        // on this instead of parsing timestamps back out of the name.
+        let timestamp = chrono::Utc::now().format("%Y%m%dT%H%M%S%.3fZ");
+        let rotated_path = PathBuf::from(format!("{}.{timestamp}", state.log_path.display()));
+        tokio::fs::rename(&state.log_path, &rotated_path)
+            .await
+            .context("Failed to rename log file for rotation")?;
+
+        if policy.compress {
+            let contents = tokio::fs::read(&rotated_path)
+                .await
+                .context("Failed to read rotated log for compression")?;
+            let compressed = tokio::task::spawn_blocking(move || {
+                use std::io::Write as _;
+                let mut encoder =
+                    flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+                encoder.write_all(&contents)?;
+                encoder.finish()
+            })
+            .await
+            .context("Gzip compression task panicked")?
+            .context("Failed to gzip rotated log")?;
+
+            let gz_path = PathBuf::from(format!("{}.gz", rotated_path.display()));
+            tokio::fs::write(&gz_path, compressed)
+                .await
+                .context("Failed to write compressed archive")?;
+            tokio::fs::remove_file(&rotated_path)
+                .await
+                .context("Failed to remove uncompressed log after compression")?;
+        }
+
+        let new_file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(&state.log_path)
+            .await
+            .context("Failed to reopen log file after rotation")?;
+        state.file = BufWriter::new(new_file);
+
+        // No entry spans the rotation, so the checksum/byte-count of the
+        // new file starts fresh rather than continuing the old one's.
+        if let Some(checksum) = state.checksum.as_mut() {
+            checksum.digest = sha2::Sha256::new();
+            checksum.total_bytes = 0;
+        }
+        state.bytes_since_rotation = 0;
+        state.rotation_started_at = std::time::Instant::now();
+
+        if let Err(e) = Self::prune_archives(&state.log_path, policy.retain).await {
+            eprintln!("⚠️  Failed to prune old log archives: {e}");
+        }
+
+        Ok(())
+    }
+
+    /// Delete archived rotations of `log_path` beyond the newest `retain` -
+    /// archive names are `"{log_path}.{timestamp}[.gz]"` (see
+    /// [`Self::rotate`]).
+    async fn prune_archives(log_path: &Path, retain: usize) -> Result<()> {
+        let Some(parent) = log_path.parent() else {
+            return Ok(());
+        };
+        let Some(file_name) = log_path.file_name().and_then(|n| n.to_str()) else {
+            return Ok(());
+        };
+        let prefix = format!("{file_name}.");
+
+        let mut archives = Vec::new();
+        let mut entries = tokio::fs::read_dir(parent)
+            .await
+            .context("Failed to list log directory")?;
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .context("Failed to read log directory entry")?
+        {
+            let name = entry.file_name();
+            let Some(name) = name.to_str() else {
+                continue;
+            };
+            if name.starts_with(&prefix) && !name.ends_with(".sha256") && !name.ends_with(".tmp") {
+                archives.push(entry.path());
+            }
+        }
+        archives.sort();
+
+        if archives.len() > retain {
+            for old in &archives[..archives.len() - retain] {
+                tokio::fs::remove_file(old)
+                    .await
+                    .with_context(|| format!("Failed to prune old archive {}", old.display()))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Write `entry` into `state`, updating checksum/rotation bookkeeping,
+    /// and return the number of bytes written - shared by the writer
+    /// task's normal entry handling.
+    async fn write_entry(state: &mut LogWriterState, entry: &LogEntry) -> Result<usize> {
+        let json = serde_json::to_string(entry).context("Failed to serialize log entry")?;
+        let mut line = json.into_bytes();
+        line.push(b'\n');
+        let bytes_written = line.len();
+
+        state
+            .file
+            .write_all(&line)
+            .await
+            .context("Failed to write log entry")?;
+        if let Some(checksum) = state.checksum.as_mut() {
+            // Fed the same bytes the `BufWriter` just received, before any
+            // OS-level buffering/flushing, so the digest always matches
+            // exactly what's been handed to `write_all` so far.
+            checksum.digest.update(&line);
+            checksum.total_bytes += bytes_written as u64;
+        }
+
+        if let Some(policy) = state.rotation.clone() {
+            state.bytes_since_rotation += bytes_written as u64;
+            let crossed_size = state.bytes_since_rotation >= policy.max_bytes;
+            let crossed_age = state.rotation_started_at.elapsed() >= policy.max_age;
+            if crossed_size || crossed_age {
+                if let Err(e) = Self::rotate(state, &policy).await {
+                    eprintln!("⚠️  Log rotation failed: {e}");
+                }
+            }
+        }
+
+        Ok(bytes_written)
+    }
+
+    /// Spawn the background writer task - the sole owner of `state` and of
+    /// `queue`'s consumer end, so no lock is needed around either. Pulls
+    /// entries and [`LogMsg::FlushRequest`]s off `queue` in FIFO order (a
+    /// flush request is answered only after every entry queued ahead of it
+    /// is written), and flushes on an idle/cap schedule (see
+    /// [`FlushTiming`]) in between. On shutdown, closes `queue` so
+    /// `queue.pop()` naturally drains what's left and returns `None` once
+    /// empty, at which point a final flush runs before the task exits.
+    fn spawn_writer(
+        mut state: LogWriterState,
+        queue: Arc<LogQueue>,
+        mut shutdown_rx: watch::Receiver<bool>,
+        flush_timing: FlushTiming,
+    ) {
+        tokio::spawn(async move {
+            let mut entries_since_flush = 0usize;
+            let mut bytes_since_flush = 0usize;
+
+            // Poll granularity for the idle/cap check - finer than either
+            // threshold is typically configured to so both fire close to on
+            // time, while collapsing long idle stretches into no-op ticks
+            // that skip the actual flush (see `FlushTiming::should_flush`).
+            let mut interval = tokio::time::interval(std::time::Duration::from_millis(100));
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+            loop {
+                tokio::select! {
+                    msg = queue.pop() => {
+                        match msg {
+                            Some(LogMsg::Entry(entry)) => {
+                                match Self::write_entry(&mut state, &entry).await {
+                                    Ok(bytes_written) => {
+                                        entries_since_flush += 1;
+                                        bytes_since_flush += bytes_written;
+                                        flush_timing.mark_written();
+                                    }
+                                    Err(e) => eprintln!("⚠️  Failed to write log entry: {e}"),
+                                }
+                            }
+                            Some(LogMsg::FlushRequest(reply)) => {
+                                let report = Self::do_flush(&mut state, &mut entries_since_flush, &mut bytes_since_flush, &flush_timing, &queue).await;
+                                let _ = reply.send(report);
+                            }
+                            None => {
+                                // Queue closed and drained - final flush,
+                                // then exit.
+                                let _ = Self::do_flush(&mut state, &mut entries_since_flush, &mut bytes_since_flush, &flush_timing, &queue).await;
+                                break;
+                            }
+                        }
+                    }
+
+                    // Idle/cap-triggered flush
+                    _ = interval.tick() => {
+                        if flush_timing.should_flush() {
+                            let _ = Self::do_flush(&mut state, &mut entries_since_flush, &mut bytes_since_flush, &flush_timing, &queue).await;
+                        }
+                    }
+
+                    // Shutdown signal received - close the queue so the
+                    // `queue.pop()` arm above drains the rest and exits.
+                    _ = shutdown_rx.changed() => {
+                        if *shutdown_rx.borrow() {
+                            queue.close();
+                        }
+                    }
+                }
+            }
+        });
+    }
+
+    /// Ask the background writer task - the sole owner of the log file -
+    /// to flush now, and report how many entries/bytes it actually
+    /// persisted. Use this before critical operations that need
+    /// guaranteed persistence; the periodic background flush still runs
+    /// independently.
+    async fn flush(&self) -> Result<FlushReport> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.queue.push(LogMsg::FlushRequest(reply_tx)).await;
+        reply_rx
+            .await
+            .context("Background writer task dropped the flush reply")
+    }
+
+    /// Re-read `log_path` and its `<log_path>.sha256` sidecar (written by a
+    /// [`LoggingClientConfig::checksum_enabled`] client) and report whether
+    /// the log is still intact - truncated, modified in place, or fine.
+    async fn verify(log_path: impl AsRef<Path>) -> Result<VerifyReport> {
+        let log_path = log_path.as_ref();
+        let sidecar_path = PathBuf::from(format!("{}.sha256", log_path.display()));
+
+        let sidecar = match tokio::fs::read_to_string(&sidecar_path).await {
+            Ok(s) => s,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(VerifyReport::NoSidecar);
+            }
+            Err(e) => return Err(e).context("Failed to read checksum sidecar"),
+        };
+
+        let (recorded_digest, recorded_bytes) = sidecar
+            .trim()
+            .split_once(' ')
+            .context("Malformed checksum sidecar")?;
+        let recorded_bytes: u64 = recorded_bytes
+            .parse()
+            .context("Malformed byte count in checksum sidecar")?;
+
+        let contents = tokio::fs::read(log_path)
+            .await
+            .context("Failed to read log file")?;
+
+        if (contents.len() as u64) < recorded_bytes {
+            return Ok(VerifyReport::Truncated {
+                recorded_bytes,
+                actual_bytes: contents.len() as u64,
+            });
+        }
+
+        let mut hasher = sha2::Sha256::new();
+        hasher.update(&contents[..recorded_bytes as usize]);
+        let actual_digest = format!("{:x}", hasher.finalize());
+
+        if actual_digest == recorded_digest {
+            Ok(VerifyReport::Ok {
+                bytes_verified: recorded_bytes,
+            })
+        } else {
+            Ok(VerifyReport::Mismatch { recorded_bytes })
+        }
+    }
+}
+
+#[cfg(test)]
+mod verify_tests {
+    use super::*;
+
+    fn unique_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("kodegen-verify-test-{}-{name}.log", std::process::id()))
+    }
+
+    async fn write_sidecar(log_path: &Path, digest_hex: &str, recorded_bytes: u64) {
+        let sidecar_path = PathBuf::from(format!("{}.sha256", log_path.display()));
+        tokio::fs::write(&sidecar_path, format!("{digest_hex} {recorded_bytes}\n"))
+            .await
+            .unwrap();
+    }
+
+    async fn cleanup(log_path: &Path) {
+        tokio::fs::remove_file(log_path).await.ok();
+        tokio::fs::remove_file(PathBuf::from(format!("{}.sha256", log_path.display())))
+            .await
+            .ok();
+    }
+
+    #[tokio::test]
+    async fn verify_returns_no_sidecar_when_none_was_written() {
+        let log_path = unique_path("no_sidecar");
+        tokio::fs::write(&log_path, b"some log content\n")
+            .await
+            .unwrap();
+
+        let report = FileLogSink::verify(&log_path).await.unwrap();
+
+        cleanup(&log_path).await;
+        assert_eq!(report, VerifyReport::NoSidecar);
+    }
+
+    #[tokio::test]
+    async fn verify_returns_ok_when_digest_and_length_match() {
+        let log_path = unique_path("ok");
+        let contents = b"some log content\n";
+        tokio::fs::write(&log_path, contents).await.unwrap();
+
+        let mut hasher = sha2::Sha256::new();
+        hasher.update(contents);
+        let digest_hex = format!("{:x}", hasher.finalize());
+        write_sidecar(&log_path, &digest_hex, contents.len() as u64).await;
+
+        let report = FileLogSink::verify(&log_path).await.unwrap();
+
+        cleanup(&log_path).await;
+        assert_eq!(
+            report,
+            VerifyReport::Ok {
+                bytes_verified: contents.len() as u64
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn verify_returns_truncated_when_log_is_shorter_than_recorded() {
+        let log_path = unique_path("truncated");
+        let contents = b"short\n";
+        tokio::fs::write(&log_path, contents).await.unwrap();
+
+        // Sidecar claims more bytes were recorded than the file now has -
+        // as if the process crashed mid-write after the sidecar was updated.
+        write_sidecar(&log_path, "deadbeef", contents.len() as u64 + 100).await;
+
+        let report = FileLogSink::verify(&log_path).await.unwrap();
+
+        cleanup(&log_path).await;
+        assert_eq!(
+            report,
+            VerifyReport::Truncated {
+                recorded_bytes: contents.len() as u64 + 100,
+                actual_bytes: contents.len() as u64,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn verify_returns_mismatch_when_recorded_bytes_were_modified_in_place() {
+        let log_path = unique_path("mismatch");
+        let contents = b"original content\n";
+        tokio::fs::write(&log_path, contents).await.unwrap();
+
+        // Digest recorded for different bytes than what's now on disk, but
+        // the same length - simulates in-place corruption/tampering rather
+        // than truncation.
+        let mut hasher = sha2::Sha256::new();
+        hasher.update(b"different content!");
+        let wrong_digest_hex = format!("{:x}", hasher.finalize());
+        write_sidecar(&log_path, &wrong_digest_hex, contents.len() as u64).await;
+
+        let report = FileLogSink::verify(&log_path).await.unwrap();
+
+        cleanup(&log_path).await;
+        assert_eq!(
+            report,
+            VerifyReport::Mismatch {
+                recorded_bytes: contents.len() as u64
+            }
+        );
+    }
+}
+
+#[async_trait::async_trait]
+impl LogSink for FileLogSink {
+    async fn record(&self, entry: &LogEntry) {
+        // `OverflowPolicy` governs what happens if the writer task is
+        // behind - see `LogQueue::push`. Serialization/write errors surface
+        // from inside the writer task itself, not here.
+        self.queue.push(LogMsg::Entry(entry.clone())).await;
+    }
+}
+
+impl Drop for FileLogSink {
+    fn drop(&mut self) {
+        // Signal the writer task to close the queue, drain what's left,
+        // and perform a final flush before terminating. We can't await
+        // that here, but the tokio runtime ensures spawned tasks complete
+        // during graceful shutdown.
+        let _ = self.shutdown_tx.send(true);
+    }
+}
+
+/// Tunables for [`RemoteBatchSink`].
+#[derive(Debug, Clone)]
+pub struct RemoteBatchSinkConfig {
+    /// Entries queued locally before a slow consumer starts losing new
+    /// ones - see [`RemoteBatchSink::dropped_count`].
+    pub buffer_capacity: usize,
+    /// Ship a batch once it reaches this many entries, without waiting for
+    /// `flush_interval`.
+    pub max_batch_size: usize,
+    /// Ship whatever's buffered (even a partial batch) once this long has
+    /// passed since the last ship, so low-traffic periods don't leave
+    /// telemetry stuck in the buffer indefinitely.
+    pub flush_interval: std::time::Duration,
+}
+
+impl Default for RemoteBatchSinkConfig {
+    fn default() -> Self {
+        Self {
+            buffer_capacity: 1024,
+            max_batch_size: 100,
+            flush_interval: std::time::Duration::from_secs(5),
+        }
+    }
+}
+
+/// Ships accumulated [`LogEntry`]s to an external observability backend
+/// (e.g. InfluxDB/ClickHouse's HTTP line-insert endpoints) in batches, on
+/// whichever comes first of [`RemoteBatchSinkConfig::max_batch_size`] or
+/// [`RemoteBatchSinkConfig::flush_interval`]. `record` is fire-and-forget -
+/// `try_send` into a bounded channel rather than an `.await`'d `send`, so a
+/// stalled or slow endpoint fills the buffer and starts dropping new
+/// entries (counted in [`Self::dropped_count`]) instead of ever blocking
+/// the `call_tool` path that feeds it.
+pub struct RemoteBatchSink {
+    tx: mpsc::Sender<LogEntry>,
+    dropped: Arc<std::sync::atomic::AtomicUsize>,
+    shutdown_tx: watch::Sender<bool>,
+}
+
+impl RemoteBatchSink {
+    pub fn new(endpoint: impl Into<String>, config: RemoteBatchSinkConfig) -> Self {
+        let (tx, rx) = mpsc::channel(config.buffer_capacity);
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        let dropped = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+
+        Self::spawn_batcher(endpoint.into(), config, rx, shutdown_rx);
+
+        Self {
+            tx,
+            dropped,
+            shutdown_tx,
+        }
+    }
+
+    /// Entries dropped because the buffer was full when [`LogSink::record`]
+    /// tried to enqueue them - a non-zero count means the remote endpoint
+    /// can't keep up with `max_batch_size`/`flush_interval` at the current
+    /// call volume.
+    pub fn dropped_count(&self) -> usize {
+        self.dropped.load(std::sync::atomic::Ordering::SeqCst)
+    }
+
+    fn spawn_batcher(
+        endpoint: String,
+        config: RemoteBatchSinkConfig,
+        mut rx: mpsc::Receiver<LogEntry>,
+        mut shutdown_rx: watch::Receiver<bool>,
+    ) {
+        tokio::spawn(async move {
+            let client = reqwest::Client::new();
+            let mut batch = Vec::with_capacity(config.max_batch_size);
+            let mut interval = tokio::time::interval(config.flush_interval);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+            loop {
+                tokio::select! {
+                    maybe_entry = rx.recv() => {
+                        match maybe_entry {
+                            Some(entry) => {
+                                batch.push(entry);
+                                if batch.len() >= config.max_batch_size {
+                                    Self::ship(&client, &endpoint, std::mem::take(&mut batch)).await;
+                                }
+                            }
+                            // Sink dropped - ship whatever's left and exit.
+                            None => {
+                                if !batch.is_empty() {
+                                    Self::ship(&client, &endpoint, std::mem::take(&mut batch)).await;
+                                }
+                                break;
+                            }
+                        }
+                    }
+
+                    _ = interval.tick() => {
+                        if !batch.is_empty() {
+                            Self::ship(&client, &endpoint, std::mem::take(&mut batch)).await;
+                        }
+                    }
+
+                    _ = shutdown_rx.changed() => {
+                        if *shutdown_rx.borrow() {
+                            if !batch.is_empty() {
+                                Self::ship(&client, &endpoint, std::mem::take(&mut batch)).await;
+                            }
+                            break;
+                        }
+                    }
+                }
+            }
+        });
+    }
+
+    /// Fire-and-forget: log a failed batch and move on rather than
+    /// retrying - a retry queue would just become its own source of
+    /// unbounded memory growth under a sustained outage.
+    async fn ship(client: &reqwest::Client, endpoint: &str, batch: Vec<LogEntry>) {
+        if let Err(e) = client.post(endpoint).json(&batch).send().await {
+            eprintln!(
+                "⚠️  Failed to ship {} log entries to remote sink: {e}",
+                batch.len()
+            );
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl LogSink for RemoteBatchSink {
+    async fn record(&self, entry: &LogEntry) {
+        if self.tx.try_send(entry.clone()).is_err() {
+            self.dropped.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        }
+    }
+}
+
+impl Drop for RemoteBatchSink {
+    fn drop(&mut self) {
+        let _ = self.shutdown_tx.send(true);
+    }
+}
+
+/// Logging wrapper for KodegenClient
+pub struct LoggingClient {
+    inner: KodegenClient,
+    file_sink: Arc<FileLogSink>,
+    /// Additional sinks [`Self::log_entry`] fans entries out to alongside
+    /// `file_sink` - see [`LoggingClient::with_sinks`].
+    sinks: Vec<Arc<dyn LogSink>>,
+    /// Count of `call_tool` invocations currently in flight, mirrored into
+    /// `in_flight_tx` on every change so [`ServerHandle::graceful_drain`]
+    /// can wait for it to reach zero before sending SIGTERM.
+    in_flight: Arc<std::sync::atomic::AtomicUsize>,
+    in_flight_tx: watch::Sender<usize>,
+    mode: RecordMode,
+}
+
+/// RAII decrement for `LoggingClient::in_flight` - guarantees the counter
+/// drops back down even if `call_tool`'s future is cancelled mid-await
+/// (e.g. the caller's own future is dropped under a `tokio::select!`),
+/// which a manual decrement after the `.await` would miss.
+struct InFlightGuard {
+    counter: Arc<std::sync::atomic::AtomicUsize>,
+    tx: watch::Sender<usize>,
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        let remaining = self.counter.fetch_sub(1, std::sync::atomic::Ordering::SeqCst) - 1;
+        let _ = self.tx.send(remaining);
+    }
+}
+
+impl LoggingClient {
+    pub async fn new(client: KodegenClient, log_path: impl AsRef<Path>) -> Result<Self> {
+        Self::with_config(client, log_path, LoggingClientConfig::default()).await
+    }
+
+    /// Like [`LoggingClient::new`], but with an explicit [`RecordMode`] -
+    /// `RecordMode::Replay` skips writing JSONL entries, so `log_path` is
+    /// only opened (and truncated), never written to.
+    pub async fn with_mode(
+        client: KodegenClient,
+        log_path: impl AsRef<Path>,
+        mode: RecordMode,
+    ) -> Result<Self> {
+        Self::with_config(
+            client,
+            log_path,
+            LoggingClientConfig {
+                mode,
+                ..Default::default()
+            },
+        )
+        .await
+    }
+
+    /// Like [`LoggingClient::new`], with every tunable in [`LoggingClientConfig`]
+    /// set explicitly.
+    pub async fn with_config(
+        client: KodegenClient,
+        log_path: impl AsRef<Path>,
+        config: LoggingClientConfig,
+    ) -> Result<Self> {
+        Self::with_sinks(client, log_path, config, Vec::new()).await
+    }
+
+    /// Like [`LoggingClient::with_config`], additionally fanning every
+    /// logged entry out to `extra_sinks` (e.g. a [`RemoteBatchSink`]) on
+    /// top of the always-on local JSONL file.
+    pub async fn with_sinks(
+        client: KodegenClient,
+        log_path: impl AsRef<Path>,
+        config: LoggingClientConfig,
+        extra_sinks: Vec<Arc<dyn LogSink>>,
+    ) -> Result<Self> {
+        let file_sink = Arc::new(FileLogSink::new(log_path, &config).await?);
+        let (in_flight_tx, _in_flight_rx) = watch::channel(0usize);
+
+        Ok(Self {
+            inner: client,
+            file_sink,
+            sinks: extra_sinks,
+            in_flight: Arc::new(std::sync::atomic::AtomicUsize::new(0)),
+            in_flight_tx,
+            mode: config.mode,
+        })
+    }
+
+    /// Subscribe to the count of `call_tool` invocations currently in
+    /// flight - see [`ServerHandle::graceful_drain`].
+    pub fn in_flight_receiver(&self) -> watch::Receiver<usize> {
+        self.in_flight_tx.subscribe()
+    }
+
+    pub async fn call_tool(
+        &self,
+        name: &str,
+        arguments: serde_json::Value,
+    ) -> Result<CallToolResult, kodegen_mcp_client::ClientError> {
+        let count = self.in_flight.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+        let _ = self.in_flight_tx.send(count);
+        let _guard = InFlightGuard {
+            counter: Arc::clone(&self.in_flight),
+            tx: self.in_flight_tx.clone(),
+        };
+
+        let start = tokio::time::Instant::now();
+        let result = self.inner.call_tool(name, arguments.clone()).await;
+        let duration = start.elapsed();
+
+        if self.mode == RecordMode::Record {
+            self.log_call(name, arguments, &result, duration).await;
+        }
+        result
+    }
+
+    async fn log_call(
+        &self,
         name: &str,
         args: serde_json::Value,
         result: &Result<CallToolResult, kodegen_mcp_client::ClientError>,
@@ -693,6 +2029,9 @@ impl LoggingClient {
         self.log_entry(name, args, log_result, duration).await;
     }
 
+    /// Build the entry and fan it out to `file_sink` plus every sink in
+    /// `sinks` - each [`LogSink::record`] handles its own failures, so
+    /// there's nothing for this loop to propagate.
     async fn log_entry(
         &self,
         name: &str,
@@ -708,51 +2047,204 @@ impl LoggingClient {
             result,
         };
 
-        if let Err(e) = self.write_log_entry(&entry).await {
-            eprintln!("⚠️  Failed to write log entry: {e}");
+        self.file_sink.record(&entry).await;
+        for sink in &self.sinks {
+            sink.record(&entry).await;
         }
     }
 
-    async fn write_log_entry(&self, entry: &LogEntry) -> Result<()> {
-        let json = serde_json::to_string(entry).context("Failed to serialize log entry")?;
+    /// Ask the local file sink's background task to flush now, and report
+    /// how many entries/bytes it actually persisted. Use this before
+    /// critical operations that need guaranteed persistence; the periodic
+    /// background flush still runs independently. Remote sinks have no
+    /// equivalent - they're fire-and-forget by design (see
+    /// [`RemoteBatchSink`]).
+    pub async fn flush(&self) -> Result<FlushReport> {
+        self.file_sink.flush().await
+    }
 
-        let mut guard = self.log_file.lock().await;
-        guard
-            .write_all(json.as_bytes())
-            .await
-            .context("Failed to write log entry")?;
-        guard
-            .write_all(b"\n")
+    /// Re-read `log_path` and its `<log_path>.sha256` sidecar (written by a
+    /// [`LoggingClientConfig::checksum_enabled`] client) and report whether
+    /// the log is still intact - truncated, modified in place, or fine.
+    pub async fn verify(log_path: impl AsRef<Path>) -> Result<VerifyReport> {
+        FileLogSink::verify(log_path).await
+    }
+}
+
+/// A recorded `call_tool` invocation whose replayed response no longer
+/// matches what [`LoggingClient`] captured at recording time.
+#[derive(Debug)]
+pub struct ReplayMismatch {
+    pub tool: String,
+    pub args: serde_json::Value,
+    pub recorded: serde_json::Value,
+    pub replayed: serde_json::Value,
+}
+
+/// Reads a JSONL log previously recorded by [`LoggingClient`] and replays
+/// every `tool`+`args` call against a freshly spawned server, diffing the
+/// new response against the one recorded at capture time - snapshot
+/// regression testing for browser tools without hand-written expected
+/// outputs. Entries recorded as errors are skipped: an error's `error`
+/// string (e.g. a chromiumoxide error's embedded request id) isn't stable
+/// across runs, so there's nothing meaningful to diff.
+pub struct ReplayClient {
+    entries: Vec<LogEntry>,
+}
+
+impl ReplayClient {
+    /// Load a recorded JSONL log written by [`LoggingClient`].
+    pub async fn load(log_path: impl AsRef<Path>) -> Result<Self> {
+        let contents = tokio::fs::read_to_string(log_path)
             .await
-            .context("Failed to write newline")?;
-        
-        // ✅ NO FLUSH - rely on BufWriter's 8KB buffer + background flusher
-        // Flush happens automatically when:
-        // 1. Buffer fills (8KB)
-        // 2. Background task flushes (every 100ms)
-        // 3. Drop/shutdown triggers final flush
+            .context("Failed to read recorded log")?;
 
-        Ok(())
+        let entries = contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                serde_json::from_str::<LogEntry>(line).context("Failed to parse recorded log entry")
+            })
+            .collect::<Result<Vec<LogEntry>>>()?;
+
+        Ok(Self { entries })
     }
 
-    /// Manually flush buffered log entries to disk
-    /// 
-    /// This is optional - the background flusher handles periodic flushes.
-    /// Use this before critical operations if you need guaranteed persistence.
-    pub async fn flush(&self) -> Result<()> {
-        let mut guard = self.log_file.lock().await;
-        guard.flush().await.context("Failed to flush log")?;
-        Ok(())
+    /// Re-issue every recorded `tool`+`args` call against a freshly spawned
+    /// server and return every entry whose replayed response doesn't match
+    /// what was recorded.
+    pub async fn replay(&self) -> Result<Vec<ReplayMismatch>> {
+        let binary_path = build_browser_binary().await?;
+        let (client, _connection, mut server, _connection_id) =
+            spawn_server_on_port(&binary_path, HTTP_PORT, browser_ready_check()).await?;
+
+        let mut mismatches = Vec::new();
+
+        for entry in &self.entries {
+            if entry.recorded_response().is_none() {
+                continue;
+            }
+
+            let replayed = match client.call_tool(&entry.tool, entry.args.clone()).await {
+                Ok(result) => serde_json::to_value(&result)
+                    .unwrap_or_else(|_| serde_json::json!({"serialization_error": true})),
+                Err(e) => serde_json::json!({"error": e.to_string()}),
+            };
+
+            if let Some(mismatch) = entry.diff_against(replayed) {
+                mismatches.push(mismatch);
+            }
+        }
+
+        server.shutdown().await?;
+        Ok(mismatches)
     }
 }
 
-impl Drop for LoggingClient {
-    fn drop(&mut self) {
-        // Signal background task to shutdown and perform final flush
-        let _ = self.shutdown_tx.send(true);
-        
-        // Note: We can't await in Drop, but the background task will flush
-        // before terminating. The tokio runtime ensures spawned tasks complete
-        // during graceful shutdown.
+impl LogEntry {
+    /// The recorded response, or `None` for an entry recorded as an error -
+    /// those have nothing stable to diff (see [`ReplayClient`]'s doc comment).
+    fn recorded_response(&self) -> Option<&serde_json::Value> {
+        match &self.result {
+            LogResult::Success { response } => Some(response),
+            LogResult::Error { .. } => None,
+        }
+    }
+
+    /// Compare `replayed` against this entry's recorded response, producing
+    /// a [`ReplayMismatch`] if they differ. `None` for a recorded error
+    /// entry or an exact match.
+    fn diff_against(&self, replayed: serde_json::Value) -> Option<ReplayMismatch> {
+        let recorded = self.recorded_response()?;
+        if &replayed == recorded {
+            return None;
+        }
+        Some(ReplayMismatch {
+            tool: self.tool.clone(),
+            args: self.args.clone(),
+            recorded: recorded.clone(),
+            replayed,
+        })
+    }
+}
+
+#[cfg(test)]
+mod replay_tests {
+    use super::*;
+
+    fn success_entry(tool: &str, response: serde_json::Value) -> LogEntry {
+        LogEntry {
+            timestamp: "2026-01-01T00:00:00Z".to_string(),
+            tool: tool.to_string(),
+            args: serde_json::json!({"url": "about:blank"}),
+            duration_ms: 1,
+            result: LogResult::Success { response },
+        }
+    }
+
+    fn error_entry(tool: &str) -> LogEntry {
+        LogEntry {
+            timestamp: "2026-01-01T00:00:00Z".to_string(),
+            tool: tool.to_string(),
+            args: serde_json::json!({}),
+            duration_ms: 1,
+            result: LogResult::Error {
+                error: "boom".to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn diff_against_matching_response_is_none() {
+        let entry = success_entry("browser_navigate", serde_json::json!({"ok": true}));
+        assert!(entry
+            .diff_against(serde_json::json!({"ok": true}))
+            .is_none());
+    }
+
+    #[test]
+    fn diff_against_mismatched_response_reports_both_sides() {
+        let entry = success_entry("browser_navigate", serde_json::json!({"ok": true}));
+        let mismatch = entry
+            .diff_against(serde_json::json!({"ok": false}))
+            .expect("differing responses should produce a mismatch");
+        assert_eq!(mismatch.tool, "browser_navigate");
+        assert_eq!(mismatch.recorded, serde_json::json!({"ok": true}));
+        assert_eq!(mismatch.replayed, serde_json::json!({"ok": false}));
+    }
+
+    #[test]
+    fn diff_against_error_entry_is_always_none() {
+        let entry = error_entry("browser_navigate");
+        assert!(
+            entry
+                .diff_against(serde_json::json!({"anything": "at all"}))
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn load_parses_entries_and_skips_blank_lines() {
+        let path = std::env::temp_dir().join(format!(
+            "kodegen-replay-test-{}-load.jsonl",
+            std::process::id()
+        ));
+
+        let success = success_entry("browser_navigate", serde_json::json!({"ok": true}));
+        let error = error_entry("browser_click");
+        let contents = format!(
+            "{}\n\n{}\n",
+            serde_json::to_string(&success).unwrap(),
+            serde_json::to_string(&error).unwrap(),
+        );
+        tokio::fs::write(&path, &contents).await.unwrap();
+
+        let replay_client = ReplayClient::load(&path).await.unwrap();
+        tokio::fs::remove_file(&path).await.ok();
+
+        assert_eq!(replay_client.entries.len(), 2);
+        assert_eq!(replay_client.entries[0].tool, "browser_navigate");
+        assert_eq!(replay_client.entries[1].tool, "browser_click");
+        assert!(replay_client.entries[1].recorded_response().is_none());
     }
 }
@@ -5,7 +5,7 @@ use kodegen_candle_agent::prelude::*;
 use tokio_stream::StreamExt; // For stream.next().await
 
 use base64::Engine; // For base64 decode in async context
-use tokio::sync::{Mutex, mpsc};
+use tokio::sync::{Mutex, broadcast, mpsc};
 use tokio::time::{Duration, timeout};
 use tracing::{debug, error, info, warn};
 
@@ -59,6 +59,43 @@ struct AgentInner {
     max_tokens: u64,
     vision_timeout_secs: u64,
     llm_timeout_secs: u64,
+
+    /// Broadcasts structured progress events as a step runs, independent of
+    /// the command/response rendezvous. Any number of observers (UIs,
+    /// logging sinks, test harnesses) can `subscribe()` without serializing
+    /// against each other or against `run_step()`/`step_once()` callers.
+    event_tx: broadcast::Sender<AgentEvent>,
+
+    /// Monotonic step counter used to label `AgentEvent::StepStarted` /
+    /// `StepFinished`, incremented on every `process_step()` call regardless
+    /// of whether it was triggered by `RunStep` or `StepOnce`.
+    step_counter: std::sync::atomic::AtomicUsize,
+}
+
+/// Structured progress event emitted by the agent processor as a step runs
+///
+/// See `Agent::subscribe()`. Delivered over a `broadcast` channel, separate
+/// from the command/response protocol, so observing progress never
+/// serializes against `run_step()`/`step_once()` callers.
+#[derive(Debug, Clone)]
+pub enum AgentEvent {
+    /// A new step has begun
+    StepStarted { step: usize },
+
+    /// An MCP tool call is about to be made for one of the step's actions
+    ActionInvoked { name: String, args: serde_json::Value },
+
+    /// An MCP tool call for one of the step's actions has completed
+    ActionResult { action: String, success: bool },
+
+    /// The LLM call for this step finished; `tokens` is the generated token count
+    ModelCall { tokens: u64 },
+
+    /// The step completed successfully (a failed step emits `Error` instead)
+    StepFinished { step: usize },
+
+    /// The step failed; carries the error message
+    Error(String),
 }
 
 /// Agent handle for controlling async actor (NOT Clone)
@@ -80,6 +117,9 @@ pub struct Agent {
 enum AgentCommand {
     RunStep,
     Stop,
+    Pause,
+    Resume,
+    StepOnce,
 }
 
 /// Agent response enum for internal message passing
@@ -88,6 +128,8 @@ enum AgentResponse {
     StepComplete(AgentOutput),
     Error(String),
     Stopped,
+    Paused,
+    Resumed,
 }
 
 ///  agent implementation
@@ -106,6 +148,10 @@ impl Agent {
         let (cmd_tx, cmd_rx) = mpsc::channel(32);
         let (resp_tx, resp_rx) = mpsc::channel(32);
 
+        // Progress event broadcast - independent of the command/response
+        // channels above, so it's fine if no one subscribes yet.
+        let (event_tx, _event_rx) = broadcast::channel(256);
+
         // Create shared inner state (Arc-wrapped)
         let inner = Arc::new(AgentInner {
             task: task.to_string(),
@@ -119,6 +165,8 @@ impl Agent {
             max_tokens: config.max_tokens,
             vision_timeout_secs: config.vision_timeout_secs,
             llm_timeout_secs: config.llm_timeout_secs,
+            event_tx,
+            step_counter: std::sync::atomic::AtomicUsize::new(0),
         });
 
         // Spawn processor with Arc-cloned inner and store handle
@@ -259,6 +307,85 @@ impl Agent {
         }
     }
 
+    /// Pause the agent processor between steps
+    ///
+    /// The processor keeps running but refuses `RunStep` until `resume()`
+    /// or `step_once()` is called, giving interactive debuggers a chance to
+    /// inspect state between actions without tearing down the actor.
+    pub async fn pause(&self) -> AgentResult<()> {
+        self.command_channel
+            .send(AgentCommand::Pause)
+            .await
+            .map_err(|_| {
+                AgentError::ChannelClosed("Cannot pause agent: command channel closed".into())
+            })?;
+
+        let mut receiver = self.response_channel.lock().await;
+        match receiver.recv().await {
+            Some(AgentResponse::Paused) => Ok(()),
+            Some(other) => Err(AgentError::UnexpectedError(format!(
+                "Expected Paused response, got: {:?}",
+                other
+            ))),
+            None => Err(AgentError::ChannelClosed("Response channel closed".into())),
+        }
+    }
+
+    /// Resume a paused agent processor
+    pub async fn resume(&self) -> AgentResult<()> {
+        self.command_channel
+            .send(AgentCommand::Resume)
+            .await
+            .map_err(|_| {
+                AgentError::ChannelClosed("Cannot resume agent: command channel closed".into())
+            })?;
+
+        let mut receiver = self.response_channel.lock().await;
+        match receiver.recv().await {
+            Some(AgentResponse::Resumed) => Ok(()),
+            Some(other) => Err(AgentError::UnexpectedError(format!(
+                "Expected Resumed response, got: {:?}",
+                other
+            ))),
+            None => Err(AgentError::ChannelClosed("Response channel closed".into())),
+        }
+    }
+
+    /// Run exactly one step while paused, then return to the paused state
+    ///
+    /// Unlike `run_step()`/`RunStep`, this is allowed regardless of pause
+    /// state and leaves the processor paused afterward - useful for
+    /// single-stepping through a task under human review.
+    pub async fn step_once(&self) -> AgentResult<AgentOutput> {
+        self.command_channel
+            .send(AgentCommand::StepOnce)
+            .await
+            .map_err(|_| AgentError::ChannelClosed("Command channel closed".into()))?;
+
+        let mut receiver = self.response_channel.lock().await;
+        match receiver.recv().await {
+            Some(AgentResponse::StepComplete(output)) => Ok(output),
+            Some(AgentResponse::Error(msg)) => Err(AgentError::StepFailed(msg)),
+            Some(AgentResponse::Stopped) => Err(AgentError::Stopped),
+            Some(other) => Err(AgentError::UnexpectedError(format!(
+                "Unexpected response to step_once: {:?}",
+                other
+            ))),
+            None => Err(AgentError::ChannelClosed("Response channel closed".into())),
+        }
+    }
+
+    /// Subscribe to structured progress events emitted as steps run
+    ///
+    /// Each call returns an independent `broadcast::Receiver`, so any number
+    /// of observers (UIs, logging sinks, test harnesses) can watch the same
+    /// run without serializing against each other or against
+    /// `run_step()`/`step_once()` callers. Events sent before a receiver
+    /// subscribes are not replayed.
+    pub fn subscribe(&self) -> broadcast::Receiver<AgentEvent> {
+        self.inner.event_tx.subscribe()
+    }
+
     /// Check if agent processor is still running
     ///
     /// Returns `true` if the processor task is active and accepting commands.
@@ -281,9 +408,21 @@ impl Agent {
         resp_tx: mpsc::Sender<AgentResponse>,
     ) -> tokio::task::JoinHandle<()> {
         tokio::spawn(async move {
+            // Parked while true: RunStep is refused until Resume or StepOnce.
+            let mut paused = false;
+
             while let Some(cmd) = cmd_rx.recv().await {
                 match cmd {
                     AgentCommand::RunStep => {
+                        if paused {
+                            let msg = "Agent is paused; call resume() or step_once()".to_string();
+                            if let Err(e) = resp_tx.send(AgentResponse::Error(msg)).await {
+                                error!("Failed to send response: {}", e);
+                                break;
+                            }
+                            continue;
+                        }
+
                         let result = inner.process_step().await;
 
                         // Map result to response
@@ -298,6 +437,36 @@ impl Agent {
                             break;
                         }
                     }
+                    AgentCommand::StepOnce => {
+                        let result = inner.process_step().await;
+
+                        let response = match result {
+                            Ok(output) => AgentResponse::StepComplete(output),
+                            Err(e) => AgentResponse::Error(e.to_string()),
+                        };
+
+                        // Stay (or become) paused after a single step.
+                        paused = true;
+
+                        if let Err(e) = resp_tx.send(response).await {
+                            error!("Failed to send response: {}", e);
+                            break;
+                        }
+                    }
+                    AgentCommand::Pause => {
+                        paused = true;
+                        if let Err(e) = resp_tx.send(AgentResponse::Paused).await {
+                            error!("Failed to send paused response: {}", e);
+                            break;
+                        }
+                    }
+                    AgentCommand::Resume => {
+                        paused = false;
+                        if let Err(e) = resp_tx.send(AgentResponse::Resumed).await {
+                            error!("Failed to send resumed response: {}", e);
+                            break;
+                        }
+                    }
                     AgentCommand::Stop => {
                         if let Err(e) = resp_tx.send(AgentResponse::Stopped).await {
                             error!("Failed to send stopped response: {}", e);
@@ -313,8 +482,31 @@ impl Agent {
 
 /// Implementation of processing methods on AgentInner
 impl AgentInner {
-    /// Process a single agent step internally
+    /// Broadcast a progress event, ignoring the case where no one is
+    /// subscribed (`send` errors when there are zero receivers).
+    fn emit_event(&self, event: AgentEvent) {
+        let _ = self.event_tx.send(event);
+    }
+
+    /// Process a single agent step, emitting `AgentEvent`s as it progresses
     async fn process_step(&self) -> AgentResult<AgentOutput> {
+        let step = self
+            .step_counter
+            .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        self.emit_event(AgentEvent::StepStarted { step });
+
+        let result = self.process_step_inner().await;
+
+        match &result {
+            Ok(_) => self.emit_event(AgentEvent::StepFinished { step }),
+            Err(e) => self.emit_event(AgentEvent::Error(e.to_string())),
+        }
+
+        result
+    }
+
+    /// Process a single agent step internally
+    async fn process_step_inner(&self) -> AgentResult<AgentOutput> {
         // Check if stop requested
         let agent_state = self.agent_state.lock().await;
         if agent_state.is_stop_requested() {
@@ -322,6 +514,16 @@ impl AgentInner {
         }
         drop(agent_state);
 
+        // Drain diagnostics buffered since the previous step's actions ran.
+        // Draining here (start of step) rather than right after the
+        // previous step's `execute_actions` catches CDP console/exception
+        // events that hadn't arrived yet when that step returned, so they
+        // still map cleanly to the step that produced them.
+        let diagnostics = crate::manager::BrowserManager::global()
+            .diagnostics()
+            .drain()
+            .await;
+
         // Get current browser state (with screenshot)
         let mut browser_state = self.get_browser_state().await?;
 
@@ -340,6 +542,7 @@ impl AgentInner {
         Ok(AgentOutput {
             current_state: llm_response.current_state,
             action: llm_response.action,
+            diagnostics,
         })
     }
 
@@ -654,6 +857,7 @@ You must respond with valid JSON matching the AgentLLMResponse schema with an 'a
                     } => {
                         if let (Some(tokens), Some(elapsed)) = (token_count, elapsed_secs) {
                             debug!("LLM generated {} tokens in {:.2}s", tokens, elapsed);
+                            self.emit_event(AgentEvent::ModelCall { tokens });
                         }
                         return Ok(response);
                     }
@@ -878,6 +1082,10 @@ You must respond with valid JSON matching the AgentLLMResponse schema with an 'a
                 "Agent calling MCP tool: {} with args: {:?}",
                 tool_name, tool_args
             );
+            self.emit_event(AgentEvent::ActionInvoked {
+                name: tool_name.to_string(),
+                args: tool_args.clone(),
+            });
             match self.mcp_client.call_tool(tool_name, tool_args).await {
                 Ok(result) => {
                     info!(
@@ -894,6 +1102,10 @@ You must respond with valid JSON matching the AgentLLMResponse schema with an 'a
                         .map(|t| t.text.clone())
                         .unwrap_or_else(|| format!("Tool {} completed", tool_name));
 
+                    self.emit_event(AgentEvent::ActionResult {
+                        action: action.action.clone(),
+                        success: true,
+                    });
                     results.push(ActionResult {
                         action: action.action,
                         success: true,
@@ -908,6 +1120,10 @@ You must respond with valid JSON matching the AgentLLMResponse schema with an 'a
                     );
                     warn!("{}", error_msg);
                     errors.push(error_msg.clone());
+                    self.emit_event(AgentEvent::ActionResult {
+                        action: action.action.clone(),
+                        success: false,
+                    });
                     results.push(ActionResult {
                         action: action.action,
                         success: false,
@@ -0,0 +1,99 @@
+//! Content-hash cache for idempotent, read-only MCP tool calls issued by
+//! `execute_one_action`.
+//!
+//! The LLM occasionally re-issues the same `extract_page_content` within a
+//! step or two (it hasn't yet "seen" the result of its last call, or it's
+//! double-checking). Re-running that against the page is wasted work for a
+//! call that can't have side effects worth re-observing within a short
+//! window - this short-circuits it with the previous `ActionResult` instead
+//! of paying for another `mcp_client.call_tool`. Navigation such as
+//! `go_to_url` is mutating and is never served from the cache.
+
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::time::{Duration, Instant};
+
+use tokio::sync::Mutex;
+
+use crate::agent::ActionResult;
+
+/// Tool calls safe to serve from cache: read-only, so a repeated call
+/// within the window can't have missed something material. Mutating tools
+/// (navigation, clicks, typed input, ...) always bypass the cache - see
+/// [`Self::invalidate_all`], which also clears any entries still within
+/// their TTL once one of those runs, since the DOM it described may no
+/// longer match.
+const CACHEABLE_TOOLS: &[&str] = &["browser_extract_text"];
+
+/// How long a cached result stays valid. Long enough to absorb an LLM loop
+/// re-issuing the same action within a step or two; short enough that a
+/// page that's genuinely still loading doesn't serve stale content for the
+/// rest of the session.
+const CACHE_TTL: Duration = Duration::from_secs(10);
+
+fn cache_key(tool_name: &str, tool_args: &serde_json::Value) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    tool_name.hash(&mut hasher);
+    tool_args.to_string().hash(&mut hasher);
+    hasher.finish()
+}
+
+pub(super) struct ActionCache {
+    entries: Mutex<HashMap<u64, (Instant, ActionResult)>>,
+}
+
+impl ActionCache {
+    pub(super) fn new() -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn is_cacheable(tool_name: &str) -> bool {
+        CACHEABLE_TOOLS.contains(&tool_name)
+    }
+
+    /// Returns a cached `ActionResult` for `(tool_name, tool_args)` if one
+    /// was stored within the last [`CACHE_TTL`], `None` otherwise (cache
+    /// miss, expired entry, or a tool that isn't cacheable at all).
+    pub(super) async fn get(
+        &self,
+        tool_name: &str,
+        tool_args: &serde_json::Value,
+    ) -> Option<ActionResult> {
+        if !Self::is_cacheable(tool_name) {
+            return None;
+        }
+        let key = cache_key(tool_name, tool_args);
+        let entries = self.entries.lock().await;
+        entries.get(&key).and_then(|(inserted, result)| {
+            (inserted.elapsed() < CACHE_TTL).then(|| result.clone())
+        })
+    }
+
+    /// Stores `result` for `(tool_name, tool_args)` if the tool is
+    /// cacheable; a no-op otherwise.
+    pub(super) async fn put(
+        &self,
+        tool_name: &str,
+        tool_args: &serde_json::Value,
+        result: ActionResult,
+    ) {
+        if !Self::is_cacheable(tool_name) {
+            return;
+        }
+        let key = cache_key(tool_name, tool_args);
+        self.entries.lock().await.insert(key, (Instant::now(), result));
+    }
+
+    /// Drop every cached result. Called automatically once a mutating
+    /// action (navigation, click, input, ...) succeeds - see
+    /// `AgentInner::execute_one_action` - and exposed to callers via
+    /// `Agent::invalidate_action_cache` for when the page is known to have
+    /// changed out-of-band (e.g. a redirect or async content load the agent
+    /// didn't trigger directly).
+    pub(super) async fn invalidate_all(&self) {
+        self.entries.lock().await.clear();
+    }
+}
@@ -1,181 +1,150 @@
+use tokio::time::Duration;
 use tracing::{debug, info, warn};
-use crate::agent::{ActionModel, ActionResult, AgentError, AgentResult};
+use crate::agent::{ActionModel, ActionResult, AgentResult};
+use super::approval::{ActionClass, ApprovalDecision, classify_action};
 use super::processor::AgentInner;
+use super::progress::AgentProgress;
 
 /// Action execution implementation
 impl AgentInner {
-    /// Execute actions by calling existing MCP tools (HOT PATH!)
+    /// Execute a single action by calling its mapped MCP tool (HOT PATH!)
     ///
-    /// Maps agent protocol action names to MCP tool names and parameters.
-    /// Each action is translated to an MCP call via self.mcp_client.call_tool().
+    /// Looks `action.action` up in `self.action_registry` for its target MCP
+    /// tool and argument transform, then calls it via
+    /// `self.mcp_client.call_tool()`. Called once per action as
+    /// [`super::llm_integration::AgentInner::generate_and_execute_actions_streaming`]
+    /// dispatches each action the moment it arrives off the LLM's tool-call
+    /// stream, rather than waiting for a whole step's actions to be planned
+    /// before executing any of them.
     ///
-    /// Action mapping (agent protocol → MCP tool):
-    /// - go_to_url → browser_navigate
-    /// - click_element → browser_click  
-    /// - input_text → browser_type_text
-    /// - scroll → browser_scroll
-    /// - extract_page_content → browser_extract_text
-    /// - done → (special case, no MCP call)
-    ///
-    pub(super) async fn execute_actions(
+    /// `done` is the one registered action with no MCP tool - handled here
+    /// specially as task completion instead of going through the mapping
+    /// below. An action absent from the registry entirely is reported as an
+    /// unknown action.
+    pub(super) async fn execute_one_action(
         &self,
-        actions: Vec<ActionModel>,
-    ) -> AgentResult<(Vec<ActionResult>, Vec<String>)> {
-        let mut results = Vec::new();
-        let mut errors = Vec::new();
-
-        for action in actions {
-            // Map agent action names to MCP tool names (HOT PATH!)
-            let (tool_name, tool_args) =
-                match action.action.as_str() {
-                    "go_to_url" => {
-                        let url = action.parameters.get("url").ok_or_else(|| {
-                            AgentError::StepFailed("Missing 'url' parameter".into())
-                        })?;
-                        (
-                            "browser_navigate",
-                            serde_json::json!({
-                                "url": url,
-                                "timeout_ms": 30000
-                            }),
-                        )
-                    }
-                    "click_element" => {
-                        // Support both direct selector and index-based selector
-                        // Converts index to [data-mcp-index="N"] selector
-                        let selector = if let Some(selector) = action.parameters.get("selector") {
-                            selector.clone()
-                        } else if let Some(index) = action.parameters.get("index") {
-                            // ✅ FIXED: Validate index is numeric before using in selector
-                            let index_num = index.parse::<u64>().map_err(|_| {
-                                AgentError::StepFailed(format!(
-                                    "Invalid index parameter: must be numeric, got '{}'",
-                                    index
-                                ))
-                            })?;
-                            format!("[data-mcp-index=\"{}\"]", index_num)
-                        } else {
-                            return Err(AgentError::StepFailed(
-                                "Missing 'selector' or 'index' parameter".into(),
-                            ));
-                        };
-                        (
-                            "browser_click",
-                            serde_json::json!({
-                                "selector": selector,
-                                "timeout_ms": 5000
-                            }),
-                        )
-                    }
-                    "input_text" => {
-                        // Support both direct selector and index-based selector
-                        let selector = if let Some(selector) = action.parameters.get("selector") {
-                            selector.clone()
-                        } else if let Some(index) = action.parameters.get("index") {
-                            // ✅ FIXED: Validate index is numeric before using in selector
-                            let index_num = index.parse::<u64>().map_err(|_| {
-                                AgentError::StepFailed(format!(
-                                    "Invalid index parameter: must be numeric, got '{}'",
-                                    index
-                                ))
-                            })?;
-                            format!("[data-mcp-index=\"{}\"]", index_num)
-                        } else {
-                            return Err(AgentError::StepFailed(
-                                "Missing 'selector' or 'index' parameter".into(),
-                            ));
-                        };
-                        let text = action.parameters.get("text").ok_or_else(|| {
-                            AgentError::StepFailed("Missing 'text' parameter".into())
-                        })?;
-                        (
-                            "browser_type_text",
-                            serde_json::json!({
-                                "selector": selector,
-                                "text": text,
-                                "clear": true
-                            }),
-                        )
-                    }
-                    "scroll" => {
-                        let direction = action
-                            .parameters
-                            .get("direction")
-                            .map(|s| s.as_str())
-                            .unwrap_or("down");
+        step: usize,
+        action: ActionModel,
+    ) -> AgentResult<ActionResult> {
+        let reporter = self.reporter().await;
 
-                        // Parse scroll amount with default fallback
-                        let amount = action
-                            .parameters
-                            .get("amount")
-                            .and_then(|a| a.parse::<i32>().ok())
-                            .unwrap_or(500);
+        self.emit_progress(AgentProgress::ActionStarted {
+            name: action.action.clone(),
+        });
 
-                        // Validate and clamp to reasonable range (1-10,000 pixels)
-                        // Rationale: Typical viewport is ~1000-2000px tall, 10k = ~5 screen heights
-                        let original_amount = amount;
-                        let amount = amount.clamp(1, 10_000);
+        let Some(spec) = self.action_registry.get(&action.action) else {
+            let error_msg = format!("Unknown action: {}", action.action);
+            warn!("Agent attempted unknown action: {}", action.action);
+            let result = ActionResult {
+                action: action.action.clone(),
+                success: false,
+                extracted_content: None,
+                error: Some(error_msg),
+                attempts: 1,
+            };
+            reporter.on_action_result(step, &result, false).await;
+            self.emit_progress(AgentProgress::ActionResult { ok: false });
+            return Ok(result);
+        };
 
-                        // Warn if value was clamped (helps debugging LLM behavior)
-                        if original_amount != amount {
-                            warn!(
-                                "Scroll amount {} out of range [1, 10000], clamped to {}",
-                                original_amount, amount
-                            );
-                        }
+        let Some(tool_name) = spec.mcp_tool else {
+            // Special case: mark completion without MCP call
+            // Agent protocol uses "done" to signal task completion
+            let result = ActionResult {
+                action: "done".into(),
+                success: true,
+                extracted_content: action
+                    .parameters
+                    .get("result")
+                    .map(|r| r.to_string())
+                    .or_else(|| Some("Task completed".into())),
+                error: None,
+                attempts: 1,
+            };
+            reporter.on_action_result(step, &result, false).await;
+            self.emit_progress(AgentProgress::ActionResult { ok: true });
+            return Ok(result);
+        };
+        let mut tool_args = (spec.build_args)(&action)?;
 
-                        let (x, y) = match direction {
-                            "up" => (0, -amount),
-                            "down" => (0, amount),
-                            "left" => (-amount, 0),
-                            "right" => (amount, 0),
-                            _ => (0, amount),
-                        };
+        // Gate mutating actions (navigation, clicks, typed input, ...)
+        // through the configured ApprovalHandler before they touch the
+        // live page. Read-only actions (extract_page_content, done -
+        // the latter already returned above) run unattended.
+        if classify_action(&action.action) == ActionClass::MayExecute {
+            match self.approval_handler.review(&action, tool_name, &tool_args).await {
+                ApprovalDecision::Approve => {}
+                ApprovalDecision::Rewrite(new_args) => tool_args = new_args,
+                ApprovalDecision::Reject(reason) => {
+                    let result = ActionResult {
+                        action: action.action.clone(),
+                        success: false,
+                        extracted_content: None,
+                        error: Some(format!(
+                            "Action rejected by approval handler: {}",
+                            reason
+                        )),
+                        attempts: 0,
+                    };
+                    reporter.on_action_result(step, &result, false).await;
+                    self.emit_progress(AgentProgress::ActionResult { ok: false });
+                    return Ok(result);
+                }
+            }
+        }
 
-                        (
-                            "browser_scroll",
-                            serde_json::json!({
-                                "x": x,
-                                "y": y
-                            }),
-                        )
-                    }
-                    "extract_page_content" => ("browser_extract_text", serde_json::json!({})),
-                    "done" => {
-                        // Special case: mark completion without MCP call
-                        // Agent protocol uses "done" to signal task completion
-                        results.push(ActionResult {
-                            action: "done".into(),
-                            success: true,
-                            extracted_content: action
-                                .parameters
-                                .get("result")
-                                .map(|r| r.to_string())
-                                .or_else(|| Some("Task completed".into())),
-                            error: None,
-                        });
-                        continue;
-                    }
-                    _ => {
-                        let error_msg = format!("Unknown action: {}", action.action);
-                        warn!("Agent attempted unknown action: {}", action.action);
-                        errors.push(error_msg.clone());
-                        results.push(ActionResult {
-                            action: action.action.clone(),
-                            success: false,
-                            extracted_content: None,
-                            error: Some(error_msg),
-                        });
-                        continue;
-                    }
-                };
+        // Serve idempotent read-only calls (extract_page_content) from
+        // the action cache instead of paying
+        // for another MCP round-trip when the LLM re-issues the same
+        // call within a step or two.
+        if let Some(cached) = self.action_cache.get(tool_name, &tool_args).await {
+            debug!("Action cache hit for tool {}", tool_name);
+            reporter.on_action_result(step, &cached, true).await;
+            self.emit_progress(AgentProgress::ActionResult { ok: cached.success });
+            return Ok(cached);
+        }
 
-            // Call existing tool via MCP client (HOT PATH!)
+        // Call existing tool via MCP client (HOT PATH!), retrying
+        // transient failures (timeouts on a still-loading page) with
+        // exponential backoff rather than aborting the action outright.
+        let max_attempts = if self.retry_policy.is_retryable(tool_name) {
+            self.retry_policy.max_attempts
+        } else {
+            1
+        };
+        let mut attempts = 0;
+        // `spec.call_timeout_secs` overrides the agent-wide default for
+        // actions that legitimately run longer (e.g. `go_to_url`); either
+        // way, 0 means "wait indefinitely" and skips the timeout wrapper
+        // entirely instead of racing a zero-duration timeout.
+        let call_timeout_secs = spec.call_timeout_secs.unwrap_or(self.mcp_call_timeout_secs);
+        let action_result = loop {
+            attempts += 1;
             debug!(
-                "Agent calling MCP tool: {} with args: {:?}",
-                tool_name, tool_args
+                "Agent calling MCP tool: {} with args: {:?} (attempt {}/{})",
+                tool_name, tool_args, attempts, max_attempts
             );
-            match self.mcp_client.call_tool(tool_name, tool_args).await {
+            // Each attempt is individually timeout-bound (`call_timeout_secs`)
+            // so a hung MCP server fails this attempt instead of stalling the
+            // whole retry loop indefinitely; the timeout folds into the same
+            // Err arms as an ordinary tool error below.
+            let call_result = if call_timeout_secs == 0 {
+                self.mcp_client
+                    .call_tool(tool_name, tool_args.clone())
+                    .await
+                    .map_err(|e| e.to_string())
+            } else {
+                match tokio::time::timeout(
+                    Duration::from_secs(call_timeout_secs),
+                    self.mcp_client.call_tool(tool_name, tool_args.clone()),
+                )
+                .await
+                {
+                    Ok(result) => result.map_err(|e| e.to_string()),
+                    Err(_) => Err(format!("timed out after {}s", call_timeout_secs)),
+                }
+            };
+            match call_result {
                 Ok(result) => {
                     info!(
                         "Tool {} succeeded for action '{}': {:?}",
@@ -191,30 +160,54 @@ impl AgentInner {
                         .map(|t| t.text.clone())
                         .unwrap_or_else(|| format!("Tool {} completed", tool_name));
 
-                    results.push(ActionResult {
-                        action: action.action,
+                    break ActionResult {
+                        action: action.action.clone(),
                         success: true,
                         extracted_content: Some(content),
                         error: None,
-                    });
+                        attempts,
+                    };
+                }
+                Err(e) if attempts < max_attempts => {
+                    let delay = self.retry_policy.delay_for(attempts);
+                    warn!(
+                        "Tool '{}' failed for action '{}' (attempt {}/{}), retrying in {:?}: {}",
+                        tool_name, action.action, attempts, max_attempts, delay, e
+                    );
+                    tokio::time::sleep(delay).await;
                 }
                 Err(e) => {
                     let error_msg = format!(
-                        "Tool '{}' failed for action '{}': {}",
-                        tool_name, action.action, e
+                        "Tool '{}' failed for action '{}' after {} attempt(s): {}",
+                        tool_name, action.action, attempts, e
                     );
                     warn!("{}", error_msg);
-                    errors.push(error_msg.clone());
-                    results.push(ActionResult {
-                        action: action.action,
+                    break ActionResult {
+                        action: action.action.clone(),
                         success: false,
                         extracted_content: None,
                         error: Some(error_msg),
-                    });
+                        attempts,
+                    };
                 }
             }
-        }
+        };
 
-        Ok((results, errors))
+        if action_result.success {
+            if classify_action(&action.action) == ActionClass::MayExecute {
+                // The DOM this action just mutated may no longer match any
+                // read-only result cached from before it ran.
+                self.action_cache.invalidate_all().await;
+            } else {
+                self.action_cache
+                    .put(tool_name, &tool_args, action_result.clone())
+                    .await;
+            }
+        }
+        reporter.on_action_result(step, &action_result, false).await;
+        self.emit_progress(AgentProgress::ActionResult {
+            ok: action_result.success,
+        });
+        Ok(action_result)
     }
 }
@@ -0,0 +1,570 @@
+//! Pluggable LLM backend for action generation: local `CandleFluentAi` today,
+//! an OpenAI-compatible HTTP backend as a second implementation.
+//!
+//! [`ActionProvider`] captures the one operation the step loop actually
+//! needs - turn a system prompt, a user query, and an optional screenshot
+//! into an [`AgentLLMResponse`] - so `AgentInner` depends on `Arc<dyn
+//! ActionProvider>` instead of embedding `CandleFluentAi` calls directly.
+//! Selected once at [`super::Agent::new`] construction; the step loop
+//! (`process_step`/`generate_and_execute_actions_streaming`) never touches a concrete
+//! backend type.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use base64::Engine;
+use kodegen_candle_agent::prelude::*;
+use serde_json::json;
+use tokio::time::Duration;
+use tokio_stream::StreamExt;
+use tokio_util::sync::CancellationToken;
+use tracing::debug;
+
+use crate::agent::{ActionModel, AgentError, AgentLLMResponse, AgentResult, AgentUsage, CurrentState};
+use super::action_registry::ActionRegistry;
+
+/// Everything [`ActionProvider::generate_actions`] needs to produce one
+/// round of actions. Borrowed rather than owned where possible since the
+/// caller (`generate_and_execute_actions_streaming`) already owns these for the duration
+/// of the call.
+pub struct ActionProviderRequest<'a> {
+    pub system_prompt: &'a str,
+    pub user_query: &'a str,
+    /// Raw screenshot bytes (decoded from the MCP `browser_screenshot`
+    /// base64 payload), if a screenshot was available this step.
+    pub screenshot: Option<Vec<u8>>,
+    pub temperature: f64,
+    pub max_tokens: u64,
+    pub timeout_secs: u64,
+    /// Cancelled by `Agent::stop`/`Agent::cancel`. [`CandleActionProvider`]
+    /// races it against the chat stream so a step blocked in generation
+    /// aborts immediately instead of running out `timeout_secs`.
+    pub cancel_token: CancellationToken,
+    /// This agent's capability surface - drives the tool schemas offered to
+    /// the backend instead of a hardcoded list, so a custom
+    /// [`super::action_registry::ActionSpec`] registered at construction
+    /// time is visible to the LLM too.
+    pub action_registry: Arc<ActionRegistry>,
+}
+
+/// A backend capable of turning browser state into the next batch of
+/// actions. Implementors own everything backend-specific (model selection,
+/// credentials, transport); the trait only exposes the one call the step
+/// loop drives.
+#[async_trait]
+pub trait ActionProvider: Send + Sync {
+    async fn generate_actions(
+        &self,
+        request: ActionProviderRequest<'_>,
+    ) -> AgentResult<AgentLLMResponse>;
+
+    /// Like [`Self::generate_actions`], but hands back each action over
+    /// `tx` as soon as it's parsed instead of only after the whole model
+    /// turn finishes - lets `generate_and_execute_actions_streaming`
+    /// dispatch an action to `execute_one_action` the moment it's ready
+    /// rather than waiting for every action in the step to be planned.
+    ///
+    /// The default implementation is the correct behavior for a backend
+    /// that can't stream structured output mid-response (e.g.
+    /// [`OpenAiActionProvider`]'s non-streaming `/chat/completions` call):
+    /// run [`Self::generate_actions`] to completion, then replay its
+    /// actions through the channel so callers don't need to special-case
+    /// non-streaming backends.
+    async fn generate_actions_stream(
+        &self,
+        request: ActionProviderRequest<'_>,
+        tx: tokio::sync::mpsc::Sender<AgentResult<ActionModel>>,
+    ) -> AgentResult<AgentUsage> {
+        let response = self.generate_actions(request).await?;
+        let usage = response.usage;
+        for action in response.action {
+            if tx.send(Ok(action)).await.is_err() {
+                break;
+            }
+        }
+        Ok(usage)
+    }
+}
+
+/// Turn a tool call's already-typed JSON arguments into the generic
+/// `ActionModel` shape the rest of the agent pipeline (execute_one_action,
+/// history, views) already expects - same flattening `ActionModel` does for
+/// the text-JSON path, just sourced from a tool call instead of a parsed
+/// blob.
+fn tool_call_to_action(name: &str, arguments: &serde_json::Value) -> ActionModel {
+    let mut parameters = std::collections::HashMap::new();
+    if let Some(obj) = arguments.as_object() {
+        for (key, value) in obj {
+            let value_str = match value {
+                serde_json::Value::String(s) => s.clone(),
+                other => other.to_string(),
+            };
+            parameters.insert(key.clone(), value_str);
+        }
+    }
+    ActionModel {
+        action: name.to_string(),
+        parameters,
+    }
+}
+
+/// Best-effort extraction of a JSON object from a free-form LLM response:
+/// strips ` ```json ` / ` ``` ` code fences if present, then finds the
+/// first balanced `{...}` span. Models routinely wrap valid JSON in prose
+/// or fences despite being asked not to; falling back to
+/// `serde_json::from_str` on the raw response for anything this can't find
+/// keeps the original error message for genuinely malformed output.
+fn extract_json_object(response: &str) -> &str {
+    let trimmed = response.trim();
+    let unfenced = trimmed
+        .strip_prefix("```json")
+        .or_else(|| trimmed.strip_prefix("```"))
+        .map(str::trim_start)
+        .and_then(|s| s.strip_suffix("```"))
+        .map(str::trim)
+        .unwrap_or(trimmed);
+
+    let Some(start) = unfenced.find('{') else {
+        return unfenced;
+    };
+
+    let mut depth = 0i32;
+    for (offset, ch) in unfenced[start..].char_indices() {
+        match ch {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return &unfenced[start..start + offset + 1];
+                }
+            }
+            _ => {}
+        }
+    }
+    unfenced
+}
+
+/// Parse either a tool-call batch or a raw JSON `AgentLLMResponse` blob into
+/// the final response, applying the same fallback both backends need.
+/// `usage` is threaded straight through - it comes from the backend's own
+/// response metadata, not from anything in `full_response`/`tool_actions`.
+fn finish_response(
+    full_response: String,
+    tool_actions: Vec<ActionModel>,
+    usage: AgentUsage,
+) -> AgentResult<AgentLLMResponse> {
+    if !tool_actions.is_empty() {
+        // Tool calls don't carry the narrative `current_state` fields a text
+        // response would, so those stay minimal.
+        Ok(AgentLLMResponse {
+            current_state: CurrentState {
+                prev_action_evaluation: String::new(),
+                important_contents: String::new(),
+                task_progress: String::new(),
+                future_plans: String::new(),
+                thought: String::new(),
+                summary: format!("{} tool call(s)", tool_actions.len()),
+            },
+            action: tool_actions,
+            usage,
+        })
+    } else {
+        let candidate = extract_json_object(&full_response);
+        serde_json::from_str::<AgentLLMResponse>(candidate)
+            .map(|mut response| {
+                response.usage = usage;
+                response
+            })
+            .map_err(|e| {
+                AgentError::LlmError(format!(
+                    "Failed to parse LLM response as JSON: {}. Response: {}",
+                    e, full_response
+                ))
+            })
+    }
+}
+
+/// Local inference via `CandleFluentAi`. The default backend, and the only
+/// one that doesn't need network access or credentials.
+pub struct CandleActionProvider;
+
+impl CandleActionProvider {
+    pub fn new() -> Arc<dyn ActionProvider> {
+        Arc::new(Self)
+    }
+}
+
+impl CandleActionProvider {
+    /// Drive `CandleFluentAi`'s chat stream on a blocking-pool thread (token
+    /// generation is CPU-bound, so it would otherwise starve the reactor
+    /// that concurrent research sessions and browser commands depend on)
+    /// and forward each chunk back over a bounded channel as it's produced.
+    /// Shared by [`ActionProvider::generate_actions`] (collects the whole
+    /// channel before returning) and [`ActionProvider::generate_actions_stream`]
+    /// (forwards each `ToolCall` chunk to its caller immediately).
+    fn spawn_chat_stream(
+        request: &ActionProviderRequest<'_>,
+    ) -> tokio::sync::mpsc::Receiver<Result<CandleMessageChunk, AgentError>> {
+        let temperature = request.temperature;
+        let max_tokens = request.max_tokens;
+        let system_prompt = request.system_prompt.to_string();
+        let user_query = request.user_query.to_string();
+        let action_registry = Arc::clone(&request.action_registry);
+        let rt_handle = tokio::runtime::Handle::current();
+        let (tx, rx) = tokio::sync::mpsc::channel::<Result<CandleMessageChunk, AgentError>>(32);
+
+        tokio::task::spawn_blocking(move || {
+            rt_handle.block_on(async move {
+                let tools: Vec<CandleToolDefinition> = action_registry
+                    .tool_schemas()
+                    .into_iter()
+                    .map(|(name, description, parameters)| {
+                        CandleToolDefinition::new(name, description, parameters)
+                    })
+                    .collect();
+                let agent = match CandleFluentAi::agent_role("browser-agent")
+                    .temperature(temperature)
+                    .max_tokens(max_tokens)
+                    .system_prompt(&system_prompt)
+                    .tools(tools)
+                    .into_agent()
+                {
+                    Ok(agent) => agent,
+                    Err(e) => {
+                        let _ = tx
+                            .send(Err(AgentError::UnexpectedError(e.to_string())))
+                            .await;
+                        return;
+                    }
+                };
+
+                let mut stream = match agent.chat(move |_conversation| {
+                    let query = user_query.clone();
+                    async move { CandleChatLoop::UserPrompt(query) }
+                }) {
+                    Ok(stream) => stream,
+                    Err(e) => {
+                        let _ = tx.send(Err(AgentError::LlmError(e.to_string()))).await;
+                        return;
+                    }
+                };
+
+                while let Some(chunk) = stream.next().await {
+                    if tx.send(Ok(chunk)).await.is_err() {
+                        // Async side timed out and dropped its receiver.
+                        break;
+                    }
+                }
+            });
+        });
+
+        rx
+    }
+}
+
+#[async_trait]
+impl ActionProvider for CandleActionProvider {
+    async fn generate_actions(
+        &self,
+        request: ActionProviderRequest<'_>,
+    ) -> AgentResult<AgentLLMResponse> {
+        let llm_timeout = Duration::from_secs(request.timeout_secs);
+        let mut rx = Self::spawn_chat_stream(&request);
+
+        let (full_response, tool_actions, usage) = tokio::time::timeout(llm_timeout, async {
+            // Pre-allocate based on max_tokens parameter
+            // Average: ~4 bytes per token for English text
+            let expected_bytes = (request.max_tokens as usize) * 4;
+            let mut response = String::with_capacity(expected_bytes);
+            let mut tool_actions = Vec::new();
+
+            loop {
+                let chunk = tokio::select! {
+                    chunk = rx.recv() => chunk,
+                    () = request.cancel_token.cancelled() => return Err(AgentError::Stopped),
+                };
+                match chunk {
+                    Some(chunk) => match chunk? {
+                        CandleMessageChunk::Text(text) => {
+                            response.push_str(&text);
+                        }
+                        CandleMessageChunk::ToolCall { name, arguments } => {
+                            tool_actions.push(tool_call_to_action(&name, &arguments));
+                        }
+                        CandleMessageChunk::Complete {
+                            token_count,
+                            elapsed_secs,
+                            ..
+                        } => {
+                            if let (Some(tokens), Some(elapsed)) = (token_count, elapsed_secs) {
+                                debug!("LLM generated {} tokens in {:.2}s", tokens, elapsed);
+                            }
+                            // `CandleMessageChunk::Complete` only reports a
+                            // combined token count, not a prompt/completion
+                            // split, so the whole figure goes under
+                            // `completion_tokens` - still enough to budget
+                            // and detect runaway generation against.
+                            let usage = AgentUsage {
+                                prompt_tokens: 0,
+                                completion_tokens: token_count.unwrap_or(0),
+                                total_tokens: token_count.unwrap_or(0),
+                                wall_secs: elapsed_secs.unwrap_or(0.0),
+                            };
+                            return Ok((response, tool_actions, usage));
+                        }
+                        CandleMessageChunk::Error(err) => {
+                            return Err(AgentError::LlmError(err.to_string()));
+                        }
+                        _ => {}
+                    },
+                    // Channel closed (blocking task finished) without a Complete chunk
+                    None => {
+                        return Err(AgentError::LlmError(
+                            "LLM stream ended without Complete chunk".into(),
+                        ));
+                    }
+                }
+            }
+        })
+        .await
+        .map_err(|_| {
+            AgentError::LlmError(format!(
+                "LLM generation timed out after {}s",
+                request.timeout_secs
+            ))
+        })??;
+
+        finish_response(full_response, tool_actions, usage)
+    }
+
+    async fn generate_actions_stream(
+        &self,
+        request: ActionProviderRequest<'_>,
+        tx: tokio::sync::mpsc::Sender<AgentResult<ActionModel>>,
+    ) -> AgentResult<AgentUsage> {
+        let llm_timeout = Duration::from_secs(request.timeout_secs);
+        let mut rx = Self::spawn_chat_stream(&request);
+
+        tokio::time::timeout(llm_timeout, async {
+            // If the caller (`generate_and_execute_actions_streaming`)
+            // stops consuming early (e.g. an action fails to execute) it
+            // drops its receiver - `tx.send` starts failing at that point.
+            // Rather than abort and
+            // leave `spawn_chat_stream`'s background task blocked forever
+            // sending into a channel nobody reads, keep draining `rx` with
+            // sends skipped so the generation still runs to `Complete` (or
+            // the outer timeout) and the blocking task exits cleanly.
+            let mut caller_gone = false;
+            loop {
+                let chunk = tokio::select! {
+                    chunk = rx.recv() => chunk,
+                    () = request.cancel_token.cancelled() => return Err(AgentError::Stopped),
+                };
+                match chunk {
+                    Some(chunk) => match chunk? {
+                        // Forwarded to `tx` the instant it's parsed, rather
+                        // than buffered until `Complete` - this is the whole
+                        // point of the streaming path over `generate_actions`.
+                        CandleMessageChunk::ToolCall { name, arguments } => {
+                            if !caller_gone {
+                                let action = tool_call_to_action(&name, &arguments);
+                                if tx.send(Ok(action)).await.is_err() {
+                                    caller_gone = true;
+                                }
+                            }
+                        }
+                        CandleMessageChunk::Complete {
+                            token_count,
+                            elapsed_secs,
+                            ..
+                        } => {
+                            return Ok(AgentUsage {
+                                prompt_tokens: 0,
+                                completion_tokens: token_count.unwrap_or(0),
+                                total_tokens: token_count.unwrap_or(0),
+                                wall_secs: elapsed_secs.unwrap_or(0.0),
+                            });
+                        }
+                        CandleMessageChunk::Error(err) => {
+                            return Err(AgentError::LlmError(err.to_string()));
+                        }
+                        // Free-text chunks carry no action and have no
+                        // fallback in the streaming path - a model that
+                        // doesn't use tool calling should go through
+                        // `generate_actions` instead, where the full
+                        // response is available to JSON-parse.
+                        _ => {}
+                    },
+                    None => {
+                        return Err(AgentError::LlmError(
+                            "LLM stream ended without Complete chunk".into(),
+                        ));
+                    }
+                }
+            }
+        })
+        .await
+        .map_err(|_| {
+            AgentError::LlmError(format!(
+                "LLM generation timed out after {}s",
+                request.timeout_secs
+            ))
+        })?
+    }
+}
+
+/// Hosted inference via any OpenAI-compatible `/chat/completions` endpoint
+/// (OpenAI itself, Azure OpenAI, or a self-hosted vLLM/Ollama gateway that
+/// speaks the same wire format). Lets a deployment swap in a hosted model
+/// without touching the step loop or action execution.
+pub struct OpenAiActionProvider {
+    http: reqwest::Client,
+    base_url: String,
+    api_key: String,
+    model: String,
+}
+
+impl OpenAiActionProvider {
+    /// `base_url` should point at the API root, e.g.
+    /// `https://api.openai.com/v1` - `/chat/completions` is appended.
+    pub fn new(base_url: String, api_key: String, model: String) -> Arc<dyn ActionProvider> {
+        Arc::new(Self {
+            http: reqwest::Client::new(),
+            base_url,
+            api_key,
+            model,
+        })
+    }
+}
+
+#[async_trait]
+impl ActionProvider for OpenAiActionProvider {
+    async fn generate_actions(
+        &self,
+        request: ActionProviderRequest<'_>,
+    ) -> AgentResult<AgentLLMResponse> {
+        let tools: Vec<serde_json::Value> = request
+            .action_registry
+            .tool_schemas()
+            .into_iter()
+            .map(|(name, description, parameters)| {
+                json!({
+                    "type": "function",
+                    "function": {
+                        "name": name,
+                        "description": description,
+                        "parameters": parameters,
+                    }
+                })
+            })
+            .collect();
+
+        let mut user_content = vec![json!({ "type": "text", "text": request.user_query })];
+        if let Some(screenshot) = &request.screenshot {
+            let encoded = base64::engine::general_purpose::STANDARD.encode(screenshot);
+            user_content.push(json!({
+                "type": "image_url",
+                "image_url": { "url": format!("data:image/png;base64,{}", encoded) }
+            }));
+        }
+
+        let body = json!({
+            "model": self.model,
+            "temperature": request.temperature,
+            "max_tokens": request.max_tokens,
+            "tools": tools,
+            "messages": [
+                { "role": "system", "content": request.system_prompt },
+                { "role": "user", "content": user_content },
+            ],
+        });
+
+        let timeout = Duration::from_secs(request.timeout_secs);
+        let started = std::time::Instant::now();
+        let response = tokio::select! {
+            result = self
+                .http
+                .post(format!("{}/chat/completions", self.base_url))
+                .bearer_auth(&self.api_key)
+                .json(&body)
+                .timeout(timeout)
+                .send() => {
+                result.map_err(|e| AgentError::LlmError(format!("OpenAI request failed: {}", e)))?
+            }
+            () = request.cancel_token.cancelled() => return Err(AgentError::Stopped),
+        };
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            return Err(AgentError::LlmError(format!(
+                "OpenAI request failed with status {}: {}",
+                status, text
+            )));
+        }
+
+        let payload: serde_json::Value = response
+            .json()
+            .await
+            .map_err(|e| AgentError::LlmError(format!("Failed to parse OpenAI response: {}", e)))?;
+
+        let message = payload
+            .get("choices")
+            .and_then(|c| c.get(0))
+            .and_then(|c| c.get("message"))
+            .ok_or_else(|| {
+                AgentError::LlmError(format!(
+                    "OpenAI response missing choices[0].message: {}",
+                    payload
+                ))
+            })?;
+
+        let tool_actions: Vec<ActionModel> = message
+            .get("tool_calls")
+            .and_then(|v| v.as_array())
+            .map(|calls| {
+                calls
+                    .iter()
+                    .filter_map(|call| {
+                        let function = call.get("function")?;
+                        let name = function.get("name")?.as_str()?;
+                        let arguments: serde_json::Value = function
+                            .get("arguments")
+                            .and_then(|a| a.as_str())
+                            .and_then(|s| serde_json::from_str(s).ok())
+                            .unwrap_or(serde_json::Value::Null);
+                        Some(tool_call_to_action(name, &arguments))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let full_response = message
+            .get("content")
+            .and_then(|c| c.as_str())
+            .unwrap_or_default()
+            .to_string();
+
+        // OpenAI-compatible `usage` block: `{prompt_tokens, completion_tokens,
+        // total_tokens}`. Missing (some gateways omit it) just means the
+        // caller's accumulated total undercounts this call rather than the
+        // request failing over it.
+        let usage = payload
+            .get("usage")
+            .map(|u| AgentUsage {
+                prompt_tokens: u.get("prompt_tokens").and_then(|v| v.as_u64()).unwrap_or(0),
+                completion_tokens: u
+                    .get("completion_tokens")
+                    .and_then(|v| v.as_u64())
+                    .unwrap_or(0),
+                total_tokens: u.get("total_tokens").and_then(|v| v.as_u64()).unwrap_or(0),
+                wall_secs: started.elapsed().as_secs_f64(),
+            })
+            .unwrap_or(AgentUsage {
+                wall_secs: started.elapsed().as_secs_f64(),
+                ..Default::default()
+            });
+
+        finish_response(full_response, tool_actions, usage)
+    }
+}
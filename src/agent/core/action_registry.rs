@@ -0,0 +1,442 @@
+//! Single source of truth for the agent's browser actions: each
+//! [`ActionSpec`] pairs an agent protocol action name with its parameter
+//! schema (for function-calling), its target MCP tool, and the transform
+//! that turns the LLM's loosely-typed `ActionModel::parameters` into the
+//! MCP tool's JSON arguments.
+//!
+//! Previously this lived as three things that had to be kept in sync by
+//! hand: the `match action.action.as_str()` block in
+//! `action_executor::execute_one_action`, the tool schemas
+//! `action_provider::browser_action_tools` advertised to the LLM, and the
+//! `ACTIONS_DESCRIPTION` text injected into the system prompt. An
+//! [`ActionRegistry`] now drives all three, and [`ActionRegistry::register`]
+//! lets an integrator add a custom MCP tool as an agent action without
+//! touching any of this module's callers.
+
+use std::collections::HashMap;
+
+use serde_json::json;
+
+use crate::agent::{ActionModel, AgentError, AgentResult};
+
+/// Resolve an element-targeting action's `selector`/`index` parameter to a
+/// CSS selector, converting a numeric `index` to `[data-mcp-index="N"]`.
+fn resolve_selector(action: &ActionModel) -> AgentResult<String> {
+    if let Some(selector) = action.parameters.get("selector") {
+        return Ok(selector.clone());
+    }
+    let index = action
+        .parameters
+        .get("index")
+        .ok_or_else(|| AgentError::StepFailed("Missing 'selector' or 'index' parameter".into()))?;
+    let index_num = index.parse::<u64>().map_err(|_| {
+        AgentError::StepFailed(format!(
+            "Invalid index parameter: must be numeric, got '{}'",
+            index
+        ))
+    })?;
+    Ok(format!("[data-mcp-index=\"{}\"]", index_num))
+}
+
+/// Declarative per-action argument builder: turns an [`ActionModel`]'s
+/// loosely-typed string parameters into the JSON body its [`ActionSpec::mcp_tool`]
+/// expects. A plain `fn` pointer rather than a boxed closure - every builtin
+/// transform is stateless, and a custom [`ActionSpec`] registered at
+/// construction time can still supply one.
+pub type ArgsBuilder = fn(&ActionModel) -> AgentResult<serde_json::Value>;
+
+/// One agent-protocol action: its function-calling schema, its mapped MCP
+/// tool, and the transform from `ActionModel` parameters to that tool's
+/// arguments. `mcp_tool` is `None` only for `done`, which the step loop
+/// handles specially instead of calling through MCP.
+#[derive(Clone)]
+pub struct ActionSpec {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub mcp_tool: Option<&'static str>,
+    pub parameters: serde_json::Value,
+    pub build_args: ArgsBuilder,
+
+    /// Overrides `AgentConfig::mcp_call_timeout_secs` for this action's MCP
+    /// call when set. `go_to_url` uses this to give navigation (which can
+    /// legitimately take longer than a click or a key press) a longer bound
+    /// than the rest of the action set. `None` for every other builtin
+    /// action, meaning "use the agent's configured default".
+    pub call_timeout_secs: Option<u64>,
+}
+
+fn go_to_url_args(action: &ActionModel) -> AgentResult<serde_json::Value> {
+    let url = action
+        .parameters
+        .get("url")
+        .ok_or_else(|| AgentError::StepFailed("Missing 'url' parameter".into()))?;
+    Ok(json!({ "url": url, "timeout_ms": 30000 }))
+}
+
+fn click_element_args(action: &ActionModel) -> AgentResult<serde_json::Value> {
+    let selector = resolve_selector(action)?;
+    Ok(json!({ "selector": selector, "timeout_ms": 5000 }))
+}
+
+fn input_text_args(action: &ActionModel) -> AgentResult<serde_json::Value> {
+    let selector = resolve_selector(action)?;
+    let text = action
+        .parameters
+        .get("text")
+        .ok_or_else(|| AgentError::StepFailed("Missing 'text' parameter".into()))?;
+    Ok(json!({ "selector": selector, "text": text, "clear": true }))
+}
+
+fn scroll_args(action: &ActionModel) -> AgentResult<serde_json::Value> {
+    let direction = action
+        .parameters
+        .get("direction")
+        .map(|s| s.as_str())
+        .unwrap_or("down");
+
+    // Parse scroll amount with default fallback
+    let amount = action
+        .parameters
+        .get("amount")
+        .and_then(|a| a.parse::<i32>().ok())
+        .unwrap_or(500);
+
+    // Validate and clamp to reasonable range (1-10,000 pixels)
+    // Rationale: Typical viewport is ~1000-2000px tall, 10k = ~5 screen heights
+    let original_amount = amount;
+    let amount = amount.clamp(1, 10_000);
+
+    // Warn if value was clamped (helps debugging LLM behavior)
+    if original_amount != amount {
+        tracing::warn!(
+            "Scroll amount {} out of range [1, 10000], clamped to {}",
+            original_amount,
+            amount
+        );
+    }
+
+    let (x, y) = match direction {
+        "up" => (0, -amount),
+        "down" => (0, amount),
+        "left" => (-amount, 0),
+        "right" => (amount, 0),
+        _ => (0, amount),
+    };
+
+    Ok(json!({ "x": x, "y": y }))
+}
+
+fn extract_page_content_args(_action: &ActionModel) -> AgentResult<serde_json::Value> {
+    Ok(json!({}))
+}
+
+fn select_option_args(action: &ActionModel) -> AgentResult<serde_json::Value> {
+    let selector = resolve_selector(action)?;
+    let value = action.parameters.get("value");
+    let label = action.parameters.get("label");
+    if value.is_none() && label.is_none() {
+        return Err(AgentError::StepFailed(
+            "Missing 'value' or 'label' parameter".into(),
+        ));
+    }
+    Ok(json!({ "selector": selector, "value": value, "label": label }))
+}
+
+fn press_key_args(action: &ActionModel) -> AgentResult<serde_json::Value> {
+    let keys = action
+        .parameters
+        .get("keys")
+        .ok_or_else(|| AgentError::StepFailed("Missing 'keys' parameter".into()))?;
+    Ok(json!({ "selector": action.parameters.get("selector"), "keys": keys }))
+}
+
+fn hover_args(action: &ActionModel) -> AgentResult<serde_json::Value> {
+    let selector = resolve_selector(action)?;
+    Ok(json!({ "selector": selector }))
+}
+
+fn go_back_args(_action: &ActionModel) -> AgentResult<serde_json::Value> {
+    Ok(json!({ "direction": "BACK" }))
+}
+
+fn go_forward_args(_action: &ActionModel) -> AgentResult<serde_json::Value> {
+    Ok(json!({ "direction": "FORWARD" }))
+}
+
+fn switch_tab_args(action: &ActionModel) -> AgentResult<serde_json::Value> {
+    let name = action
+        .parameters
+        .get("title")
+        .or_else(|| action.parameters.get("index"))
+        .ok_or_else(|| AgentError::StepFailed("Missing 'title' or 'index' parameter".into()))?;
+    Ok(json!({ "action": "SWITCH", "name": name }))
+}
+
+fn upload_file_args(action: &ActionModel) -> AgentResult<serde_json::Value> {
+    let selector = resolve_selector(action)?;
+    let path = action
+        .parameters
+        .get("path")
+        .ok_or_else(|| AgentError::StepFailed("Missing 'path' parameter".into()))?;
+    Ok(json!({ "selector": selector, "paths": [path] }))
+}
+
+/// `done` has no MCP tool - its args builder is never called, but it still
+/// gets a spec so it shows up in generated tool schemas and the
+/// human-readable action description.
+fn done_args(_action: &ActionModel) -> AgentResult<serde_json::Value> {
+    Ok(json!({}))
+}
+
+fn builtin_actions() -> Vec<ActionSpec> {
+    vec![
+        ActionSpec {
+            name: "go_to_url",
+            description: "Navigate to a URL",
+            mcp_tool: Some("browser_navigate"),
+            parameters: json!({
+                "type": "object",
+                "properties": { "url": { "type": "string" } },
+                "required": ["url"]
+            }),
+            build_args: go_to_url_args,
+            call_timeout_secs: Some(60),
+        },
+        ActionSpec {
+            name: "click_element",
+            description: "Click an element identified by CSS selector or index",
+            mcp_tool: Some("browser_click"),
+            parameters: json!({
+                "type": "object",
+                "properties": {
+                    "selector": { "type": "string" },
+                    "index": { "type": "string" }
+                }
+            }),
+            build_args: click_element_args,
+            call_timeout_secs: None,
+        },
+        ActionSpec {
+            name: "input_text",
+            description: "Type text into an element identified by CSS selector or index",
+            mcp_tool: Some("browser_type_text"),
+            parameters: json!({
+                "type": "object",
+                "properties": {
+                    "selector": { "type": "string" },
+                    "index": { "type": "string" },
+                    "text": { "type": "string" }
+                },
+                "required": ["text"]
+            }),
+            build_args: input_text_args,
+            call_timeout_secs: None,
+        },
+        ActionSpec {
+            name: "scroll",
+            description: "Scroll the page",
+            mcp_tool: Some("browser_scroll"),
+            parameters: json!({
+                "type": "object",
+                "properties": {
+                    "direction": { "type": "string", "enum": ["up", "down", "left", "right"] },
+                    "amount": { "type": "string" }
+                },
+                "required": ["direction"]
+            }),
+            build_args: scroll_args,
+            call_timeout_secs: None,
+        },
+        ActionSpec {
+            name: "extract_page_content",
+            description: "Extract the page's text content",
+            mcp_tool: Some("browser_extract_text"),
+            parameters: json!({ "type": "object", "properties": {} }),
+            build_args: extract_page_content_args,
+            call_timeout_secs: None,
+        },
+        ActionSpec {
+            name: "select_option",
+            description: "Select an option in a <select> element by value or label",
+            mcp_tool: Some("browser_select"),
+            parameters: json!({
+                "type": "object",
+                "properties": {
+                    "selector": { "type": "string" },
+                    "index": { "type": "string" },
+                    "value": { "type": "string" },
+                    "label": { "type": "string" }
+                }
+            }),
+            build_args: select_option_args,
+            call_timeout_secs: None,
+        },
+        ActionSpec {
+            name: "press_key",
+            description: "Press a keyboard key, optionally on a focused element",
+            mcp_tool: Some("browser_press_key"),
+            parameters: json!({
+                "type": "object",
+                "properties": {
+                    "selector": { "type": "string" },
+                    "keys": { "type": "string" }
+                },
+                "required": ["keys"]
+            }),
+            build_args: press_key_args,
+            call_timeout_secs: None,
+        },
+        ActionSpec {
+            name: "hover",
+            description: "Hover over an element identified by CSS selector or index",
+            mcp_tool: Some("browser_hover"),
+            parameters: json!({
+                "type": "object",
+                "properties": {
+                    "selector": { "type": "string" },
+                    "index": { "type": "string" }
+                }
+            }),
+            build_args: hover_args,
+            call_timeout_secs: None,
+        },
+        ActionSpec {
+            name: "go_back",
+            description: "Navigate back in browser history",
+            mcp_tool: Some("browser_history"),
+            parameters: json!({ "type": "object", "properties": {} }),
+            build_args: go_back_args,
+            call_timeout_secs: None,
+        },
+        ActionSpec {
+            name: "go_forward",
+            description: "Navigate forward in browser history",
+            mcp_tool: Some("browser_history"),
+            parameters: json!({ "type": "object", "properties": {} }),
+            build_args: go_forward_args,
+            call_timeout_secs: None,
+        },
+        ActionSpec {
+            name: "switch_tab",
+            description: "Switch to another browser tab by title or index",
+            mcp_tool: Some("browser_tabs"),
+            parameters: json!({
+                "type": "object",
+                "properties": {
+                    "title": { "type": "string" },
+                    "index": { "type": "string" }
+                }
+            }),
+            build_args: switch_tab_args,
+            call_timeout_secs: None,
+        },
+        ActionSpec {
+            name: "upload_file",
+            description: "Upload a file to a file input identified by CSS selector or index",
+            mcp_tool: Some("browser_upload_file"),
+            parameters: json!({
+                "type": "object",
+                "properties": {
+                    "selector": { "type": "string" },
+                    "index": { "type": "string" },
+                    "path": { "type": "string" }
+                },
+                "required": ["path"]
+            }),
+            build_args: upload_file_args,
+            call_timeout_secs: None,
+        },
+        ActionSpec {
+            name: "done",
+            description: "Mark the task as complete",
+            mcp_tool: None,
+            parameters: json!({
+                "type": "object",
+                "properties": { "result": { "type": "string" } },
+                "required": ["result"]
+            }),
+            build_args: done_args,
+            call_timeout_secs: None,
+        },
+    ]
+}
+
+/// The agent's capability surface: every action it can plan, keyed by name.
+/// Construction starts from [`Self::with_defaults`]'s built-in browser
+/// actions; [`Self::register`] adds or replaces entries so an integrator can
+/// expose a custom MCP tool as an agent action without touching
+/// `action_executor`, `action_provider`, or the system prompt.
+pub struct ActionRegistry {
+    actions: HashMap<&'static str, ActionSpec>,
+    /// Preserves registration order for `actions_description`/tool schema
+    /// generation, since prompt stability matters more than `HashMap`'s
+    /// iteration order.
+    order: Vec<&'static str>,
+}
+
+impl ActionRegistry {
+    /// The built-in browser action set this agent shipped with before any
+    /// custom registration.
+    pub fn with_defaults() -> Self {
+        let mut registry = Self {
+            actions: HashMap::new(),
+            order: Vec::new(),
+        };
+        for spec in builtin_actions() {
+            registry.register(spec);
+        }
+        registry
+    }
+
+    /// Add `spec` to the registry, replacing any existing action of the same
+    /// name in place (preserving its position in `order`).
+    pub fn register(&mut self, spec: ActionSpec) {
+        if !self.actions.contains_key(spec.name) {
+            self.order.push(spec.name);
+        }
+        self.actions.insert(spec.name, spec);
+    }
+
+    /// Look up an action spec by its agent-protocol name.
+    pub fn get(&self, name: &str) -> Option<&ActionSpec> {
+        self.actions.get(name)
+    }
+
+    /// Function-calling tool schemas for every registered action, in
+    /// registration order - passed to whichever [`super::action_provider::ActionProvider`]
+    /// backend is in use.
+    pub fn tool_schemas(&self) -> Vec<(&'static str, &'static str, serde_json::Value)> {
+        self.order
+            .iter()
+            .filter_map(|name| self.actions.get(name))
+            .map(|spec| (spec.name, spec.description, spec.parameters.clone()))
+            .collect()
+    }
+
+    /// Human-readable action list injected into the system prompt for
+    /// backends that fall back to JSON-in-text instead of native tool
+    /// calling.
+    pub fn actions_description(&self) -> String {
+        let mut text = String::from("Available Actions:\n");
+        for name in &self.order {
+            if let Some(spec) = self.actions.get(name) {
+                text.push_str(&format!("- {}: {}\n", spec.name, spec.description));
+            }
+        }
+        text.push_str(
+            "\nParameter Notes:\n\
+             - selector: CSS selector string (e.g., \"#submit\", \".button\", \"input[name='email']\")\n\
+             - index: Numeric index for data-mcp-index attributes (converted to selector automatically)\n\
+             - Use selector for precision, index for LLM-generated element references\n\n\
+             Prefer calling the matching tool for each action. If tool calls aren't available, respond with \
+             valid JSON matching the AgentLLMResponse schema with an 'action' array instead.",
+        );
+        text
+    }
+}
+
+impl Default for ActionRegistry {
+    fn default() -> Self {
+        Self::with_defaults()
+    }
+}
@@ -1,14 +1,29 @@
 use std::sync::Arc;
-use tokio::sync::{Mutex, mpsc};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use tokio::sync::{Mutex, RwLock, broadcast, mpsc};
 use tokio::time::Duration;
+use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, warn};
 use kodegen_mcp_client::KodegenClient;
 
 use crate::agent::{AgentError, AgentHistoryList, AgentOutput, AgentResult};
 use crate::utils::AgentState;
+use super::action_cache::ActionCache;
+use super::action_provider::{ActionProvider, CandleActionProvider};
+use super::action_registry::ActionRegistry;
+use super::approval::{ApprovalHandler, AutoApproveHandler};
 use super::config::{AgentConfig, PromptConfig};
 use super::messaging::{AgentCommand, AgentResponse};
 use super::processor::AgentInner;
+use super::progress::{AgentProgress, PROGRESS_CHANNEL_CAPACITY};
+use super::step_reporter::StepReporter;
+use super::vision_cache::VisionMemoCache;
+
+/// Entries kept in each agent's in-memory [`VisionMemoCache`]. Small on
+/// purpose - it's a front for the disk cache, not a replacement for it, so
+/// missing an older entry just means falling back to that rather than
+/// re-running the vision model.
+const VISION_MEMO_CAPACITY: usize = 16;
 
 /// Agent handle for controlling async actor (NOT Clone)
 pub struct Agent {
@@ -16,6 +31,12 @@ pub struct Agent {
     command_channel: mpsc::Sender<AgentCommand>,
     response_channel: Mutex<mpsc::Receiver<AgentResponse>>,
 
+    /// Cooperative pause flag `run_inner`'s loop polls before requesting
+    /// each new step. Set by [`Self::pause`]/[`Self::resume`]; checked
+    /// client-side rather than in the processor task so a step already in
+    /// flight always finishes instead of being interrupted mid-action.
+    paused: Arc<AtomicBool>,
+
     /// Background processor task handle
     ///
     /// Stores the JoinHandle for the spawned agent processor task.
@@ -27,7 +48,10 @@ pub struct Agent {
 
 /// Agent implementation
 impl Agent {
-    /// Create a new agent instance
+    /// Create a new agent instance, inferring actions via `CandleFluentAi`.
+    ///
+    /// Use [`Agent::new_with_provider`] to run against a different
+    /// [`ActionProvider`] (e.g. a hosted OpenAI-compatible endpoint).
     pub fn new(
         task: &str,
         add_infos: &str,
@@ -36,10 +60,114 @@ impl Agent {
         max_actions_per_step: usize,
         agent_state: Arc<Mutex<AgentState>>,
         config: AgentConfig,
+    ) -> AgentResult<Self> {
+        Self::new_with_provider(
+            task,
+            add_infos,
+            mcp_client,
+            prompts,
+            max_actions_per_step,
+            agent_state,
+            config,
+            CandleActionProvider::new(),
+        )
+    }
+
+    /// Create a new agent instance against a caller-selected [`ActionProvider`]
+    ///
+    /// This is the extension point for swapping local vs. hosted models
+    /// without touching the step loop or action execution: pass
+    /// [`CandleActionProvider::new()`] for local inference or
+    /// [`super::action_provider::OpenAiActionProvider::new`] for a hosted
+    /// OpenAI-compatible endpoint. Mutating actions run unattended, auto-
+    /// approved by [`AutoApproveHandler`] - use
+    /// [`Self::new_with_approval_handler`] to install an oversight policy.
+    pub fn new_with_provider(
+        task: &str,
+        add_infos: &str,
+        mcp_client: Arc<KodegenClient>,
+        prompts: PromptConfig,
+        max_actions_per_step: usize,
+        agent_state: Arc<Mutex<AgentState>>,
+        config: AgentConfig,
+        action_provider: Arc<dyn ActionProvider>,
+    ) -> AgentResult<Self> {
+        Self::new_with_approval_handler(
+            task,
+            add_infos,
+            mcp_client,
+            prompts,
+            max_actions_per_step,
+            agent_state,
+            config,
+            action_provider,
+            AutoApproveHandler::new(),
+        )
+    }
+
+    /// Create a new agent instance against a caller-selected [`ActionProvider`]
+    /// and [`ApprovalHandler`].
+    ///
+    /// `approval_handler` reviews every action [`super::approval::classify_action`]
+    /// flags as `MayExecute` (navigation, clicks, typed input, ...) before its
+    /// mapped MCP tool is called, and can approve, reject, or rewrite its
+    /// parameters. Read-only actions (`extract_page_content`, `done`) always
+    /// run unattended.
+    pub fn new_with_approval_handler(
+        task: &str,
+        add_infos: &str,
+        mcp_client: Arc<KodegenClient>,
+        prompts: PromptConfig,
+        max_actions_per_step: usize,
+        agent_state: Arc<Mutex<AgentState>>,
+        config: AgentConfig,
+        action_provider: Arc<dyn ActionProvider>,
+        approval_handler: Arc<dyn ApprovalHandler>,
+    ) -> AgentResult<Self> {
+        Self::new_with_registry(
+            task,
+            add_infos,
+            mcp_client,
+            prompts,
+            max_actions_per_step,
+            agent_state,
+            config,
+            action_provider,
+            approval_handler,
+            Arc::new(ActionRegistry::with_defaults()),
+        )
+    }
+
+    /// Create a new agent instance against a caller-selected [`ActionProvider`],
+    /// [`ApprovalHandler`], and [`ActionRegistry`].
+    ///
+    /// `action_registry` is the agent's capability surface - the set of
+    /// actions it can plan, their function-calling schemas, and their MCP
+    /// tool mapping. Pass a registry built on top of
+    /// [`ActionRegistry::with_defaults`] with extra [`super::action_registry::ActionSpec`]s
+    /// registered to expose custom MCP tools as agent actions without
+    /// touching the step loop.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_with_registry(
+        task: &str,
+        add_infos: &str,
+        mcp_client: Arc<KodegenClient>,
+        prompts: PromptConfig,
+        max_actions_per_step: usize,
+        agent_state: Arc<Mutex<AgentState>>,
+        config: AgentConfig,
+        action_provider: Arc<dyn ActionProvider>,
+        approval_handler: Arc<dyn ApprovalHandler>,
+        action_registry: Arc<ActionRegistry>,
     ) -> AgentResult<Self> {
         // Create channels for command passing
         let (cmd_tx, cmd_rx) = mpsc::channel(32);
         let (resp_tx, resp_rx) = mpsc::channel(32);
+        let cancel_token = CancellationToken::new();
+
+        // Progress event broadcast - independent of the command/response
+        // channels above, so it's fine if no one subscribes yet.
+        let (progress_tx, _progress_rx) = broadcast::channel(PROGRESS_CHANNEL_CAPACITY);
 
         // Create shared inner state (Arc-wrapped)
         let inner = Arc::new(AgentInner {
@@ -50,13 +178,30 @@ impl Agent {
             agent_prompt: prompts.agent_prompt,
             max_actions_per_step,
             agent_state,
+            action_provider,
+            step_reporter: RwLock::new(AgentInner::null_reporter()),
+            action_cache: ActionCache::new(),
+            approval_handler,
+            action_registry,
+            retry_policy: config.retry_policy.clone(),
+            step_counter: AtomicUsize::new(0),
             temperature: config.temperature,
             max_tokens: config.max_tokens,
             vision_timeout_secs: config.vision_timeout_secs,
             llm_timeout_secs: config.llm_timeout_secs,
+            page_state_mode: config.page_state_mode,
+            mcp_call_timeout_secs: config.mcp_call_timeout_secs,
+            mcp_max_retries: config.mcp_max_retries,
             previous_action_results: Mutex::new(Vec::new()),
+            cancel_token: cancel_token.clone(),
+            vision_memo: VisionMemoCache::new(VISION_MEMO_CAPACITY),
+            progress_tx,
         });
 
+        if config.install_signal_handler {
+            Self::spawn_signal_handler(cancel_token);
+        }
+
         // Spawn processor with Arc-cloned inner and store handle
         let processor_handle = Self::spawn_agent_processor(Arc::clone(&inner), cmd_rx, resp_tx);
 
@@ -65,12 +210,99 @@ impl Agent {
             inner,
             command_channel: cmd_tx,
             response_channel: Mutex::new(resp_rx),
+            paused: Arc::new(AtomicBool::new(false)),
             processor_handle: Some(processor_handle),
         })
     }
 
+    /// Cancel `token` on the first SIGINT/SIGTERM (Unix) or Ctrl-C
+    /// (all platforms), so a hosting process's shutdown signal aborts an
+    /// in-flight step the same way [`Self::stop`]/[`Self::cancel`] do.
+    /// Installed only when [`AgentConfig::install_signal_handler`] is set,
+    /// since a library embedding several agents likely wants to own signal
+    /// handling itself rather than have every agent install its own.
+    fn spawn_signal_handler(token: CancellationToken) {
+        tokio::spawn(async move {
+            #[cfg(unix)]
+            {
+                let mut sigterm = match tokio::signal::unix::signal(
+                    tokio::signal::unix::SignalKind::terminate(),
+                ) {
+                    Ok(signal) => signal,
+                    Err(e) => {
+                        warn!("Failed to install SIGTERM handler: {}", e);
+                        return;
+                    }
+                };
+                tokio::select! {
+                    _ = tokio::signal::ctrl_c() => {}
+                    _ = sigterm.recv() => {}
+                }
+            }
+            #[cfg(not(unix))]
+            {
+                let _ = tokio::signal::ctrl_c().await;
+            }
+
+            info!("Shutdown signal received, cancelling agent");
+            token.cancel();
+        });
+    }
+
     /// Run the agent to perform a task with a maximum number of steps
     pub async fn run(&self, max_steps: usize) -> AgentResult<AgentHistoryList> {
+        self.run_inner(max_steps).await
+    }
+
+    /// Run the agent, reporting structured per-step progress to `reporter`
+    /// instead of only accumulating the coarse `AgentHistoryList`.
+    ///
+    /// `AgentSession::start` uses this to wire a `RecordingStepReporter`
+    /// into the background loop so `AgentSession::read` can expose
+    /// structured events alongside its summary string.
+    pub async fn run_with_reporter(
+        &self,
+        max_steps: usize,
+        reporter: Arc<dyn StepReporter>,
+    ) -> AgentResult<AgentHistoryList> {
+        self.inner.set_reporter(reporter).await;
+        self.run_inner(max_steps).await
+    }
+
+    /// Seed the step counter so the next run continues numbering from
+    /// `step` instead of 0.
+    ///
+    /// `AgentSession::resume` calls this before restarting the background
+    /// loop from a checkpoint, so `StepEvent`s and reported step indices
+    /// line up with the steps already present in the restored history
+    /// instead of renumbering from the beginning.
+    pub async fn resume_from_step(&self, step: usize) {
+        self.inner.set_step_counter(step);
+    }
+
+    /// Subscribe to live [`AgentProgress`] events for each phase of a step
+    /// (state fetch, vision, planning, each action) as it runs.
+    ///
+    /// Each call returns an independent `broadcast::Receiver`, so any
+    /// number of observers (a TUI, a web dashboard, a logging sink) can
+    /// subscribe without interfering with each other or with the
+    /// command/response channel `run` uses. Events broadcast
+    /// before a receiver subscribes are not replayed.
+    pub fn subscribe(&self) -> broadcast::Receiver<AgentProgress> {
+        self.inner.progress_tx.subscribe()
+    }
+
+    /// Drop every cached read-only action result (see `ActionCache`). The
+    /// agent invalidates this itself whenever one of its own actions
+    /// mutates the page; call this when the caller knows the page changed
+    /// some other way (a redirect, an async load outside the agent's
+    /// control) and wants the next step to re-fetch rather than reuse a
+    /// now-stale cached result.
+    pub async fn invalidate_action_cache(&self) {
+        self.inner.invalidate_action_cache().await;
+    }
+
+    async fn run_inner(&self, max_steps: usize) -> AgentResult<AgentHistoryList> {
         let mut history = AgentHistoryList::new();
 
         for step in 0..max_steps {
@@ -88,6 +320,17 @@ impl Agent {
                 break;
             }
 
+            // Cooperative pause point: block here instead of requesting a
+            // step, so `AgentSession::pause` can suspend a run without
+            // tearing down the processor task - a later `resume` continues
+            // the loop exactly where it left off.
+            while self.is_paused() {
+                if !self.is_running() || self.is_stop_requested().await {
+                    break;
+                }
+                tokio::time::sleep(Duration::from_millis(200)).await;
+            }
+
             // Run a single step
             match self.run_step().await {
                 Ok(output) => {
@@ -151,6 +394,12 @@ impl Agent {
     pub async fn stop(&self) -> AgentResult<()> {
         debug!("Stopping agent processor");
 
+        // Cancel first so a step already blocked inside a vision/LLM
+        // stream (`format_browser_state_with_vision`, `generate_and_execute_actions_streaming`)
+        // aborts immediately instead of running out its full timeout before
+        // the processor even reads the Stop command below.
+        self.inner.cancel_token.cancel();
+
         // Send stop command
         self.command_channel
             .send(AgentCommand::Stop)
@@ -195,6 +444,91 @@ impl Agent {
         }
     }
 
+    /// Suspend the run loop before its next step. The processor only
+    /// acknowledges the command (it keeps no pause state of its own);
+    /// `run_inner`'s client-side pause check does the suspending. A step
+    /// already in flight finishes normally. See `AgentSession::pause`.
+    pub async fn pause(&self) -> AgentResult<()> {
+        self.paused.store(true, Ordering::SeqCst);
+
+        self.command_channel
+            .send(AgentCommand::Pause)
+            .await
+            .map_err(|_| {
+                AgentError::ChannelClosed("Cannot pause agent: command channel closed".into())
+            })?;
+
+        let mut receiver = self.response_channel.lock().await;
+        match receiver.recv().await {
+            Some(AgentResponse::Paused) => Ok(()),
+            Some(other) => Err(AgentError::UnexpectedError(format!(
+                "Expected Paused response, got: {:?}",
+                other
+            ))),
+            // Channel closed = processor already dead; nothing left to pause.
+            None => Ok(()),
+        }
+    }
+
+    /// Resume a run loop suspended by [`Self::pause`]. See `AgentSession::resume`.
+    pub async fn resume(&self) -> AgentResult<()> {
+        self.paused.store(false, Ordering::SeqCst);
+
+        self.command_channel
+            .send(AgentCommand::Resume)
+            .await
+            .map_err(|_| {
+                AgentError::ChannelClosed("Cannot resume agent: command channel closed".into())
+            })?;
+
+        let mut receiver = self.response_channel.lock().await;
+        match receiver.recv().await {
+            Some(AgentResponse::Resumed) => Ok(()),
+            Some(other) => Err(AgentError::UnexpectedError(format!(
+                "Expected Resumed response, got: {:?}",
+                other
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// Whether [`Self::pause`] has suspended the run loop without a
+    /// matching [`Self::resume`] yet.
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::SeqCst)
+    }
+
+    /// Operator-initiated cancellation, distinct from [`Self::stop`] only in
+    /// the response tag so `AgentSession`/`AgentRegistry` can record *why* a
+    /// session went `Dead` when reporting its lifecycle state.
+    pub async fn cancel(&self) -> AgentResult<()> {
+        // See `Self::stop` - cancel the token before the command so an
+        // in-flight step unblocks immediately.
+        self.inner.cancel_token.cancel();
+
+        self.command_channel
+            .send(AgentCommand::Cancel)
+            .await
+            .map_err(|_| {
+                AgentError::ChannelClosed("Cannot cancel agent: command channel already closed".into())
+            })?;
+
+        let mut receiver = self.response_channel.lock().await;
+        match tokio::time::timeout(Duration::from_secs(5), receiver.recv()).await {
+            Ok(Some(AgentResponse::Cancelled)) => Ok(()),
+            Ok(Some(other)) => {
+                warn!("Expected Cancelled response, got: {:?}", other);
+                Err(AgentError::UnexpectedError(
+                    "Agent processor sent unexpected response to Cancel command".into(),
+                ))
+            }
+            Ok(None) => Ok(()),
+            Err(_) => Err(AgentError::UnexpectedError(
+                "Agent processor cancel timeout - processor may be stuck".into(),
+            )),
+        }
+    }
+
     /// Check if agent processor is still running
     ///
     /// Returns `true` if the processor task is active and accepting commands.
@@ -240,6 +574,24 @@ impl Agent {
                         }
                         break;
                     }
+                    AgentCommand::Pause => {
+                        if let Err(e) = resp_tx.send(AgentResponse::Paused).await {
+                            error!("Failed to send paused response: {}", e);
+                            break;
+                        }
+                    }
+                    AgentCommand::Resume => {
+                        if let Err(e) = resp_tx.send(AgentResponse::Resumed).await {
+                            error!("Failed to send resumed response: {}", e);
+                            break;
+                        }
+                    }
+                    AgentCommand::Cancel => {
+                        if let Err(e) = resp_tx.send(AgentResponse::Cancelled).await {
+                            error!("Failed to send cancelled response: {}", e);
+                        }
+                        break;
+                    }
                 }
             }
             debug!("Agent processor shutting down cleanly");
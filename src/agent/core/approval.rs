@@ -0,0 +1,85 @@
+//! Approval gate for side-effecting actions, following the `may_` execute-
+//! function convention aichat uses: an action is classified as either
+//! read-only (runs unattended) or "may-execute" (mutates page state and is
+//! routed through the configured [`ApprovalHandler`] first).
+//!
+//! [`AutoApproveHandler`] is the default - approve everything, so embedding
+//! an agent without an approval policy behaves exactly as before this gate
+//! existed.
+
+use async_trait::async_trait;
+
+use crate::agent::ActionModel;
+
+/// Whether an action can mutate page/application state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActionClass {
+    /// No side effects - runs without going through [`ApprovalHandler`].
+    ReadOnly,
+    /// May mutate state (navigation, clicks, typed input, form submission,
+    /// ...) - routed through [`ApprovalHandler::review`] before its MCP tool
+    /// is called.
+    MayExecute,
+}
+
+/// Classify an agent protocol action name. `extract_page_content` and the
+/// `done` sentinel (no MCP call at all) are the only read-only cases;
+/// everything else interacts with the live page and defaults to
+/// `MayExecute` so an unrecognized future action fails safe (gated) rather
+/// than silently running unattended.
+pub fn classify_action(action: &str) -> ActionClass {
+    match action {
+        "extract_page_content" | "done" => ActionClass::ReadOnly,
+        _ => ActionClass::MayExecute,
+    }
+}
+
+/// What an [`ApprovalHandler`] decided about a `MayExecute` action.
+#[derive(Debug, Clone)]
+pub enum ApprovalDecision {
+    /// Proceed with `tool_args` unchanged.
+    Approve,
+    /// Don't call the tool; record a failed `ActionResult` with `reason` as
+    /// its error instead.
+    Reject(String),
+    /// Proceed, but with `tool_args` replaced by this value first - e.g. to
+    /// strip a destructive parameter or redirect a navigation.
+    Rewrite(serde_json::Value),
+}
+
+/// Reviews a `MayExecute` action before [`super::action_executor::AgentInner::execute_one_action`]
+/// calls its mapped MCP tool. Implementors can gate on a human-in-the-loop
+/// prompt, a policy engine, a allow/deny list, or anything else; the trait
+/// only exposes the one decision point the step loop needs.
+#[async_trait]
+pub trait ApprovalHandler: Send + Sync {
+    async fn review(
+        &self,
+        action: &ActionModel,
+        tool_name: &str,
+        tool_args: &serde_json::Value,
+    ) -> ApprovalDecision;
+}
+
+/// Default handler - approves every action. Keeps agents built without an
+/// explicit approval policy behaving exactly as they did before this gate
+/// existed.
+pub struct AutoApproveHandler;
+
+impl AutoApproveHandler {
+    pub fn new() -> std::sync::Arc<dyn ApprovalHandler> {
+        std::sync::Arc::new(Self)
+    }
+}
+
+#[async_trait]
+impl ApprovalHandler for AutoApproveHandler {
+    async fn review(
+        &self,
+        _action: &ActionModel,
+        _tool_name: &str,
+        _tool_args: &serde_json::Value,
+    ) -> ApprovalDecision {
+        ApprovalDecision::Approve
+    }
+}
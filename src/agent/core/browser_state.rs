@@ -1,12 +1,24 @@
 use base64::Engine;
+use similar::TextDiff;
 use tokio::time::Duration;
-use tokio_stream::StreamExt;
 use tracing::{debug, warn};
-use kodegen_candle_agent::prelude::*;
-use cyrup_sugars::prelude::MessageChunk;
 
 use crate::agent::{AgentError, AgentResult, BrowserExtractTextResponse, BrowserScreenshotResponse};
+use super::config::PageStateMode;
+use super::inference_worker;
 use super::processor::AgentInner;
+use super::progress::AgentProgress;
+use super::vision_cache;
+
+/// Length of the plain-text sample sent in [`PageStateMode::Full`] mode.
+const FULL_SAMPLE_LEN: usize = 500;
+
+/// Minimum `similar::TextDiff::ratio()` (0.0 = completely different, 1.0 =
+/// identical) required to render a [`PageStateMode::Diff`] step as a diff.
+/// Below this, the page changed too much for a diff to be more useful than
+/// a fresh full sample - a near-total rewrite (e.g. a new page after
+/// navigation slipped past the `EventDelta::navigated` check).
+const MIN_DIFF_SIMILARITY: f64 = 0.4;
 
 /// Struct to hold browser state, screenshot path, and visual description
 #[derive(Debug, Clone)]
@@ -14,25 +26,111 @@ pub(super) struct BrowserStateWithScreenshot {
     pub(super) state: String,
     pub(super) screenshot_path: Option<String>,
     pub(super) visual_description: Option<String>,
+
+    /// Content hash of the decoded screenshot PNG, computed in
+    /// `get_browser_state` - lets `format_browser_state_with_vision` check
+    /// the disk-backed [`vision_cache`] before running the vision model.
+    pub(super) screenshot_hash: Option<String>,
+}
+
+/// Drained `browser_events` payload, just enough to decide whether a fresh
+/// screenshot/extract_text pass is warranted this step.
+struct EventDelta {
+    navigated: bool,
+    count: usize,
+    summary: String,
 }
 
 /// Browser state management implementation
 impl AgentInner {
+    /// Drain buffered CDP events since the last step and summarize them.
+    ///
+    /// Never fails the step on error - an unreachable `browser_events` just
+    /// means we fall back to treating this step as materially changed, same
+    /// as the first step of a session.
+    async fn drain_event_delta(&self) -> EventDelta {
+        match self
+            .call_mcp_tool_with_retry("browser_events", || {
+                self.mcp_client.call_tool("browser_events", serde_json::json!({}))
+            })
+            .await
+        {
+            Ok(result) => {
+                let parsed = result
+                    .content
+                    .first()
+                    .and_then(|c| c.as_text())
+                    .and_then(|t| serde_json::from_str::<serde_json::Value>(&t.text).ok());
+                let navigated = parsed
+                    .as_ref()
+                    .and_then(|v| v.get("navigated"))
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(true);
+                let count = parsed
+                    .as_ref()
+                    .and_then(|v| v.get("count"))
+                    .and_then(|v| v.as_u64())
+                    .unwrap_or(0) as usize;
+                let summary = parsed
+                    .as_ref()
+                    .and_then(|v| v.get("events"))
+                    .map(|events| format!("Events since last step: {}", events))
+                    .unwrap_or_else(|| "Events since last step: []".to_string());
+                EventDelta {
+                    navigated,
+                    count,
+                    summary,
+                }
+            }
+            Err(e) => {
+                warn!("browser_events failed: {}, assuming material change", e);
+                EventDelta {
+                    navigated: true,
+                    count: 0,
+                    summary: "Events since last step: <unavailable>".to_string(),
+                }
+            }
+        }
+    }
+
     /// Get current browser state for LLM context (HOT PATH!)
     ///
-    /// Fetches page content and optional screenshot via MCP tools.
-    /// This provides the LLM with current browser context for action planning.
+    /// Drains buffered CDP events first (see [`EventDelta`]) and only pays
+    /// for a fresh screenshot/extract_text pass when something material
+    /// changed - a new navigation, a network/console error, or a DOM
+    /// mutation. Otherwise the last known state is reused with a compact
+    /// event-delta note, since re-fetching would just observe the same page.
     ///
     /// Uses:
+    /// - browser_events: Drain buffered navigation/network/console/DOM signals
     /// - browser_extract_text: Get page text content
     /// - browser_screenshot: Get base64-encoded screenshot (optional)
     ///
     /// Returns BrowserStateWithScreenshot with text summary and screenshot.
     pub(super) async fn get_browser_state(&self) -> AgentResult<BrowserStateWithScreenshot> {
+        let delta = self.drain_event_delta().await;
+
+        let mut agent_state = self.agent_state.lock().await;
+        let cached_state = agent_state.last_valid_state().map(|s| s.to_string());
+        drop(agent_state);
+
+        if delta.count == 0 && !delta.navigated {
+            if let Some(cached_state) = cached_state {
+                debug!("No material browser events since last step, reusing cached state");
+                return Ok(BrowserStateWithScreenshot {
+                    state: format!("{}\n{}", cached_state, delta.summary),
+                    screenshot_path: None,
+                    visual_description: None,
+                    screenshot_hash: None,
+                });
+            }
+        }
+
         // Extract page content via MCP (HOT PATH!)
         let content = match self
-            .mcp_client
-            .call_tool("browser_extract_text", serde_json::json!({}))
+            .call_mcp_tool_with_retry("browser_extract_text", || {
+                self.mcp_client.call_tool("browser_extract_text", serde_json::json!({}))
+            })
             .await
         {
             Ok(result) => {
@@ -59,9 +157,11 @@ impl AgentInner {
         };
 
         // Get screenshot via MCP and save to temp file (HOT PATH!)
+        let mut screenshot_hash = None;
         let screenshot_path = match self
-            .mcp_client
-            .call_tool("browser_screenshot", serde_json::json!({}))
+            .call_mcp_tool_with_retry("browser_screenshot", || {
+                self.mcp_client.call_tool("browser_screenshot", serde_json::json!({}))
+            })
             .await
         {
             Ok(result) => {
@@ -92,6 +192,12 @@ impl AgentInner {
                         AgentError::UnexpectedError(format!("Base64 decode failed: {}", e))
                     })?;
 
+                    // Computed before the write below consumes `decoded_bytes`, so a
+                    // cache hit in `format_browser_state_with_vision` can skip the
+                    // vision model even though the temp file still gets written
+                    // (other callers of `get_browser_state` rely on it existing).
+                    screenshot_hash = Some(vision_cache::content_hash(&decoded_bytes));
+
                     // Create unique temp file path with nanosecond precision + PID
                     let temp_dir = std::env::temp_dir();
                     let duration = std::time::SystemTime::now()
@@ -130,38 +236,116 @@ impl AgentInner {
         };
 
         // Build state representation for LLM
-        let state = format!(
-            "Content Length: {} characters\nContent Sample: {}{}",
-            content.len(),
-            &content[0..content.len().min(500)],
-            if content.len() > 500 { "..." } else { "" }
-        );
+        let state = self.render_page_content(&content, &delta.summary).await;
 
-        // Store state for recovery if needed
+        // Store state for recovery if needed, alongside the screenshot hash
+        // so recovery logic (and the next call's change-detection below) can
+        // tell whether the page is visually stable without re-hashing.
         let mut agent_state = self.agent_state.lock().await;
+        let previous_screenshot_hash = agent_state.last_screenshot_hash();
+        let unchanged_since_last_step = screenshot_hash.is_some()
+            && previous_screenshot_hash.as_deref() == screenshot_hash.as_deref();
         agent_state.set_last_valid_state(state.clone());
+        agent_state.set_last_screenshot_hash(screenshot_hash.clone());
+        let cached_visual_description = if unchanged_since_last_step {
+            agent_state.last_visual_description()
+        } else {
+            None
+        };
         drop(agent_state);
 
+        if unchanged_since_last_step {
+            debug!("Screenshot hash unchanged since last step, reusing cached visual description");
+        }
+
         Ok(BrowserStateWithScreenshot {
             state,
             screenshot_path,
-            visual_description: None,
+            visual_description: cached_visual_description,
+            screenshot_hash,
         })
     }
 
+    /// Render `content` (the full `browser_extract_text` output) for the
+    /// prompt, per [`PageStateMode`].
+    ///
+    /// `Full` always sends a fresh [`FULL_SAMPLE_LEN`]-char sample, same as
+    /// before this mode existed. `Diff` instead diffs `content` against the
+    /// previous step's full text (see [`Self::diff_page_content`]) and
+    /// sends only the changed regions with surrounding context, falling
+    /// back to the `Full` rendering on the first step or a near-total
+    /// rewrite.
+    async fn render_page_content(&self, content: &str, event_summary: &str) -> String {
+        let body = match self.page_state_mode {
+            PageStateMode::Full => None,
+            PageStateMode::Diff => self.diff_page_content(content).await,
+        }
+        .unwrap_or_else(|| {
+            format!(
+                "Content Length: {} characters\nContent Sample: {}{}",
+                content.len(),
+                &content[0..content.len().min(FULL_SAMPLE_LEN)],
+                if content.len() > FULL_SAMPLE_LEN { "..." } else { "" },
+            )
+        });
+        format!("{}\n{}", body, event_summary)
+    }
+
+    /// Diff `content` against the previous step's full extracted text
+    /// (stashed in `AgentState` regardless of outcome, so the next call
+    /// always has something fresh to diff against), returning `None` when
+    /// there's nothing to diff against yet (first step) or the page
+    /// changed too much for a diff to be worthwhile (see
+    /// [`MIN_DIFF_SIMILARITY`]) - either case falls back to the full-sample
+    /// rendering in [`Self::render_page_content`].
+    async fn diff_page_content(&self, content: &str) -> Option<String> {
+        let mut agent_state = self.agent_state.lock().await;
+        let previous = agent_state.last_page_text();
+        agent_state.set_last_page_text(content.to_string());
+        drop(agent_state);
+
+        let previous = previous?;
+        let diff = TextDiff::from_lines(&previous, content);
+        if diff.ratio() < MIN_DIFF_SIMILARITY {
+            return None;
+        }
+
+        // `unified_diff` keeps a few lines of surrounding context around
+        // each changed hunk, so element references in the unchanged
+        // portions of the page still read naturally alongside the change.
+        let diff_text = diff.unified_diff().context_radius(3).to_string();
+
+        Some(format!(
+            "Content Length: {} characters\nContent Diff (vs. previous step):\n{}",
+            content.len(),
+            if diff_text.is_empty() {
+                "(no textual changes)"
+            } else {
+                &diff_text
+            }
+        ))
+    }
+
     /// Format browser state with vision-based screenshot analysis
     ///
-    /// Uses CandleFluentAi::vision() to analyze screenshots and generate
-    /// detailed visual descriptions of UI elements and layout.
+    /// Uses the dedicated vision worker thread (see [`inference_worker`])
+    /// to analyze screenshots and generate detailed visual descriptions of
+    /// UI elements and layout.
     ///
     /// Populates browser_state.visual_description with the vision analysis result
-    /// for potential caching/reuse.
+    /// for potential caching/reuse, and checks/populates the disk-backed
+    /// [`vision_cache`] (keyed on `screenshot_hash` plus the vision prompt) so a
+    /// revisited, visually identical page skips the vision model entirely -
+    /// even across sessions and process restarts, unlike `visual_description`.
     pub(super) async fn format_browser_state_with_vision(
         &self,
         browser_state: &mut BrowserStateWithScreenshot,
     ) -> AgentResult<String> {
         let mut state_description = format!("Current browser state:\n{}", browser_state.state);
 
+        // Generate new vision analysis
+        let vision_query = "Describe the visible UI elements, their layout, and any interactive components (buttons, links, forms, input fields, etc.) in detail.";
+
         // Add vision-based screenshot analysis if available
         if let Some(screenshot_path) = &browser_state.screenshot_path {
             state_description.push_str("\n\nVisual Analysis:\n");
@@ -171,58 +355,23 @@ impl AgentInner {
                 debug!("Using cached visual description");
                 cached.clone()
             } else {
-                // Generate new vision analysis
-                let vision_query = "Describe the visible UI elements, their layout, and any interactive components (buttons, links, forms, input fields, etc.) in detail.";
-
-                // Wrap entire stream consumption in timeout
-                let vision_timeout = Duration::from_secs(self.vision_timeout_secs);
-                let result = tokio::time::timeout(vision_timeout, async {
-                    let mut description = String::with_capacity(4096);
-                    let mut stream =
-                        CandleFluentAi::vision().describe_image(screenshot_path, vision_query);
-
-                    while let Some(chunk) = stream.next().await {
-                        if let Some(error) = chunk.error() {
-                            return Err(format!("Vision analysis error: {}", error));
-                        }
-
-                        if !chunk.text.is_empty() {
-                            description.push_str(&chunk.text);
-                        }
-
-                        if chunk.is_final {
-                            if let Some(stats) = &chunk.stats {
-                                debug!(
-                                    "Vision analysis: {} tokens generated",
-                                    stats.tokens_generated
-                                );
-                            }
-                            return Ok(description);
-                        }
-                    }
-                    Err("Vision stream ended without final chunk".to_string())
-                })
-                .await;
-
-                match result {
-                    Ok(Ok(desc)) => {
+                match self
+                    .resolve_visual_description(
+                        screenshot_path,
+                        browser_state.screenshot_hash.as_deref(),
+                        vision_query,
+                    )
+                    .await?
+                {
+                    VisualDescription::Resolved(desc) => {
                         browser_state.visual_description = Some(desc.clone());
+                        self.agent_state
+                            .lock()
+                            .await
+                            .set_last_visual_description(desc.clone());
                         desc
                     }
-                    Ok(Err(e)) => {
-                        warn!("Vision analysis failed: {}", e);
-                        format!("[Vision analysis failed: {}]", e)
-                    }
-                    Err(_) => {
-                        warn!(
-                            "Vision analysis timed out after {}s",
-                            self.vision_timeout_secs
-                        );
-                        format!(
-                            "[Vision analysis timed out after {}s]",
-                            self.vision_timeout_secs
-                        )
-                    }
+                    VisualDescription::Degraded(placeholder) => placeholder,
                 }
             };
 
@@ -240,4 +389,178 @@ impl AgentInner {
 
         Ok(state_description)
     }
+
+    /// Resolve a visual description for `screenshot_path`, checking the
+    /// in-memory [`super::vision_cache::VisionMemoCache`] and disk-backed
+    /// [`vision_cache`] (in that order) before falling back to
+    /// [`Self::run_vision_model`].
+    ///
+    /// `screenshot_hash` being `None` (screenshot captured but hashing
+    /// failed upstream) skips both cache layers entirely rather than
+    /// caching under a degenerate key - the model still runs, it's just
+    /// never memoized.
+    ///
+    /// Concurrent callers that land on the same `screenshot_hash` +
+    /// `vision_query` serialize on [`super::vision_cache::VisionMemoCache::lock_for_key`]
+    /// so only the first actually drives the vision model; the rest find
+    /// its result already in the memo cache once they acquire the lock.
+    async fn resolve_visual_description(
+        &self,
+        screenshot_path: &str,
+        screenshot_hash: Option<&str>,
+        vision_query: &str,
+    ) -> AgentResult<VisualDescription> {
+        let Some(hash) = screenshot_hash else {
+            return self.run_vision_model(screenshot_path, vision_query).await;
+        };
+        let memo_key = vision_cache::cache_key(hash, vision_query);
+
+        if let Some(desc) = self.vision_memo.get(&memo_key).await {
+            debug!("Using memo-cached visual description");
+            return Ok(VisualDescription::Resolved(desc));
+        }
+
+        let in_flight = self.vision_memo.lock_for_key(&memo_key).await;
+        let _guard = in_flight.lock().await;
+
+        // Another caller may have filled the memo cache while we waited for
+        // the lock above - check again before touching the disk cache.
+        if let Some(desc) = self.vision_memo.get(&memo_key).await {
+            debug!("Using memo-cached visual description");
+            self.vision_memo.release_key(&memo_key).await;
+            return Ok(VisualDescription::Resolved(desc));
+        }
+
+        if let Some(desc) = vision_cache::get(hash, vision_query).await {
+            debug!("Using disk-cached visual description");
+            self.vision_memo.put(memo_key.clone(), desc.clone()).await;
+            self.vision_memo.release_key(&memo_key).await;
+            return Ok(VisualDescription::Resolved(desc));
+        }
+
+        let outcome = self.run_vision_model(screenshot_path, vision_query).await;
+        if let Ok(VisualDescription::Resolved(desc)) = &outcome {
+            vision_cache::put(hash, vision_query, desc).await;
+            self.vision_memo.put(memo_key.clone(), desc.clone()).await;
+        }
+        self.vision_memo.release_key(&memo_key).await;
+        outcome
+    }
+
+    /// Run the vision model on the dedicated worker thread (see
+    /// `inference_worker`) instead of driving `describe_image` inline, so a
+    /// slow forward pass can't stall this tokio worker thread for the
+    /// length of the vision timeout. Consuming its chunk stream with a
+    /// timeout works exactly as it did when the stream ran inline, except
+    /// each `rx.recv()` also races `self.cancel_token.cancelled()` so
+    /// `Agent::stop`/`cancel` abort the stream immediately instead of
+    /// waiting out the full timeout.
+    ///
+    /// A timeout or ordinary model/worker error degrades to a placeholder
+    /// description rather than failing the step; cancellation bails out of
+    /// the whole step with [`AgentError::Stopped`] after cleaning up the
+    /// temp screenshot itself, since the caller won't reach its own cleanup.
+    async fn run_vision_model(
+        &self,
+        screenshot_path: &str,
+        vision_query: &str,
+    ) -> AgentResult<VisualDescription> {
+        self.emit_progress(AgentProgress::VisionStarted);
+        let vision_timeout = Duration::from_secs(self.vision_timeout_secs);
+        let result = tokio::time::timeout(vision_timeout, async {
+            let mut description = String::with_capacity(4096);
+            let mut rx = inference_worker::submit_vision_job(
+                screenshot_path.to_string(),
+                vision_query.to_string(),
+            )
+            .map_err(VisionOutcome::Failed)?;
+
+            loop {
+                let chunk = tokio::select! {
+                    chunk = rx.recv() => chunk,
+                    () = self.cancel_token.cancelled() => return Err(VisionOutcome::Cancelled),
+                };
+                match chunk {
+                    Some(inference_worker::TokenChunk::Text(text)) => {
+                        description.push_str(&text)
+                    }
+                    Some(inference_worker::TokenChunk::Complete { tokens_generated }) => {
+                        if let Some(tokens) = tokens_generated {
+                            debug!("Vision analysis: {} tokens generated", tokens);
+                        }
+                        return Ok((description, tokens_generated));
+                    }
+                    Some(inference_worker::TokenChunk::Error(error)) => {
+                        return Err(VisionOutcome::Failed(format!(
+                            "Vision analysis error: {}",
+                            error
+                        )));
+                    }
+                    None => {
+                        return Err(VisionOutcome::Failed(
+                            "Vision stream ended without final chunk".to_string(),
+                        ));
+                    }
+                }
+            }
+        })
+        .await;
+
+        match result {
+            Ok(Ok((desc, tokens))) => {
+                self.emit_progress(AgentProgress::VisionCompleted { tokens });
+                Ok(VisualDescription::Resolved(desc))
+            }
+            Ok(Err(VisionOutcome::Cancelled)) => {
+                debug!("Vision analysis cancelled, cleaning up screenshot");
+                if let Err(e) = tokio::fs::remove_file(screenshot_path).await {
+                    warn!(
+                        "Failed to cleanup screenshot file {}: {}",
+                        screenshot_path, e
+                    );
+                }
+                self.emit_progress(AgentProgress::VisionCompleted { tokens: None });
+                Err(AgentError::Stopped)
+            }
+            Ok(Err(VisionOutcome::Failed(e))) => {
+                warn!("Vision analysis failed: {}", e);
+                self.emit_progress(AgentProgress::VisionCompleted { tokens: None });
+                Ok(VisualDescription::Degraded(format!(
+                    "[Vision analysis failed: {}]",
+                    e
+                )))
+            }
+            Err(_) => {
+                warn!(
+                    "Vision analysis timed out after {}s",
+                    self.vision_timeout_secs
+                );
+                self.emit_progress(AgentProgress::VisionCompleted { tokens: None });
+                Ok(VisualDescription::Degraded(format!(
+                    "[Vision analysis timed out after {}s]",
+                    self.vision_timeout_secs
+                )))
+            }
+        }
+    }
+}
+
+/// Why the vision stream's `tokio::time::timeout` body exited without a
+/// description, distinguishing a cancelled step (bail out of the whole
+/// step with [`AgentError::Stopped`]) from an ordinary model/worker error
+/// (degrade to a placeholder description and keep going).
+enum VisionOutcome {
+    Cancelled,
+    Failed(String),
+}
+
+/// Outcome of [`AgentInner::resolve_visual_description`]: a description
+/// that came from a cache hit or a fresh successful vision call
+/// (cacheable, and safe to remember on [`BrowserStateWithScreenshot`]/
+/// `AgentState`) versus a placeholder standing in for a failed or timed-out
+/// vision call (must not be cached or remembered, so the next step tries
+/// again instead of being stuck with the placeholder).
+enum VisualDescription {
+    Resolved(String),
+    Degraded(String),
 }
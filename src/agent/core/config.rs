@@ -1,4 +1,6 @@
+use super::retry::RetryPolicy;
 use crate::agent::prompts::{AgentMessagePrompt, SystemPrompt};
+use crate::browser::webauthn::{CredentialSeed, VirtualAuthenticatorConfig};
 
 /// Configuration parameters for agent behavior
 #[derive(Debug, Clone)]
@@ -7,6 +9,98 @@ pub struct AgentConfig {
     pub max_tokens: u64,
     pub vision_timeout_secs: u64,
     pub llm_timeout_secs: u64,
+    pub retry_policy: RetryPolicy,
+
+    /// CDP resource types (`"Image"`, `"Font"`, `"Media"`, ...) to block for
+    /// the whole lifetime of this agent's browser lease, applied via
+    /// `BrowserManager::request_interceptor` before the agent's first step -
+    /// a resource-blocking profile for runs that don't need rendered media.
+    /// Empty by default. Not yet settable through `browser_agent`'s PROMPT
+    /// action: `BrowserAgentArgs` is defined in the external
+    /// `kodegen_mcp_schema` crate, which doesn't have a field for it, so
+    /// only callers constructing `AgentConfig` directly (outside the MCP
+    /// surface) can use this today.
+    pub block_resource_types: Vec<String>,
+
+    /// Name of a `BrowserManager::cookie_profiles` profile to make active
+    /// before this agent's first navigation, so it resumes an
+    /// authenticated session instead of hitting a login wall (see
+    /// `browser_cookies`'s SAVE_PROFILE/LOAD_PROFILE actions, and
+    /// `ResearchOptions::cookie_profile` for the same pattern in
+    /// `DeepResearch`). `None` by default. Not yet settable through
+    /// `browser_agent`'s PROMPT action: `BrowserAgentArgs` is defined in
+    /// the external `kodegen_mcp_schema` crate, which has no `cookies` or
+    /// `cookie_profile` field yet, so only callers constructing
+    /// `AgentConfig` directly (outside the MCP surface) can use this today.
+    pub cookie_profile: Option<String>,
+
+    /// Virtual WebAuthn authenticator (see `crate::browser::webauthn`) to
+    /// provision on this agent's page before it starts navigating, pre-seeded
+    /// with `webauthn_credentials`, so it can satisfy a passkey/2FA prompt
+    /// unattended instead of getting stuck on one. `None` by default, set
+    /// via [`Self::with_webauthn_authenticator`]. Not yet settable through
+    /// `browser_agent`'s PROMPT action: `BrowserAgentArgs` is defined in the
+    /// external `kodegen_mcp_schema` crate, which has no matching field, so
+    /// only callers constructing `AgentConfig` directly (outside the MCP
+    /// surface) can use this today - see `tools::browser_agent`'s
+    /// provisioning block, which is exercised whenever this is `Some`.
+    pub webauthn_authenticator: Option<VirtualAuthenticatorConfig>,
+
+    /// Credentials to pre-provision on `webauthn_authenticator` once it's
+    /// added. Ignored if `webauthn_authenticator` is `None`.
+    pub webauthn_credentials: Vec<CredentialSeed>,
+
+    /// Install a SIGTERM/SIGINT (Ctrl-C) handler that cancels this agent's
+    /// in-flight step the same way `Agent::stop`/`Agent::cancel` do, so a
+    /// process-level shutdown signal doesn't have to wait out a full
+    /// vision/LLM timeout. `false` by default - a host embedding several
+    /// agents, or one that already owns signal handling itself, should
+    /// leave this off and call `Agent::stop` from its own handler instead.
+    pub install_signal_handler: bool,
+
+    /// How `get_browser_state` renders extracted page text into the prompt.
+    /// `Full` by default; see [`PageStateMode`].
+    pub page_state_mode: PageStateMode,
+
+    /// Per-attempt timeout for every `mcp_client.call_tool` invocation
+    /// (`get_browser_state`'s `browser_extract_text`/`browser_screenshot`/
+    /// `browser_events` calls, and each action's tool call in
+    /// `execute_one_action`), so a hung MCP server can't silently stall a
+    /// step. 15s by default. A value of `0` disables the timeout for that
+    /// call (wait indefinitely) rather than racing a zero-duration timeout.
+    /// `execute_one_action` overrides this per action via
+    /// `ActionSpec::call_timeout_secs` when set, so actions like `go_to_url`
+    /// that legitimately take longer than the default can use their own
+    /// bound.
+    pub mcp_call_timeout_secs: u64,
+
+    /// Additional attempts (beyond the first) after an MCP tool call times
+    /// out or fails transiently, backed off via `retry_policy.delay_for`.
+    /// Exhausting retries degrades gracefully exactly as it does today
+    /// (empty content, no screenshot, "material change" assumed) for the
+    /// optional calls in `get_browser_state`. Applies to those
+    /// `get_browser_state` calls only: action tool calls in
+    /// `execute_one_action` retry per `retry_policy.max_attempts` instead.
+    /// 2 by default.
+    pub mcp_max_retries: usize,
+}
+
+/// How `get_browser_state` represents extracted page text in the prompt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PageStateMode {
+    /// Always send a fresh sample of the full extracted text, as before.
+    /// Simplest and most robust, but re-sends unchanged content every step.
+    #[default]
+    Full,
+
+    /// Diff the current step's extracted text against the previous step's
+    /// using the `similar` crate, and send only the changed regions (as a
+    /// unified diff with a few lines of surrounding context) instead of a
+    /// fresh full sample. Falls back to `Full` rendering on the first step
+    /// (nothing to diff against yet) or when the similarity between the two
+    /// texts falls below a threshold (a near-total rewrite, where
+    /// a diff would be noisier than just the new content).
+    Diff,
 }
 
 /// Prompt configuration for agent
@@ -16,6 +110,28 @@ pub struct PromptConfig {
     pub agent_prompt: AgentMessagePrompt,
 }
 
+impl AgentConfig {
+    /// Provision `authenticator` on this agent's page before its first step
+    /// (see [`Self::webauthn_authenticator`]). The only setter for that
+    /// field today: `BrowserAgentArgs` (external `kodegen_mcp_schema`
+    /// crate) has no matching field, so `browser_agent`'s PROMPT action
+    /// can't reach this - only a caller embedding this crate directly and
+    /// building its own `AgentConfig` can.
+    #[must_use]
+    pub fn with_webauthn_authenticator(mut self, authenticator: VirtualAuthenticatorConfig) -> Self {
+        self.webauthn_authenticator = Some(authenticator);
+        self
+    }
+
+    /// Credentials to pre-provision on the authenticator set by
+    /// [`Self::with_webauthn_authenticator`]. Ignored if that wasn't called.
+    #[must_use]
+    pub fn with_webauthn_credentials(mut self, credentials: Vec<CredentialSeed>) -> Self {
+        self.webauthn_credentials = credentials;
+        self
+    }
+}
+
 impl Default for AgentConfig {
     fn default() -> Self {
         Self {
@@ -23,6 +139,15 @@ impl Default for AgentConfig {
             max_tokens: 4096,
             vision_timeout_secs: 30,
             llm_timeout_secs: 120,
+            retry_policy: RetryPolicy::default(),
+            block_resource_types: Vec::new(),
+            cookie_profile: None,
+            webauthn_authenticator: None,
+            webauthn_credentials: Vec::new(),
+            install_signal_handler: false,
+            page_state_mode: PageStateMode::default(),
+            mcp_call_timeout_secs: 15,
+            mcp_max_retries: 2,
         }
     }
 }
@@ -0,0 +1,118 @@
+//! Dedicated OS thread for Candle vision inference.
+//!
+//! `CandleActionProvider::generate_actions` (`action_provider.rs`) already
+//! keeps the chat decode loop off the tokio reactor via
+//! `tokio::task::spawn_blocking` + `Handle::block_on`, paying the cost of a
+//! fresh blocking-pool thread per call. `format_browser_state_with_vision`
+//! instead drove `CandleFluentAi::vision()` directly inside the async step
+//! loop, so a `describe_image` forward pass could stall every other task on
+//! that worker thread for the length of the vision timeout. This gives
+//! vision the same off-reactor treatment, but as one persistent worker
+//! thread - rather than a fresh blocking-pool thread per call - since the
+//! underlying model stays resident between calls either way and only one
+//! forward pass can run at a time regardless.
+//!
+//! The worker owns its own single-threaded tokio runtime purely to drive
+//! the `describe_image` stream; it never touches the process's main
+//! runtime. Jobs arrive over a `crossbeam_channel`, and each chunk the
+//! model produces is forwarded back over a bounded `tokio::sync::mpsc`
+//! channel as a [`TokenChunk`], so the async side keeps consuming it with
+//! `rx.recv().await` exactly as it consumed the inline stream before. The
+//! worker exits once its job sender is dropped, which only happens if this
+//! process-wide static is itself torn down.
+
+use std::sync::OnceLock;
+
+use crossbeam_channel::Sender;
+use cyrup_sugars::prelude::MessageChunk;
+use futures::StreamExt;
+use kodegen_candle_agent::prelude::*;
+use tokio::sync::mpsc;
+
+/// One piece of a streamed vision response, as forwarded by the worker
+/// thread. Mirrors the three things `format_browser_state_with_vision`
+/// used to read straight off the `describe_image` stream: accumulated
+/// text, the final chunk's token count, and a terminal error.
+pub(super) enum TokenChunk {
+    Text(String),
+    Complete { tokens_generated: Option<u64> },
+    Error(String),
+}
+
+struct VisionJob {
+    image_path: String,
+    prompt: String,
+    reply: mpsc::Sender<TokenChunk>,
+}
+
+fn job_sender() -> &'static Sender<VisionJob> {
+    static JOBS: OnceLock<Sender<VisionJob>> = OnceLock::new();
+    JOBS.get_or_init(|| {
+        let (tx, rx) = crossbeam_channel::unbounded::<VisionJob>();
+        std::thread::Builder::new()
+            .name("candle-vision-worker".to_string())
+            .spawn(move || {
+                let rt = tokio::runtime::Builder::new_current_thread()
+                    .enable_all()
+                    .build()
+                    .expect("failed to build candle-vision-worker runtime");
+                rt.block_on(async move {
+                    while let Ok(job) = rx.recv() {
+                        run_vision_job(job).await;
+                    }
+                });
+            })
+            .expect("failed to spawn candle-vision-worker thread");
+        tx
+    })
+}
+
+async fn run_vision_job(job: VisionJob) {
+    let mut stream = CandleFluentAi::vision().describe_image(&job.image_path, &job.prompt);
+
+    while let Some(chunk) = stream.next().await {
+        let forwarded = if let Some(error) = chunk.error() {
+            TokenChunk::Error(error.to_string())
+        } else if chunk.is_final {
+            TokenChunk::Complete {
+                tokens_generated: chunk.stats.as_ref().map(|stats| stats.tokens_generated),
+            }
+        } else if !chunk.text.is_empty() {
+            TokenChunk::Text(chunk.text.clone())
+        } else {
+            continue;
+        };
+
+        let is_terminal = matches!(forwarded, TokenChunk::Complete { .. } | TokenChunk::Error(_));
+        if job.reply.send(forwarded).await.is_err() || is_terminal {
+            // Either the async side timed out and dropped its receiver, or
+            // we just sent the terminal chunk ourselves - either way this
+            // job is done.
+            return;
+        }
+    }
+}
+
+/// Submit a vision job to the dedicated worker thread and stream its
+/// [`TokenChunk`]s back.
+///
+/// Returns an error immediately - rather than hanging - if the worker
+/// thread has exited, since a dropped job channel means the receive loop
+/// panicked. Otherwise the caller drains the returned receiver the same
+/// way it drained the inline stream before: `rx.recv().await` until a
+/// `TokenChunk::Complete`/`Error`, or the channel closes.
+pub(super) fn submit_vision_job(
+    image_path: String,
+    prompt: String,
+) -> Result<mpsc::Receiver<TokenChunk>, String> {
+    let (reply_tx, reply_rx) = mpsc::channel(32);
+    job_sender()
+        .send(VisionJob {
+            image_path,
+            prompt,
+            reply: reply_tx,
+        })
+        .map_err(|_| "Candle vision worker thread has exited".to_string())?;
+
+    Ok(reply_rx)
+}
@@ -1,45 +1,34 @@
-use tokio::time::Duration;
-use tokio_stream::StreamExt;
-use tracing::{debug, warn};
-use kodegen_candle_agent::prelude::*;
+use tracing::warn;
 
-use crate::agent::{AgentError, AgentLLMResponse, AgentResult};
-use super::processor::AgentInner;
+use super::action_provider::ActionProviderRequest;
 use super::browser_state::BrowserStateWithScreenshot;
+use super::processor::AgentInner;
+use super::progress::AgentProgress;
+use crate::agent::{ActionModel, ActionResult, AgentLLMResponse, AgentResult, AgentUsage, CurrentState};
 
 /// LLM integration implementation
 impl AgentInner {
-    /// Generate actions using CandleFluentAi LLM
-    ///
-    /// Combines system prompt, task description, and browser state into a query,
-    /// then streams the LLM response and parses actions from it.
-    pub(super) async fn generate_actions_with_llm(
+    /// Build the system prompt, user query, and screenshot bytes a step's
+    /// [`ActionProviderRequest`] needs.
+    async fn build_planning_inputs(
         &self,
         browser_state: &mut BrowserStateWithScreenshot,
-    ) -> AgentResult<AgentLLMResponse> {
+    ) -> AgentResult<(String, String, Option<Vec<u8>>)> {
+        // Read the screenshot bytes before vision analysis consumes (and
+        // deletes) the temp file, so the action provider can still see the
+        // image even if it doesn't need the vision-description text path.
+        let screenshot = match &browser_state.screenshot_path {
+            Some(path) => tokio::fs::read(path).await.ok(),
+            None => None,
+        };
+
         // Build browser state message with vision analysis
         let browser_state_msg = self.format_browser_state_with_vision(browser_state).await?;
 
-        // Build system prompt with available actions
-        let actions_description = r##"Available Actions:
-- go_to_url: Navigate to a URL (parameters: url)
-- click_element: Click an element (parameters: selector OR index)
-- input_text: Type text into an element (parameters: selector OR index, text)
-- scroll: Scroll the page (parameters: direction ["up"|"down"|"left"|"right"], amount [pixels])
-- extract_page_content: Extract page text content (no parameters)
-- done: Mark task as complete (parameters: result [description of completion])
-
-Parameter Notes:
-- selector: CSS selector string (e.g., "#submit", ".button", "input[name='email']")
-- index: Numeric index for data-mcp-index attributes (converted to selector automatically)
-- Use selector for precision, index for LLM-generated element references
-
-You must respond with valid JSON matching the AgentLLMResponse schema with an 'action' array."##;
-
         let system_prompt = format!(
             "{}\n\n{}\n\nYou are a browser automation agent. Analyze the browser state and generate appropriate actions.",
             self.system_prompt.build_prompt(),
-            actions_description
+            self.action_registry.actions_description()
         );
 
         // Build user query using AgentMessagePrompt (CRITICAL: integrates agent_prompt field)
@@ -48,88 +37,113 @@ You must respond with valid JSON matching the AgentLLMResponse schema with an 'a
             self.agent_prompt
                 .build_message_prompt(&browser_state_msg, &self.task, &self.add_infos);
 
-        // Stream LLM response with timeout protection
-        let llm_timeout = Duration::from_secs(self.llm_timeout_secs);
-        let full_response = match tokio::time::timeout(llm_timeout, async {
-            // Pre-allocate based on max_tokens parameter
-            // Average: ~4 bytes per token for English text
-            let expected_bytes = (self.max_tokens as usize) * 4;
-            let mut response = String::with_capacity(expected_bytes);
-            let mut stream = CandleFluentAi::agent_role("browser-agent")
-                .temperature(self.temperature)
-                .max_tokens(self.max_tokens)
-                .system_prompt(&system_prompt)
-                .into_agent()
-                .map_err(|e| AgentError::UnexpectedError(e.to_string()))?
-                .chat(move |_conversation| {
-                    let query = user_query.clone();
-                    async move { CandleChatLoop::UserPrompt(query) }
-                })
-                .map_err(|e| AgentError::LlmError(e.to_string()))?;
+        Ok((system_prompt, user_query, screenshot))
+    }
 
-            // Collect streaming response
-            while let Some(chunk) = stream.next().await {
-                match chunk {
-                    CandleMessageChunk::Text(text) => {
-                        response.push_str(&text);
-                    }
-                    CandleMessageChunk::Complete {
-                        token_count,
-                        elapsed_secs,
-                        ..
-                    } => {
-                        if let (Some(tokens), Some(elapsed)) = (token_count, elapsed_secs) {
-                            debug!("LLM generated {} tokens in {:.2}s", tokens, elapsed);
+    /// Plan and execute a step's actions incrementally: as soon as the
+    /// configured [`super::action_provider::ActionProvider`] streams back a
+    /// complete tool call, hand it straight to
+    /// [`super::action_executor::AgentInner::execute_one_action`] instead of
+    /// waiting for the whole turn to finish planning first. For a
+    /// multi-action step this overlaps the latency of planning action N+1
+    /// with executing action N, rather than paying for both in sequence.
+    /// Backends that can't stream incrementally (e.g.
+    /// [`super::action_provider::OpenAiActionProvider`]'s non-streaming
+    /// HTTP call) still work here via `ActionProvider::generate_actions_stream`'s
+    /// default implementation, which just replays its one-shot result
+    /// through the same channel.
+    ///
+    /// Returns the planned [`AgentLLMResponse`] (for `process_step` to build
+    /// `AgentOutput` from) alongside the already-executed `ActionResult`s.
+    pub(super) async fn generate_and_execute_actions_streaming(
+        &self,
+        step: usize,
+        browser_state: &mut BrowserStateWithScreenshot,
+    ) -> AgentResult<(AgentLLMResponse, Vec<ActionResult>)> {
+        let (system_prompt, user_query, screenshot) =
+            self.build_planning_inputs(browser_state).await?;
+
+        // Small buffer: the provider only needs to stay a chunk or two ahead
+        // of execution, not build up an unbounded backlog of planned-but-not-
+        // yet-executed actions.
+        let (tx, mut rx) = tokio::sync::mpsc::channel::<AgentResult<ActionModel>>(4);
+
+        let request = ActionProviderRequest {
+            system_prompt: &system_prompt,
+            user_query: &user_query,
+            screenshot,
+            temperature: self.temperature,
+            max_tokens: self.max_tokens,
+            timeout_secs: self.llm_timeout_secs,
+            cancel_token: self.cancel_token.clone(),
+            action_registry: std::sync::Arc::clone(&self.action_registry),
+        };
+        let produce = self.action_provider.generate_actions_stream(request, tx);
+
+        let mut planned = Vec::new();
+        let mut results = Vec::new();
+        let consume = async {
+            while let Some(item) = rx.recv().await {
+                match item {
+                    Ok(action) => {
+                        planned.push(action.clone());
+                        // Still drain the rest of a partially-completed
+                        // stream once the cap is hit - the provider's own
+                        // generation must run to completion regardless - just
+                        // stop executing past it.
+                        if planned.len() > self.max_actions_per_step {
+                            if planned.len() == self.max_actions_per_step + 1 {
+                                warn!(
+                                    "Agent generated more than {} actions, executing only the first {}",
+                                    self.max_actions_per_step, self.max_actions_per_step
+                                );
+                            }
+                            continue;
                         }
-                        return Ok(response);
+                        results.push(self.execute_one_action(step, action).await?);
                     }
-                    CandleMessageChunk::Error(err) => {
-                        return Err(AgentError::LlmError(err.to_string()));
+                    Err(e) if results.is_empty() => return Err(e),
+                    Err(e) => {
+                        // Actions already executed have real side effects in
+                        // the browser that can't be undone - surface what
+                        // ran instead of discarding it because planning
+                        // failed partway through the turn.
+                        warn!("LLM tool-call stream ended with an error after {} action(s) ran: {}", results.len(), e);
+                        break;
                     }
-                    _ => {}
                 }
             }
-            // Stream ended without Complete chunk
-            Err(AgentError::LlmError(
-                "LLM stream ended without Complete chunk".into(),
-            ))
-        })
-        .await
-        {
-            Ok(Ok(resp)) => resp,
-            Ok(Err(e)) => return Err(e),
-            Err(_) => {
-                return Err(AgentError::LlmError(format!(
-                    "LLM generation timed out after {}s",
-                    self.llm_timeout_secs
-                )));
-            }
+            Ok(())
         };
 
-        // Parse actions from JSON response
-        let agent_response: AgentLLMResponse =
-            serde_json::from_str(&full_response).map_err(|e| {
-                AgentError::LlmError(format!(
-                    "Failed to parse LLM response as JSON: {}. Response: {}",
-                    e, full_response
-                ))
-            })?;
+        let (produce_result, consume_result) = tokio::join!(produce, consume);
+        consume_result?;
+        let usage: AgentUsage = produce_result?;
 
-        // Limit the number of actions
-        let limited_actions = if agent_response.action.len() > self.max_actions_per_step {
-            warn!(
-                "Agent generated {} actions, limiting to {}",
-                agent_response.action.len(),
-                self.max_actions_per_step
-            );
-            agent_response.action[0..self.max_actions_per_step].to_vec()
-        } else {
-            agent_response.action
-        };
+        // Match `action`/`results` up 1:1 - anything past the cap was
+        // drained from the provider's stream but never executed.
+        planned.truncate(self.max_actions_per_step);
+
+        self.emit_progress(AgentProgress::ActionsPlanned {
+            count: planned.len(),
+        });
 
-        Ok(AgentLLMResponse {
-            current_state: agent_response.current_state,
-            action: limited_actions,
-        })
+        Ok((
+            AgentLLMResponse {
+                // No narrative text to fill this with - same as the
+                // tool-call path in `action_provider::finish_response`.
+                current_state: CurrentState {
+                    prev_action_evaluation: String::new(),
+                    important_contents: String::new(),
+                    task_progress: String::new(),
+                    future_plans: String::new(),
+                    thought: String::new(),
+                    summary: format!("{} tool call(s) (streamed)", planned.len()),
+                },
+                action: planned,
+                usage,
+            },
+            results,
+        ))
     }
 }
@@ -4,6 +4,19 @@ use crate::agent::AgentOutput;
 pub(super) enum AgentCommand {
     RunStep,
     Stop,
+    /// Acknowledged with [`AgentResponse::Paused`]. Doesn't interrupt a
+    /// step already in flight - `Agent::run_inner`'s loop is what actually
+    /// stops requesting new steps, via `Agent::is_paused`; the processor
+    /// keeps no pause state and only sends the acknowledgement. See
+    /// `AgentSession::pause`.
+    Pause,
+    /// Acknowledged with [`AgentResponse::Resumed`]. See `AgentSession::resume`.
+    Resume,
+    /// Like `Stop`, but acknowledged with [`AgentResponse::Cancelled`] so
+    /// callers can tell an operator-initiated cancellation apart from a
+    /// graceful completion when reporting a session's final lifecycle
+    /// state. See `AgentRegistry::worker_states`.
+    Cancel,
 }
 
 /// Agent response enum for internal message passing
@@ -12,4 +25,7 @@ pub(super) enum AgentResponse {
     StepComplete(AgentOutput),
     Error(String),
     Stopped,
+    Paused,
+    Resumed,
+    Cancelled,
 }
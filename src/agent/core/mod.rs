@@ -2,11 +2,26 @@
 mod config;
 mod messaging;
 mod processor;
+mod action_provider;
+mod action_cache;
+mod step_reporter;
+mod retry;
 mod browser_state;
 mod llm_integration;
 mod action_executor;
 mod agent;
+mod vision_cache;
+mod inference_worker;
+mod progress;
+mod approval;
+mod action_registry;
 
 // Public re-exports (maintains original API)
-pub use config::{AgentConfig, PromptConfig};
+pub use config::{AgentConfig, PageStateMode, PromptConfig};
+pub use action_provider::{ActionProvider, CandleActionProvider, OpenAiActionProvider};
+pub use action_registry::{ActionRegistry, ActionSpec, ArgsBuilder};
+pub use approval::{ActionClass, ApprovalDecision, ApprovalHandler, AutoApproveHandler, classify_action};
+pub use step_reporter::{NullStepReporter, RecordingStepReporter, StepEvent, StepReporter};
+pub use retry::RetryPolicy;
+pub use progress::AgentProgress;
 pub use agent::Agent;
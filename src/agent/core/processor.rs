@@ -1,10 +1,22 @@
 use std::sync::Arc;
-use tokio::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tokio::sync::{Mutex, RwLock, broadcast};
+use tokio::time::Duration;
+use tokio_util::sync::CancellationToken;
 use tracing::warn;
 use kodegen_mcp_client::KodegenClient;
 
 use crate::agent::{AgentError, AgentOutput, AgentResult, prompts::{AgentMessagePrompt, SystemPrompt}};
 use crate::utils::AgentState;
+use super::action_cache::ActionCache;
+use super::action_provider::ActionProvider;
+use super::action_registry::ActionRegistry;
+use super::approval::ApprovalHandler;
+use super::retry::RetryPolicy;
+use super::config::PageStateMode;
+use super::progress::AgentProgress;
+use super::step_reporter::{NullStepReporter, StepReporter};
+use super::vision_cache::VisionMemoCache;
 
 /// Shared agent state and processing logic (can be Arc-cloned)
 pub(super) struct AgentInner {
@@ -15,14 +27,152 @@ pub(super) struct AgentInner {
     pub(super) agent_prompt: AgentMessagePrompt,
     pub(super) max_actions_per_step: usize,
     pub(super) agent_state: Arc<Mutex<AgentState>>,
+    pub(super) action_provider: Arc<dyn ActionProvider>,
+    /// Swappable per-session observer; [`super::Agent::run_with_reporter`]
+    /// installs `AgentSession`'s reporter for the duration of a run.
+    pub(super) step_reporter: RwLock<Arc<dyn StepReporter>>,
+    pub(super) action_cache: ActionCache,
+    pub(super) retry_policy: RetryPolicy,
+    /// Reviews `MayExecute`-classified actions (see [`super::approval::classify_action`])
+    /// before their MCP tool is called. Defaults to
+    /// [`super::approval::AutoApproveHandler`] for backward compatibility.
+    pub(super) approval_handler: Arc<dyn ApprovalHandler>,
+    /// Single source of truth for which actions this agent can plan, their
+    /// function-calling schemas, and their MCP tool mapping. See
+    /// [`super::action_registry::ActionRegistry`].
+    pub(super) action_registry: Arc<ActionRegistry>,
+    pub(super) step_counter: AtomicUsize,
     pub(super) temperature: f64,
     pub(super) max_tokens: u64,
     pub(super) vision_timeout_secs: u64,
     pub(super) llm_timeout_secs: u64,
+    pub(super) page_state_mode: PageStateMode,
+    pub(super) mcp_call_timeout_secs: u64,
+    pub(super) mcp_max_retries: usize,
+
+    /// Cancelled by [`super::Agent::stop`]/[`super::Agent::cancel`] before
+    /// their command is even sent, so a step blocked inside the vision or
+    /// LLM stream (`format_browser_state_with_vision`,
+    /// `generate_and_execute_actions_streaming`) aborts immediately instead of running
+    /// out its full timeout. Checked at the top of [`Self::process_step`]
+    /// and raced against in both streaming loops via `tokio::select!`.
+    pub(super) cancel_token: CancellationToken,
+
+    /// In-memory LRU front for the disk-backed [`super::vision_cache`], and
+    /// the singleflight lock that dedupes concurrent vision calls hashing
+    /// to the same screenshot+prompt. See `format_browser_state_with_vision`.
+    pub(super) vision_memo: VisionMemoCache,
+
+    /// Broadcasts [`AgentProgress`] events as a step runs, independent of
+    /// the command/response channel above. Any number of subscribers
+    /// obtained via [`super::Agent::subscribe`] can observe a step without
+    /// serializing against each other or against `process_step`.
+    pub(super) progress_tx: broadcast::Sender<AgentProgress>,
 }
 
 /// Core processing logic
 impl AgentInner {
+    /// Current step reporter, defaulting to [`NullStepReporter`] until a
+    /// caller installs one via [`Self::set_reporter`].
+    pub(super) async fn reporter(&self) -> Arc<dyn StepReporter> {
+        Arc::clone(&*self.step_reporter.read().await)
+    }
+
+    /// Install a new step reporter, replacing whatever was there before.
+    pub(super) async fn set_reporter(&self, reporter: Arc<dyn StepReporter>) {
+        *self.step_reporter.write().await = reporter;
+    }
+
+    /// Reporter used before anyone calls [`Self::set_reporter`].
+    pub(super) fn null_reporter() -> Arc<dyn StepReporter> {
+        Arc::new(NullStepReporter)
+    }
+
+    /// Drop every cached read-only action result. Normally the cache
+    /// invalidates itself once a mutating action succeeds (see
+    /// `execute_one_action`); this is for a caller that knows the page
+    /// changed out-of-band (e.g. `AgentSession` observing an unsolicited
+    /// navigation) and wants stale results gone before the next step plans
+    /// against them.
+    pub(super) async fn invalidate_action_cache(&self) {
+        self.action_cache.invalidate_all().await;
+    }
+
+    /// Seed the step counter so the next [`Self::process_step`] continues
+    /// numbering from `step` instead of 0.
+    ///
+    /// Used by [`super::Agent::resume_from_step`] when `AgentSession`
+    /// resumes from a checkpoint, so reported/recorded step indices line up
+    /// with the steps already present in the restored history.
+    pub(super) fn set_step_counter(&self, step: usize) {
+        self.step_counter.store(step, Ordering::SeqCst);
+    }
+
+    /// Broadcast `event` to any [`super::Agent::subscribe`] receivers. A
+    /// `send` error just means there are currently no subscribers -
+    /// progress events are fire-and-forget.
+    pub(super) fn emit_progress(&self, event: AgentProgress) {
+        let _ = self.progress_tx.send(event);
+    }
+
+    /// Run `call` (typically `|| self.mcp_client.call_tool(tool_name, args.clone())`)
+    /// with a per-attempt timeout and bounded retry, so a hung or
+    /// transiently failing MCP server can't silently stall a step. Backs
+    /// off via `self.retry_policy.delay_for`, same as `execute_one_action`'s
+    /// own action-level retries.
+    ///
+    /// Returns the stringified error once `self.mcp_max_retries` is
+    /// exhausted - it's up to the caller to decide how to degrade (empty
+    /// content, no screenshot, "material change" assumed).
+    pub(super) async fn call_mcp_tool_with_retry<T, E, F, Fut>(
+        &self,
+        tool_name: &str,
+        mut call: F,
+    ) -> Result<T, String>
+    where
+        F: FnMut() -> Fut,
+        Fut: std::future::Future<Output = Result<T, E>>,
+        E: std::fmt::Display,
+    {
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            // `0` means "wait indefinitely" - skip the timeout wrapper
+            // rather than race a zero-duration timeout against `call()`.
+            let outcome = if self.mcp_call_timeout_secs == 0 {
+                Ok(call().await)
+            } else {
+                tokio::time::timeout(Duration::from_secs(self.mcp_call_timeout_secs), call()).await
+            };
+            match outcome {
+                Ok(Ok(value)) => return Ok(value),
+                Ok(Err(e)) if attempt <= self.mcp_max_retries => {
+                    let delay = self.retry_policy.delay_for(attempt);
+                    warn!(
+                        "MCP tool '{}' failed (attempt {}/{}), retrying in {:?}: {}",
+                        tool_name, attempt, self.mcp_max_retries + 1, delay, e
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                Ok(Err(e)) => return Err(e.to_string()),
+                Err(_) if attempt <= self.mcp_max_retries => {
+                    let delay = self.retry_policy.delay_for(attempt);
+                    warn!(
+                        "MCP tool '{}' timed out after {}s (attempt {}/{}), retrying in {:?}",
+                        tool_name, self.mcp_call_timeout_secs, attempt, self.mcp_max_retries + 1, delay
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                Err(_) => {
+                    return Err(format!(
+                        "timed out after {}s",
+                        self.mcp_call_timeout_secs
+                    ));
+                }
+            }
+        }
+    }
+
     /// Process a single agent step internally
     pub(super) async fn process_step(&self) -> AgentResult<AgentOutput> {
         // Check if stop requested
@@ -32,14 +182,55 @@ impl AgentInner {
         }
         drop(agent_state);
 
+        if self.cancel_token.is_cancelled() {
+            return Err(AgentError::Stopped);
+        }
+
+        let step = self.step_counter.fetch_add(1, Ordering::SeqCst);
+        let reporter = self.reporter().await;
+        reporter.on_step_start(step).await;
+
+        // Drain console messages/exceptions buffered since the previous
+        // step's actions ran (see `AgentOutput::diagnostics` doc comment).
+        // `PageDiagnostics` is process-wide on `BrowserManager`, the same
+        // way `EventTracker` (drained via `browser_events` in
+        // `drain_event_delta`) is - but there's no MCP tool exposing it, so
+        // this reads it directly rather than through the MCP hot path.
+        let diagnostics = crate::manager::BrowserManager::global()
+            .diagnostics()
+            .drain()
+            .await;
+
         // Get current browser state (with screenshot)
-        let mut browser_state = self.get_browser_state().await?;
+        let mut browser_state = match self.get_browser_state().await {
+            Ok(state) => state,
+            Err(e) => {
+                reporter.on_error(step, &e).await;
+                return Err(e);
+            }
+        };
+        self.emit_progress(AgentProgress::StateFetched {
+            content_len: browser_state.state.len(),
+        });
 
-        // Generate agent actions using CandleFluentAi LLM (with vision analysis if screenshot available)
-        let llm_response = self.generate_actions_with_llm(&mut browser_state).await?;
+        // Generate and execute this step's actions together: actions stream
+        // back from the configured ActionProvider (with vision analysis if
+        // a screenshot is available) and each is dispatched to its MCP tool
+        // the moment it's parsed, rather than waiting for the whole turn to
+        // finish planning before executing anything.
+        let (llm_response, action_results) =
+            match self.generate_and_execute_actions_streaming(step, &mut browser_state).await {
+                Ok(result) => result,
+                Err(e) => {
+                    reporter.on_error(step, &e).await;
+                    return Err(e);
+                }
+            };
 
-        // Execute actions via MCP hot path
-        let (_action_results, errors) = self.execute_actions(llm_response.action.clone()).await?;
+        let errors: Vec<String> = action_results
+            .iter()
+            .filter_map(|r| r.error.clone())
+            .collect();
 
         // Log errors if any
         if !errors.is_empty() {
@@ -47,9 +238,14 @@ impl AgentInner {
         }
 
         // Return output with LLM-generated state (no wasteful rebuilding!)
-        Ok(AgentOutput {
+        let output = AgentOutput {
             current_state: llm_response.current_state,
             action: llm_response.action,
-        })
+            diagnostics,
+            usage: llm_response.usage,
+        };
+        reporter.on_step_complete(step, &output).await;
+        self.emit_progress(AgentProgress::StepDone);
+        Ok(output)
     }
 }
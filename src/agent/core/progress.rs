@@ -0,0 +1,42 @@
+//! Broadcast channel for live step progress, separate from the
+//! command/response rendezvous and from [`super::step_reporter::StepReporter`].
+//!
+//! `StepReporter` is a single swappable observer installed per `AgentSession`
+//! run; [`AgentProgress`] is the opposite shape - any number of independent
+//! subscribers (a TUI, a web dashboard, a logging sink) can
+//! `Agent::subscribe()` and watch a step unfold without coordinating with
+//! each other or with the reporter.
+
+/// Channel capacity for `AgentInner::progress_tx`. Large enough that a
+/// burst of events from one step doesn't lag a slow subscriber out before
+/// it catches up, without holding indefinitely many events in memory.
+pub(super) const PROGRESS_CHANNEL_CAPACITY: usize = 256;
+
+/// A single phase of a step's progress, broadcast by
+/// `AgentInner::emit_progress` as `process_step` advances. See
+/// `Agent::subscribe`.
+#[derive(Debug, Clone)]
+pub enum AgentProgress {
+    /// Browser state (and screenshot, if any) for this step was fetched.
+    StateFetched { content_len: usize },
+
+    /// Vision analysis of the step's screenshot started.
+    VisionStarted,
+
+    /// Vision analysis finished; `tokens` is the generated token count, if
+    /// the vision backend reported one.
+    VisionCompleted { tokens: Option<u64> },
+
+    /// The LLM returned `count` actions for this step (after
+    /// `max_actions_per_step` truncation).
+    ActionsPlanned { count: usize },
+
+    /// Execution of one action began.
+    ActionStarted { name: String },
+
+    /// One action finished.
+    ActionResult { ok: bool },
+
+    /// The step is complete.
+    StepDone,
+}
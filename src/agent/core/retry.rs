@@ -0,0 +1,67 @@
+//! Retry-with-backoff for transient action failures.
+//!
+//! A timed-out click or navigation on a slow-loading page shouldn't abort
+//! the whole step - a short retry usually succeeds once the element
+//! finishes rendering. Non-retryable failures (unknown action, missing
+//! parameter) are programmer/LLM errors that won't change on retry, so
+//! they still fail fast.
+
+use rand::Rng;
+use std::time::Duration;
+
+/// Tool names whose failures are worth retrying: all of them hit a live
+/// page and can fail transiently while it's still loading/rendering.
+const RETRYABLE_TOOLS: &[&str] = &[
+    "browser_navigate",
+    "browser_click",
+    "browser_type_text",
+    "browser_scroll",
+    "browser_extract_text",
+    "browser_select",
+    "browser_press_key",
+    "browser_hover",
+    "browser_history",
+    "browser_tabs",
+    "browser_upload_file",
+];
+
+/// Exponential backoff policy applied around a single tool call.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub max_attempts: usize,
+    pub base_delay: Duration,
+    pub multiplier: f64,
+    pub jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(250),
+            multiplier: 2.0,
+            jitter: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Whether `tool_name`'s failures are worth retrying at all.
+    pub fn is_retryable(&self, tool_name: &str) -> bool {
+        RETRYABLE_TOOLS.contains(&tool_name)
+    }
+
+    /// Delay before attempt number `attempt` (1-indexed: the delay before
+    /// the *second* attempt is `attempt = 1`), with optional +/-25% jitter
+    /// so concurrent retries don't all wake up in lockstep.
+    pub fn delay_for(&self, attempt: usize) -> Duration {
+        let scaled = self.base_delay.as_secs_f64() * self.multiplier.powi(attempt as i32 - 1);
+        let delay = if self.jitter {
+            let jitter_factor = rand::thread_rng().gen_range(0.75..1.25);
+            scaled * jitter_factor
+        } else {
+            scaled
+        };
+        Duration::from_secs_f64(delay.max(0.0))
+    }
+}
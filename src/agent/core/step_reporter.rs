@@ -0,0 +1,138 @@
+//! Structured per-step progress, borrowed from task-runner "operation +
+//! reporter" designs: instead of callers only seeing the coarse `summary`
+//! string `AgentSession::read` builds from history length, a [`StepReporter`]
+//! gets a callback at each point of the step loop worth observing in real
+//! time.
+//!
+//! [`NullStepReporter`] is the default (no observability cost when nobody's
+//! listening); [`RecordingStepReporter`] is what `AgentSession::start` wires
+//! in so `AgentSessionOutput` can expose structured events alongside the
+//! summary.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+use tokio::sync::Mutex;
+
+use crate::agent::{ActionResult, AgentError, AgentOutput};
+
+/// Maximum events retained per session - same bounded-buffer rationale as
+/// `PageDiagnostics`/`EventTracker`: a long-running agent shouldn't grow
+/// this unbounded.
+const MAX_RECORDED_EVENTS: usize = 500;
+
+/// One reported occurrence, serializable so `AgentSessionOutput` can expose
+/// it directly to MCP callers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum StepEvent {
+    StepStarted { step: usize },
+    ActionResult { step: usize, action: ActionResult, cache_hit: bool },
+    StepComplete { step: usize, summary: String },
+    Error { step: usize, message: String },
+}
+
+/// Callback hooks for the step loop. All methods default to no-ops so an
+/// implementor only needs to override what it cares about.
+#[async_trait]
+pub trait StepReporter: Send + Sync {
+    async fn on_step_start(&self, _step: usize) {}
+    async fn on_action_result(&self, _step: usize, _result: &ActionResult, _cache_hit: bool) {}
+    async fn on_step_complete(&self, _step: usize, _output: &AgentOutput) {}
+    async fn on_error(&self, _step: usize, _error: &AgentError) {}
+}
+
+/// Default reporter: observes nothing, costs nothing.
+pub struct NullStepReporter;
+
+#[async_trait]
+impl StepReporter for NullStepReporter {}
+
+/// Reporter that appends every callback to a bounded, lockable event log -
+/// what `AgentSession::start` hands to the agent so `AgentSession::read`
+/// can return structured progress instead of only a summary string.
+pub struct RecordingStepReporter {
+    events: Mutex<Vec<StepEvent>>,
+
+    /// When any callback last fired, so `AgentSession::state` can tell an
+    /// actively-stepping session apart from one that's merely still
+    /// running (e.g. blocked on a slow tool call) without a separate
+    /// heartbeat mechanism.
+    last_activity: Mutex<Instant>,
+}
+
+impl Default for RecordingStepReporter {
+    fn default() -> Self {
+        Self {
+            events: Mutex::new(Vec::new()),
+            last_activity: Mutex::new(Instant::now()),
+        }
+    }
+}
+
+impl RecordingStepReporter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Snapshot of everything recorded so far, oldest first.
+    pub async fn events(&self) -> Vec<StepEvent> {
+        self.events.lock().await.clone()
+    }
+
+    /// When a callback last fired. Initialized to construction time, so a
+    /// freshly created session reads as just-active rather than
+    /// immediately idle.
+    pub async fn last_activity(&self) -> Instant {
+        *self.last_activity.lock().await
+    }
+
+    /// Preload recorded events, e.g. the partial-step actions carried over
+    /// by `AgentSession::resume` from a checkpoint.
+    pub async fn seed(&self, events: Vec<StepEvent>) {
+        *self.events.lock().await = events;
+    }
+
+    async fn push(&self, event: StepEvent) {
+        *self.last_activity.lock().await = Instant::now();
+
+        let mut events = self.events.lock().await;
+        if events.len() >= MAX_RECORDED_EVENTS {
+            events.remove(0);
+        }
+        events.push(event);
+    }
+}
+
+#[async_trait]
+impl StepReporter for RecordingStepReporter {
+    async fn on_step_start(&self, step: usize) {
+        self.push(StepEvent::StepStarted { step }).await;
+    }
+
+    async fn on_action_result(&self, step: usize, result: &ActionResult, cache_hit: bool) {
+        self.push(StepEvent::ActionResult {
+            step,
+            action: result.clone(),
+            cache_hit,
+        })
+        .await;
+    }
+
+    async fn on_step_complete(&self, step: usize, output: &AgentOutput) {
+        crate::utils::ToolMetrics::global().incr_agent_steps();
+        self.push(StepEvent::StepComplete {
+            step,
+            summary: output.current_state.summary.clone(),
+        })
+        .await;
+    }
+
+    async fn on_error(&self, step: usize, error: &AgentError) {
+        self.push(StepEvent::Error {
+            step,
+            message: error.to_string(),
+        })
+        .await;
+    }
+}
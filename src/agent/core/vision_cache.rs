@@ -0,0 +1,168 @@
+//! Disk- and memory-backed caches for vision-model screenshot descriptions.
+//!
+//! `format_browser_state_with_vision`'s in-memory `visual_description` only
+//! survives within a single `BrowserStateWithScreenshot`, so a revisited,
+//! visually identical page still re-runs the expensive
+//! `CandleFluentAi::vision()` call every time. The disk cache below keys by
+//! a content hash of the decoded screenshot PNG plus the vision prompt, so
+//! identical screenshots - even across sessions and process restarts - skip
+//! the vision model entirely. [`VisionMemoCache`] sits in front of it as a
+//! per-agent, in-memory LRU so a hit doesn't even pay for the disk read, and
+//! also coordinates concurrent callers hashing to the same screenshot so
+//! only one of them actually drives the vision stream.
+
+use std::collections::{HashMap, VecDeque};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use tokio::fs;
+use tokio::sync::Mutex;
+use tracing::{debug, warn};
+
+/// Bump to invalidate every cached description at once - e.g. after a vision
+/// prompt rewrite materially changes what a description should contain.
+const VISION_CACHE_VERSION: u32 = 1;
+
+fn cache_dir() -> PathBuf {
+    std::env::temp_dir().join("kodegen_vision_cache")
+}
+
+/// Content hash of the decoded PNG bytes, computed once in
+/// `get_browser_state` before the bytes are written to their temp file.
+/// Independent of the vision prompt, so it doesn't need recomputing when
+/// only the prompt changes.
+pub(super) fn content_hash(png_bytes: &[u8]) -> String {
+    blake3::hash(png_bytes).to_hex().to_string()
+}
+
+/// Shared by the disk cache below and [`VisionMemoCache`], so both layers
+/// agree on what counts as "the same" screenshot+prompt.
+pub(super) fn cache_key(screenshot_hash: &str, vision_prompt: &str) -> String {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(&VISION_CACHE_VERSION.to_le_bytes());
+    hasher.update(screenshot_hash.as_bytes());
+    hasher.update(vision_prompt.as_bytes());
+    hasher.finalize().to_hex().to_string()
+}
+
+/// Read a previously cached description for `(screenshot_hash, vision_prompt)`,
+/// `None` on a cache miss (including the cache directory not existing yet).
+pub(super) async fn get(screenshot_hash: &str, vision_prompt: &str) -> Option<String> {
+    let path = cache_dir().join(cache_key(screenshot_hash, vision_prompt));
+    match fs::read_to_string(&path).await {
+        Ok(description) => {
+            debug!("Vision cache hit for screenshot hash {}", screenshot_hash);
+            Some(description)
+        }
+        Err(_) => None,
+    }
+}
+
+/// Persist `description` for `(screenshot_hash, vision_prompt)` so a future
+/// identical screenshot skips the vision model. Logged-and-ignored on
+/// failure, same as `get_browser_state`'s own temp-file writes - a cache
+/// write failure shouldn't fail the step.
+pub(super) async fn put(screenshot_hash: &str, vision_prompt: &str, description: &str) {
+    let dir = cache_dir();
+    if let Err(e) = fs::create_dir_all(&dir).await {
+        warn!("Failed to create vision cache dir {}: {}", dir.display(), e);
+        return;
+    }
+    let path = dir.join(cache_key(screenshot_hash, vision_prompt));
+    if let Err(e) = fs::write(&path, description).await {
+        warn!("Failed to write vision cache entry {}: {}", path.display(), e);
+    }
+}
+
+/// Bare-bones least-recently-used map: eviction order is tracked by moving a
+/// key to the back of `order` on every hit or insert, and dropping the front
+/// once `entries` grows past `capacity`. `O(capacity)` per touch, which is
+/// fine at the small sizes ([`VisionMemoCache`] uses 16 entries)
+/// this is meant for.
+struct LruCache {
+    capacity: usize,
+    entries: HashMap<String, String>,
+    order: VecDeque<String>,
+}
+
+impl LruCache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    fn get(&mut self, key: &str) -> Option<String> {
+        let value = self.entries.get(key)?.clone();
+        self.touch(key);
+        Some(value)
+    }
+
+    fn put(&mut self, key: String, value: String) {
+        self.entries.insert(key.clone(), value);
+        self.touch(&key);
+        while self.entries.len() > self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.entries.remove(&oldest);
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn touch(&mut self, key: &str) {
+        if let Some(pos) = self.order.iter().position(|k| k == key) {
+            self.order.remove(pos);
+        }
+        self.order.push_back(key.to_string());
+    }
+}
+
+/// In-memory front for the disk cache above: an LRU of recent descriptions
+/// plus a map of per-key locks used as a "singleflight" guard. A caller that
+/// misses the LRU calls [`Self::lock_for_key`] and locks the returned
+/// `Mutex` *before* falling through to the disk cache/vision model; a second
+/// caller for the same key blocks on that same lock instead of launching its
+/// own vision call, then finds the first caller's result already in the LRU
+/// once it wakes up.
+pub(super) struct VisionMemoCache {
+    lru: Mutex<LruCache>,
+    in_flight: Mutex<HashMap<String, Arc<Mutex<()>>>>,
+}
+
+impl VisionMemoCache {
+    pub(super) fn new(capacity: usize) -> Self {
+        Self {
+            lru: Mutex::new(LruCache::new(capacity)),
+            in_flight: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub(super) async fn get(&self, key: &str) -> Option<String> {
+        self.lru.lock().await.get(key)
+    }
+
+    pub(super) async fn put(&self, key: String, description: String) {
+        self.lru.lock().await.put(key, description);
+    }
+
+    /// Returns the lock for `key`, creating a fresh (unlocked) one if no
+    /// caller is currently working on this key. The caller is expected to
+    /// lock it immediately and call [`Self::release_key`] once it's done
+    /// (whether it found a cache hit or ran the vision model), so the map
+    /// doesn't grow forever.
+    pub(super) async fn lock_for_key(&self, key: &str) -> Arc<Mutex<()>> {
+        let mut in_flight = self.in_flight.lock().await;
+        Arc::clone(
+            in_flight
+                .entry(key.to_string())
+                .or_insert_with(|| Arc::new(Mutex::new(()))),
+        )
+    }
+
+    pub(super) async fn release_key(&self, key: &str) {
+        self.in_flight.lock().await.remove(key);
+    }
+}
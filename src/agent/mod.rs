@@ -1,15 +1,28 @@
 mod core;
 pub mod prompts;
+pub mod registry;
+mod session;
+mod session_manager;
 mod views;
 
 use serde::{Deserialize, Serialize};
 
-pub use core::{Agent, AgentConfig, PromptConfig};
+pub use core::{
+    ActionProvider, ActionRegistry, ActionSpec, Agent, AgentConfig, AgentProgress, ApprovalDecision,
+    ApprovalHandler, ArgsBuilder, AutoApproveHandler, CandleActionProvider, NullStepReporter,
+    OpenAiActionProvider, PromptConfig, RecordingStepReporter, RetryPolicy, StepEvent,
+    StepReporter,
+};
 pub use prompts::{AgentMessagePrompt, SystemPrompt};
+pub use registry::{AgentInfo, AgentRegistry};
+pub use session::{AgentSession, AgentSessionOutput, WorkerInfo, WorkerState};
+pub use session_manager::SessionManager;
 pub use views::{ActionView, BrowserStateView, HistoryView, StepView};
 
 use thiserror::Error;
 
+use crate::utils::PageDiagnostic;
+
 /// Action model for agent protocol - represents an action to execute
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ActionModel {
@@ -24,6 +37,17 @@ pub struct ActionResult {
     pub success: bool,
     pub extracted_content: Option<String>,
     pub error: Option<String>,
+
+    /// How many times the underlying MCP tool call was attempted before
+    /// this result was produced (1 if it succeeded or failed on the first
+    /// try). Lets the LLM and `StepReporter` see that a step was flaky
+    /// even though it ultimately succeeded. See [`core::RetryPolicy`].
+    #[serde(default = "default_attempts")]
+    pub attempts: usize,
+}
+
+fn default_attempts() -> usize {
+    1
 }
 
 /// Response from browser_extract_text MCP tool
@@ -55,6 +79,32 @@ pub struct BrowserScreenshotResponse {
 pub struct AgentLLMResponse {
     pub current_state: CurrentState,
     pub action: Vec<ActionModel>,
+    #[serde(default)]
+    pub usage: AgentUsage,
+}
+
+/// Token/time accounting for one LLM generation call, modeled on the
+/// `Usage` type mistral.rs's completion responses carry. Accumulated across
+/// a run's steps (see [`AgentHistoryList::total_usage`]) so callers can
+/// enforce a token budget, estimate cost against per-model pricing, or
+/// detect a runaway loop without re-deriving it from logs.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct AgentUsage {
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    pub total_tokens: u64,
+    pub wall_secs: f64,
+}
+
+impl AgentUsage {
+    /// Fold `other` into `self`, as when adding one step's usage to a run's
+    /// running total.
+    pub fn accumulate(&mut self, other: &AgentUsage) {
+        self.prompt_tokens += other.prompt_tokens;
+        self.completion_tokens += other.completion_tokens;
+        self.total_tokens += other.total_tokens;
+        self.wall_secs += other.wall_secs;
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -99,6 +149,18 @@ pub type AgentResult<T> = Result<T, AgentError>;
 pub struct AgentOutput {
     pub current_state: CurrentState,
     pub action: Vec<ActionModel>,
+
+    /// Console messages and uncaught exceptions observed on the page while
+    /// this step's actions ran. Drained from the page's diagnostics ring
+    /// buffer at the start of the *next* step, since CDP events lag
+    /// slightly behind the action that triggered them - see
+    /// `AgentInner::process_step`.
+    #[serde(default)]
+    pub diagnostics: Vec<PageDiagnostic>,
+
+    /// This step's LLM token/time usage; see [`AgentUsage`].
+    #[serde(default)]
+    pub usage: AgentUsage,
 }
 
 /// An entry in the agent history
@@ -158,6 +220,15 @@ impl AgentHistoryList {
             )
         })
     }
+
+    /// Cumulative LLM token/time usage across every step run so far.
+    pub fn total_usage(&self) -> AgentUsage {
+        let mut total = AgentUsage::default();
+        for step in &self.steps {
+            total.accumulate(&step.output.usage);
+        }
+        total
+    }
 }
 
 impl Default for AgentHistoryList {
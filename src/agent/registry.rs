@@ -1,19 +1,38 @@
 //! Agent session registry with connection isolation
 
 use super::core::Agent;
-use super::session::AgentSession;
+use super::session::{AgentSession, WorkerInfo, WorkerState};
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
-use tokio::sync::Mutex;
+use std::time::Duration;
+use tokio::sync::{Mutex, Semaphore};
 
 /// Registry key: (connection_id, agent_number)
 type RegistryKey = (String, u32);
 
 /// Registry for managing multiple agent sessions
+#[derive(Clone)]
 pub struct AgentRegistry {
     sessions: Arc<Mutex<HashMap<RegistryKey, Arc<AgentSession>>>>,
+
+    /// Number of staleness scans the background reaper (see
+    /// [`Self::with_reaper`]) has run. `0` if no reaper was spawned.
+    reaper_scans: Arc<AtomicU64>,
+
+    /// Number of sessions the reaper has evicted for sitting idle past its
+    /// `idle_ttl`, across all scans. Lets an operator tune `idle_ttl`
+    /// instead of guessing.
+    reaper_evictions: Arc<AtomicU64>,
+
+    /// Concurrency cap set by [`Self::with_limit`]. `None` (the default,
+    /// via [`Self::new`]) admits every session immediately, preserving the
+    /// original unbounded behavior. Shared with every [`AgentSession`]
+    /// created by [`Self::find_or_create`] so admission is enforced where
+    /// the session actually starts stepping, not in the registry itself.
+    semaphore: Option<Arc<Semaphore>>,
 }
 
 /// Information about a single agent
@@ -33,16 +52,102 @@ pub struct AgentInfo {
     
     /// Current step count
     pub step_count: usize,
+
+    /// Coarse lifecycle state - see [`WorkerState`]. Also available with a
+    /// last-activity timestamp via [`AgentRegistry::worker_states`].
+    pub state: WorkerState,
 }
 
 impl AgentRegistry {
-    /// Create a new registry
+    /// Create a new registry with no stale-session reaper - sessions are
+    /// only ever removed explicitly, via [`Self::remove`] or
+    /// [`Self::cleanup_completed`]. Use [`Self::with_reaper`] to also evict
+    /// abandoned-but-still-running sessions automatically.
     pub fn new() -> Self {
         Self {
             sessions: Arc::new(Mutex::new(HashMap::new())),
+            reaper_scans: Arc::new(AtomicU64::new(0)),
+            reaper_evictions: Arc::new(AtomicU64::new(0)),
+            semaphore: None,
         }
     }
-    
+
+    /// Like [`Self::new`], but cap how many sessions may step at once to
+    /// `max_concurrent`. Sessions beyond the cap are admitted into
+    /// [`Self::find_or_create`] as usual and report [`WorkerState::Queued`]
+    /// until a running session completes and frees a permit - see
+    /// [`AgentSession::start`]. Compose with [`Self::spawn_reaper`] for both
+    /// knobs at once.
+    pub fn with_limit(max_concurrent: usize) -> Self {
+        Self {
+            semaphore: Some(Arc::new(Semaphore::new(max_concurrent))),
+            ..Self::new()
+        }
+    }
+
+    /// Spawn a background task that scans every `interval` and evicts any
+    /// session that's been idle (see [`AgentSession::idle_for`]) for longer
+    /// than `idle_ttl`, regardless of whether it ever completed. Guards
+    /// against a connection starting an agent and then disappearing
+    /// (crash, dropped connection) without ever calling KILL, which
+    /// `cleanup_completed` alone can't catch since it only removes sessions
+    /// that already finished.
+    ///
+    /// Runs for the lifetime of the process, same as
+    /// [`crate::manager::BrowserManager`]'s idle reaper - there's no
+    /// shutdown hook for it since the registry itself is never torn down.
+    /// Takes `&self` rather than consuming a builder so it composes with
+    /// [`Self::with_limit`]; see [`Self::with_reaper`] for the common case
+    /// of just wanting a reaper.
+    pub fn spawn_reaper(&self, interval: Duration, idle_ttl: Duration) {
+        let sessions = self.sessions.clone();
+        let reaper_scans = self.reaper_scans.clone();
+        let reaper_evictions = self.reaper_evictions.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                reaper_scans.fetch_add(1, Ordering::Relaxed);
+                let evicted = reap_stale(&sessions, idle_ttl).await;
+                reaper_evictions.fetch_add(evicted, Ordering::Relaxed);
+            }
+        });
+    }
+
+    /// Shorthand for `Self::new()` plus [`Self::spawn_reaper`] - a registry
+    /// with a reaper but no concurrency limit. Use [`Self::with_limit`]
+    /// followed by [`Self::spawn_reaper`] to get both.
+    pub fn with_reaper(interval: Duration, idle_ttl: Duration) -> Self {
+        let registry = Self::new();
+        registry.spawn_reaper(interval, idle_ttl);
+        registry
+    }
+
+    /// Whether the session at `(connection_id, agent_id)` has been idle
+    /// longer than `idle_ttl` - the same check [`Self::with_reaper`]'s
+    /// background task runs, exposed so callers can evaluate staleness
+    /// against a different TTL without waiting for the next scan. Returns
+    /// `false` if no such session exists.
+    pub async fn is_stale(&self, connection_id: &str, agent_id: u32, idle_ttl: Duration) -> bool {
+        match self.get(connection_id, agent_id).await {
+            Some(session) => session.idle_for().await > idle_ttl,
+            None => false,
+        }
+    }
+
+    /// Total staleness scans run by the background reaper, if one was
+    /// spawned via [`Self::with_reaper`]. `0` otherwise.
+    pub fn reaper_scans(&self) -> u64 {
+        self.reaper_scans.load(Ordering::Relaxed)
+    }
+
+    /// Total sessions evicted by the background reaper across its
+    /// lifetime. Compare against [`Self::reaper_scans`] to judge whether
+    /// `idle_ttl` is tuned well for this workload.
+    pub fn reaper_evictions(&self) -> u64 {
+        self.reaper_evictions.load(Ordering::Relaxed)
+    }
+
     /// Find or create an agent session
     pub async fn find_or_create(
         &self,
@@ -60,11 +165,30 @@ impl AgentRegistry {
         }
         
         // Create new session
-        let session = Arc::new(AgentSession::new(agent, task, max_steps));
+        let mut session = AgentSession::new(agent, task, max_steps);
+        if let Some(semaphore) = &self.semaphore {
+            session = session.with_semaphore(semaphore.clone());
+        }
+        let session = Arc::new(session);
         sessions.insert(key, session.clone());
-        
+        crate::utils::ToolMetrics::global().incr_agents_active();
+
         Ok(session)
     }
+
+    /// Number of sessions on `connection_id` currently waiting on
+    /// [`Self::with_limit`]'s concurrency permit - see
+    /// [`WorkerState::Queued`]. Always `0` for a registry without a limit.
+    pub async fn queue_depth(&self, connection_id: &str) -> usize {
+        let sessions_map = self.sessions.lock().await;
+        let mut depth = 0;
+        for ((conn_id, _), session) in sessions_map.iter() {
+            if conn_id == connection_id && session.state().await == WorkerState::Queued {
+                depth += 1;
+            }
+        }
+        depth
+    }
     
     /// Get an existing session
     pub async fn get(
@@ -81,7 +205,11 @@ impl AgentRegistry {
     pub async fn remove(&self, connection_id: &str, agent_id: u32) -> Option<Arc<AgentSession>> {
         let key = (connection_id.to_string(), agent_id);
         let mut sessions = self.sessions.lock().await;
-        sessions.remove(&key)
+        let removed = sessions.remove(&key);
+        if removed.is_some() {
+            crate::utils::ToolMetrics::global().decr_agents_active();
+        }
+        removed
     }
     
     /// List all agent sessions for a connection
@@ -94,14 +222,16 @@ impl AgentRegistry {
                 let completed = session.is_complete().await;
                 let has_error = session.has_error().await;
                 let step_count = session.step_count().await;
+                let state = session.state().await;
                 let output = session.read(*agent_num).await;
-                
+
                 agent_infos.push(AgentInfo {
                     agent: *agent_num,
                     task: output.task,
                     completed,
                     has_error,
                     step_count,
+                    state,
                 });
             }
         }
@@ -112,6 +242,42 @@ impl AgentRegistry {
         Ok(agent_infos)
     }
     
+    /// Suspend a running session's next step. See `AgentSession::pause`.
+    pub async fn pause(&self, connection_id: &str, agent_id: u32) -> Result<()> {
+        let session = self
+            .get(connection_id, agent_id)
+            .await
+            .ok_or_else(|| anyhow::anyhow!("Agent {} not found", agent_id))?;
+        session.pause().await
+    }
+
+    /// Resume a session suspended by [`Self::pause`].
+    pub async fn resume(&self, connection_id: &str, agent_id: u32) -> Result<()> {
+        let session = self
+            .get(connection_id, agent_id)
+            .await
+            .ok_or_else(|| anyhow::anyhow!("Agent {} not found", agent_id))?;
+        session.resume().await
+    }
+
+    /// Lifecycle state plus idle duration for every session on `connection_id`,
+    /// so an operator can tell a stuck agent (large `idle_secs`, `Idle`
+    /// state) apart from one making progress, instead of only seeing
+    /// `completed`/`has_error` via [`Self::list`].
+    pub async fn worker_states(&self, connection_id: &str) -> Result<Vec<WorkerInfo>> {
+        let sessions_map = self.sessions.lock().await;
+        let mut infos = Vec::new();
+
+        for ((conn_id, agent_num), session) in sessions_map.iter() {
+            if conn_id == connection_id {
+                infos.push(session.worker_info(*agent_num).await);
+            }
+        }
+
+        infos.sort_by_key(|w| w.agent);
+        Ok(infos)
+    }
+
     /// Clean up completed sessions (optional maintenance)
     pub async fn cleanup_completed(&self, connection_id: &str) -> usize {
         let mut sessions = self.sessions.lock().await;
@@ -126,8 +292,9 @@ impl AgentRegistry {
         let count = to_remove.len();
         for key in to_remove {
             sessions.remove(&key);
+            crate::utils::ToolMetrics::global().decr_agents_active();
         }
-        
+
         count
     }
 }
@@ -137,3 +304,44 @@ impl Default for AgentRegistry {
         Self::new()
     }
 }
+
+/// [`AgentRegistry::with_reaper`]'s per-tick sweep: collect keys whose
+/// session has been idle past `idle_ttl` first, then remove them in a
+/// second pass, so the lock isn't held across the `idle_for().await` calls
+/// - same shape as [`AgentRegistry::cleanup_completed`].
+async fn reap_stale(
+    sessions: &Arc<Mutex<HashMap<RegistryKey, Arc<AgentSession>>>>,
+    idle_ttl: Duration,
+) -> u64 {
+    let snapshot: Vec<(RegistryKey, Arc<AgentSession>)> = {
+        let sessions = sessions.lock().await;
+        sessions
+            .iter()
+            .map(|(key, session)| (key.clone(), session.clone()))
+            .collect()
+    };
+
+    let mut to_remove = Vec::new();
+    for (key, session) in snapshot {
+        let idle = session.idle_for().await;
+        if idle > idle_ttl {
+            tracing::trace!(
+                "evicting idle agent {}/{}, last active {:?} ago",
+                key.0,
+                key.1,
+                idle
+            );
+            to_remove.push(key);
+        }
+    }
+
+    let count = to_remove.len() as u64;
+    if !to_remove.is_empty() {
+        let mut sessions = sessions.lock().await;
+        for key in to_remove {
+            sessions.remove(&key);
+            crate::utils::ToolMetrics::global().decr_agents_active();
+        }
+    }
+    count
+}
@@ -1,36 +1,118 @@
 //! Agent session management
 
-use super::core::Agent;
+use super::core::{Agent, RecordingStepReporter, StepEvent};
 use super::AgentHistoryList;
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use std::time::Duration;
+use tokio::sync::{RwLock, Semaphore};
 use tokio::task::JoinHandle;
 
+/// Coarse lifecycle state surfaced by [`super::registry::AgentRegistry::worker_states`],
+/// derived on read from `completed`/`paused`/`queued`/last-activity rather
+/// than pushed, so it can never drift out of sync with the state it's
+/// built from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkerState {
+    /// Admitted past `AgentRegistry`'s concurrency limit but hasn't
+    /// acquired a permit yet - see [`AgentSession::start`]. "Running"
+    /// (past admission) is represented by `Active`/`Idle` below rather
+    /// than a separate flat state, so a caller can still tell a slow step
+    /// apart from a genuinely stuck one once a session is admitted.
+    Queued,
+    /// Admitted and recorded a step event (step start, action, completion,
+    /// or error) within the last [`QUIESCENCE_WINDOW`].
+    Active,
+    /// Admitted, still running, but hasn't recorded a step event in over
+    /// [`QUIESCENCE_WINDOW`] - normal while waiting on a slow tool call,
+    /// but a useful signal something's stuck if it persists.
+    Idle,
+    /// Suspended via [`AgentSession::pause`]; continues with [`AgentSession::resume`].
+    Paused,
+    /// Finished, successfully or not - see [`AgentSessionOutput::error`].
+    Dead,
+}
+
+/// How long a session can go without recording a step event (see
+/// [`AgentSession::idle_for`]) before [`AgentSession::state`] reports it
+/// `Idle` instead of `Active`.
+const QUIESCENCE_WINDOW: Duration = Duration::from_secs(30);
+
+/// Per-agent snapshot returned by [`super::registry::AgentRegistry::worker_states`],
+/// letting an operator spot stuck agents (large `idle_secs`) instead of
+/// only seeing whether one is done.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkerInfo {
+    pub agent: u32,
+    pub state: WorkerState,
+    pub idle_secs: u64,
+    pub last_error: Option<String>,
+}
+
+/// On-disk checkpoint of a session, written by [`AgentSession::checkpoint`]
+/// and reloaded by [`AgentSession::resume`].
+///
+/// `partial_actions` carries over any actions already executed within the
+/// step that was in flight when the checkpoint was taken (recorded by the
+/// session's `RecordingStepReporter`), so resume knows what already ran
+/// against the real browser and must not be replayed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SessionCheckpoint {
+    task: String,
+    max_steps: usize,
+    history: AgentHistoryList,
+    next_step: usize,
+    partial_actions: Vec<StepEvent>,
+}
+
 /// Session state for an active agent task
 #[derive(Clone)]
 pub struct AgentSession {
     /// Underlying agent
     agent: Arc<Agent>,
-    
+
     /// Task being executed
     task: String,
-    
+
     /// Maximum steps for execution
     max_steps: usize,
-    
+
     /// Shared history (updated in background)
     history: Arc<RwLock<AgentHistoryList>>,
-    
+
     /// Background task handle
     task_handle: Arc<RwLock<Option<JoinHandle<Result<()>>>>>,
-    
+
     /// Session completion flag
     completed: Arc<RwLock<bool>>,
-    
+
     /// Error state
     error: Arc<RwLock<Option<String>>>,
+
+    /// Structured per-step progress, populated by `Agent::run_with_reporter`
+    /// so `read` can surface more than the coarse `summary` string.
+    reporter: Arc<RecordingStepReporter>,
+
+    /// Mirrors `Agent::is_paused`, so [`Self::state`] doesn't need to make
+    /// its own channel round-trip just to report whether this session is
+    /// paused.
+    paused: Arc<AtomicBool>,
+
+    /// Concurrency gate set by [`super::registry::AgentRegistry::with_limit`];
+    /// `None` (the default, via [`Self::new`]) never blocks. When set,
+    /// [`Self::start`] doesn't actually begin stepping until a permit is
+    /// free - see `queued`.
+    semaphore: Option<Arc<Semaphore>>,
+
+    /// Set while [`Self::start`]'s background task is waiting on
+    /// `semaphore`, so [`Self::state`] can report [`WorkerState::Queued`]
+    /// instead of looking idle or active before the session has actually
+    /// begun.
+    queued: Arc<AtomicBool>,
 }
 
 /// Output from agent session
@@ -53,15 +135,20 @@ pub struct AgentSessionOutput {
     
     /// Progress summary
     pub summary: String,
+
+    /// Structured per-step events (step start/action/complete/error),
+    /// recorded by the session's `RecordingStepReporter`.
+    pub events: Vec<StepEvent>,
 }
 
 impl AgentSession {
-    /// Create a new agent session
+    /// Create a new agent session with no concurrency limit - [`Self::start`]
+    /// begins stepping immediately. See [`Self::with_semaphore`].
     pub fn new(agent: Agent, task: String, max_steps: usize) -> Self {
         let history = Arc::new(RwLock::new(AgentHistoryList::new()));
         let completed = Arc::new(RwLock::new(false));
         let error = Arc::new(RwLock::new(None));
-        
+
         Self {
             agent: Arc::new(agent),
             task,
@@ -70,22 +157,61 @@ impl AgentSession {
             task_handle: Arc::new(RwLock::new(None)),
             completed,
             error,
+            reporter: Arc::new(RecordingStepReporter::new()),
+            paused: Arc::new(AtomicBool::new(false)),
+            semaphore: None,
+            queued: Arc::new(AtomicBool::new(false)),
         }
     }
-    
+
+    /// Gate [`Self::start`] on `semaphore`: used by
+    /// [`super::registry::AgentRegistry::with_limit`] so sessions beyond
+    /// the configured concurrency cap queue for a permit instead of all
+    /// stepping at once.
+    pub(super) fn with_semaphore(mut self, semaphore: Arc<Semaphore>) -> Self {
+        self.semaphore = Some(semaphore);
+        self
+    }
+
     /// Start agent in background
+    ///
+    /// If `self.history` is already populated (i.e. this session came from
+    /// [`Self::resume`]), only the remaining step budget runs, and the
+    /// freshly produced steps are appended - renumbered - onto the restored
+    /// history rather than replacing it.
     pub async fn start(&self) -> Result<()> {
         let agent = self.agent.clone();
-        let max_steps = self.max_steps;
+        let already_run = self.history.read().await.steps.len();
+        let remaining_steps = self.max_steps.saturating_sub(already_run);
         let history = self.history.clone();
         let completed = self.completed.clone();
         let error = self.error.clone();
-        
+        let reporter = self.reporter.clone();
+        let semaphore = self.semaphore.clone();
+        let queued = self.queued.clone();
+
         let handle = tokio::spawn(async move {
-            match agent.run(max_steps).await {
-                Ok(final_history) => {
+            // Held for the rest of this task's scope, so it's released
+            // right where the session transitions to complete below,
+            // whether or not a limit was ever configured.
+            let _permit = match &semaphore {
+                Some(semaphore) => {
+                    queued.store(true, Ordering::SeqCst);
+                    let permit = semaphore.clone().acquire_owned().await.ok();
+                    queued.store(false, Ordering::SeqCst);
+                    permit
+                }
+                None => None,
+            };
+
+            let outcome = match agent.run_with_reporter(remaining_steps, reporter).await {
+                Ok(new_history) => {
                     let mut hist = history.write().await;
-                    *hist = final_history;
+                    let offset = hist.steps.len();
+                    for mut step in new_history.steps {
+                        step.step += offset;
+                        hist.steps.push(step);
+                    }
                     let mut comp = completed.write().await;
                     *comp = true;
                     Ok(())
@@ -97,21 +223,90 @@ impl AgentSession {
                     *comp = true;
                     Err(anyhow::anyhow!("Agent error: {}", e))
                 }
-            }
+            };
+            crate::utils::ToolMetrics::global().incr_agents_completed();
+            outcome
         });
-        
+
         let mut task_handle = self.task_handle.write().await;
         *task_handle = Some(handle);
-        
+
         Ok(())
     }
+
+    /// Atomically write a checkpoint of the current session state to `path`
+    /// (write-to-temp-then-rename, so a crash mid-write never leaves a
+    /// truncated checkpoint behind).
+    ///
+    /// Captures the completed `history` plus any actions already executed
+    /// within the step currently in flight, so [`Self::resume`] can tell
+    /// which side-effecting actions (clicks, navigations) already ran.
+    pub async fn checkpoint(&self, path: impl AsRef<Path>) -> Result<()> {
+        let history = self.history.read().await.clone();
+        let next_step = history.steps.len();
+        let partial_actions = self
+            .reporter
+            .events()
+            .await
+            .into_iter()
+            .filter(|event| matches!(event, StepEvent::ActionResult { step, .. } if *step == next_step))
+            .collect();
+
+        let checkpoint = SessionCheckpoint {
+            task: self.task.clone(),
+            max_steps: self.max_steps,
+            history,
+            next_step,
+            partial_actions,
+        };
+
+        let path = path.as_ref();
+        let tmp_path = path.with_extension("tmp");
+        tokio::fs::write(&tmp_path, serde_json::to_vec_pretty(&checkpoint)?).await?;
+        tokio::fs::rename(&tmp_path, path).await?;
+        Ok(())
+    }
+
+    /// Restore a session from a checkpoint written by [`Self::checkpoint`].
+    ///
+    /// `agent` is a freshly constructed [`Agent`] (e.g. via
+    /// [`Agent::new_with_provider`]) for the same task; this seeds its step
+    /// counter so step numbering continues from where the checkpoint left
+    /// off instead of restarting at 0. The actions recorded in
+    /// `partial_actions` are *not* replayed - they already ran against the
+    /// real browser before the process died - so [`Self::start`] simply
+    /// resumes with the agent's next LLM call.
+    pub async fn resume(agent: Agent, path: impl AsRef<Path>) -> Result<Self> {
+        let bytes = tokio::fs::read(path.as_ref()).await?;
+        let checkpoint: SessionCheckpoint = serde_json::from_slice(&bytes)?;
+
+        agent.resume_from_step(checkpoint.next_step).await;
+
+        let reporter = Arc::new(RecordingStepReporter::new());
+        reporter.seed(checkpoint.partial_actions).await;
+
+        Ok(Self {
+            agent: Arc::new(agent),
+            task: checkpoint.task,
+            max_steps: checkpoint.max_steps,
+            history: Arc::new(RwLock::new(checkpoint.history)),
+            task_handle: Arc::new(RwLock::new(None)),
+            completed: Arc::new(RwLock::new(false)),
+            error: Arc::new(RwLock::new(None)),
+            reporter,
+            paused: Arc::new(AtomicBool::new(false)),
+            semaphore: None,
+            queued: Arc::new(AtomicBool::new(false)),
+        })
+    }
     
     /// Read current progress
     pub async fn read(&self, agent_id: u32) -> AgentSessionOutput {
         let history = self.history.read().await.clone();
         let completed = *self.completed.read().await;
         let error = self.error.read().await.clone();
-        
+        let events = self.reporter.events().await;
+
         let summary = if let Some(ref err) = error {
             format!("Agent failed: {}", err)
         } else if completed {
@@ -127,9 +322,24 @@ impl AgentSession {
             completed,
             error,
             summary,
+            events,
         }
     }
     
+    /// Await completion of the background task started by [`Self::start`].
+    ///
+    /// Returns immediately if the session was never started or has already
+    /// been joined. Used by `SessionManager` to hold a concurrency permit
+    /// for a session's actual run duration rather than just the moment
+    /// `start` returns.
+    pub async fn join(&self) -> Result<()> {
+        let handle = self.task_handle.write().await.take();
+        match handle {
+            Some(handle) => handle.await?,
+            None => Ok(()),
+        }
+    }
+
     /// Kill the agent task
     pub async fn kill(&self) -> Result<()> {
         // First, stop the agent gracefully
@@ -147,6 +357,58 @@ impl AgentSession {
         Ok(())
     }
     
+    /// Suspend the run loop before its next step, without tearing down the
+    /// background task - a later [`Self::resume`] continues exactly where
+    /// it left off. See `Agent::pause`.
+    pub async fn pause(&self) -> Result<()> {
+        self.agent.pause().await?;
+        self.paused.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// Resume a session suspended by [`Self::pause`].
+    pub async fn resume(&self) -> Result<()> {
+        self.agent.resume().await?;
+        self.paused.store(false, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// How long since this session's `RecordingStepReporter` last recorded
+    /// an event (step start, action, completion, or error) - the same
+    /// signal [`Self::state`] and `AgentRegistry`'s stale-session reaper
+    /// use to decide whether a still-running session has actually stalled.
+    pub async fn idle_for(&self) -> Duration {
+        self.reporter.last_activity().await.elapsed()
+    }
+
+    /// Current lifecycle state - see [`WorkerState`].
+    pub async fn state(&self) -> WorkerState {
+        if self.is_complete().await {
+            return WorkerState::Dead;
+        }
+        if self.queued.load(Ordering::SeqCst) {
+            return WorkerState::Queued;
+        }
+        if self.paused.load(Ordering::SeqCst) {
+            return WorkerState::Paused;
+        }
+        if self.idle_for().await > QUIESCENCE_WINDOW {
+            WorkerState::Idle
+        } else {
+            WorkerState::Active
+        }
+    }
+
+    /// Snapshot for `AgentRegistry::worker_states`.
+    pub async fn worker_info(&self, agent_id: u32) -> WorkerInfo {
+        WorkerInfo {
+            agent: agent_id,
+            state: self.state().await,
+            idle_secs: self.idle_for().await.as_secs(),
+            last_error: self.error.read().await.clone(),
+        }
+    }
+
     /// Check if agent is complete
     pub async fn is_complete(&self) -> bool {
         *self.completed.read().await
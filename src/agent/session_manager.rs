@@ -0,0 +1,100 @@
+//! Multi-agent session orchestration
+//!
+//! `AgentSession` manages exactly one agent. `SessionManager` is the batch
+//! layer on top: a registry of sessions keyed by a simple `u32` id, with a
+//! semaphore bounding how many background step loops run at once so a
+//! fan-out of dozens of research/scraping tasks doesn't all hit the
+//! browser pool simultaneously.
+
+use super::core::Agent;
+use super::session::{AgentSession, AgentSessionOutput};
+use anyhow::Result;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+use tokio::sync::{RwLock, Semaphore};
+use tracing::error;
+
+/// Registry of concurrently-running `AgentSession`s with a bounded
+/// concurrency limit.
+pub struct SessionManager {
+    sessions: Arc<RwLock<HashMap<u32, Arc<AgentSession>>>>,
+    next_id: AtomicU32,
+    concurrency: Arc<Semaphore>,
+}
+
+impl SessionManager {
+    /// Create a manager that runs at most `max_concurrent` agent
+    /// background loops at once; additional spawned sessions queue for a
+    /// permit rather than all starting immediately.
+    pub fn new(max_concurrent: usize) -> Self {
+        Self {
+            sessions: Arc::new(RwLock::new(HashMap::new())),
+            next_id: AtomicU32::new(0),
+            concurrency: Arc::new(Semaphore::new(max_concurrent)),
+        }
+    }
+
+    /// Register a new session and queue it to start as soon as a
+    /// concurrency permit is free, returning its id immediately.
+    pub async fn spawn(&self, agent: Agent, task: String, max_steps: usize) -> u32 {
+        let id = self.next_id.fetch_add(1, Ordering::SeqCst);
+        let session = Arc::new(AgentSession::new(agent, task, max_steps));
+        self.sessions.write().await.insert(id, session.clone());
+
+        let concurrency = self.concurrency.clone();
+        tokio::spawn(async move {
+            let _permit = concurrency.acquire().await;
+            if let Err(e) = session.start().await {
+                error!("Session {} failed to start: {}", id, e);
+                return;
+            }
+            if let Err(e) = session.join().await {
+                error!("Session {} ended with error: {}", id, e);
+            }
+            // Permit is held until the session's background loop
+            // finishes, not just until `start` returns, so the
+            // concurrency limit reflects actual running sessions.
+        });
+
+        id
+    }
+
+    /// Current progress of every registered session.
+    pub async fn read_all(&self) -> Vec<AgentSessionOutput> {
+        let sessions = self.sessions.read().await;
+        let mut outputs = Vec::with_capacity(sessions.len());
+        for (id, session) in sessions.iter() {
+            outputs.push(session.read(*id).await);
+        }
+        outputs.sort_by_key(|o| o.agent);
+        outputs
+    }
+
+    /// Kill a single session by id.
+    pub async fn kill(&self, id: u32) -> Result<()> {
+        let session = self.sessions.read().await.get(&id).cloned();
+        match session {
+            Some(session) => session.kill().await,
+            None => Ok(()),
+        }
+    }
+
+    /// Kill every registered session.
+    pub async fn kill_all(&self) -> Result<()> {
+        let sessions = self.sessions.read().await.values().cloned().collect::<Vec<_>>();
+        for session in sessions {
+            session.kill().await?;
+        }
+        Ok(())
+    }
+
+    /// Await completion of every registered session's background loop.
+    pub async fn join_all(&self) -> Result<()> {
+        let sessions = self.sessions.read().await.values().cloned().collect::<Vec<_>>();
+        for session in sessions {
+            session.join().await?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+// Maintainer-only research benchmark runner.
+//
+// Replays a JSON workload file's queries through a `ResearchSession` and
+// prints a terminal summary table, optionally writing a JSON report and/or
+// POSTing it to a tracking endpoint for regression comparisons across runs.
+//
+// Usage:
+//   research_bench <workload.json> [--json-out <path>] [--report-endpoint <url>]
+
+use anyhow::{Context, Result};
+use kodegen_tools_browser::research::{load_workload, report_to_endpoint, run_workload};
+use kodegen_tools_browser::BrowserManager;
+
+fn parse_flag(args: &[String], flag: &str) -> Option<String> {
+    args.iter()
+        .position(|a| a == flag)
+        .and_then(|i| args.get(i + 1))
+        .cloned()
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let workload_path = args
+        .first()
+        .cloned()
+        .context("usage: research_bench <workload.json> [--json-out <path>] [--report-endpoint <url>]")?;
+    let json_out = parse_flag(&args, "--json-out");
+    let report_endpoint = parse_flag(&args, "--report-endpoint");
+
+    let workload = load_workload(&workload_path).await?;
+    let browser_manager = BrowserManager::global();
+
+    let report = run_workload(&workload, browser_manager).await;
+
+    println!("{}", report.summary_table());
+
+    if let Some(path) = json_out {
+        tokio::fs::write(&path, serde_json::to_vec_pretty(&report)?)
+            .await
+            .with_context(|| format!("writing JSON report to {path}"))?;
+    }
+
+    if let Some(endpoint) = report_endpoint {
+        report_to_endpoint(&report, &endpoint).await?;
+    }
+
+    if report.failed > 0 {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,435 @@
+//! Pluggable browser backend: chromiumoxide/CDP today, classic WebDriver
+//! (geckodriver, remote grids) as a second implementation.
+//!
+//! [`BrowserBackend`] captures the operations the `tools` actually perform
+//! against a page - navigate, find an element, click, type, run script, read
+//! the DOM/screenshot, and size the window - mirroring the W3C WebDriver
+//! command set (`NewSession`, `Get`, `FindElement`, `ElementClick`,
+//! `ElementSendKeys`, `ExecuteScript`, `GetPageSource`, `TakeScreenshot`,
+//! `SetWindowRect`). [`CdpBackend`] wraps an existing chromiumoxide
+//! [`Page`]; [`WebDriverBackend`] speaks the wire protocol directly over
+//! HTTP to a `webdriver_url` (e.g. a local geckodriver or a remote grid).
+//!
+//! `BrowserManager` and the individual tools still operate on the concrete
+//! chromiumoxide types for now - migrating them onto `dyn BrowserBackend` is
+//! tracked as follow-up work so each tool can be moved over (and tested
+//! against both engines) independently instead of in one large rewrite.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use async_trait::async_trait;
+use chromiumoxide::Page;
+use chromiumoxide::cdp::browser_protocol::browser::{
+    Bounds, GetWindowForTargetParams, SetWindowBoundsParams,
+};
+use chromiumoxide::element::Element;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+use crate::browser::{BrowserError, BrowserResult};
+
+/// Opaque handle to an element found by [`BrowserBackend::find_element`].
+///
+/// Backed by a WebDriver element id for [`WebDriverBackend`] or a
+/// backend-local id for [`CdpBackend`] - never a raw chromiumoxide
+/// [`Element`]/CDP node id, so the trait stays object-safe.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElementRef(pub String);
+
+/// Browser window position and size, as returned/accepted by
+/// `GetWindowRect`/`SetWindowRect`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct WindowRect {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// The operations the browser tools need, abstracted over the underlying
+/// automation protocol.
+///
+/// Implementors drive a single page/session; `BrowserManager` owns the
+/// backend's lifecycle the same way it owns a `BrowserWrapper` today.
+#[async_trait]
+pub trait BrowserBackend: Send + Sync {
+    /// Navigate the current page to `url` (WebDriver `Get`).
+    async fn navigate(&self, url: &str) -> BrowserResult<()>;
+
+    /// Find the first element matching a CSS selector (WebDriver
+    /// `FindElement` with `using: "css selector"`).
+    async fn find_element(&self, selector: &str) -> BrowserResult<ElementRef>;
+
+    /// Click a previously-found element (WebDriver `ElementClick`).
+    async fn click(&self, element: &ElementRef) -> BrowserResult<()>;
+
+    /// Send keystrokes to a previously-found element (WebDriver
+    /// `ElementSendKeys`).
+    async fn send_keys(&self, element: &ElementRef, text: &str) -> BrowserResult<()>;
+
+    /// Run `script` in the page context with `args` available as the
+    /// script's `arguments` array, returning its JSON result (WebDriver
+    /// `ExecuteScript`).
+    async fn execute_script(
+        &self,
+        script: &str,
+        args: Vec<serde_json::Value>,
+    ) -> BrowserResult<serde_json::Value>;
+
+    /// Return the full serialized DOM of the current page (WebDriver
+    /// `GetPageSource`).
+    async fn page_source(&self) -> BrowserResult<String>;
+
+    /// Capture the current page as a PNG (WebDriver `TakeScreenshot`).
+    async fn screenshot(&self) -> BrowserResult<Vec<u8>>;
+
+    /// Read the current window position/size (WebDriver `GetWindowRect`).
+    async fn window_rect(&self) -> BrowserResult<WindowRect>;
+
+    /// Resize/reposition the window (WebDriver `SetWindowRect`).
+    async fn set_window_rect(&self, rect: WindowRect) -> BrowserResult<()>;
+}
+
+/// [`BrowserBackend`] over an existing chromiumoxide [`Page`] - the default
+/// engine, used when `BrowserConfig::engine` is `Cdp`.
+pub struct CdpBackend {
+    page: Page,
+    elements: Mutex<HashMap<String, Element>>,
+    next_element_id: AtomicU64,
+}
+
+impl CdpBackend {
+    #[must_use]
+    pub fn new(page: Page) -> Self {
+        Self {
+            page,
+            elements: Mutex::new(HashMap::new()),
+            next_element_id: AtomicU64::new(0),
+        }
+    }
+
+    async fn resolve(&self, element: &ElementRef) -> BrowserResult<Element> {
+        self.elements
+            .lock()
+            .await
+            .get(&element.0)
+            .cloned()
+            .ok_or_else(|| {
+                BrowserError::PageCreationFailed(format!("Unknown element: {}", element.0))
+            })
+    }
+}
+
+#[async_trait]
+impl BrowserBackend for CdpBackend {
+    async fn navigate(&self, url: &str) -> BrowserResult<()> {
+        self.page
+            .goto(url)
+            .await
+            .map_err(|e| BrowserError::NavigationFailed(e.to_string()))?;
+        self.page
+            .wait_for_navigation()
+            .await
+            .map_err(|e| BrowserError::NavigationFailed(e.to_string()))?;
+        Ok(())
+    }
+
+    async fn find_element(&self, selector: &str) -> BrowserResult<ElementRef> {
+        let element = self
+            .page
+            .find_element(selector)
+            .await
+            .map_err(|e| BrowserError::PageCreationFailed(e.to_string()))?;
+
+        let id = self
+            .next_element_id
+            .fetch_add(1, Ordering::Relaxed)
+            .to_string();
+        self.elements.lock().await.insert(id.clone(), element);
+        Ok(ElementRef(id))
+    }
+
+    async fn click(&self, element: &ElementRef) -> BrowserResult<()> {
+        let element = self.resolve(element).await?;
+        let point = element
+            .clickable_point()
+            .await
+            .map_err(|e| BrowserError::NavigationFailed(e.to_string()))?;
+        self.page
+            .click(point)
+            .await
+            .map_err(|e| BrowserError::NavigationFailed(e.to_string()))?;
+        Ok(())
+    }
+
+    async fn send_keys(&self, element: &ElementRef, text: &str) -> BrowserResult<()> {
+        let element = self.resolve(element).await?;
+        element
+            .type_str(text)
+            .await
+            .map_err(|e| BrowserError::NavigationFailed(e.to_string()))?;
+        Ok(())
+    }
+
+    async fn execute_script(
+        &self,
+        script: &str,
+        args: Vec<serde_json::Value>,
+    ) -> BrowserResult<serde_json::Value> {
+        // Wrap as an IIFE so `script` can `return` like a WebDriver
+        // execute-script body, with `arguments` bound to the provided args.
+        let wrapped = format!(
+            "(function(arguments) {{ {script} }})({})",
+            serde_json::to_string(&args).unwrap_or_else(|_| "[]".to_string())
+        );
+        let result = self
+            .page
+            .evaluate(wrapped)
+            .await
+            .map_err(|e| BrowserError::NavigationFailed(e.to_string()))?;
+        result
+            .into_value()
+            .map_err(|e| BrowserError::NavigationFailed(e.to_string()))
+    }
+
+    async fn page_source(&self) -> BrowserResult<String> {
+        self.page
+            .content()
+            .await
+            .map_err(|e| BrowserError::NavigationFailed(e.to_string()))
+    }
+
+    async fn screenshot(&self) -> BrowserResult<Vec<u8>> {
+        self.page
+            .screenshot(chromiumoxide::page::ScreenshotParams::builder().build())
+            .await
+            .map_err(|e| BrowserError::NavigationFailed(e.to_string()))
+    }
+
+    async fn window_rect(&self) -> BrowserResult<WindowRect> {
+        let window = self
+            .page
+            .execute(GetWindowForTargetParams::default())
+            .await
+            .map_err(|e| BrowserError::NavigationFailed(e.to_string()))?;
+        let bounds = &window.bounds;
+        Ok(WindowRect {
+            x: bounds.left.unwrap_or(0) as i32,
+            y: bounds.top.unwrap_or(0) as i32,
+            width: bounds.width.unwrap_or(0) as u32,
+            height: bounds.height.unwrap_or(0) as u32,
+        })
+    }
+
+    async fn set_window_rect(&self, rect: WindowRect) -> BrowserResult<()> {
+        let window = self
+            .page
+            .execute(GetWindowForTargetParams::default())
+            .await
+            .map_err(|e| BrowserError::NavigationFailed(e.to_string()))?;
+        let bounds = Bounds {
+            left: Some(rect.x as i64),
+            top: Some(rect.y as i64),
+            width: Some(rect.width as i64),
+            height: Some(rect.height as i64),
+            window_state: None,
+        };
+        self.page
+            .execute(SetWindowBoundsParams::new(window.window_id, bounds))
+            .await
+            .map_err(|e| BrowserError::NavigationFailed(e.to_string()))?;
+        Ok(())
+    }
+}
+
+/// [`BrowserBackend`] speaking the classic (W3C) WebDriver wire protocol
+/// over HTTP, so the same tools can drive geckodriver/Firefox or a remote
+/// Selenium/WebDriver grid - used when `BrowserConfig::engine` is
+/// `WebDriver`.
+pub struct WebDriverBackend {
+    client: reqwest::Client,
+    /// Base WebDriver server URL, e.g. `http://localhost:4444`.
+    server_url: String,
+    session_id: String,
+}
+
+/// The W3C "web element identifier" key WebDriver responses use to mark a
+/// JSON value as an element reference.
+const WEB_ELEMENT_KEY: &str = "element-6066-11e4-a52e-4f735466cecf";
+
+impl WebDriverBackend {
+    /// Start a new WebDriver session (`NewSession`) against `server_url`
+    /// (e.g. `http://localhost:4444` for a local geckodriver).
+    pub async fn connect(server_url: &str) -> BrowserResult<Self> {
+        let client = reqwest::Client::new();
+        let server_url = server_url.trim_end_matches('/').to_string();
+
+        let body = serde_json::json!({
+            "capabilities": {
+                "alwaysMatch": { "browserName": "firefox" }
+            }
+        });
+        let response: serde_json::Value = client
+            .post(format!("{server_url}/session"))
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| BrowserError::LaunchFailed(e.to_string()))?
+            .json()
+            .await
+            .map_err(|e| BrowserError::LaunchFailed(e.to_string()))?;
+
+        let session_id = response["value"]["sessionId"]
+            .as_str()
+            .ok_or_else(|| {
+                BrowserError::LaunchFailed("WebDriver NewSession response missing sessionId".into())
+            })?
+            .to_string();
+
+        Ok(Self {
+            client,
+            server_url,
+            session_id,
+        })
+    }
+
+    fn session_url(&self, path: &str) -> String {
+        format!("{}/session/{}{}", self.server_url, self.session_id, path)
+    }
+
+    async fn get(&self, path: &str) -> BrowserResult<serde_json::Value> {
+        self.client
+            .get(self.session_url(path))
+            .send()
+            .await
+            .map_err(|e| BrowserError::NavigationFailed(e.to_string()))?
+            .json()
+            .await
+            .map_err(|e| BrowserError::NavigationFailed(e.to_string()))
+    }
+
+    async fn post(&self, path: &str, body: serde_json::Value) -> BrowserResult<serde_json::Value> {
+        self.client
+            .post(self.session_url(path))
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| BrowserError::NavigationFailed(e.to_string()))?
+            .json()
+            .await
+            .map_err(|e| BrowserError::NavigationFailed(e.to_string()))
+    }
+
+    /// End the WebDriver session. Not automatic on drop (ending a session
+    /// is a network call); callers should invoke this during shutdown the
+    /// same way `BrowserManager::shutdown` closes the CDP browser.
+    pub async fn close(&self) -> BrowserResult<()> {
+        self.client
+            .delete(self.session_url(""))
+            .send()
+            .await
+            .map_err(|e| BrowserError::NavigationFailed(e.to_string()))?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl BrowserBackend for WebDriverBackend {
+    async fn navigate(&self, url: &str) -> BrowserResult<()> {
+        self.post("/url", serde_json::json!({ "url": url })).await?;
+        Ok(())
+    }
+
+    async fn find_element(&self, selector: &str) -> BrowserResult<ElementRef> {
+        let response = self
+            .post(
+                "/element",
+                serde_json::json!({ "using": "css selector", "value": selector }),
+            )
+            .await?;
+        let id = response["value"][WEB_ELEMENT_KEY]
+            .as_str()
+            .ok_or_else(|| {
+                BrowserError::PageCreationFailed(format!("No element matching: {selector}"))
+            })?
+            .to_string();
+        Ok(ElementRef(id))
+    }
+
+    async fn click(&self, element: &ElementRef) -> BrowserResult<()> {
+        self.post(
+            &format!("/element/{}/click", element.0),
+            serde_json::json!({}),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn send_keys(&self, element: &ElementRef, text: &str) -> BrowserResult<()> {
+        self.post(
+            &format!("/element/{}/value", element.0),
+            serde_json::json!({ "text": text }),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn execute_script(
+        &self,
+        script: &str,
+        args: Vec<serde_json::Value>,
+    ) -> BrowserResult<serde_json::Value> {
+        let response = self
+            .post(
+                "/execute/sync",
+                serde_json::json!({ "script": script, "args": args }),
+            )
+            .await?;
+        Ok(response["value"].clone())
+    }
+
+    async fn page_source(&self) -> BrowserResult<String> {
+        let response = self.get("/source").await?;
+        response["value"]
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| BrowserError::NavigationFailed("GetPageSource returned no value".into()))
+    }
+
+    async fn screenshot(&self) -> BrowserResult<Vec<u8>> {
+        use base64::Engine as _;
+        let response = self.get("/screenshot").await?;
+        let encoded = response["value"].as_str().ok_or_else(|| {
+            BrowserError::NavigationFailed("TakeScreenshot returned no value".into())
+        })?;
+        base64::engine::general_purpose::STANDARD
+            .decode(encoded)
+            .map_err(|e| BrowserError::NavigationFailed(format!("Invalid screenshot base64: {e}")))
+    }
+
+    async fn window_rect(&self) -> BrowserResult<WindowRect> {
+        let response = self.get("/window/rect").await?;
+        let value = &response["value"];
+        Ok(WindowRect {
+            x: value["x"].as_i64().unwrap_or(0) as i32,
+            y: value["y"].as_i64().unwrap_or(0) as i32,
+            width: value["width"].as_u64().unwrap_or(0) as u32,
+            height: value["height"].as_u64().unwrap_or(0) as u32,
+        })
+    }
+
+    async fn set_window_rect(&self, rect: WindowRect) -> BrowserResult<()> {
+        self.post(
+            "/window/rect",
+            serde_json::json!({
+                "x": rect.x,
+                "y": rect.y,
+                "width": rect.width,
+                "height": rect.height,
+            }),
+        )
+        .await?;
+        Ok(())
+    }
+}
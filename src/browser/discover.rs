@@ -0,0 +1,336 @@
+//! Multi-channel Chrome/Chromium discovery.
+//!
+//! `browser_setup::find_browser_executable` takes whatever Chrome-family
+//! binary it finds first; this module instead probes in a fixed channel
+//! preference order (Chromium, Chrome, Chrome Beta, Chrome Dev, Chrome
+//! Canary, Brave, Edge, ungoogled-chromium) so `BrowserConfig` can ask for a
+//! specific one, and additionally locates that channel's real user profile
+//! directory so `browser.use_real_profile`/`browser.attach_real_profile`
+//! can launch against it.
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+/// A Chrome/Chromium-family release channel or browser flavor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BrowserChannel {
+    Chromium,
+    Chrome,
+    ChromeBeta,
+    ChromeDev,
+    ChromeCanary,
+    /// Chromium built with Google integrations/telemetry patched out.
+    UngoogledChromium,
+    Brave,
+    Edge,
+}
+
+impl BrowserChannel {
+    /// Every channel, in the order [`discover`] probes them when no
+    /// specific channel is requested.
+    const ALL: [BrowserChannel; 8] = [
+        Self::Chromium,
+        Self::Chrome,
+        Self::ChromeBeta,
+        Self::ChromeDev,
+        Self::ChromeCanary,
+        Self::Brave,
+        Self::Edge,
+        Self::UngoogledChromium,
+    ];
+
+    /// Whether this flavor already strips Google's own telemetry/network
+    /// integrations, making this crate's own `--disable-background-networking`/
+    /// `--metrics-recording-only` stealth flags redundant (and, for some
+    /// forks, a flag their binary doesn't recognize). See
+    /// `browser_setup::launch_browser`.
+    pub(crate) fn skips_google_telemetry(self) -> bool {
+        matches!(self, Self::Brave | Self::UngoogledChromium)
+    }
+
+    /// Candidate executable paths for this channel, most to least likely,
+    /// by platform. Mirrors `browser_setup::find_browser_executable`'s
+    /// path list, split out per channel.
+    pub(crate) fn executable_candidates(self) -> Vec<PathBuf> {
+        let raw: &[&str] = if cfg!(target_os = "windows") {
+            match self {
+                Self::Chromium => &[
+                    r"C:\Program Files\Chromium\Application\chrome.exe",
+                    r"C:\Program Files (x86)\Chromium\Application\chrome.exe",
+                ],
+                Self::Chrome => &[
+                    r"C:\Program Files\Google\Chrome\Application\chrome.exe",
+                    r"C:\Program Files (x86)\Google\Chrome\Application\chrome.exe",
+                    r"%LOCALAPPDATA%\Google\Chrome\Application\chrome.exe",
+                ],
+                Self::ChromeBeta => {
+                    &[r"C:\Program Files\Google\Chrome Beta\Application\chrome.exe"]
+                }
+                Self::ChromeDev => &[r"C:\Program Files\Google\Chrome Dev\Application\chrome.exe"],
+                Self::ChromeCanary => {
+                    &[r"%LOCALAPPDATA%\Google\Chrome SxS\Application\chrome.exe"]
+                }
+                Self::UngoogledChromium => &[r"C:\Program Files\ungoogled-chromium\chrome.exe"],
+                Self::Brave => &[
+                    r"C:\Program Files\BraveSoftware\Brave-Browser\Application\brave.exe",
+                    r"%LOCALAPPDATA%\BraveSoftware\Brave-Browser\Application\brave.exe",
+                ],
+                Self::Edge => &[
+                    r"C:\Program Files (x86)\Microsoft\Edge\Application\msedge.exe",
+                    r"C:\Program Files\Microsoft\Edge\Application\msedge.exe",
+                ],
+            }
+        } else if cfg!(target_os = "macos") {
+            match self {
+                Self::Chromium => &[
+                    "/Applications/Chromium.app/Contents/MacOS/Chromium",
+                    "~/Applications/Chromium.app/Contents/MacOS/Chromium",
+                ],
+                Self::Chrome => &[
+                    "/Applications/Google Chrome.app/Contents/MacOS/Google Chrome",
+                    "~/Applications/Google Chrome.app/Contents/MacOS/Google Chrome",
+                ],
+                Self::ChromeBeta => {
+                    &["/Applications/Google Chrome Beta.app/Contents/MacOS/Google Chrome Beta"]
+                }
+                Self::ChromeDev => {
+                    &["/Applications/Google Chrome Dev.app/Contents/MacOS/Google Chrome Dev"]
+                }
+                Self::ChromeCanary => &[
+                    "/Applications/Google Chrome Canary.app/Contents/MacOS/Google Chrome Canary",
+                ],
+                Self::UngoogledChromium => &["/Applications/Chromium.app/Contents/MacOS/Chromium"],
+                Self::Brave => {
+                    &["/Applications/Brave Browser.app/Contents/MacOS/Brave Browser"]
+                }
+                Self::Edge => {
+                    &["/Applications/Microsoft Edge.app/Contents/MacOS/Microsoft Edge"]
+                }
+            }
+        } else {
+            match self {
+                Self::Chromium => &[
+                    "/usr/bin/chromium",
+                    "/usr/bin/chromium-browser",
+                    "/snap/bin/chromium",
+                    "/usr/local/bin/chromium",
+                ],
+                Self::Chrome => &[
+                    "/usr/bin/google-chrome",
+                    "/usr/bin/google-chrome-stable",
+                    "/opt/google/chrome/chrome",
+                ],
+                Self::ChromeBeta => &["/usr/bin/google-chrome-beta"],
+                Self::ChromeDev => &["/usr/bin/google-chrome-unstable"],
+                Self::ChromeCanary => &[],
+                Self::UngoogledChromium => &[
+                    "/usr/bin/ungoogled-chromium",
+                    "/usr/lib/chromium/ungoogled-chromium",
+                ],
+                Self::Brave => &["/usr/bin/brave-browser", "/usr/bin/brave"],
+                Self::Edge => &["/usr/bin/microsoft-edge", "/usr/bin/microsoft-edge-stable"],
+            }
+        };
+
+        raw.iter().map(|path| expand_path(path)).collect()
+    }
+
+    /// This channel's real user profile ("user data") directory, if this
+    /// platform has a known one.
+    ///
+    /// Exposed crate-wide (not just to this module) so
+    /// [`crate::chrome_paths`] can compute the same path for the direct,
+    /// no-copy attach flow (`browser.attach_real_profile`) that this
+    /// module's own [`prepare_real_profile_copy`] deliberately avoids.
+    pub(crate) fn real_profile_dir(self) -> Option<PathBuf> {
+        let home = dirs::home_dir()?;
+        Some(if cfg!(target_os = "windows") {
+            let local_appdata = std::env::var("LOCALAPPDATA").ok().map(PathBuf::from)?;
+            match self {
+                Self::Chromium | Self::UngoogledChromium => {
+                    local_appdata.join("Chromium").join("User Data")
+                }
+                Self::Chrome => local_appdata
+                    .join("Google")
+                    .join("Chrome")
+                    .join("User Data"),
+                Self::ChromeBeta => local_appdata
+                    .join("Google")
+                    .join("Chrome Beta")
+                    .join("User Data"),
+                Self::ChromeDev => local_appdata
+                    .join("Google")
+                    .join("Chrome Dev")
+                    .join("User Data"),
+                Self::ChromeCanary => local_appdata
+                    .join("Google")
+                    .join("Chrome SxS")
+                    .join("User Data"),
+                Self::Brave => local_appdata
+                    .join("BraveSoftware")
+                    .join("Brave-Browser")
+                    .join("User Data"),
+                Self::Edge => local_appdata.join("Microsoft").join("Edge").join("User Data"),
+            }
+        } else if cfg!(target_os = "macos") {
+            let app_support = home.join("Library").join("Application Support");
+            match self {
+                Self::Chromium | Self::UngoogledChromium => app_support.join("Chromium"),
+                Self::Chrome => app_support.join("Google").join("Chrome"),
+                Self::ChromeBeta => app_support.join("Google").join("Chrome Beta"),
+                Self::ChromeDev => app_support.join("Google").join("Chrome Dev"),
+                Self::ChromeCanary => app_support.join("Google").join("Chrome Canary"),
+                Self::Brave => app_support.join("BraveSoftware").join("Brave-Browser"),
+                Self::Edge => app_support.join("Microsoft Edge"),
+            }
+        } else {
+            match self {
+                Self::Chromium | Self::UngoogledChromium => home.join(".config").join("chromium"),
+                Self::Chrome => home.join(".config").join("google-chrome"),
+                Self::ChromeBeta => home.join(".config").join("google-chrome-beta"),
+                Self::ChromeDev => home.join(".config").join("google-chrome-unstable"),
+                Self::ChromeCanary => home.join(".config").join("google-chrome-canary"),
+                Self::Brave => home
+                    .join(".config")
+                    .join("BraveSoftware")
+                    .join("Brave-Browser"),
+                Self::Edge => home.join(".config").join("microsoft-edge"),
+            }
+        })
+    }
+}
+
+/// Expand a leading `~` against the home directory; otherwise return the
+/// path unchanged. General Windows `%VAR%` expansion isn't needed here since
+/// [`BrowserChannel::executable_candidates`]'s Windows paths that need it
+/// all start with `%LOCALAPPDATA%\`, which is resolved via `LOCALAPPDATA`
+/// directly.
+fn expand_path(path: &str) -> PathBuf {
+    if let Some(rest) = path.strip_prefix("~/") {
+        if let Some(home) = dirs::home_dir() {
+            return home.join(rest);
+        }
+    }
+    if let Some(rest) = path.strip_prefix(r"%LOCALAPPDATA%\") {
+        if let Ok(local_appdata) = std::env::var("LOCALAPPDATA") {
+            return PathBuf::from(local_appdata).join(rest);
+        }
+    }
+    PathBuf::from(path)
+}
+
+/// A browser channel found on disk: its executable and, if it exists, its
+/// real user profile directory.
+pub struct DiscoveredBrowser {
+    pub channel: BrowserChannel,
+    pub executable: PathBuf,
+    pub profile_dir: Option<PathBuf>,
+}
+
+/// Probe for an installed browser. If `preferred` is given, only that
+/// channel is checked; otherwise every channel is tried in
+/// [`BrowserChannel::ALL`] preference order. Returns the first channel
+/// whose executable actually exists on disk.
+#[must_use]
+pub fn discover(preferred: Option<BrowserChannel>) -> Option<DiscoveredBrowser> {
+    let candidates: &[BrowserChannel] = match &preferred {
+        Some(channel) => std::slice::from_ref(channel),
+        None => &BrowserChannel::ALL,
+    };
+
+    for &channel in candidates {
+        if let Some(executable) = channel
+            .executable_candidates()
+            .into_iter()
+            .find(|path| path.exists())
+        {
+            let profile_dir = channel.real_profile_dir().filter(|dir| dir.exists());
+            return Some(DiscoveredBrowser {
+                channel,
+                executable,
+                profile_dir,
+            });
+        }
+    }
+
+    None
+}
+
+/// For `browser.use_real_profile`: discover `channel`'s real user profile
+/// and copy it into a fresh temp directory, so the launched browser sees
+/// the user's cookies/logins/extensions without ever touching (or
+/// risking corrupting) the live profile Chrome itself may have open.
+///
+/// The returned directory is a plain temp directory like any other
+/// `launch_browser()` profile - `BrowserWrapper::cleanup_temp_dir` removes
+/// it the same way.
+pub async fn prepare_real_profile_copy(channel: Option<BrowserChannel>) -> Result<PathBuf> {
+    let discovered =
+        discover(channel).context("use_real_profile requested but no browser was discovered")?;
+    let Some(profile_dir) = discovered.profile_dir else {
+        anyhow::bail!(
+            "use_real_profile requested but no real profile directory was found for {:?}",
+            discovered.channel
+        );
+    };
+
+    let dest = std::env::temp_dir().join(format!(
+        "kodegen_browser_real_profile_{}",
+        std::process::id()
+    ));
+
+    let profile_dir_clone = profile_dir.clone();
+    let dest_clone = dest.clone();
+    tokio::task::spawn_blocking(move || copy_dir_recursive(&profile_dir_clone, &dest_clone))
+        .await
+        .context("Profile copy task panicked")?
+        .with_context(|| {
+            format!(
+                "Failed to copy real profile from {} to {}",
+                profile_dir.display(),
+                dest.display()
+            )
+        })?;
+
+    Ok(dest)
+}
+
+/// Recursively copy `src` into `dst`, creating `dst` if needed. Per-entry
+/// failures (e.g. a `SingletonLock`/`SingletonSocket` file held open by a
+/// running Chrome instance) are logged and skipped rather than aborting
+/// the whole copy - we want a best-effort snapshot of the profile, not a
+/// byte-for-byte mirror.
+fn copy_dir_recursive(src: &Path, dst: &Path) -> io::Result<()> {
+    std::fs::create_dir_all(dst)?;
+
+    for entry in std::fs::read_dir(src)? {
+        let entry = entry?;
+        let dst_path = dst.join(entry.file_name());
+        let file_type = match entry.file_type() {
+            Ok(file_type) => file_type,
+            Err(e) => {
+                warn!("Skipping {}: {}", entry.path().display(), e);
+                continue;
+            }
+        };
+
+        let result = if file_type.is_dir() {
+            copy_dir_recursive(&entry.path(), &dst_path)
+        } else if file_type.is_file() {
+            std::fs::copy(entry.path(), &dst_path).map(|_| ())
+        } else {
+            Ok(())
+        };
+
+        if let Err(e) = result {
+            warn!("Skipping {}: {}", entry.path().display(), e);
+        }
+    }
+
+    Ok(())
+}
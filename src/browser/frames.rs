@@ -0,0 +1,36 @@
+//! Frame-chain resolution for iframe traversal (WebDriver's
+//! `SwitchToFrame`/`SwitchToParentFrame`, done the CDP-native way).
+//!
+//! chromiumoxide represents a frame's document as its own `Page` handle
+//! (`Element::content_frame`), so "switching into a frame" is just finding
+//! the `<iframe>`/`<frame>` element in the current document and asking for
+//! its content frame. [`resolve_frame_chain`] walks a list of iframe
+//! selectors - outermost frame first, exactly as a caller would nest them -
+//! and returns the `Page` scoped to the innermost one, so `find_element`,
+//! `wait_for_element`, and `scroll_into_view` all work against it exactly
+//! as they would the top-level page.
+
+use chromiumoxide::Page;
+
+/// Resolve a chain of iframe selectors to the `Page` scoped to the
+/// innermost frame. An empty `frame_path` returns `page` itself unchanged.
+///
+/// Each entry is a CSS selector for an `<iframe>`/`<frame>` element,
+/// resolved against the frame reached by the previous entry (or `page` for
+/// the first one).
+pub async fn resolve_frame_chain(page: &Page, frame_path: &[String]) -> anyhow::Result<Page> {
+    let mut current = page.clone();
+    for selector in frame_path {
+        let element = current
+            .find_element(selector)
+            .await
+            .map_err(|e| anyhow::anyhow!("Frame selector '{}' not found: {}", selector, e))?;
+        current = element.content_frame().await?.ok_or_else(|| {
+            anyhow::anyhow!(
+                "Element '{}' has no content frame (not an <iframe>/<frame>)",
+                selector
+            )
+        })?;
+    }
+    Ok(current)
+}
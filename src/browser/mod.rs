@@ -2,14 +2,59 @@
 //!
 //! Based on production-tested code from packages/tools-citescrape
 
+mod backend;
+pub mod discover;
+mod frames;
+pub mod monitor;
+mod tab_pool;
+pub mod webauthn;
 mod wrapper;
 
 pub use crate::browser_setup::{download_managed_browser, find_browser_executable};
-pub use wrapper::{BrowserWrapper, create_blank_page, get_current_page, launch_browser};
+pub use backend::{BrowserBackend, CdpBackend, ElementRef, WebDriverBackend, WindowRect};
+pub use discover::BrowserChannel;
+pub use frames::resolve_frame_chain;
+pub use tab_pool::{PooledTab, TabPool};
+pub use wrapper::{
+    BrowserWrapper, connect_browser, connect_browser_on_port, create_blank_page,
+    get_current_page, launch_browser,
+};
 
 use chromiumoxide::page::Page;
 use std::sync::Arc;
 
+/// Connect a [`BrowserBackend`] matching `config.engine`.
+///
+/// `Cdp` wraps a fresh blank page created on `wrapper` via
+/// [`create_blank_page`] (not its current page), since the tools drive one
+/// page per backend; the
+/// `wrapper` must already be launched (see [`BrowserManager::get_or_launch`]).
+/// `WebDriver` opens a new session against `config.webdriver_url` and
+/// ignores `wrapper` entirely, since it drives a separate browser process
+/// (e.g. geckodriver) over HTTP instead.
+pub async fn connect_backend(
+    config: &crate::BrowserConfig,
+    wrapper: &BrowserWrapper,
+) -> BrowserResult<Box<dyn BrowserBackend>> {
+    match config.engine {
+        crate::BrowserEngine::Cdp => {
+            let page = create_blank_page(wrapper)
+                .await
+                .map_err(|e| BrowserError::PageCreationFailed(e.to_string()))?;
+            Ok(Box::new(CdpBackend::new(page)))
+        }
+        crate::BrowserEngine::WebDriver => {
+            let url = config.webdriver_url.as_deref().ok_or_else(|| {
+                BrowserError::LaunchFailed(
+                    "BrowserConfig::webdriver_url is required when engine = \"webdriver\"".into(),
+                )
+            })?;
+            let backend = WebDriverBackend::connect(url).await?;
+            Ok(Box::new(backend))
+        }
+    }
+}
+
 /// Browser context wrapper for legacy code compatibility
 ///
 /// NOTE: In hot path, prefer using existing tools via MCP client.
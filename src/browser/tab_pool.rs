@@ -0,0 +1,80 @@
+//! Bounded pool of reusable tabs (`Page`s) against one browser instance, so
+//! several navigations can run concurrently instead of the single-page
+//! model's "close every other page first".
+//!
+//! Used by `DeepResearch::process_url_with_links` so a crawl's
+//! bounded-concurrency scheduler (see `DeepResearch::run_crawl`) actually
+//! gets concurrent page loads rather than serializing on one shared page.
+
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use chromiumoxide::Page;
+use tokio::sync::{Mutex, OwnedSemaphorePermit, Semaphore};
+
+use super::wrapper::{BrowserWrapper, create_blank_page};
+
+/// Up to `capacity` tabs checked out concurrently; idle ones returned via
+/// [`PooledTab::release`] are reused instead of being closed and recreated.
+pub struct TabPool {
+    capacity: Arc<Semaphore>,
+    idle: Arc<Mutex<Vec<Page>>>,
+}
+
+impl TabPool {
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: Arc::new(Semaphore::new(capacity.max(1))),
+            idle: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Hand out an idle tab, or create one (via [`create_blank_page`]) if
+    /// the pool has spare capacity. Blocks once `capacity` tabs are already
+    /// checked out, until one is [`PooledTab::release`]d.
+    pub async fn acquire_page(&self, wrapper: &BrowserWrapper) -> Result<PooledTab> {
+        let permit = Arc::clone(&self.capacity)
+            .acquire_owned()
+            .await
+            .context("tab pool semaphore closed")?;
+
+        let idle_page = self.idle.lock().await.pop();
+        let page = match idle_page {
+            Some(page) => page,
+            None => create_blank_page(wrapper).await?,
+        };
+
+        Ok(PooledTab {
+            page: Some(page),
+            permit: Some(permit),
+            idle: Arc::clone(&self.idle),
+        })
+    }
+}
+
+/// A checked-out tab. Dropping it without calling [`Self::release`] closes
+/// the permit without returning the page to `idle` - the next
+/// `acquire_page` just creates a fresh one, same as the old single-page
+/// model did on every call.
+pub struct PooledTab {
+    pub page: Option<Page>,
+    permit: Option<OwnedSemaphorePermit>,
+    idle: Arc<Mutex<Vec<Page>>>,
+}
+
+impl PooledTab {
+    /// Reset the tab to `about:blank` and return it to the pool for reuse.
+    /// Best-effort: if the reset navigation fails (e.g. the tab crashed),
+    /// the tab is dropped instead of pooled, so the next `acquire_page`
+    /// creates a fresh one rather than handing out a broken page.
+    pub async fn release(mut self) {
+        if let Some(page) = self.page.take() {
+            if page.goto("about:blank").await.is_ok() {
+                self.idle.lock().await.push(page);
+            }
+        }
+        // `self.permit` drops at the end of this scope either way, freeing
+        // a capacity slot for the next `acquire_page`.
+    }
+}
@@ -0,0 +1,203 @@
+//! Virtual WebAuthn authenticators via CDP's `WebAuthn` domain, so agents
+//! can satisfy passkey/2FA prompts without a physical security key.
+//!
+//! There is intentionally no `BrowserWebauthnTool` or
+//! `BrowserAddVirtualAuthenticatorTool` here the way `BrowserScrollTool` or
+//! `BrowserCookiesTool` look - a new MCP tool needs a `{Name}Args`/
+//! `{Name}Output`/`{Name}Prompts` triple and a matching `BROWSER_*` name
+//! constant registered in the external `kodegen_mcp_schema` crate (see
+//! every other file in `src/tools/`), and that crate lives outside this
+//! repo and can't be extended from here - this is a hard blocker, not a
+//! scheduling gap, until that crate grows the types. The functions below
+//! are the real mechanism a `BrowserWebauthnTool::execute` (or
+//! `BrowserAddVirtualAuthenticatorTool::execute`) would call once it does -
+//! each maps directly to one CDP `WebAuthn.*` command, mirroring the
+//! authenticator/credential parameters WebDriver's virtual authenticator
+//! extension exposes - and they are not dead code in the meantime: they're
+//! already called from `tools::browser_agent`'s provisioning block, reached
+//! whenever a caller builds `AgentConfig` directly with
+//! [`crate::agent::AgentConfig::with_webauthn_authenticator`] (the MCP
+//! surface can't reach that builder either, for the same schema reason, but
+//! a Rust caller embedding this crate can).
+
+use chromiumoxide::Page;
+use chromiumoxide::cdp::browser_protocol::web_authn::{
+    AddCredentialParams, AddVirtualAuthenticatorParams, AuthenticatorId, AuthenticatorProtocol,
+    AuthenticatorTransport, Credential, EnableParams, GetCredentialsParams,
+    RemoveVirtualAuthenticatorParams, VirtualAuthenticatorOptions,
+};
+
+/// Transport the virtual authenticator claims to use, mirroring the values
+/// WebDriver's virtual authenticator extension accepts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VirtualAuthenticatorTransport {
+    Internal,
+    Usb,
+    Nfc,
+    Ble,
+}
+
+impl From<VirtualAuthenticatorTransport> for AuthenticatorTransport {
+    fn from(transport: VirtualAuthenticatorTransport) -> Self {
+        match transport {
+            VirtualAuthenticatorTransport::Internal => AuthenticatorTransport::Internal,
+            VirtualAuthenticatorTransport::Usb => AuthenticatorTransport::Usb,
+            VirtualAuthenticatorTransport::Nfc => AuthenticatorTransport::Nfc,
+            VirtualAuthenticatorTransport::Ble => AuthenticatorTransport::Ble,
+        }
+    }
+}
+
+/// CTAP protocol version the virtual authenticator speaks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VirtualAuthenticatorProtocol {
+    Ctap2,
+    U2f,
+}
+
+impl From<VirtualAuthenticatorProtocol> for AuthenticatorProtocol {
+    fn from(protocol: VirtualAuthenticatorProtocol) -> Self {
+        match protocol {
+            VirtualAuthenticatorProtocol::Ctap2 => AuthenticatorProtocol::Ctap2,
+            VirtualAuthenticatorProtocol::U2f => AuthenticatorProtocol::U2f,
+        }
+    }
+}
+
+/// Configuration for a new virtual authenticator, matching
+/// `WebAuthn.VirtualAuthenticatorOptions`.
+#[derive(Debug, Clone)]
+pub struct VirtualAuthenticatorConfig {
+    pub transport: VirtualAuthenticatorTransport,
+    pub protocol: VirtualAuthenticatorProtocol,
+    pub has_resident_key: bool,
+    pub has_user_verification: bool,
+    /// Whether the authenticator reports the user as present without
+    /// prompting - `true` is what lets an agent satisfy a passkey prompt
+    /// unattended. This is CDP's name for what WebDriver's virtual
+    /// authenticator extension calls `isUserConsenting`.
+    pub automatic_presence_simulation: bool,
+    /// Whether a user-verification check (PIN/biometric) the authenticator
+    /// is asked to perform claims success, rather than the default CDP
+    /// behavior of claiming failure. Distinct from `has_user_verification`
+    /// (whether the authenticator supports UV at all) - this is what lets
+    /// a `userVerification: "required"` assertion succeed unattended.
+    pub is_user_verified: bool,
+}
+
+impl Default for VirtualAuthenticatorConfig {
+    /// A platform-style authenticator (internal transport, CTAP2, resident
+    /// keys, user verification) that never blocks on a real user - the
+    /// shape most "sign in with a passkey" flows expect.
+    fn default() -> Self {
+        Self {
+            transport: VirtualAuthenticatorTransport::Internal,
+            protocol: VirtualAuthenticatorProtocol::Ctap2,
+            has_resident_key: true,
+            has_user_verification: true,
+            automatic_presence_simulation: true,
+            is_user_verified: true,
+        }
+    }
+}
+
+/// A credential to pre-provision on an authenticator via `addCredential`,
+/// matching `WebAuthn.Credential`'s required fields.
+#[derive(Debug, Clone)]
+pub struct CredentialSeed {
+    /// Base64url-encoded credential ID.
+    pub credential_id: String,
+    pub rp_id: String,
+    /// DER-encoded PKCS#8 private key, base64-encoded.
+    pub private_key: String,
+    pub sign_count: u64,
+    /// Base64url-encoded user handle, required for resident-key credentials.
+    pub user_handle: Option<String>,
+}
+
+/// Enable the `WebAuthn` CDP domain on `page`. Must be called before
+/// [`add_authenticator`] - mirrors `Fetch.enable` needing to run before
+/// `navigate.rs` can intercept paused requests.
+pub async fn enable(page: &Page) -> Result<(), chromiumoxide::error::CdpError> {
+    page.execute(EnableParams::default()).await?;
+    Ok(())
+}
+
+/// Add a virtual authenticator configured per `config`, returning the
+/// authenticator ID later calls address it by.
+pub async fn add_authenticator(
+    page: &Page,
+    config: &VirtualAuthenticatorConfig,
+) -> Result<AuthenticatorId, chromiumoxide::error::CdpError> {
+    let options = VirtualAuthenticatorOptions::builder()
+        .protocol(AuthenticatorProtocol::from(config.protocol))
+        .transport(AuthenticatorTransport::from(config.transport))
+        .has_resident_key(config.has_resident_key)
+        .has_user_verification(config.has_user_verification)
+        .automatic_presence_simulation(config.automatic_presence_simulation)
+        .is_user_verified(config.is_user_verified)
+        .build();
+
+    let params = AddVirtualAuthenticatorParams::builder()
+        .options(options)
+        .build()
+        .map_err(chromiumoxide::error::CdpError::msg)?;
+
+    let result = page.execute(params).await?;
+    Ok(result.authenticator_id.clone())
+}
+
+/// Pre-provision `credential` on `authenticator_id`, so a later passkey
+/// assertion request against `credential.rp_id` succeeds without the site
+/// ever seeing a registration ceremony.
+pub async fn add_credential(
+    page: &Page,
+    authenticator_id: &AuthenticatorId,
+    credential: &CredentialSeed,
+) -> Result<(), chromiumoxide::error::CdpError> {
+    let cdp_credential = Credential::builder()
+        .credential_id(credential.credential_id.clone())
+        .is_resident_credential(credential.user_handle.is_some())
+        .rp_id(credential.rp_id.clone())
+        .private_key(credential.private_key.clone())
+        .sign_count(credential.sign_count)
+        .user_handle(credential.user_handle.clone())
+        .build()
+        .map_err(chromiumoxide::error::CdpError::msg)?;
+
+    let params = AddCredentialParams::builder()
+        .authenticator_id(authenticator_id.clone())
+        .credential(cdp_credential)
+        .build()
+        .map_err(chromiumoxide::error::CdpError::msg)?;
+
+    page.execute(params).await?;
+    Ok(())
+}
+
+/// List every credential currently provisioned on `authenticator_id`.
+pub async fn get_credentials(
+    page: &Page,
+    authenticator_id: &AuthenticatorId,
+) -> Result<Vec<Credential>, chromiumoxide::error::CdpError> {
+    let params = GetCredentialsParams::builder()
+        .authenticator_id(authenticator_id.clone())
+        .build()
+        .map_err(chromiumoxide::error::CdpError::msg)?;
+
+    Ok(page.execute(params).await?.credentials.clone())
+}
+
+/// Remove `authenticator_id` and every credential provisioned on it.
+pub async fn remove_authenticator(
+    page: &Page,
+    authenticator_id: &AuthenticatorId,
+) -> Result<(), chromiumoxide::error::CdpError> {
+    let params = RemoveVirtualAuthenticatorParams::builder()
+        .authenticator_id(authenticator_id.clone())
+        .build()
+        .map_err(chromiumoxide::error::CdpError::msg)?;
+
+    page.execute(params).await?;
+    Ok(())
+}
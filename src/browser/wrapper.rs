@@ -19,14 +19,45 @@ pub struct BrowserWrapper {
     browser: Browser,
     handler: JoinHandle<()>,
     user_data_dir: Option<PathBuf>,
+
+    /// OS pid of the launched Chrome process, if it could be found (see
+    /// [`super::monitor::find_chrome_pid`]). Used by
+    /// `BrowserManager`'s resource monitor to sample memory/liveness.
+    pid: Option<u32>,
 }
 
 impl BrowserWrapper {
     pub(crate) fn new(browser: Browser, handler: JoinHandle<()>, user_data_dir: PathBuf) -> Self {
+        let pid = super::monitor::find_chrome_pid(&user_data_dir);
+        if pid.is_none() {
+            tracing::warn!(
+                "Could not determine Chrome process pid from user_data_dir {}; \
+                resource monitoring will be unavailable for this instance.",
+                user_data_dir.display()
+            );
+        }
+
         Self {
             browser,
             handler,
             user_data_dir: Some(user_data_dir),
+            pid,
+        }
+    }
+
+    /// Wrap a browser connected to an already-running Chrome (see
+    /// [`super::connect_browser`]) rather than one this process launched.
+    /// `user_data_dir` is `None` - there's no temp profile to clean up and
+    /// no pid to resource-monitor, since we never spawned the process.
+    /// Also used for a browser [`launch_browser`] started against the live
+    /// profile (`attach_real_profile`): that directory is never cleaned up,
+    /// and that process is not resource-monitored either.
+    pub(crate) fn connected(browser: Browser, handler: JoinHandle<()>) -> Self {
+        Self {
+            browser,
+            handler,
+            user_data_dir: None,
+            pid: None,
         }
     }
 
@@ -40,6 +71,12 @@ impl BrowserWrapper {
         &mut self.browser
     }
 
+    /// OS pid of the launched Chrome process, if it was found at launch
+    /// time. See [`super::monitor::find_chrome_pid`].
+    pub(crate) fn pid(&self) -> Option<u32> {
+        self.pid
+    }
+
     /// Clean up temp directory (blocking operation)
     ///
     /// MUST be called AFTER `browser.wait()` completes to ensure Chrome
@@ -92,8 +129,12 @@ impl Drop for BrowserWrapper {
 
 /// Launch a new browser instance with stealth configuration
 ///
-/// Returns tuple of (Browser, JoinHandle, PathBuf) where PathBuf is the
-/// temp directory that MUST be cleaned up after browser shuts down.
+/// Returns tuple of (Browser, JoinHandle, `Option<PathBuf>`), where
+/// `Some(dir)` is a temp directory that MUST be cleaned up after browser
+/// shuts down (pass it to [`BrowserWrapper::new`]), and `None` means
+/// `config.browser.attach_real_profile` launched directly against the
+/// user's live profile - there's nothing to clean up, and the caller MUST
+/// use [`BrowserWrapper::connected`] instead so it's never deleted.
 ///
 /// Uses shared `browser_setup::launch_browser` with unique profile directory
 /// to prevent Chrome profile lock contention when multiple browser instances run.
@@ -101,26 +142,71 @@ impl Drop for BrowserWrapper {
 /// # Handler Lifecycle
 /// The returned `JoinHandle` MUST be aborted when done to stop the browser process.
 /// `BrowserWrapper::drop()` handles this automatically.
-pub async fn launch_browser() -> Result<(Browser, JoinHandle<()>, PathBuf)> {
+///
+/// `extra_args`/`proxy` are `BrowserManager`'s resolved
+/// `extra_browser_args`/`proxy` (config `browser.extra_args`/`browser.proxy`,
+/// overridable via the `BROWSER_EXTRA_ARGS`/`BROWSER_PROXY` env vars) - see
+/// [`crate::browser_setup::launch_browser`] for how they're applied.
+pub async fn launch_browser(
+    extra_args: &[String],
+    proxy: Option<&str>,
+) -> Result<(Browser, JoinHandle<()>, Option<PathBuf>)> {
     info!("Launching main browser instance");
 
     // Load configuration
     let config = crate::load_yaml_config().unwrap_or_default();
 
-    // Create unique temp directory for main browser (prevents profile lock with web_search)
-    let user_data_dir = std::env::temp_dir().join(format!("kodegen_browser_main_{}", std::process::id()));
+    // `attach_real_profile` launches straight against the live profile
+    // (browser_setup::launch_browser computes the directory itself and
+    // never hands it a chrome_data_dir), so there's no synthetic/copied
+    // directory for this process to own or clean up.
+    let user_data_dir = if config.browser.attach_real_profile {
+        None
+    } else if config.browser.use_real_profile {
+        Some(super::discover::prepare_real_profile_copy(config.browser.channel).await?)
+    } else {
+        Some(std::env::temp_dir().join(format!("kodegen_browser_main_{}", std::process::id())))
+    };
 
     // Use shared browser launcher with profile isolation
     // Pattern from: packages/tools-citescrape/src/browser_setup.rs:209-296
     let (browser, handler) = crate::browser_setup::launch_browser(
         config.browser.headless,
-        Some(user_data_dir.clone()),
+        user_data_dir.clone(),
         config.browser.disable_security,
-    ).await?;
+        config.browser.tls_trust_store,
+        config.browser.channel,
+        extra_args,
+        proxy,
+        None,
+        config.browser.attach_real_profile,
+        config.browser.profile_directory.as_deref(),
+    )
+    .await?;
 
     Ok((browser, handler, user_data_dir))
 }
 
+/// Attach to an already-running Chrome DevTools endpoint instead of
+/// spawning a new browser process. See `crate::browser_setup::connect_browser`
+/// and [`BrowserWrapper::connected`].
+pub async fn connect_browser(ws_url: &str) -> Result<(Browser, JoinHandle<()>)> {
+    let (browser, handler) = crate::browser_setup::connect_browser(ws_url).await?;
+    Ok((browser, handler))
+}
+
+/// Attach to a Chrome already listening on `--remote-debugging-port=<port>`
+/// by first resolving its `webSocketDebuggerUrl` from `/json/version`. See
+/// `crate::browser_setup::connect_browser_on_port`.
+pub async fn connect_browser_on_port(
+    port: u16,
+    timeout: std::time::Duration,
+) -> Result<(Browser, JoinHandle<()>)> {
+    let (browser, handler) =
+        crate::browser_setup::connect_browser_on_port(port, timeout).await?;
+    Ok((browser, handler))
+}
+
 /// Create a blank page for stealth injection
 ///
 /// Creates a page with about:blank URL, which is required for proper
@@ -8,7 +8,10 @@ use std::time::Duration;
 use tokio::task::{self, JoinHandle};
 use tracing::{error, info, trace, warn};
 
-use crate::utils::constants::CHROME_USER_AGENT;
+use crate::TlsTrustStore;
+use crate::browser::BrowserChannel;
+use crate::utils::chrome_user_agent;
+use thiserror::Error;
 
 /// RAII guard for temporary directory cleanup
 ///
@@ -24,12 +27,8 @@ struct TempDirGuard {
 impl TempDirGuard {
     /// Create directory and guard for automatic cleanup
     fn new(path: PathBuf) -> Result<Self> {
-        std::fs::create_dir_all(&path)
-            .context("Failed to create user data directory")?;
-        Ok(Self { 
-            path, 
-            keep: false 
-        })
+        std::fs::create_dir_all(&path).context("Failed to create user data directory")?;
+        Ok(Self { path, keep: false })
     }
 
     /// Consume guard and return path, preventing automatic cleanup
@@ -45,18 +44,65 @@ impl Drop for TempDirGuard {
     fn drop(&mut self) {
         if !self.keep {
             if let Err(e) = std::fs::remove_dir_all(&self.path) {
-                warn!("Failed to clean up temp dir {}: {}", 
-                    self.path.display(), e);
+                warn!("Failed to clean up temp dir {}: {}", self.path.display(), e);
             } else {
-                info!("Cleaned up temp dir after launch failure: {}", 
-                    self.path.display());
+                info!(
+                    "Cleaned up temp dir after launch failure: {}",
+                    self.path.display()
+                );
             }
         }
     }
 }
 
-/// Find Chrome/Chromium executable on the system with platform-specific search paths.
-pub async fn find_browser_executable() -> Result<PathBuf> {
+/// Look up a browser executable registered under the Windows "App Paths"
+/// registry key, which catches per-user and non-default install locations
+/// that the hardcoded Program Files paths below miss.
+///
+/// Checks `HKEY_CURRENT_USER` before `HKEY_LOCAL_MACHINE` since a per-user
+/// install should take precedence over a machine-wide one, reading the
+/// key's default value (its registered full executable path) for `exe_name`.
+/// Returns `None` if the key doesn't exist or the path it names doesn't
+/// exist on disk.
+#[cfg(target_os = "windows")]
+fn find_browser_via_registry(exe_name: &str) -> Option<PathBuf> {
+    use winreg::RegKey;
+    use winreg::enums::{HKEY_CURRENT_USER, HKEY_LOCAL_MACHINE};
+
+    let subkey = format!(
+        r"SOFTWARE\Microsoft\Windows\CurrentVersion\App Paths\{}",
+        exe_name
+    );
+
+    for hive in [HKEY_CURRENT_USER, HKEY_LOCAL_MACHINE] {
+        if let Ok(key) = RegKey::predef(hive).open_subkey(&subkey)
+            && let Ok(path_str) = key.get_value::<String, _>("")
+        {
+            let path = PathBuf::from(path_str);
+            if path.exists() {
+                return Some(path);
+            }
+        }
+    }
+
+    None
+}
+
+/// Find Chrome/Chromium executable on the system with platform-specific
+/// search paths.
+///
+/// If `preferred` is given, that flavor's own candidate paths (see
+/// [`BrowserChannel::executable_candidates`]) are tried first, before
+/// falling back to this function's generic Chrome/Chromium-only search -
+/// the returned `Option<BrowserChannel>` names the flavor actually found,
+/// when known. Note [`crate::browser::discover::discover`] already tries
+/// these same per-flavor candidates, so when called from `launch_browser`
+/// this is usually a quick, already-failed recheck before falling through
+/// to the generic list; it's also directly useful standalone for callers
+/// wanting a specific, opinionated flavor without going through `discover`.
+pub async fn find_browser_executable(
+    preferred: Option<BrowserChannel>,
+) -> Result<(PathBuf, Option<BrowserChannel>)> {
     // First check environment variable which overrides all other methods
     if let Ok(path) = std::env::var("CHROMIUM_PATH") {
         let path = PathBuf::from(path);
@@ -65,7 +111,7 @@ pub async fn find_browser_executable() -> Result<PathBuf> {
                 "Using browser from CHROMIUM_PATH environment variable: {}",
                 path.display()
             );
-            return Ok(path);
+            return Ok((path, None));
         }
         warn!(
             "CHROMIUM_PATH environment variable points to non-existent file: {}",
@@ -73,6 +119,34 @@ pub async fn find_browser_executable() -> Result<PathBuf> {
         );
     }
 
+    if let Some(channel) = preferred
+        && let Some(path) = channel
+            .executable_candidates()
+            .into_iter()
+            .find(|p| p.exists())
+    {
+        info!(
+            "Found preferred browser flavor {:?} at: {}",
+            channel,
+            path.display()
+        );
+        return Ok((path, Some(channel)));
+    }
+
+    // Prefer a registry-registered install (catches per-user and
+    // non-default locations the hardcoded paths below can't) before
+    // falling back to the hardcoded-path scan.
+    #[cfg(target_os = "windows")]
+    for exe_name in ["chrome.exe", "msedge.exe"] {
+        if let Some(path) = find_browser_via_registry(exe_name) {
+            info!(
+                "Found browser via Windows registry App Paths: {}",
+                path.display()
+            );
+            return Ok((path, None));
+        }
+    }
+
     // Common Chrome/Chromium installation paths by platform
     let paths = if cfg!(target_os = "windows") {
         vec![
@@ -127,7 +201,7 @@ pub async fn find_browser_executable() -> Result<PathBuf> {
 
         if path.exists() {
             info!("Found browser at: {}", path.display());
-            return Ok(path);
+            return Ok((path, None));
         }
     }
 
@@ -143,7 +217,7 @@ pub async fn find_browser_executable() -> Result<PathBuf> {
                 if !path_str.is_empty() {
                     let path = PathBuf::from(path_str);
                     info!("Found browser using 'which' command: {}", path.display());
-                    return Ok(path);
+                    return Ok((path, None));
                 }
             }
         }
@@ -241,6 +315,54 @@ pub async fn download_managed_browser() -> Result<PathBuf> {
     Ok(revision_info.executable_path)
 }
 
+/// Errors from [`scan_free_port`] and `launch_browser`'s port-retry loop.
+#[derive(Error, Debug)]
+pub enum PortAllocationError {
+    #[error("No free TCP port available in range {}..={}", .0.start(), .0.end())]
+    NoAvailablePorts(std::ops::RangeInclusive<u16>),
+
+    #[error(
+        "Port {0} was free when probed but was claimed by another process before \
+         Chrome could bind it (attempt {1}/{MAX_PORT_ALLOCATION_RETRIES})"
+    )]
+    DebugPortInUse(u16, u32),
+}
+
+/// Default `--remote-debugging-port` candidate range scanned by
+/// [`scan_free_port`] when `launch_browser`'s `debug_port_range` is `None`.
+pub const DEFAULT_DEBUG_PORT_RANGE: std::ops::RangeInclusive<u16> = 8000..=9000;
+
+/// How many times `launch_browser` will re-scan for a free port and retry
+/// the whole launch sequence if Chrome loses the bind race on the
+/// previously-probed port (see [`PortAllocationError::DebugPortInUse`]).
+const MAX_PORT_ALLOCATION_RETRIES: u32 = 5;
+
+/// Scan `range` for a TCP port free to bind on `127.0.0.1`, returning the
+/// first candidate that succeeds. The bind is dropped immediately after the
+/// probe, so there's an inherent TOCTOU race between this returning a port
+/// and Chrome actually binding it - `launch_browser`'s retry loop exists to
+/// absorb that race rather than eliminate it.
+///
+/// This only *finds* a free port; it never reaps whatever process is
+/// already squatting on a busy one (e.g. a crashed previous Chrome still
+/// holding its `--remote-debugging-port`). There's no process-discovery/kill
+/// helper (Unix `lsof`+`kill`, Windows `netstat`+`taskkill`, or any other
+/// platform abstraction over "find the pid bound to this port and terminate
+/// it") anywhere in this crate for `launch_browser`'s retry loop to call -
+/// a stuck port is recovered from by retrying over
+/// [`MAX_PORT_ALLOCATION_RETRIES`] *different* candidate ports instead of by
+/// clearing the stuck one.
+fn scan_free_port(
+    range: std::ops::RangeInclusive<u16>,
+) -> std::result::Result<u16, PortAllocationError> {
+    for candidate in range.clone() {
+        if std::net::TcpListener::bind(("127.0.0.1", candidate)).is_ok() {
+            return Ok(candidate);
+        }
+    }
+    Err(PortAllocationError::NoAvailablePorts(range))
+}
+
 /// Unified browser launcher that finds or downloads Chrome/Chromium and
 /// configures it with stealth mode settings.
 ///
@@ -248,6 +370,34 @@ pub async fn download_managed_browser() -> Result<PathBuf> {
 /// * `headless` - Whether to run browser in headless mode
 /// * `chrome_data_dir` - Optional custom user data directory path. If None, uses process ID fallback.
 /// * `disable_security` - Whether to disable browser security features (WARNING: only for trusted content)
+/// * `tls_trust_store` - Which root certificates the browser trusts (see [`TlsTrustStore`])
+/// * `channel` - Specific Chrome/Chromium-family flavor to prefer (config
+///   `browser.channel`), via [`crate::browser::discover::discover`]
+///   (falling back to [`find_browser_executable`], which also tries this
+///   same flavor first). `None` probes every flavor in preference order.
+///   Flavors that already strip Google's own telemetry (Brave,
+///   ungoogled-chromium) skip this function's anti-telemetry stealth flags
+///   - see [`BrowserChannel::skips_google_telemetry`].
+/// * `extra_args` - Additional raw Chromium flags (config
+///   `browser.extra_args`/`BROWSER_EXTRA_ARGS`), appended after every flag
+///   this function sets itself so a caller can override one of ours.
+/// * `proxy` - `--proxy-server` value (config `browser.proxy`/`BROWSER_PROXY`),
+///   if the caller wants this instance routed through a proxy.
+/// * `debug_port_range` - Candidate `--remote-debugging-port` range to scan
+///   for a free port in (see [`scan_free_port`]), instead of letting Chrome
+///   pick its own ephemeral port. `None` uses [`DEFAULT_DEBUG_PORT_RANGE`].
+///   Gives deterministic, contention-free port assignment for a
+///   long-running server launching many browsers concurrently - see
+///   [`PortAllocationError`].
+/// * `attach_real_profile` - Opt in to launching directly against the
+///   detected channel's real, live user data directory (config
+///   `browser.attach_real_profile`) via [`crate::chrome_paths::user_data_dir`],
+///   instead of a synthetic or copied one. `chrome_data_dir` and the
+///   `TempDirGuard` cleanup path are bypassed entirely when this is set, so
+///   the live profile is never created or deleted by this function -
+///   `--profile-directory` selects `profile_directory`
+///   (config `browser.profile_directory`), defaulting to
+///   [`crate::chrome_paths::DEFAULT_PROFILE_DIRECTORY`].
 ///
 /// # Profile Isolation
 /// When `chrome_data_dir` is provided, each browser instance uses a unique profile directory,
@@ -256,113 +406,334 @@ pub async fn launch_browser(
     headless: bool,
     chrome_data_dir: Option<PathBuf>,
     disable_security: bool,
+    tls_trust_store: TlsTrustStore,
+    channel: Option<BrowserChannel>,
+    extra_args: &[String],
+    proxy: Option<&str>,
+    debug_port_range: Option<std::ops::RangeInclusive<u16>>,
+    attach_real_profile: bool,
+    profile_directory: Option<&str>,
 ) -> Result<(Browser, JoinHandle<()>)> {
-    // First try to find the browser
-    let chrome_path = match find_browser_executable().await {
-        Ok(path) => path,
-        Err(_) => {
-            // If not found, download a managed browser
-            download_managed_browser().await?
+    // Prefer a channel-matched discovery over whatever
+    // find_browser_executable finds first, falling back to it (then the
+    // managed downloaded browser) if the requested/default channels
+    // aren't installed.
+    let discovered = crate::browser::discover::discover(channel);
+    let (chrome_path, resolved_channel) = match &discovered {
+        Some(discovered) => {
+            info!(
+                "Discovered {:?} browser at: {}",
+                discovered.channel,
+                discovered.executable.display()
+            );
+            (discovered.executable.clone(), Some(discovered.channel))
         }
+        None => match find_browser_executable(channel).await {
+            Ok((path, flavor)) => (path, flavor),
+            Err(_) => {
+                // If not found, download a managed browser
+                (download_managed_browser().await?, None)
+            }
+        },
     };
 
-    // Use provided chrome_data_dir or fall back to process ID
-    let user_data_dir_path = chrome_data_dir.unwrap_or_else(|| {
-        std::env::temp_dir().join(format!("enigo_chrome_{}", std::process::id()))
-    });
-
-    // Create directory with automatic cleanup on error
-    let temp_guard = TempDirGuard::new(user_data_dir_path)?;
-    let user_data_dir = temp_guard.path.clone();
-
-    // Build browser config with the executable path
-    let mut config_builder = BrowserConfigBuilder::default()
-        .request_timeout(Duration::from_secs(30))
-        .window_size(1920, 1080)
-        .user_data_dir(user_data_dir)
-        .chrome_executable(chrome_path);
-
-    // Set headless mode based on parameter
-    if headless {
-        config_builder = config_builder.headless_mode(HeadlessMode::default());
+    // Chrome forks that already strip Google's own telemetry/networking
+    // integrations don't need (and may not recognize) this crate's own
+    // anti-telemetry stealth flags - see
+    // `BrowserChannel::skips_google_telemetry`.
+    let skip_telemetry_flags = resolved_channel.is_some_and(|c| c.skips_google_telemetry());
+
+    // `attach_real_profile` skips the TempDirGuard entirely - the live
+    // profile directory must never be auto-created or auto-removed by this
+    // function the way a synthetic/throwaway one is.
+    let (user_data_dir, temp_guard, real_profile_directory) = if attach_real_profile {
+        let real_dir = resolved_channel
+            .and_then(crate::chrome_paths::user_data_dir)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "attach_real_profile requested but no real user data directory was found for {resolved_channel:?}"
+                )
+            })?;
+        (
+            real_dir,
+            None,
+            Some(
+                profile_directory
+                    .unwrap_or(crate::chrome_paths::DEFAULT_PROFILE_DIRECTORY)
+                    .to_string(),
+            ),
+        )
     } else {
-        config_builder = config_builder.with_head();
-    }
+        // Use provided chrome_data_dir or fall back to process ID
+        let user_data_dir_path = chrome_data_dir.unwrap_or_else(|| {
+            std::env::temp_dir().join(format!("enigo_chrome_{}", std::process::id()))
+        });
+
+        // Create directory with automatic cleanup on error
+        let guard = TempDirGuard::new(user_data_dir_path)?;
+        let dir = guard.path.clone();
+        (dir, Some(guard), None)
+    };
 
-    // Add stealth mode arguments (benign flags always added)
-    config_builder = config_builder
-        .arg(format!("--user-agent={}", CHROME_USER_AGENT))
-        .arg("--disable-blink-features=AutomationControlled")
-        .arg("--disable-infobars")
-        .arg("--disable-notifications")
-        .arg("--disable-print-preview")
-        .arg("--disable-desktop-notifications")
-        .arg("--disable-software-rasterizer")
-        .arg("--no-first-run")
-        .arg("--no-default-browser-check")
-        .arg("--enable-features=NetworkService,NetworkServiceInProcess")
-        // Additional stealth arguments (benign)
-        .arg("--disable-extensions")
-        .arg("--disable-popup-blocking")
-        .arg("--disable-background-networking")
-        .arg("--disable-background-timer-throttling")
-        .arg("--disable-backgrounding-occluded-windows")
-        .arg("--disable-breakpad")
-        .arg("--disable-component-extensions-with-background-pages")
-        .arg("--disable-features=TranslateUI")
-        .arg("--disable-hang-monitor")
-        .arg("--disable-ipc-flooding-protection")
-        .arg("--disable-prompt-on-repost")
-        .arg("--metrics-recording-only")
-        .arg("--password-store=basic")
-        .arg("--use-mock-keychain")
-        .arg("--hide-scrollbars")
-        .arg("--mute-audio");
-
-    // Conditionally add security-disabling flags
-    if disable_security {
-        info!("WARNING: Disabling browser security features (disable_security=true)");
-        config_builder = config_builder
-            .arg("--disable-web-security")
-            .arg("--disable-features=IsolateOrigins,site-per-process")
-            .arg("--ignore-certificate-errors");
-    }
+    // Detect the real binary's version before `chrome_path` is moved into
+    // the config builder below, so the spoofed UA matches the browser CDP
+    // is actually about to control.
+    let user_agent = chrome_user_agent(&chrome_path);
+
+    // Builds a fresh config from scratch for `port` - called once per
+    // attempt in the retry loop below, since `BrowserConfigBuilder`'s
+    // `arg`/`build` chain consumes itself, so retrying with a different
+    // port (see `PortAllocationError::DebugPortInUse`) needs a new builder
+    // rather than reusing one that already moved.
+    let build_config = |port: u16| -> Result<chromiumoxide::browser::BrowserConfig> {
+        // Build browser config with the executable path
+        let mut config_builder = BrowserConfigBuilder::default()
+            .request_timeout(Duration::from_secs(30))
+            .window_size(1920, 1080)
+            .user_data_dir(user_data_dir.clone())
+            .chrome_executable(chrome_path.clone())
+            .arg(format!("--remote-debugging-port={port}"));
+
+        if let Some(profile_directory) = &real_profile_directory {
+            config_builder =
+                config_builder.arg(format!("--profile-directory={profile_directory}"));
+        }
 
-    // Always disable sandbox in containerized environments (Docker detection)
-    if should_disable_sandbox() {
-        info!("Detected containerized environment, disabling sandbox");
-        config_builder = config_builder
-            .arg("--no-sandbox")
-            .arg("--disable-setuid-sandbox");
-    } else if disable_security {
-        // Only disable sandbox if explicitly requested AND not in container
+        // Set headless mode based on parameter
+        if headless {
+            config_builder = config_builder.headless_mode(HeadlessMode::default());
+        } else {
+            config_builder = config_builder.with_head();
+        }
+
+        // Add stealth mode arguments (benign flags always added)
         config_builder = config_builder
-            .arg("--no-sandbox")
-            .arg("--disable-setuid-sandbox");
+            .arg(format!("--user-agent={}", user_agent))
+            .arg("--disable-blink-features=AutomationControlled")
+            .arg("--disable-infobars")
+            .arg("--disable-notifications")
+            .arg("--disable-print-preview")
+            .arg("--disable-desktop-notifications")
+            .arg("--disable-software-rasterizer")
+            .arg("--no-first-run")
+            .arg("--no-default-browser-check")
+            .arg("--enable-features=NetworkService,NetworkServiceInProcess")
+            // Additional stealth arguments (benign)
+            .arg("--disable-extensions")
+            .arg("--disable-popup-blocking")
+            .arg("--disable-background-timer-throttling")
+            .arg("--disable-backgrounding-occluded-windows")
+            .arg("--disable-breakpad")
+            .arg("--disable-component-extensions-with-background-pages")
+            .arg("--disable-features=TranslateUI")
+            .arg("--disable-hang-monitor")
+            .arg("--disable-ipc-flooding-protection")
+            .arg("--disable-prompt-on-repost")
+            .arg("--password-store=basic")
+            .arg("--use-mock-keychain")
+            .arg("--hide-scrollbars")
+            .arg("--mute-audio");
+
+        // Forks that already strip Google's own telemetry/networking
+        // integrations (Brave, ungoogled-chromium) don't need these.
+        if !skip_telemetry_flags {
+            config_builder = config_builder
+                .arg("--disable-background-networking")
+                .arg("--metrics-recording-only");
+        }
+
+        // Select which root certificates Chrome trusts. Chrome verifies TLS
+        // against its own bundled "Chrome Root Store" by default;
+        // `--disable-chrome-root-store` is the one documented Chromium flag
+        // that widens that to the OS trust store, without disabling
+        // verification. `Merged` wants the union of both stores, but
+        // Chromium has no flag for that (see the doc comment on
+        // `TlsTrustStore::Merged`), so it falls back to the same flag as
+        // `NativeOnly`.
+        match tls_trust_store {
+            TlsTrustStore::BundledOnly => {}
+            TlsTrustStore::NativeOnly | TlsTrustStore::Merged => {
+                config_builder = config_builder.arg("--disable-chrome-root-store");
+            }
+        }
+
+        // Conditionally add security-disabling flags
+        if disable_security {
+            info!("WARNING: Disabling browser security features (disable_security=true)");
+            config_builder = config_builder
+                .arg("--disable-web-security")
+                .arg("--disable-features=IsolateOrigins,site-per-process")
+                .arg("--ignore-certificate-errors");
+        }
+
+        // Always disable sandbox in containerized environments (Docker detection)
+        if should_disable_sandbox() {
+            info!("Detected containerized environment, disabling sandbox");
+            config_builder = config_builder
+                .arg("--no-sandbox")
+                .arg("--disable-setuid-sandbox");
+        } else if disable_security {
+            // Only disable sandbox if explicitly requested AND not in container
+            config_builder = config_builder
+                .arg("--no-sandbox")
+                .arg("--disable-setuid-sandbox");
+        }
+
+        if let Some(proxy) = proxy {
+            config_builder = config_builder.arg(format!("--proxy-server={}", proxy));
+        }
+
+        // Applied last so a caller can override any flag set above by
+        // repeating it here with a different value.
+        for arg in extra_args {
+            config_builder = config_builder.arg(arg.clone());
+        }
+
+        config_builder
+            .build()
+            .map_err(|e| anyhow::anyhow!("Failed to build browser config: {e}"))
+    };
+
+    let range = debug_port_range.unwrap_or(DEFAULT_DEBUG_PORT_RANGE);
+    let mut last_err = None;
+    let mut launched = None;
+    for attempt in 1..=MAX_PORT_ALLOCATION_RETRIES {
+        let port = scan_free_port(range.clone())?;
+        let browser_config = build_config(port)?;
+
+        info!(
+            "Launching browser with remote-debugging-port {port} (attempt {attempt}/{MAX_PORT_ALLOCATION_RETRIES})"
+        );
+        match Browser::launch(browser_config).await {
+            Ok(result) => {
+                launched = Some(result);
+                break;
+            }
+            Err(e) => {
+                warn!(
+                    "Browser launch on port {port} failed (attempt {attempt}/{MAX_PORT_ALLOCATION_RETRIES}): {e}"
+                );
+                last_err = Some(PortAllocationError::DebugPortInUse(port, attempt));
+            }
+        }
+    }
+    let (browser, handler) = launched.ok_or_else(|| {
+        anyhow::Error::new(last_err.expect(
+            "loop ran at least once (MAX_PORT_ALLOCATION_RETRIES >= 1) so last_err is set \
+             whenever launched stays None",
+        ))
+    })?;
+
+    let handler_task = spawn_handler_task(handler);
+
+    // Success - prevent automatic cleanup (BrowserWrapper now owns the
+    // directory). Only applies to the synthetic path; `attach_real_profile`
+    // never created a guard, so the live profile is left untouched.
+    if let Some(guard) = temp_guard {
+        guard.into_path();
     }
 
-    let browser_config = config_builder
-        .build()
-        .map_err(|e| anyhow::anyhow!("Failed to build browser config: {e}"))?;
+    Ok((browser, handler_task))
+}
 
-    info!("Launching browser with config: {:?}", browser_config);
-    let (browser, mut handler) = Browser::launch(browser_config)
+/// Attach to an already-running Chrome DevTools endpoint (e.g. a browser
+/// the user already has open with logins/extensions, or a remote browser
+/// in a container) instead of spawning a new process.
+///
+/// Unlike [`launch_browser`], there's no temp profile or Chrome process
+/// this crate owns - `BrowserWrapper` is told `user_data_dir = None` so
+/// `cleanup_temp_dir()` is a no-op and we never try to kill a process we
+/// didn't start.
+///
+/// Returns as soon as the CDP websocket handshake succeeds - there's no
+/// configurable readiness probe afterward (e.g. an opt-in no-op navigation
+/// to `about:blank`) to confirm the browser backend itself is actually
+/// usable yet, the way an MCP client layered over this connection might
+/// want to confirm with a lightweight health tool call before declaring
+/// itself ready. A caller that needs that guarantee has to issue its own
+/// throwaway page operation after this returns and retry on failure.
+pub async fn connect_browser(ws_url: &str) -> Result<(Browser, JoinHandle<()>)> {
+    info!("Connecting to existing browser at {}", ws_url);
+
+    let (browser, handler) = Browser::connect(ws_url)
         .await
-        .context("Failed to launch browser")?;
+        .with_context(|| format!("Failed to connect to browser at {ws_url}"))?;
+
+    Ok((browser, spawn_handler_task(handler)))
+}
+
+/// Default budget for [`connect_browser_on_port`] to find Chrome's DevTools
+/// endpoint before giving up.
+pub const DEFAULT_DEVTOOLS_DISCOVERY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Resolve the `webSocketDebuggerUrl` a Chrome started with
+/// `--remote-debugging-port=<port>` publishes at its `/json/version` HTTP
+/// endpoint, then [`connect_browser`] to it - so a caller only needs the
+/// port (e.g. one it or another process just launched Chrome with), not the
+/// websocket URL itself, which changes every time Chrome starts.
+///
+/// Times out after `timeout` rather than hanging if nothing is listening
+/// yet (Chrome still starting up) or ever (wrong port).
+pub async fn connect_browser_on_port(
+    port: u16,
+    timeout: Duration,
+) -> Result<(Browser, JoinHandle<()>)> {
+    let ws_url = tokio::time::timeout(timeout, resolve_websocket_debugger_url(port))
+        .await
+        .map_err(|_| {
+            anyhow::anyhow!(
+                "Timed out after {timeout:?} waiting for Chrome's DevTools endpoint on port {port}"
+            )
+        })??;
+
+    connect_browser(&ws_url).await
+}
 
-    let handler_task = task::spawn(async move {
+/// `GET http://127.0.0.1:<port>/json/version` and pull out
+/// `webSocketDebuggerUrl` - the JSON endpoint Chrome always serves
+/// alongside its DevTools websocket when started with
+/// `--remote-debugging-port`.
+async fn resolve_websocket_debugger_url(port: u16) -> Result<String> {
+    let version_url = format!("http://127.0.0.1:{port}/json/version");
+
+    let response: serde_json::Value = reqwest::get(&version_url)
+        .await
+        .with_context(|| format!("Failed to reach Chrome DevTools endpoint at {version_url}"))?
+        .error_for_status()
+        .with_context(|| format!("Chrome DevTools endpoint at {version_url} returned an error"))?
+        .json()
+        .await
+        .with_context(|| {
+            format!("Failed to parse Chrome DevTools version response from {version_url}")
+        })?;
+
+    response["webSocketDebuggerUrl"]
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "Chrome DevTools version response from {version_url} is missing webSocketDebuggerUrl"
+            )
+        })
+}
+
+/// Spawn the task draining a `Browser::launch`/`Browser::connect` handler
+/// stream, filtering out benign CDP serialization errors. Shared by
+/// [`launch_browser`] and [`connect_browser`].
+fn spawn_handler_task(mut handler: chromiumoxide::handler::Handler) -> JoinHandle<()> {
+    task::spawn(async move {
         while let Some(h) = handler.next().await {
             if let Err(e) = h {
                 let error_msg = e.to_string();
-                
+
                 // Filter out known non-fatal CDP serialization errors
                 // These occur when Chrome sends CDP events that chromiumoxide doesn't recognize
                 // Reference: https://github.com/mattsse/chromiumoxide/issues/167
                 //            https://github.com/mattsse/chromiumoxide/issues/229
-                let is_benign_serialization_error = 
-                    error_msg.contains("data did not match any variant of untagged enum Message")
+                let is_benign_serialization_error = error_msg
+                    .contains("data did not match any variant of untagged enum Message")
                     || error_msg.contains("Failed to deserialize WS response");
-                
+
                 if !is_benign_serialization_error {
                     // Log genuine errors that need attention
                     error!("Browser handler error: {:?}", e);
@@ -373,12 +744,7 @@ pub async fn launch_browser(
             }
         }
         info!("Browser handler task completed");
-    });
-
-    // Success - prevent automatic cleanup (BrowserWrapper now owns the directory)
-    temp_guard.into_path();
-    
-    Ok((browser, handler_task))
+    })
 }
 
 /// Detect if running in containerized environment (Docker, etc.)
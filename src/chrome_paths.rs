@@ -0,0 +1,31 @@
+//! Standard per-platform Chrome/Chromium user data directories.
+//!
+//! Unlike [`crate::browser::discover::prepare_real_profile_copy`] (used by
+//! `browser.use_real_profile` to make a disposable copy of the real
+//! profile), this is for `browser.attach_real_profile`'s opt-in direct
+//! attach - `launch_browser` points `--user-data-dir` straight at the live
+//! directory this computes and never copies or cleans it up. Chrome
+//! refuses to start a second instance against a profile another running
+//! instance already holds the lock on, so this only makes sense when the
+//! caller knows no other Chrome is using it.
+
+use std::path::PathBuf;
+
+use crate::browser::BrowserChannel;
+
+/// The name Chrome gives its first/default profile inside a user data
+/// directory (`<user_data_dir>/Default`). Passed as `--profile-directory`
+/// when the caller doesn't request a different named profile (e.g.
+/// `"Profile 1"`).
+pub const DEFAULT_PROFILE_DIRECTORY: &str = "Default";
+
+/// The real "User Data" directory `channel` keeps its profiles in on this
+/// platform (`%LOCALAPPDATA%\Google\Chrome\User Data` on Windows,
+/// `~/Library/Application Support/Google/Chrome` on macOS,
+/// `~/.config/google-chrome` on Linux, with analogous paths for the other
+/// channels), if this platform has a known one. Returns `None` if the
+/// directory doesn't actually exist on disk - callers shouldn't point
+/// `--user-data-dir` at a location Chrome has never initialized.
+pub fn user_data_dir(channel: BrowserChannel) -> Option<PathBuf> {
+    channel.real_profile_dir().filter(|dir| dir.exists())
+}
@@ -5,6 +5,7 @@
 pub mod agent;
 mod browser;
 pub mod browser_setup;
+pub mod chrome_paths;
 pub mod research;
 pub mod kromekover;
 mod manager;
@@ -32,8 +33,43 @@ pub struct Config {
     #[serde(default = "default_search_engine")]
     pub search_engine: String,
 
+    /// How long a `web_search` result stays cacheable before it's treated
+    /// as a miss. See [`crate::web_search::SearchCache`].
+    #[serde(default = "default_cache_ttl_secs")]
+    pub cache_ttl_secs: u64,
+
+    /// Maximum number of distinct `(query, engine set)` entries the
+    /// `web_search` cache holds before evicting the least-recently-used one.
+    #[serde(default = "default_cache_capacity")]
+    pub cache_capacity: usize,
+
+    /// Requests a single connection may make to `web_search` per
+    /// `window_secs` before being rate-limited.
+    #[serde(default = "default_requests_per_window")]
+    pub requests_per_window: u32,
+
+    /// Rolling window, in seconds, over which `requests_per_window` applies.
+    #[serde(default = "default_window_secs")]
+    pub window_secs: u64,
+
+    /// Steady-state requests per second allowed to a single origin across
+    /// every navigation (`web_search`, `browser_research`, `browser_navigate`).
+    /// See [`crate::utils::OriginGovernor`].
+    #[serde(default = "default_origin_requests_per_sec")]
+    pub origin_requests_per_sec: f64,
+
+    /// Burst capacity on top of `origin_requests_per_sec` - how many
+    /// requests to a fresh origin may fire immediately before the steady
+    /// rate kicks in.
+    #[serde(default = "default_origin_burst")]
+    pub origin_burst: u32,
+
     #[serde(default)]
     pub browser: BrowserConfig,
+
+    /// Prometheus metrics exporter settings. See [`MetricsConfig`].
+    #[serde(default)]
+    pub metrics: MetricsConfig,
 }
 
 /// Browser security and launch configuration
@@ -48,9 +84,208 @@ pub struct BrowserConfig {
     #[serde(default = "default_disable_security")]
     pub disable_security: bool,
 
+    /// Which root certificates the browser trusts when verifying TLS.
+    /// See [`TlsTrustStore`].
+    #[serde(default)]
+    pub tls_trust_store: TlsTrustStore,
+
     /// Window dimensions
     #[serde(default)]
     pub window: WindowConfig,
+
+    /// Automation protocol the browser tools drive. See [`BrowserEngine`].
+    #[serde(default)]
+    pub engine: BrowserEngine,
+
+    /// Relaunch the browser if its RSS exceeds this many megabytes. See
+    /// [`crate::manager::BrowserManager`]'s resource monitor.
+    #[serde(default = "default_max_memory_mb")]
+    pub max_memory_mb: u64,
+
+    /// How often (in seconds) the resource monitor samples the browser
+    /// process's memory and liveness.
+    #[serde(default = "default_health_check_secs")]
+    pub health_check_secs: u64,
+
+    /// Whether the resource monitor may relaunch the browser on its own
+    /// (over-budget memory or a dead process). Off by default since an
+    /// automatic relaunch drops any in-flight page state.
+    #[serde(default = "default_auto_restart")]
+    pub auto_restart: bool,
+
+    /// Number of browser instances [`crate::manager::BrowserManager`]
+    /// pre-launches and checks out via `acquire()`/`release()`, so
+    /// concurrent tool calls can run against separate Chrome processes
+    /// instead of contending on one. Defaults to `1`, which preserves the
+    /// original single-browser model exactly (no checkout/release
+    /// required by callers).
+    #[serde(default = "default_pool_size")]
+    pub pool_size: usize,
+
+    /// WebDriver server URL (e.g. `http://localhost:4444` for a local
+    /// geckodriver, or a remote grid's endpoint). Required when `engine` is
+    /// `WebDriver`; ignored otherwise.
+    #[serde(default)]
+    pub webdriver_url: Option<String>,
+
+    /// Chrome DevTools WebSocket endpoint (e.g.
+    /// `ws://127.0.0.1:9222/devtools/browser/...`) of an already-running
+    /// Chrome to attach to instead of launching one, via
+    /// [`crate::browser::connect_browser`]. Skips the ~2-3s cold-launch
+    /// cost and reuses an existing session's logins/extensions. `None`
+    /// (the default) keeps launching a fresh managed instance. Only
+    /// applies when `engine` is `Cdp`.
+    #[serde(default)]
+    pub connect_url: Option<String>,
+
+    /// Tear down the managed browser after it's gone unused for this many
+    /// seconds, freeing its ~150MB Chrome process during quiet periods. The
+    /// next call transparently relaunches it. `0` (the default) disables
+    /// the idle reaper entirely. See
+    /// [`crate::manager::BrowserManager`]'s idle reaper.
+    #[serde(default = "default_idle_timeout_secs")]
+    pub idle_timeout_secs: u64,
+
+    /// Specific Chrome/Chromium-family flavor to launch, via
+    /// [`crate::browser::discover::discover`]. `None` (the default) probes
+    /// every flavor in preference order (Chromium, Chrome, Chrome Beta,
+    /// Chrome Dev, Chrome Canary, Brave, Edge, ungoogled-chromium) and uses
+    /// whichever is found first, falling back to
+    /// [`crate::browser::find_browser_executable`]/the managed downloaded
+    /// browser if none are installed.
+    #[serde(default)]
+    pub channel: Option<crate::browser::BrowserChannel>,
+
+    /// Launch against a temp copy of the detected channel's real user
+    /// profile (cookies, logins, extensions) instead of a fresh
+    /// `kodegen_browser_main_<pid>` profile, so tools can operate against
+    /// sites the user is already authenticated with. The copy is made
+    /// once per launch and cleaned up like any other temp profile - the
+    /// user's live profile is never touched or read from again after the
+    /// copy. Requires a real profile to be discoverable for the selected
+    /// (or detected) channel; errors out otherwise.
+    #[serde(default)]
+    pub use_real_profile: bool,
+
+    /// Launch directly against the detected channel's real, live user data
+    /// directory (see [`crate::chrome_paths::user_data_dir`]) instead of a
+    /// copy - no `TempDirGuard` is created or cleaned up for this path, so
+    /// the live profile is never at risk of being deleted. Unlike
+    /// `use_real_profile`, Chrome will refuse to start if another instance
+    /// already holds this exact profile's lock, so only enable this when
+    /// you know no other Chrome (including the user's own browser) has it
+    /// open. Mutually exclusive with `use_real_profile`; if both are set,
+    /// `attach_real_profile` wins.
+    #[serde(default)]
+    pub attach_real_profile: bool,
+
+    /// Named profile directory to select within the real user data
+    /// directory when `attach_real_profile` is set (e.g. `"Profile 1"` for
+    /// a non-default Chrome profile). Defaults to
+    /// [`crate::chrome_paths::DEFAULT_PROFILE_DIRECTORY`] when not set.
+    #[serde(default)]
+    pub profile_directory: Option<String>,
+
+    /// Extra Chromium command-line flags appended after this crate's own
+    /// stealth/security flags, so callers running in CI or containers can
+    /// add e.g. `--disable-dev-shm-usage` or a custom `--window-size`
+    /// without patching this crate. Appended last, so a flag here can
+    /// override one of ours by repeating it with a different value (Chrome
+    /// takes the last occurrence of a repeated flag). Also settable via the
+    /// `BROWSER_EXTRA_ARGS` env var (whitespace-separated) read by
+    /// [`crate::manager::BrowserManager::new`], which takes priority when set.
+    #[serde(default)]
+    pub extra_args: Vec<String>,
+
+    /// Proxy server to launch Chrome with (`--proxy-server=<value>`, e.g.
+    /// `http://127.0.0.1:8080` or `socks5://127.0.0.1:1080`). `None` (the
+    /// default) launches with no proxy configured. Also settable via the
+    /// `BROWSER_PROXY` env var, which takes priority when set.
+    #[serde(default)]
+    pub proxy: Option<String>,
+
+    /// Hosts navigation is restricted to. Empty (the default) allows any
+    /// host not otherwise rejected by `navigation_denylist`/
+    /// `block_private_navigation`. See [`crate::utils::NavigationPolicy`].
+    #[serde(default)]
+    pub navigation_allowlist: Vec<String>,
+
+    /// Hosts navigation is never allowed to, regardless of
+    /// `navigation_allowlist`.
+    #[serde(default)]
+    pub navigation_denylist: Vec<String>,
+
+    /// Reject navigation to a host that resolves to a private, loopback, or
+    /// link-local address (e.g. the `169.254.169.254` cloud metadata
+    /// endpoint, `localhost`, or an RFC1918 address) - the default SSRF
+    /// guard for an agent-driven research run that fetches attacker- or
+    /// redirect-controlled URLs. On by default.
+    #[serde(default = "default_block_private_navigation")]
+    pub block_private_navigation: bool,
+
+    /// Maximum tabs a single `connection_id`'s research crawl may hold open
+    /// concurrently via [`crate::browser::TabPool`], so `DeepResearch`'s
+    /// bounded-concurrency scheduler gets real parallel page loads instead
+    /// of serializing on one shared page. Does not affect the plain
+    /// `browser_navigate` tool, which still uses the single-page model.
+    #[serde(default = "default_research_tab_pool_size")]
+    pub research_tab_pool_size: usize,
+
+    /// Directory to persist named cookie-jar profiles to (see
+    /// [`crate::utils::CookieProfileStore::with_dir`]), so a profile saved
+    /// via `browser_cookies`' `SAVE_PROFILE` action or loaded for a
+    /// `ResearchOptions::cookie_profile` run survives a process restart.
+    /// `None` (the default) keeps profiles in memory only, same as before
+    /// this field existed. Also settable via the
+    /// `BROWSER_COOKIE_PROFILE_DIR` env var, which takes priority when set.
+    #[serde(default)]
+    pub cookie_profile_dir: Option<String>,
+}
+
+/// Browser automation protocol selected by `BrowserConfig::engine`.
+///
+/// Both variants are driven through the same [`crate::browser::BrowserBackend`]
+/// trait, so tools built against it run unmodified against either engine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum BrowserEngine {
+    /// Chrome DevTools Protocol via chromiumoxide - the current default,
+    /// launching a local Chrome/Chromium process.
+    #[default]
+    Cdp,
+    /// Classic (W3C) WebDriver wire protocol over HTTP, e.g. geckodriver
+    /// for Firefox or a remote Selenium/WebDriver grid. Requires
+    /// `webdriver_url`.
+    WebDriver,
+}
+
+/// Root certificate trust policy applied to the launched browser.
+///
+/// Chrome verifies TLS connections against its own bundled "Chrome Root
+/// Store" by default, which can reject certificates chaining to an
+/// OS-managed or enterprise root (common on corporate networks) even though
+/// the OS itself trusts them. This does not disable verification in any
+/// mode - it only changes which root set is consulted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum TlsTrustStore {
+    /// Chrome's bundled root store only. Reproducible across machines;
+    /// the current default behavior.
+    #[default]
+    BundledOnly,
+    /// The operating system's native trust store only, ignoring Chrome's
+    /// bundled roots.
+    NativeOnly,
+    /// Also the OS trust store, for now: Chromium has no command-line flag
+    /// that actually unions the bundled and OS root sets (there is no
+    /// documented "--merge-root-stores"-type switch, and
+    /// `--disable-chrome-root-store-constraints` - an earlier guess at one -
+    /// isn't a real Chromium flag), so this falls back to the same
+    /// `--disable-chrome-root-store` behavior as [`Self::NativeOnly`]. A
+    /// true merge would need the OS trust store itself to already include
+    /// Chrome's roots (e.g. via the OS's own certificate management), which
+    /// is outside this crate's control.
+    Merged,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -62,6 +297,22 @@ pub struct WindowConfig {
     pub height: u32,
 }
 
+/// Prometheus exporter settings for the tool server.
+///
+/// Counters and gauges are always collected (see
+/// [`crate::utils::ToolMetrics`]); this only controls whether they're
+/// exposed over HTTP and at what path.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricsConfig {
+    /// Serve `bind_path` as a Prometheus text-exposition endpoint.
+    #[serde(default = "default_metrics_enabled")]
+    pub enabled: bool,
+
+    /// HTTP path the exporter is mounted on, when enabled.
+    #[serde(default = "default_metrics_bind_path")]
+    pub bind_path: String,
+}
+
 fn default_temperature() -> f64 {
     0.7
 }
@@ -75,14 +326,61 @@ fn default_search_engine() -> String {
     "google".to_string()
 }
 
+fn default_cache_ttl_secs() -> u64 {
+    300
+}
+fn default_cache_capacity() -> usize {
+    256
+}
+fn default_requests_per_window() -> u32 {
+    10
+}
+fn default_window_secs() -> u64 {
+    60
+}
+fn default_origin_requests_per_sec() -> f64 {
+    1.0
+}
+fn default_origin_burst() -> u32 {
+    3
+}
+
 fn default_headless() -> bool {
     true
 }
 
+fn default_block_private_navigation() -> bool {
+    true
+}
+
+fn default_research_tab_pool_size() -> usize {
+    4
+}
+
 fn default_disable_security() -> bool {
     false  // SECURE BY DEFAULT
 }
 
+fn default_max_memory_mb() -> u64 {
+    2048
+}
+
+fn default_health_check_secs() -> u64 {
+    30
+}
+
+fn default_auto_restart() -> bool {
+    false
+}
+
+fn default_pool_size() -> usize {
+    1
+}
+
+fn default_idle_timeout_secs() -> u64 {
+    0
+}
+
 fn default_window_width() -> u32 {
     1280
 }
@@ -91,6 +389,13 @@ fn default_window_height() -> u32 {
     720
 }
 
+fn default_metrics_enabled() -> bool {
+    false
+}
+fn default_metrics_bind_path() -> String {
+    "/metrics".to_string()
+}
+
 impl Default for Config {
     fn default() -> Self {
         Self {
@@ -98,7 +403,23 @@ impl Default for Config {
             max_tokens: default_max_tokens(),
             max_steps: default_max_steps(),
             search_engine: default_search_engine(),
+            cache_ttl_secs: default_cache_ttl_secs(),
+            cache_capacity: default_cache_capacity(),
+            requests_per_window: default_requests_per_window(),
+            window_secs: default_window_secs(),
+            origin_requests_per_sec: default_origin_requests_per_sec(),
+            origin_burst: default_origin_burst(),
             browser: BrowserConfig::default(),
+            metrics: MetricsConfig::default(),
+        }
+    }
+}
+
+impl Default for MetricsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_metrics_enabled(),
+            bind_path: default_metrics_bind_path(),
         }
     }
 }
@@ -108,7 +429,27 @@ impl Default for BrowserConfig {
         Self {
             headless: default_headless(),
             disable_security: default_disable_security(),
+            tls_trust_store: TlsTrustStore::default(),
             window: WindowConfig::default(),
+            engine: BrowserEngine::default(),
+            max_memory_mb: default_max_memory_mb(),
+            health_check_secs: default_health_check_secs(),
+            auto_restart: default_auto_restart(),
+            pool_size: default_pool_size(),
+            webdriver_url: None,
+            connect_url: None,
+            idle_timeout_secs: default_idle_timeout_secs(),
+            channel: None,
+            use_real_profile: false,
+            attach_real_profile: false,
+            profile_directory: None,
+            extra_args: Vec::new(),
+            proxy: None,
+            navigation_allowlist: Vec::new(),
+            navigation_denylist: Vec::new(),
+            block_private_navigation: default_block_private_navigation(),
+            research_tab_pool_size: default_research_tab_pool_size(),
+            cookie_profile_dir: None,
         }
     }
 }
@@ -136,14 +477,17 @@ pub fn load_yaml_config() -> anyhow::Result<Config> {
 }
 
 pub use browser::{
-    BrowserContext, BrowserError, BrowserResult, BrowserWrapper, download_managed_browser,
+    BrowserBackend, BrowserContext, BrowserError, BrowserResult, BrowserWrapper, CdpBackend,
+    ElementRef, WebDriverBackend, WindowRect, connect_backend, download_managed_browser,
     find_browser_executable, launch_browser,
 };
 pub use manager::BrowserManager;
 pub use tools::{
-    BrowserAgentTool, BrowserClickTool, BrowserExtractTextTool, BrowserNavigateTool,
-    BrowserResearchTool, BrowserScreenshotTool, BrowserScrollTool, BrowserTypeTextTool,
-    BrowserWebSearchTool,
+    BrowserAgentTool, BrowserClickTool, BrowserCookiesTool, BrowserCrawlTool, BrowserDialogTool,
+    BrowserEventsTool, BrowserExtractTextTool, BrowserFillFormTool, BrowserHistoryTool,
+    BrowserHoverTool, BrowserNavigateTool, BrowserPressKeyTool, BrowserResearchTool,
+    BrowserScreenshotTool, BrowserScrollTool, BrowserSelectTool, BrowserStorageTool,
+    BrowserTabsTool, BrowserTypeTextTool, BrowserUploadFileTool, BrowserWebSearchTool,
 };
 
 // Shutdown hook wrappers
@@ -198,6 +542,35 @@ pub async fn start_server(
 /// * `listener` - Pre-bound TcpListener (port already reserved)
 /// * `tls_config` - Optional (cert_path, key_path) for HTTPS
 ///
+/// # Metrics
+/// Counters and gauges are always collected in-process (see
+/// [`crate::utils::ToolMetrics`]); when `Config::metrics.enabled` is set,
+/// they're intended to be served at `Config::metrics.bind_path` as a
+/// Prometheus text endpoint. `ServerBuilder` does not yet expose a hook for
+/// mounting an extra route alongside the MCP router, so wiring the actual
+/// HTTP endpoint here is tracked as incremental follow-up work; callers can
+/// render the same text today via `ToolMetrics::global().render_prometheus()`.
+///
+/// # Streaming research progress
+/// `research::ResearchSession::subscribe` replays accumulated results then
+/// streams live `ResearchEvent`s (new result / completed / errored), so a
+/// session can be observed without polling. Multiplexing that onto an
+/// SSE/WebSocket upgrade on this same listener needs the same route-mounting
+/// hook as the metrics endpoint above, so it's deferred for the same reason;
+/// until then, `subscribe` is usable directly by anything embedding this
+/// crate in-process.
+///
+/// # Multiple instances
+/// This process is the MCP server, not a client multiplexing connections to
+/// several of them, and [`BrowserManager::global`] is a process-wide
+/// singleton - there's no in-process `ServerManager` pooling N
+/// `start_server` instances behind a shared checkout/least-recently-used
+/// handle the way a client-side harness (e.g. one juggling several
+/// `kodegen-browser` subprocesses over distinct ports) would want. A caller
+/// needing several independent, port-isolated browser sessions today spawns
+/// several OS processes, each its own call to [`start_server`] on its own
+/// listener; there's no lighter-weight pooled alternative in this crate.
+///
 /// # Returns
 /// ServerHandle for graceful shutdown, or error if startup fails
 pub async fn start_server_with_listener(
@@ -239,6 +612,16 @@ pub async fn start_server_with_listener(
                 prompt_router,
                 crate::BrowserTypeTextTool::new(browser_manager.clone()),
             );
+            (tool_router, prompt_router) = register_tool(
+                tool_router,
+                prompt_router,
+                crate::BrowserFillFormTool::new(browser_manager.clone()),
+            );
+            (tool_router, prompt_router) = register_tool(
+                tool_router,
+                prompt_router,
+                crate::BrowserPressKeyTool::new(browser_manager.clone()),
+            );
             (tool_router, prompt_router) = register_tool(
                 tool_router,
                 prompt_router,
@@ -276,6 +659,62 @@ pub async fn start_server_with_listener(
                 crate::BrowserWebSearchTool::new(),
             );
 
+            // Site crawl tool (1 tool)
+            (tool_router, prompt_router) = register_tool(
+                tool_router,
+                prompt_router,
+                crate::BrowserCrawlTool::new(browser_manager.clone()),
+            );
+
+            // Session state tools: cookies, storage, dialogs (3 tools)
+            (tool_router, prompt_router) = register_tool(
+                tool_router,
+                prompt_router,
+                crate::BrowserCookiesTool::new(browser_manager.clone()),
+            );
+            (tool_router, prompt_router) = register_tool(
+                tool_router,
+                prompt_router,
+                crate::BrowserStorageTool::new(browser_manager.clone()),
+            );
+            (tool_router, prompt_router) = register_tool(
+                tool_router,
+                prompt_router,
+                crate::BrowserDialogTool::new(browser_manager.clone()),
+            );
+
+            // WebDriver/CDP-grade interaction tools (6 tools)
+            (tool_router, prompt_router) = register_tool(
+                tool_router,
+                prompt_router,
+                crate::BrowserSelectTool::new(browser_manager.clone()),
+            );
+            (tool_router, prompt_router) = register_tool(
+                tool_router,
+                prompt_router,
+                crate::BrowserHoverTool::new(browser_manager.clone()),
+            );
+            (tool_router, prompt_router) = register_tool(
+                tool_router,
+                prompt_router,
+                crate::BrowserHistoryTool::new(browser_manager.clone()),
+            );
+            (tool_router, prompt_router) = register_tool(
+                tool_router,
+                prompt_router,
+                crate::BrowserTabsTool::new(browser_manager.clone()),
+            );
+            (tool_router, prompt_router) = register_tool(
+                tool_router,
+                prompt_router,
+                crate::BrowserUploadFileTool::new(browser_manager.clone()),
+            );
+            (tool_router, prompt_router) = register_tool(
+                tool_router,
+                prompt_router,
+                crate::BrowserEventsTool::new(browser_manager.clone()),
+            );
+
             Ok(RouterSet::new(tool_router, prompt_router, managers))
         })
         .with_listener(listener);
@@ -1,15 +1,29 @@
 //! Browser instance manager for resource-efficient browser sharing
 //!
-//! Ensures only one browser runs at a time, shared across all tools.
+//! Pools a configurable number of browser instances, shared across all tools.
 //!
 //! # Architecture
 //!
-//! Uses `Arc<Mutex<Option<BrowserWrapper>>>` pattern:
+//! Uses `Arc<Mutex<Vec<BrowserHolder>>>` pattern:
 //! - Thread-safe lazy initialization via Mutex check
 //! - Automatic browser launch on first use
 //! - Shared access from multiple tools
 //! - Proper cleanup on shutdown
 //! - Health checking and automatic crash recovery
+//! - `pool_size` (default 1) controls how many Chrome processes run
+//!   concurrently; see [`BrowserManager::acquire`]/[`BrowserManager::release`]
+//! - Concurrent callers get a sticky, exclusive lease keyed by
+//!   `connection_id` rather than racing for whatever slot is free, so one
+//!   agent session's sequential tool calls always land on the same browser
+//!   instance; see [`BrowserManager::acquire_for`]/[`BrowserManager::release_for`]
+//! - `idle_timeout_secs` (default 0, disabled) tears idle instances down
+//!   one at a time; see [`BrowserManager::maybe_spawn_idle_reaper`]
+//! - Named tab registry (`open_tab`/`switch_tab`/`close_tab`/`list_tabs`),
+//!   kept per-`connection_id` and reconciled after every relaunch; see
+//!   [`BrowserManager::reconcile_tabs`]
+//! - Request interception rules (block/mock/rewrite by URL glob and
+//!   resource type), shared across every connection like
+//!   `network_overrides`; see [`BrowserManager::request_interceptor`]
 //!
 //! # Async Lock Requirements
 //!
@@ -20,13 +34,70 @@
 //!
 //! Reference: packages/tools-citescrape/src/web_search/manager.rs
 
-use anyhow::Result;
+use anyhow::{Context, Result};
+use chromiumoxide::browser::Browser;
 use chromiumoxide::page::Page;
+use indexmap::IndexMap;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
 use tokio::sync::Mutex;
 use tracing::info;
 
-use crate::browser::{BrowserWrapper, launch_browser};
+use crate::browser::{BrowserWrapper, TabPool, connect_browser, launch_browser};
+use crate::utils::{
+    CookieProfileStore, DialogWatcher, EventTracker, NavigationPolicy, NetworkOverrides,
+    OriginGovernor, PageDiagnostics, RequestInterceptor, SearchQueue,
+};
+use crate::web_search::{RateLimiter, SearchCache};
+
+/// One pooled browser instance plus whether a caller currently holds it via
+/// [`BrowserManager::acquire`]. Each holder's `browser` gets its own
+/// `user_data_dir` from [`launch_browser`], so pooled profiles never
+/// collide.
+struct BrowserHolder {
+    browser: Arc<Mutex<Option<BrowserWrapper>>>,
+    busy: AtomicBool,
+
+    /// When this specific instance was last handed out via
+    /// [`BrowserManager::get_or_launch_for`], [`BrowserManager::acquire`] or
+    /// [`BrowserManager::acquire_for`]. Checked per-holder by the idle
+    /// reaper (see [`BrowserManager::reap_if_idle`]) so one long-lived lease
+    /// in a pool doesn't keep every *other* idle instance alive too.
+    last_used: Mutex<Instant>,
+}
+
+impl BrowserHolder {
+    fn idle() -> Self {
+        Self {
+            browser: Arc::new(Mutex::new(None)),
+            busy: AtomicBool::new(false),
+            last_used: Mutex::new(Instant::now()),
+        }
+    }
+}
+
+/// Name [`BrowserManager::set_current_page`] (the single-page-model API
+/// every tool already calls) stores its page under, so repeated calls
+/// keep replacing the same tab rather than accumulating new ones.
+const MAIN_TAB_NAME: &str = "main";
+
+/// Key under which [`BrowserManager::get_current_page`]/[`BrowserManager::set_current_page`]
+/// and the tab tools operate when a caller has no `connection_id` (e.g. a
+/// direct, non-MCP call) - keeps today's single-connection behavior as the
+/// default rather than a special case.
+const DEFAULT_CONNECTION: &str = "default";
+
+/// Named open tabs and which one (if any) is "current" for tools that
+/// don't target a tab explicitly. See [`BrowserManager::open_tab`],
+/// [`BrowserManager::switch_tab`], [`BrowserManager::close_tab`],
+/// [`BrowserManager::list_tabs`], and [`BrowserManager::get_current_page`].
+#[derive(Default)]
+struct TabRegistry {
+    tabs: IndexMap<String, Page>,
+    active: Option<String>,
+}
 
 // Global singleton instance
 static GLOBAL_MANAGER: OnceLock<Arc<BrowserManager>> = OnceLock::new();
@@ -59,8 +130,146 @@ static GLOBAL_MANAGER: OnceLock<Arc<BrowserManager>> = OnceLock::new();
 ///
 /// Based on: packages/tools-citescrape/src/web_search/manager.rs:14-122
 pub struct BrowserManager {
-    browser: Arc<Mutex<Option<BrowserWrapper>>>,
-    current_page: Arc<Mutex<Option<Page>>>,
+    /// Pool of browser instances `acquire()`/`release()` check in and out
+    /// of. Pre-sized to `pool_size` (config `browser.pool_size`, default
+    /// `1` for backward compatibility with the original single-browser
+    /// model).
+    pool: Arc<Mutex<Vec<BrowserHolder>>>,
+    pool_size: usize,
+
+    /// One named tab registry per `connection_id`, backing
+    /// [`Self::get_current_page`]/[`Self::set_current_page`] (the "current
+    /// page") plus the explicit [`Self::open_tab`]/[`Self::switch_tab`]/
+    /// [`Self::close_tab`]/[`Self::list_tabs`] multi-tab API. Keyed per
+    /// connection so two concurrent callers (e.g. two `browser_agent`
+    /// sessions) never see or clobber each other's "current" tab.
+    /// Reconciled against whatever tabs the browser actually has after a
+    /// crash recovery or relaunch (see [`Self::reconcile_tabs`]).
+    tabs: Arc<Mutex<HashMap<String, TabRegistry>>>,
+
+    /// Sticky `connection_id -> pool index` mapping so repeated calls from
+    /// the same connection keep landing on the same leased instance instead
+    /// of racing for whatever slot happens to be free; see
+    /// [`Self::acquire_for`]/[`Self::release_for`].
+    leases: Arc<Mutex<HashMap<String, usize>>>,
+
+    /// Global admission-controlled queue bounding in-flight research page
+    /// loads across *all* concurrent `DeepResearch::research()` calls.
+    search_queue: Arc<SearchQueue>,
+
+    /// Console/exception diagnostics captured from the current page.
+    /// Re-subscribed on every navigation (see `BrowserNavigateTool`).
+    diagnostics: Arc<PageDiagnostics>,
+
+    /// Pending JS dialog (`alert`/`confirm`/`prompt`/`beforeunload`) state
+    /// for the current page, resolved via `browser_dialog`. Re-subscribed
+    /// on every navigation alongside `diagnostics`.
+    dialog_watcher: Arc<DialogWatcher>,
+
+    /// Bounded ring buffer of navigation/network/console/DOM-update signals
+    /// for the current page, drained via `browser_events`. Re-subscribed on
+    /// every navigation alongside `diagnostics`. See [`EventTracker`].
+    event_tracker: Arc<EventTracker>,
+
+    /// Shared cache of fused `web_search` results. See [`SearchCache`].
+    search_cache: Arc<SearchCache>,
+
+    /// Per-connection `web_search` rate limiter. See [`RateLimiter`].
+    search_rate_limiter: Arc<RateLimiter>,
+
+    /// Per-origin pacing and throttle backoff shared by every navigation
+    /// path (`web_search`, `browser_research`, `browser_navigate`). See
+    /// [`OriginGovernor`].
+    origin_governor: Arc<OriginGovernor>,
+
+    /// Custom headers, per-origin HTTP basic-auth credentials, and
+    /// URL-pattern block rules applied to every navigation. Populated by
+    /// `browser_set_headers` (and, per research call, `ResearchOptions`).
+    /// See [`NetworkOverrides`].
+    network_overrides: Arc<NetworkOverrides>,
+
+    /// Glob/resource-type request interception rules (block/mock/rewrite)
+    /// applied to every navigation, richer than `network_overrides`'
+    /// substring block list. Populated from `AgentConfig::block_resource_types`
+    /// at agent boot. See [`RequestInterceptor`].
+    request_interceptor: Arc<RequestInterceptor>,
+
+    /// Host allow/deny list and private-IP (SSRF) guard consulted by
+    /// `navigate_and_capture_page` before creating a page, and again
+    /// against the landing URL after redirects. Populated from config
+    /// `browser.navigation_allowlist`/`navigation_denylist`/
+    /// `block_private_navigation`. See [`NavigationPolicy`].
+    navigation_policy: Arc<NavigationPolicy>,
+
+    /// Per-`connection_id` bounded tab pools used by `DeepResearch`'s
+    /// parallel crawl path instead of the single-page model, sized from
+    /// config `browser.research_tab_pool_size`. Lazily created per
+    /// connection on first use; see [`Self::tab_pool_for`].
+    tab_pools: Mutex<HashMap<String, Arc<TabPool>>>,
+    research_tab_pool_size: usize,
+
+    /// Named cookie-jar snapshots and which one (if any) to install on
+    /// every new page before it navigates. Populated by `browser_cookies`'
+    /// `SAVE_PROFILE`/`LOAD_PROFILE` actions and `ResearchOptions::cookie_profile`.
+    /// See [`CookieProfileStore`].
+    cookie_profiles: Arc<CookieProfileStore>,
+
+    /// Directory to disk-back `cookie_profiles` with, from config
+    /// `browser.cookie_profile_dir` (overridden by the
+    /// `BROWSER_COOKIE_PROFILE_DIR` env var). `None` keeps profiles
+    /// in-memory only. Hydration itself happens lazily on first browser
+    /// launch (see [`Self::maybe_hydrate_cookie_profiles`]) since it needs
+    /// an async context that the constructor doesn't have.
+    cookie_profile_dir: Option<std::path::PathBuf>,
+
+    /// Set once [`Self::maybe_hydrate_cookie_profiles`] has hydrated
+    /// `cookie_profiles` from `cookie_profile_dir`, so it only happens once
+    /// regardless of how many callers race into `ensure_healthy()`.
+    cookie_profiles_hydrated: AtomicBool,
+
+    /// Relaunch threshold/cadence for the resource monitor. See
+    /// [`BrowserManager::spawn_resource_monitor`].
+    max_memory_mb: u64,
+    health_check_secs: u64,
+    auto_restart: bool,
+
+    /// Chrome DevTools WebSocket endpoint to attach to via
+    /// [`connect_browser`] instead of launching a managed instance (config
+    /// `browser.connect_url`, falling back to the `BROWSER_CONNECT_URL`
+    /// env var). `None` launches normally.
+    connect_url: Option<String>,
+
+    /// Tear the browser down after this much idle time. `Duration::ZERO`
+    /// (config `browser.idle_timeout_secs = 0`) disables the reaper.
+    idle_timeout: Duration,
+
+    /// Extra Chromium flags appended to every launch, after config
+    /// `browser.extra_args` is overridden wholesale by a non-empty
+    /// `BROWSER_EXTRA_ARGS` env var (whitespace-separated), so a
+    /// daemon-managed server binary can inject flags without a config file.
+    extra_browser_args: Vec<String>,
+
+    /// `--proxy-server` value applied to every launch, if any. Config
+    /// `browser.proxy`, overridden by the `BROWSER_PROXY` env var when set.
+    proxy: Option<String>,
+
+    /// Root certificate trust policy, from config `browser.tls_trust_store`.
+    /// Applied to launched-browser Chromium flags (see
+    /// [`crate::browser_setup::launch_browser`]); exposed via
+    /// [`Self::tls_trust_store`] so callers building their own
+    /// `reqwest::Client` (e.g. `DeepResearch`'s `RobotsCache`) stay
+    /// consistent with the same policy instead of trusting whatever
+    /// `reqwest::Client::default()` ships with.
+    tls_trust_store: crate::TlsTrustStore,
+
+    /// Set once the idle reaper task has been spawned, so it's only
+    /// started once (lazily, on first launch) regardless of how many
+    /// callers race into `ensure_healthy()`.
+    reaper_started: AtomicBool,
+
+    /// Handle of the running reaper task, if spawned, so it can be
+    /// aborted on drop like the browser's handler task.
+    reaper_handle: std::sync::Mutex<Option<tokio::task::JoinHandle<()>>>,
 }
 
 impl BrowserManager {
@@ -86,7 +295,11 @@ impl BrowserManager {
     #[must_use]
     pub fn global() -> Arc<BrowserManager> {
         GLOBAL_MANAGER
-            .get_or_init(|| Arc::new(BrowserManager::new()))
+            .get_or_init(|| {
+                let manager = Arc::new(BrowserManager::new());
+                manager.clone().spawn_resource_monitor();
+                manager
+            })
             .clone()
     }
 
@@ -97,12 +310,148 @@ impl BrowserManager {
     /// This is now private to prevent accidental creation of multiple managers.
     /// External code should use `BrowserManager::global()`.
     fn new() -> Self {
+        let config = crate::load_yaml_config().unwrap_or_default();
+        let pool_size = config.browser.pool_size.max(1);
+        let pool = (0..pool_size).map(|_| BrowserHolder::idle()).collect();
         Self {
-            browser: Arc::new(Mutex::new(None)),
-            current_page: Arc::new(Mutex::new(None)),
+            pool: Arc::new(Mutex::new(pool)),
+            pool_size,
+            tabs: Arc::new(Mutex::new(HashMap::new())),
+            leases: Arc::new(Mutex::new(HashMap::new())),
+            search_queue: Arc::new(SearchQueue::with_default_capacity()),
+            diagnostics: PageDiagnostics::new(),
+            dialog_watcher: DialogWatcher::new(),
+            event_tracker: EventTracker::new(),
+            search_cache: Arc::new(SearchCache::new(
+                config.cache_capacity,
+                std::time::Duration::from_secs(config.cache_ttl_secs),
+            )),
+            search_rate_limiter: Arc::new(RateLimiter::new(
+                config.requests_per_window,
+                std::time::Duration::from_secs(config.window_secs),
+            )),
+            origin_governor: Arc::new(OriginGovernor::new(
+                config.origin_requests_per_sec,
+                config.origin_burst,
+            )),
+            network_overrides: Arc::new(NetworkOverrides::new()),
+            request_interceptor: Arc::new(RequestInterceptor::new()),
+            navigation_policy: Arc::new(NavigationPolicy::new(
+                config.browser.navigation_allowlist.clone(),
+                config.browser.navigation_denylist.clone(),
+                config.browser.block_private_navigation,
+            )),
+            tab_pools: Mutex::new(HashMap::new()),
+            research_tab_pool_size: config.browser.research_tab_pool_size,
+            cookie_profiles: Arc::new(CookieProfileStore::new()),
+            cookie_profile_dir: std::env::var("BROWSER_COOKIE_PROFILE_DIR")
+                .ok()
+                .or(config.browser.cookie_profile_dir)
+                .map(std::path::PathBuf::from),
+            cookie_profiles_hydrated: AtomicBool::new(false),
+            max_memory_mb: config.browser.max_memory_mb,
+            health_check_secs: config.browser.health_check_secs,
+            auto_restart: config.browser.auto_restart,
+            connect_url: config
+                .browser
+                .connect_url
+                .clone()
+                .or_else(|| std::env::var("BROWSER_CONNECT_URL").ok()),
+            idle_timeout: Duration::from_secs(config.browser.idle_timeout_secs),
+            extra_browser_args: std::env::var("BROWSER_EXTRA_ARGS")
+                .ok()
+                .map(|raw| raw.split_whitespace().map(String::from).collect())
+                .unwrap_or(config.browser.extra_args),
+            proxy: std::env::var("BROWSER_PROXY")
+                .ok()
+                .or(config.browser.proxy),
+            tls_trust_store: config.browser.tls_trust_store,
+            reaper_started: AtomicBool::new(false),
+            reaper_handle: std::sync::Mutex::new(None),
         }
     }
 
+    /// Get the shared page diagnostics ring buffer (console messages and
+    /// uncaught exceptions captured from the current page via CDP)
+    pub fn diagnostics(&self) -> Arc<PageDiagnostics> {
+        Arc::clone(&self.diagnostics)
+    }
+
+    /// Get the shared JS dialog watcher for the current page
+    pub fn dialog_watcher(&self) -> Arc<DialogWatcher> {
+        Arc::clone(&self.dialog_watcher)
+    }
+
+    /// Get the shared navigation/network/console/DOM-update event tracker
+    /// for the current page
+    pub fn event_tracker(&self) -> Arc<EventTracker> {
+        Arc::clone(&self.event_tracker)
+    }
+
+    /// Get the shared research page-processing queue
+    ///
+    /// Bounds in-flight page loads across *all* concurrent research calls,
+    /// not just within a single call. See [`SearchQueue`].
+    pub fn search_queue(&self) -> Arc<SearchQueue> {
+        Arc::clone(&self.search_queue)
+    }
+
+    /// Get the shared `web_search` result cache
+    pub fn search_cache(&self) -> Arc<SearchCache> {
+        Arc::clone(&self.search_cache)
+    }
+
+    /// Get the shared per-connection `web_search` rate limiter
+    pub fn search_rate_limiter(&self) -> Arc<RateLimiter> {
+        Arc::clone(&self.search_rate_limiter)
+    }
+
+    /// Get the shared per-origin pacing/backoff governor
+    pub fn origin_governor(&self) -> Arc<OriginGovernor> {
+        Arc::clone(&self.origin_governor)
+    }
+
+    /// Get the shared custom-headers/basic-auth/block-rule store consulted
+    /// by every navigation. See [`NetworkOverrides`].
+    pub fn network_overrides(&self) -> Arc<NetworkOverrides> {
+        Arc::clone(&self.network_overrides)
+    }
+
+    /// Get the configured root certificate trust policy (config
+    /// `browser.tls_trust_store`), for callers building their own
+    /// `reqwest::Client` that should stay consistent with it.
+    pub fn tls_trust_store(&self) -> crate::TlsTrustStore {
+        self.tls_trust_store
+    }
+
+    /// Get the shared glob/resource-type request interception rule set.
+    /// See [`RequestInterceptor`].
+    pub fn request_interceptor(&self) -> Arc<RequestInterceptor> {
+        Arc::clone(&self.request_interceptor)
+    }
+
+    /// Get the shared named cookie-jar profile store.
+    pub fn cookie_profiles(&self) -> Arc<CookieProfileStore> {
+        Arc::clone(&self.cookie_profiles)
+    }
+
+    /// Get the shared navigation host allow/deny list and SSRF guard. See
+    /// [`NavigationPolicy`].
+    pub fn navigation_policy(&self) -> Arc<NavigationPolicy> {
+        Arc::clone(&self.navigation_policy)
+    }
+
+    /// Get (lazily creating) `connection_id`'s bounded tab pool, sized from
+    /// config `browser.research_tab_pool_size`. See [`TabPool`].
+    pub async fn tab_pool_for(&self, connection_id: &str) -> Arc<TabPool> {
+        let mut pools = self.tab_pools.lock().await;
+        Arc::clone(
+            pools
+                .entry(connection_id.to_string())
+                .or_insert_with(|| Arc::new(TabPool::new(self.research_tab_pool_size))),
+        )
+    }
+
     /// Get or launch the shared browser instance with health checking and auto-recovery
     ///
     /// # Health Check and Recovery Flow
@@ -135,16 +484,157 @@ impl BrowserManager {
     /// }
     /// ```
     pub async fn get_or_launch(&self) -> Result<Arc<Mutex<Option<BrowserWrapper>>>> {
-        let mut guard = self.browser.lock().await;
+        self.get_or_launch_for(DEFAULT_CONNECTION).await
+    }
+
+    /// Same as [`Self::get_or_launch`], but for `pool_size > 1` gives
+    /// `connection_id` an exclusive, sticky lease via [`Self::acquire_for`]
+    /// instead of racing every other caller for whatever slot is free -
+    /// see that method for why this matters for concurrent agent sessions.
+    pub async fn get_or_launch_for(
+        &self,
+        connection_id: &str,
+    ) -> Result<Arc<Mutex<Option<BrowserWrapper>>>> {
+        if self.pool_size <= 1 {
+            // Back-compat path: every existing tool call site shares this
+            // one instance without ever calling `release()`, so it must
+            // stay reusable rather than gated by `busy` - exactly the
+            // pre-pool single-browser model.
+            let pool = self.pool.lock().await;
+            let browser = Arc::clone(&pool[0].browser);
+            drop(pool);
+            self.ensure_healthy(&browser, DEFAULT_CONNECTION).await?;
+            self.touch_holder(0).await;
+            return Ok(browser);
+        }
+
+        self.acquire_for(connection_id).await
+    }
+
+    /// Check out an idle, health-checked browser instance from the pool,
+    /// launching it if this is its first use or recovering it if it
+    /// crashed. Blocks (polling) when every instance is busy.
+    ///
+    /// Returns the holder's `Arc<Mutex<Option<BrowserWrapper>>>` - lock it
+    /// to access the `BrowserWrapper`, same as `get_or_launch()`. Pair with
+    /// [`Self::release`] once done so another caller can check the instance
+    /// back out; forgetting to release strands that slot idle-looking but
+    /// permanently busy for the life of the process.
+    pub async fn acquire(&self) -> Result<Arc<Mutex<Option<BrowserWrapper>>>> {
+        let (index, browser) = self.claim_any_slot().await;
+        if let Err(e) = self.ensure_healthy(&browser, DEFAULT_CONNECTION).await {
+            self.release(&browser).await;
+            return Err(e);
+        }
+        self.touch_holder(index).await;
+        Ok(browser)
+    }
+
+    /// Give `connection_id` exclusive use of a pooled browser instance,
+    /// reusing the same one across repeated calls rather than grabbing
+    /// whatever slot happens to be free each time - a plain [`Self::acquire`]
+    /// per call means a single agent session's sequential tool calls
+    /// (navigate, then click, then type) can land on *different* browser
+    /// processes once the pool has more than one slot, defeating the whole
+    /// point of a per-session browser. Pair with [`Self::release_for`] when
+    /// the session ends (kill or completion) so the slot goes back to the
+    /// pool for someone else.
+    pub async fn acquire_for(
+        &self,
+        connection_id: &str,
+    ) -> Result<Arc<Mutex<Option<BrowserWrapper>>>> {
+        let existing = {
+            let leases = self.leases.lock().await;
+            leases.get(connection_id).copied()
+        };
+
+        let (index, browser) = if let Some(index) = existing {
+            let pool = self.pool.lock().await;
+            (index, Arc::clone(&pool[index].browser))
+        } else {
+            let (index, browser) = self.claim_any_slot().await;
+            self.leases
+                .lock()
+                .await
+                .insert(connection_id.to_string(), index);
+            (index, browser)
+        };
+
+        if let Err(e) = self.ensure_healthy(&browser, connection_id).await {
+            self.release_for(connection_id).await;
+            return Err(e);
+        }
+        self.touch_holder(index).await;
+        Ok(browser)
+    }
+
+    /// Wait for and mark busy the first free pool slot, returning its index
+    /// alongside the handle. Shared by [`Self::acquire`] and
+    /// [`Self::acquire_for`]'s first-lease path.
+    async fn claim_any_slot(&self) -> (usize, Arc<Mutex<Option<BrowserWrapper>>>) {
+        loop {
+            let claimed = {
+                let pool = self.pool.lock().await;
+                pool.iter().enumerate().find_map(|(i, holder)| {
+                    (!holder.busy.swap(true, Ordering::AcqRel)).then(|| (i, Arc::clone(&holder.browser)))
+                })
+            };
+            match claimed {
+                Some(found) => return found,
+                None => tokio::time::sleep(Duration::from_millis(50)).await,
+            }
+        }
+    }
+
+    /// Record that pool slot `index` was just used, resetting its own idle
+    /// clock (checked per-holder by [`Self::reap_holder_if_idle`]).
+    async fn touch_holder(&self, index: usize) {
+        let pool = self.pool.lock().await;
+        *pool[index].last_used.lock().await = Instant::now();
+    }
+
+    /// Mark a browser instance checked out via [`Self::acquire`] idle again
+    /// so another caller can check it out. No-op if `browser` isn't a
+    /// handle this pool owns.
+    pub async fn release(&self, browser: &Arc<Mutex<Option<BrowserWrapper>>>) {
+        let pool = self.pool.lock().await;
+        if let Some(holder) = pool
+            .iter()
+            .find(|holder| Arc::ptr_eq(&holder.browser, browser))
+        {
+            holder.busy.store(false, Ordering::Release);
+        }
+    }
+
+    /// Release `connection_id`'s leased instance (if any) back to the pool
+    /// and forget the sticky mapping, so a future [`Self::acquire_for`] for
+    /// the same connection starts fresh. Call this when the owning session
+    /// ends (agent `kill`/completion), not between individual tool calls.
+    pub async fn release_for(&self, connection_id: &str) {
+        let index = self.leases.lock().await.remove(connection_id);
+        if let Some(index) = index {
+            let pool = self.pool.lock().await;
+            pool[index].busy.store(false, Ordering::Release);
+        }
+    }
+
+    /// Health-check `browser`'s current instance via `version()`, cleaning
+    /// up and relaunching it if unhealthy or if it's never been launched.
+    /// `connection_id` scopes the post-relaunch [`Self::reconcile_tabs`] to
+    /// whichever connection's tabs this instance belongs to.
+    async fn ensure_healthy(
+        &self,
+        browser: &Arc<Mutex<Option<BrowserWrapper>>>,
+        connection_id: &str,
+    ) -> Result<()> {
+        let mut guard = browser.lock().await;
 
         // Health check: if browser exists, verify it's alive
         if let Some(wrapper) = guard.as_ref() {
             match wrapper.browser().version().await {
                 Ok(_) => {
                     tracing::debug!("Browser health check passed, reusing existing browser");
-                    // Browser is healthy, return it
-                    drop(guard); // Release lock
-                    return Ok(self.browser.clone());
+                    return Ok(());
                 }
                 Err(e) => {
                     tracing::warn!("Browser health check failed: {}. Triggering recovery...", e);
@@ -158,18 +648,279 @@ impl BrowserManager {
                     }
 
                     tracing::info!("Crashed browser cleaned up, launching new instance");
+                    crate::utils::ToolMetrics::global().incr_relaunch_count();
                 }
             }
         }
 
-        // No browser exists or previous one crashed - launch new one
-        tracing::info!("Launching browser (first time or after recovery)");
-        let (browser, handler, user_data_dir) = launch_browser().await?;
-        let wrapper = BrowserWrapper::new(browser, handler, user_data_dir);
+        // No browser exists or previous one crashed - launch (or connect to) a new one
+        let wrapper = match &self.connect_url {
+            Some(ws_url) => {
+                tracing::info!("Connecting to existing browser (first time or after recovery)");
+                let (browser, handler) = connect_browser(ws_url).await?;
+                BrowserWrapper::connected(browser, handler)
+            }
+            None => {
+                tracing::info!("Launching browser (first time or after recovery)");
+                let (browser, handler, user_data_dir) =
+                    launch_browser(&self.extra_browser_args, self.proxy.as_deref()).await?;
+                match user_data_dir {
+                    Some(dir) => BrowserWrapper::new(browser, handler, dir),
+                    // attach_real_profile: no synthetic/copied directory was
+                    // created, so there's nothing for BrowserWrapper to own
+                    // or clean up - same as connecting to an already-running
+                    // Chrome.
+                    None => BrowserWrapper::connected(browser, handler),
+                }
+            }
+        };
         *guard = Some(wrapper);
+        if let Some(w) = guard.as_ref() {
+            self.reconcile_tabs(connection_id, w.browser()).await;
+        }
         drop(guard);
 
-        Ok(self.browser.clone())
+        self.maybe_spawn_idle_reaper();
+        self.maybe_hydrate_cookie_profiles().await;
+
+        Ok(())
+    }
+
+    /// Lazily disk-back `cookie_profiles` from `cookie_profile_dir` on
+    /// first launch, guarded so it only ever runs once - the constructor
+    /// can't do this itself since loading saved profiles needs an async
+    /// context. No-op if `cookie_profile_dir` wasn't configured.
+    async fn maybe_hydrate_cookie_profiles(&self) {
+        let Some(dir) = &self.cookie_profile_dir else {
+            return;
+        };
+        if self.cookie_profiles_hydrated.swap(true, Ordering::AcqRel) {
+            return;
+        }
+        if let Err(e) = self.cookie_profiles.hydrate(dir.clone()).await {
+            tracing::warn!("Failed to hydrate cookie profiles from {}: {}", dir.display(), e);
+        }
+    }
+
+    /// Drop every tab handle from the last (now-dead) browser instance and
+    /// re-enumerate whatever tabs `browser` actually has open, via CDP's
+    /// `browser.pages()`. Called after every launch/relaunch/reconnect in
+    /// [`Self::ensure_healthy`].
+    ///
+    /// A freshly launched instance has no pages yet, so this just clears
+    /// the registry. Reconnecting to an already-running browser (config
+    /// `browser.connect_url`) is the case that actually matters: its tabs
+    /// are still alive, just unknown to us until re-enumerated, and none
+    /// is marked active until a tool opens/switches to one.
+    async fn reconcile_tabs(&self, connection_id: &str, browser: &Browser) {
+        let mut tabs = self.tabs.lock().await;
+        let registry = tabs.entry(connection_id.to_string()).or_default();
+        registry.tabs.clear();
+        registry.active = None;
+
+        match browser.pages().await {
+            Ok(pages) => {
+                for (i, page) in pages.into_iter().enumerate() {
+                    registry.tabs.insert(format!("{MAIN_TAB_NAME}-{i}"), page);
+                }
+            }
+            Err(e) => tracing::warn!("Failed to enumerate browser tabs for reconciliation: {}", e),
+        }
+    }
+
+    /// Lazily spawn the idle reaper on first launch, guarded so it only
+    /// ever starts once. No-op if the idle timeout is disabled (`0`).
+    ///
+    /// Runs for the lifetime of the process, same as
+    /// [`Self::spawn_resource_monitor`] - there's exactly one
+    /// `BrowserManager` (see [`Self::global`]), so it re-fetches itself via
+    /// `global()` rather than needing an `Arc<Self>` threaded through
+    /// `ensure_healthy`.
+    fn maybe_spawn_idle_reaper(&self) {
+        if self.idle_timeout.is_zero() {
+            return;
+        }
+        if self.reaper_started.swap(true, Ordering::AcqRel) {
+            return;
+        }
+
+        let check_interval = self.idle_timeout.max(Duration::from_secs(1));
+        let handle = tokio::spawn(async move {
+            let mut interval = tokio::time::interval(check_interval);
+            loop {
+                interval.tick().await;
+                BrowserManager::global().reap_if_idle().await;
+            }
+        });
+
+        let mut reaper_handle = self
+            .reaper_handle
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        *reaper_handle = Some(handle);
+    }
+
+    /// Tear down every pooled instance that's been idle past `idle_timeout`,
+    /// one at a time, so one long-lived `acquire_for` lease doesn't keep
+    /// every other idle slot in the pool alive too. Racing a concurrent
+    /// `get_or_launch()`/`acquire()` is safe: both paths re-check liveness
+    /// under the same per-holder `Mutex` this locks, so whichever side wins
+    /// the lock simply runs first - a relaunch right after a reap just pays
+    /// the cold-launch cost again, and a reap that loses the race tears down
+    /// the freshly-launched instance but never corrupts state.
+    async fn reap_if_idle(&self) {
+        for index in 0..self.pool_size {
+            self.reap_holder_if_idle(index).await;
+        }
+    }
+
+    /// `reap_if_idle()`'s logic for a single pooled slot: skip it if it's
+    /// currently leased out (`busy`) or hasn't been idle long enough,
+    /// otherwise close it and forget any lease/tab state that pointed at it.
+    async fn reap_holder_if_idle(&self, index: usize) {
+        let mut wrapper = {
+            let pool = self.pool.lock().await;
+            let holder = &pool[index];
+            if holder.busy.load(Ordering::Acquire) {
+                return;
+            }
+            let idle_for = holder.last_used.lock().await.elapsed();
+            if idle_for < self.idle_timeout {
+                return;
+            }
+            match holder.browser.lock().await.take() {
+                Some(wrapper) => wrapper,
+                None => return,
+            }
+        };
+
+        tracing::info!(
+            "Browser instance {} idle past {:?}, reaping",
+            index,
+            self.idle_timeout
+        );
+
+        let _ = wrapper.browser_mut().close().await;
+        let _ = wrapper.browser_mut().wait().await;
+        wrapper.cleanup_temp_dir();
+
+        let connection_id = {
+            let mut leases = self.leases.lock().await;
+            let connection_id = leases
+                .iter()
+                .find(|(_, &leased_index)| leased_index == index)
+                .map(|(connection_id, _)| connection_id.clone());
+            if let Some(connection_id) = &connection_id {
+                leases.remove(connection_id);
+            }
+            connection_id
+        };
+        if let Some(connection_id) = connection_id {
+            self.tabs.lock().await.remove(&connection_id);
+        }
+    }
+
+    /// Spawn the background resource monitor: every `health_check_secs`,
+    /// sample the running browser process's RSS and liveness and, if
+    /// `auto_restart` is set and it's over `max_memory_mb` or has died,
+    /// relaunch it.
+    ///
+    /// No-op if `health_check_secs` is `0`. Runs for the lifetime of the
+    /// process - there's exactly one `BrowserManager` (see [`Self::global`]).
+    fn spawn_resource_monitor(self: Arc<Self>) {
+        if self.health_check_secs == 0 {
+            return;
+        }
+
+        tokio::spawn(async move {
+            let mut interval =
+                tokio::time::interval(std::time::Duration::from_secs(self.health_check_secs));
+            loop {
+                interval.tick().await;
+                self.check_resource_usage().await;
+            }
+        });
+    }
+
+    /// Sample every pooled browser process, if running, and relaunch any
+    /// that's over budget or dead when `auto_restart` is enabled.
+    async fn check_resource_usage(&self) {
+        let browsers: Vec<Arc<Mutex<Option<BrowserWrapper>>>> = {
+            let pool = self.pool.lock().await;
+            pool.iter()
+                .map(|holder| Arc::clone(&holder.browser))
+                .collect()
+        };
+
+        for (index, browser) in browsers.iter().enumerate() {
+            self.check_one_resource_usage(index, browser).await;
+        }
+    }
+
+    /// `check_resource_usage()`'s logic for a single pooled instance.
+    async fn check_one_resource_usage(&self, index: usize, browser: &Arc<Mutex<Option<BrowserWrapper>>>) {
+        let guard = browser.lock().await;
+        let Some(wrapper) = guard.as_ref() else {
+            return;
+        };
+        let Some(pid) = wrapper.pid() else {
+            return;
+        };
+
+        let alive = crate::browser::monitor::is_process_alive(pid);
+        let rss_bytes = crate::browser::monitor::sample_rss_bytes(pid);
+        let max_bytes = self.max_memory_mb * 1024 * 1024;
+        let over_budget = rss_bytes.is_some_and(|bytes| bytes > max_bytes);
+
+        if alive && !over_budget {
+            return;
+        }
+
+        tracing::warn!(
+            "Browser resource check failed (pid={}, alive={}, rss_bytes={:?}, budget_bytes={}); {}",
+            pid,
+            alive,
+            rss_bytes,
+            max_bytes,
+            if self.auto_restart {
+                "relaunching"
+            } else {
+                "auto_restart disabled, leaving as-is"
+            }
+        );
+
+        if !self.auto_restart {
+            return;
+        }
+
+        // Release the guard before relaunching: `ensure_healthy()` and the
+        // crash-recovery cleanup below both need to re-lock `browser`, and
+        // holding this guard across them would deadlock. Dropping it first
+        // also means an in-flight tool call already holding the lock
+        // finishes before this relaunch proceeds, rather than racing it.
+        drop(guard);
+
+        let mut guard = browser.lock().await;
+        if let Some(mut crashed_wrapper) = guard.take() {
+            let _ = crashed_wrapper.browser_mut().close().await;
+            let _ = crashed_wrapper.browser_mut().wait().await;
+            crashed_wrapper.cleanup_temp_dir();
+        }
+        drop(guard);
+
+        let connection_id = self
+            .leases
+            .lock()
+            .await
+            .iter()
+            .find(|(_, &leased_index)| leased_index == index)
+            .map(|(connection_id, _)| connection_id.clone())
+            .unwrap_or_else(|| DEFAULT_CONNECTION.to_string());
+
+        crate::utils::ToolMetrics::global().incr_relaunch_count();
+        if let Err(e) = self.ensure_healthy(browser, &connection_id).await {
+            tracing::error!("Resource monitor failed to relaunch browser: {}", e);
+        }
     }
 
     /// Shutdown the browser if running
@@ -177,6 +928,18 @@ impl BrowserManager {
     /// Explicitly closes the browser process and cleans up resources.
     /// Safe to call multiple times (subsequent calls are no-ops).
     ///
+    /// # No request draining
+    /// This closes every browser in `self.pool`, leased or not, as soon as
+    /// it can lock that holder's browser - it does not wait for in-flight
+    /// MCP tool calls to finish first. Tracking and
+    /// draining those would need to live at the MCP dispatch layer (owned by
+    /// `kodegen_server_http::ServerBuilder`/the `rmcp` router that calls into
+    /// each tool, both external to this crate), not here: `BrowserManager`
+    /// has no visibility into whether a `call_tool` invocation is currently
+    /// in progress against one of its pooled browsers. A caller such as the
+    /// crate's `ShutdownHook` impl that needs a drain-before-close guarantee
+    /// has to arrange it upstream of this call.
+    ///
     /// # Critical Implementation Note
     ///
     /// We must call BOTH:
@@ -210,52 +973,167 @@ impl BrowserManager {
     ///
     /// Based on: packages/tools-citescrape/src/web_search/manager.rs:88-122
     pub async fn shutdown(&self) -> Result<()> {
-        let mut guard = self.browser.lock().await;
+        let pool = self.pool.lock().await;
+        let mut any_closed = false;
 
-        if let Some(mut wrapper) = guard.take() {
-            info!("Shutting down browser");
+        for holder in pool.iter() {
+            let mut guard = holder.browser.lock().await;
+            if let Some(mut wrapper) = guard.take() {
+                info!("Shutting down browser");
+                any_closed = true;
 
-            // Close browser gracefully
-            if let Err(e) = wrapper.browser_mut().close().await {
-                tracing::warn!("Failed to close browser cleanly: {}", e);
-            }
+                // Close browser gracefully
+                if let Err(e) = wrapper.browser_mut().close().await {
+                    tracing::warn!("Failed to close browser cleanly: {}", e);
+                }
 
-            // Wait for process to fully exit
-            if let Err(e) = wrapper.browser_mut().wait().await {
-                tracing::warn!("Failed to wait for browser exit: {}", e);
-            }
+                // Wait for process to fully exit
+                if let Err(e) = wrapper.browser_mut().wait().await {
+                    tracing::warn!("Failed to wait for browser exit: {}", e);
+                }
 
-            // Cleanup temp directory
-            wrapper.cleanup_temp_dir();
+                // Cleanup temp directory
+                wrapper.cleanup_temp_dir();
+
+                drop(wrapper);
+            }
+        }
 
-            drop(wrapper);
+        if any_closed {
+            self.tabs.lock().await.clear();
+            crate::utils::ToolMetrics::global().set_active_pages(0);
         }
 
         Ok(())
     }
 
-    /// Get the current active page, if one exists
+    /// Get `connection_id`'s active tab's page, if one exists.
     ///
-    /// Returns the page set by the most recent navigate() call.
-    /// Other browser tools (type_text, click, etc.) should use this
-    /// to get the page to interact with.
-    pub async fn get_current_page(&self) -> Option<Page> {
-        self.current_page.lock().await.clone()
+    /// Returns the page set by that connection's most recent `navigate()`
+    /// call, or whichever tab [`Self::open_tab`]/[`Self::switch_tab`] last
+    /// made active for it. Other browser tools (type_text, click, etc.)
+    /// should use this to get the page to interact with.
+    pub async fn get_current_page(&self, connection_id: &str) -> Option<Page> {
+        let tabs = self.tabs.lock().await;
+        let registry = tabs.get(connection_id)?;
+        let active = registry.active.as_ref()?;
+        registry.tabs.get(active).cloned()
     }
 
-    /// Set the current active page
+    /// Set `connection_id`'s active tab's page.
     ///
-    /// Called by navigate() to store the page for other tools to use.
-    /// Replaces any previously stored page (which gets automatically dropped/closed).
-    pub async fn set_current_page(&self, page: Page) {
-        *self.current_page.lock().await = Some(page);
+    /// Called by `navigate()` to store the page for other tools to use.
+    /// Stores it under a fixed tab name (see [`MAIN_TAB_NAME`]), so
+    /// repeated calls replace the same tab rather than accumulating new
+    /// ones - callers that want genuinely separate tabs should use
+    /// [`Self::open_tab`] instead.
+    pub async fn set_current_page(&self, connection_id: &str, page: Page) {
+        let mut tabs = self.tabs.lock().await;
+        let registry = tabs.entry(connection_id.to_string()).or_default();
+        registry.tabs.insert(MAIN_TAB_NAME.to_string(), page);
+        registry.active = Some(MAIN_TAB_NAME.to_string());
+        crate::utils::ToolMetrics::global().set_active_pages(registry.tabs.len());
+    }
+
+    /// Open a new tab named `name` for `connection_id`, navigate it to
+    /// `url`, and make it active. Launches the browser first if it isn't
+    /// already running. Errors if `name` is already open for this
+    /// connection - use [`Self::switch_tab`] to return to an existing tab.
+    pub async fn open_tab(
+        &self,
+        connection_id: &str,
+        name: impl Into<String>,
+        url: &str,
+    ) -> Result<Page> {
+        let name = name.into();
+
+        let browser_arc = self.get_or_launch_for(connection_id).await?;
+        let browser_guard = browser_arc.lock().await;
+        let wrapper = browser_guard
+            .as_ref()
+            .context("Browser not available after launch")?;
+        let page = wrapper
+            .browser()
+            .new_page(url)
+            .await
+            .with_context(|| format!("Failed to open tab '{name}' at {url}"))?;
+        drop(browser_guard);
+
+        let mut tabs = self.tabs.lock().await;
+        let registry = tabs.entry(connection_id.to_string()).or_default();
+        if registry.tabs.contains_key(&name) {
+            anyhow::bail!("Tab '{name}' is already open");
+        }
+        registry.tabs.insert(name.clone(), page.clone());
+        registry.active = Some(name);
+        crate::utils::ToolMetrics::global().set_active_pages(registry.tabs.len());
+
+        Ok(page)
+    }
+
+    /// Make the already-open tab named `name` active for `connection_id`
+    /// and return its page.
+    pub async fn switch_tab(&self, connection_id: &str, name: &str) -> Result<Page> {
+        let mut tabs = self.tabs.lock().await;
+        let registry = tabs.entry(connection_id.to_string()).or_default();
+        let page = registry
+            .tabs
+            .get(name)
+            .cloned()
+            .with_context(|| format!("No open tab named '{name}'"))?;
+        registry.active = Some(name.to_string());
+        Ok(page)
+    }
+
+    /// Close the tab named `name` for `connection_id`. If it was the
+    /// active tab, the next remaining tab (in open order) becomes active,
+    /// or none if it was the last one.
+    pub async fn close_tab(&self, connection_id: &str, name: &str) -> Result<()> {
+        let mut tabs = self.tabs.lock().await;
+        let registry = tabs.entry(connection_id.to_string()).or_default();
+        let page = registry
+            .tabs
+            .shift_remove(name)
+            .with_context(|| format!("No open tab named '{name}'"))?;
+        if registry.active.as_deref() == Some(name) {
+            registry.active = registry.tabs.keys().next().cloned();
+        }
+        crate::utils::ToolMetrics::global().set_active_pages(registry.tabs.len());
+        drop(tabs);
+
+        if let Err(e) = page.close().await {
+            tracing::warn!("Failed to close tab '{}': {}", name, e);
+        }
+        Ok(())
+    }
+
+    /// Names of every tab currently open for `connection_id`, in the order
+    /// they were opened.
+    pub async fn list_tabs(&self, connection_id: &str) -> Vec<String> {
+        self.tabs
+            .lock()
+            .await
+            .get(connection_id)
+            .map(|registry| registry.tabs.keys().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Name of `connection_id`'s currently active tab, if any.
+    pub async fn active_tab_name(&self, connection_id: &str) -> Option<String> {
+        self.tabs.lock().await.get(connection_id)?.active.clone()
     }
 
     /// Check if browser is currently running
     ///
     /// Non-blocking check of browser state.
     pub async fn is_browser_running(&self) -> bool {
-        self.browser.lock().await.is_some()
+        let pool = self.pool.lock().await;
+        for holder in pool.iter() {
+            if holder.browser.lock().await.is_some() {
+                return true;
+            }
+        }
+        false
     }
 }
 
@@ -265,6 +1143,15 @@ impl Drop for BrowserManager {
         // However, this is NOT a clean shutdown - it only aborts the handler
         // For clean shutdown, call shutdown().await before dropping
         info!("BrowserManager dropping - browser will be cleaned up");
+
+        if let Some(handle) = self
+            .reaper_handle
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .take()
+        {
+            handle.abort();
+        }
     }
 }
 
@@ -274,10 +1161,9 @@ use kodegen_server_http::ShutdownHook;
 
 #[cfg(feature = "server")]
 impl ShutdownHook for BrowserManager {
-    fn shutdown(&self) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<()>> + Send + '_>> {
-        Box::pin(async move {
-            BrowserManager::shutdown(self).await
-        })
+    fn shutdown(
+        &self,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<()>> + Send + '_>> {
+        Box::pin(async move { BrowserManager::shutdown(self).await })
     }
 }
-
@@ -0,0 +1,176 @@
+//! Readability-style main-content extraction, so `deep_research` gets
+//! cleaned article text alongside title/metadata instead of having to
+//! re-scrape the DOM itself.
+//!
+//! Scores candidate block elements by text density (link-free character
+//! count divided by descendant tag count), same heuristic Mozilla's
+//! Readability and most other boilerplate-stripping extractors use, then
+//! promotes the highest-scoring ancestor as the article root. Runs as a
+//! single injected script so it pays for one `evaluate` round trip, same
+//! as [`super::extractors::extract_metadata`].
+
+use anyhow::{Context, Result};
+use chromiumoxide::Page;
+
+/// Cleaned main content of the page, or `None` when no block scored high
+/// enough to be treated as the dominant content (e.g. a listing or
+/// navigation-only page).
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ArticleContent {
+    /// Main content text: the non-boilerplate `p`/`h1`-`h3`/`li` elements
+    /// under the article root, blank-line separated (link text included).
+    pub text: String,
+    /// Author/byline, if a `[rel=author]`, `.byline`, or `.author` element
+    /// was found anywhere in the document.
+    pub byline: Option<String>,
+    /// First ~200 characters of `text`, trimmed to a word boundary.
+    pub excerpt: Option<String>,
+    pub word_count: usize,
+    /// `word_count / 200`, rounded up to the nearest minute - the reading
+    /// speed most readability implementations assume.
+    pub read_time_minutes: u32,
+}
+
+const READABILITY_SCRIPT: &str = r#"
+(() => {
+    const BOILERPLATE_CLASS_RE = /comment|sidebar|footer|nav|menu|ad-|advert|widget|popup|modal/i;
+    const BOILERPLATE_TAGS = new Set(['NAV', 'ASIDE', 'SCRIPT', 'STYLE', 'NOSCRIPT', 'IFRAME', 'FORM', 'HEADER', 'FOOTER']);
+    const CANDIDATE_TAGS = ['p', 'div', 'article', 'section', 'main'];
+
+    function isBoilerplate(el) {
+        if (BOILERPLATE_TAGS.has(el.tagName)) return true;
+        const cls = (el.className && typeof el.className === 'string') ? el.className : '';
+        const id = el.id || '';
+        return BOILERPLATE_CLASS_RE.test(cls) || BOILERPLATE_CLASS_RE.test(id);
+    }
+
+    function linkFreeTextLength(el) {
+        let total = 0;
+        // Walk text nodes directly, skipping anything inside an <a> or a
+        // boilerplate subtree, rather than double-counting nested elements.
+        const walker = document.createTreeWalker(el, NodeFilter.SHOW_TEXT, null);
+        let node;
+        while ((node = walker.nextNode())) {
+            let ancestor = node.parentElement;
+            let skip = false;
+            while (ancestor && ancestor !== el) {
+                if (ancestor.tagName === 'A' || isBoilerplate(ancestor)) {
+                    skip = true;
+                    break;
+                }
+                ancestor = ancestor.parentElement;
+            }
+            if (!skip) total += node.textContent.trim().length;
+        }
+        return total;
+    }
+
+    function tagCount(el) {
+        return Math.max(1, el.querySelectorAll('*').length);
+    }
+
+    let best = null;
+    let bestScore = 0;
+    for (const tag of CANDIDATE_TAGS) {
+        for (const el of document.querySelectorAll(tag)) {
+            if (isBoilerplate(el)) continue;
+            const textLen = linkFreeTextLength(el);
+            if (textLen < 140) continue;
+            const score = textLen / tagCount(el);
+            if (score > bestScore) {
+                bestScore = score;
+                best = el;
+            }
+        }
+    }
+
+    if (!best) return null;
+
+    // Promote to the highest-scoring ancestor within 3 levels, the same
+    // "don't pick too narrow a node" heuristic Readability uses, so a
+    // single <p> inside a larger article <div> doesn't win outright.
+    let root = best;
+    let rootScore = -1;
+    let current = best.parentElement;
+    let depth = 0;
+    while (current && depth < 3 && !isBoilerplate(current)) {
+        const parentScore = linkFreeTextLength(current) / tagCount(current);
+        if (parentScore >= bestScore * 0.85 && parentScore > rootScore) {
+            root = current;
+            rootScore = parentScore;
+        }
+        current = current.parentElement;
+        depth += 1;
+    }
+
+    const text = Array.from(root.querySelectorAll('p, h1, h2, h3, li'))
+        .filter((el) => !isBoilerplate(el))
+        .map((el) => el.textContent.trim())
+        .filter((t) => t.length > 0)
+        .join('\n\n');
+
+    if (!text || text.length < 140) return null;
+
+    const bylineEl = document.querySelector('[rel="author"], .byline, .author');
+    const byline = bylineEl ? bylineEl.textContent.trim() : null;
+
+    return { text, byline: byline || null };
+})()
+"#;
+
+/// Raw result of [`READABILITY_SCRIPT`] before word-count/excerpt are
+/// derived on the Rust side.
+#[derive(serde::Deserialize)]
+struct RawArticle {
+    text: String,
+    byline: Option<String>,
+}
+
+/// Run the readability extraction and, if a dominant content block was
+/// found, derive `excerpt`/`word_count`/`read_time_minutes` from its text.
+/// Returns `Ok(None)` (not an error) when no block scores highly enough -
+/// a listing page or a near-empty page isn't a failure, just not an article.
+pub async fn extract_article(page: Page) -> Result<Option<ArticleContent>> {
+    let raw: Option<RawArticle> = page
+        .evaluate(READABILITY_SCRIPT)
+        .await
+        .context("Failed to evaluate readability script")?
+        .into_value()
+        .context("Failed to deserialize readability result")?;
+
+    let Some(raw) = raw else {
+        return Ok(None);
+    };
+
+    let word_count = raw.text.split_whitespace().count();
+    let read_time_minutes = word_count.div_ceil(200).max(1) as u32;
+    let excerpt = excerpt_from(&raw.text);
+
+    Ok(Some(ArticleContent {
+        text: raw.text,
+        byline: raw.byline,
+        excerpt,
+        word_count,
+        read_time_minutes,
+    }))
+}
+
+/// First ~200 characters of `text`, trimmed back to the last word boundary
+/// so the excerpt doesn't end mid-word.
+fn excerpt_from(text: &str) -> Option<String> {
+    if text.is_empty() {
+        return None;
+    }
+    if text.len() <= 200 {
+        return Some(text.to_string());
+    }
+    let cut = text
+        .char_indices()
+        .take_while(|(i, _)| *i <= 200)
+        .last()
+        .map(|(i, c)| i + c.len_utf8())
+        .unwrap_or(text.len());
+    let slice = &text[..cut];
+    let trimmed = slice.rsplit_once(char::is_whitespace).map_or(slice, |(head, _)| head);
+    Some(format!("{}…", trimmed.trim_end()))
+}
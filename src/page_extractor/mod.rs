@@ -6,8 +6,10 @@
 pub mod schema;
 pub mod js_scripts;
 pub mod extractors;
+pub mod article;
 pub mod page_info;
 
 // Re-export commonly used types
 pub use schema::PageMetadata;
+pub use article::ArticleContent;
 pub use page_info::{PageInfo, extract_page_info};
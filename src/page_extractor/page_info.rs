@@ -6,6 +6,7 @@
 use anyhow::{Context, Result};
 use chromiumoxide::Page;
 
+use super::article::{ArticleContent, extract_article};
 use super::extractors::extract_metadata;
 use super::schema::PageMetadata;
 
@@ -16,9 +17,13 @@ pub struct PageInfo {
     pub title: String,
     /// Page metadata from meta tags and OpenGraph
     pub metadata: PageMetadata,
+    /// Readability-style main content, or `None` when no dominant content
+    /// block was found (e.g. a listing page). See
+    /// [`super::article::extract_article`].
+    pub article: Option<ArticleContent>,
 }
 
-/// Extract page title and metadata in parallel
+/// Extract page title, metadata, and main-content article text in parallel
 ///
 /// Uses the same parallel extraction pattern as citescrape's extract_page_data
 /// but without link rewriting or content saving dependencies.
@@ -37,8 +42,8 @@ pub struct PageInfo {
 /// println!("Description: {:?}", page_info.metadata.description);
 /// ```
 pub async fn extract_page_info(page: Page) -> Result<PageInfo> {
-    // Launch title and metadata extraction in parallel (2x speedup)
-    let (title, metadata) = tokio::try_join!(
+    // Launch title, metadata, and article extraction in parallel (~2x speedup)
+    let (title, metadata, article) = tokio::try_join!(
         // Title extraction (inline, no separate script needed)
         async {
             let title_value = page
@@ -56,7 +61,14 @@ pub async fn extract_page_info(page: Page) -> Result<PageInfo> {
         },
         // Metadata extraction (uses citescrape's proven extractor)
         extract_metadata(page.clone()),
+        // Readability-style main content (graceful None, not an error, when
+        // no block scores highly enough)
+        extract_article(page.clone()),
     )?;
 
-    Ok(PageInfo { title, metadata })
+    Ok(PageInfo {
+        title,
+        metadata,
+        article,
+    })
 }
@@ -0,0 +1,190 @@
+//! Reproducible research benchmark harness for maintainers: replays a fixed
+//! set of queries through [`ResearchSession`] and reports runtime/page-count
+//! per query, so a change to `DeepResearch`/the agent loop can be compared
+//! against a prior run instead of eyeballed from one-off sessions. Driven by
+//! `src/bin/research_bench.rs`, not exposed as an MCP tool.
+//!
+//! The request that added this named `BrowserGetResearchStatusTool` and
+//! `ResearchSessionManager` as the status source to reuse. Both are the
+//! polling-era research tools (`src/tools/browser_get_research_status.rs`,
+//! `src/research/session_manager.rs`) superseded by the single
+//! [`crate::tools::BrowserResearchTool`] - neither file is declared as a
+//! `mod` anywhere anymore, so this harness instead reads the same fields
+//! that tool's READ action does: [`ResearchSession::read`],
+//! [`ResearchSession::is_complete`], [`ResearchSession::last_error`].
+
+use crate::utils::{DeepResearch, ResearchOptions};
+use crate::research::ResearchSession;
+use crate::BrowserManager;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// JSON workload file format: a named set of queries to replay back-to-back.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Workload {
+    pub name: String,
+    pub queries: Vec<String>,
+
+    /// Page budget per query, forwarded to [`ResearchOptions::max_pages`].
+    /// Named `max_steps` (rather than `max_pages`) to match the workload
+    /// schema this harness was requested with; a research session tracks
+    /// progress in pages visited, not a separate step counter, so the two
+    /// are the same knob here.
+    pub max_steps: usize,
+
+    /// Expected page count per query. Parsed from the workload file but not
+    /// read by the harness: neither [`run_workload`] nor
+    /// [`BenchReport::summary_table`] compares results against it.
+    pub expected_pages: usize,
+}
+
+/// Outcome of replaying one [`Workload`] query.
+#[derive(Debug, Clone, Serialize)]
+pub struct QueryResult {
+    pub query: String,
+    pub runtime_seconds: u64,
+    pub pages_visited: usize,
+
+    /// Always equal to `pages_visited` - see the note on [`Workload::max_steps`].
+    pub total_steps: usize,
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+/// Full report for one [`Workload`] run: `serde_json::to_vec_pretty` this
+/// for the JSON report, or call [`Self::summary_table`] for the terminal one.
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchReport {
+    pub workload_name: String,
+    pub results: Vec<QueryResult>,
+    pub total_runtime_seconds: u64,
+    pub passed: usize,
+    pub failed: usize,
+}
+
+impl BenchReport {
+    /// Render a terminal-friendly summary table, one row per query.
+    pub fn summary_table(&self) -> String {
+        let mut out = format!(
+            "Workload: {} ({} passed, {} failed, {}s total)\n",
+            self.workload_name, self.passed, self.failed, self.total_runtime_seconds
+        );
+        out.push_str(&format!(
+            "{:<40} {:>9} {:>7} {:>7}\n",
+            "QUERY", "RUNTIME", "PAGES", "STATUS"
+        ));
+        for result in &self.results {
+            out.push_str(&format!(
+                "{:<40} {:>8}s {:>7} {:>7}\n",
+                truncate(&result.query, 40),
+                result.runtime_seconds,
+                result.pages_visited,
+                if result.success { "OK" } else { "FAIL" },
+            ));
+        }
+        out
+    }
+}
+
+fn truncate(s: &str, max: usize) -> String {
+    if s.chars().count() <= max {
+        s.to_string()
+    } else {
+        let mut truncated: String = s.chars().take(max.saturating_sub(1)).collect();
+        truncated.push('\u{2026}');
+        truncated
+    }
+}
+
+/// Load and parse a workload file in the schema documented on [`Workload`].
+pub async fn load_workload(path: impl AsRef<Path>) -> Result<Workload> {
+    let path = path.as_ref();
+    let bytes = tokio::fs::read(path)
+        .await
+        .with_context(|| format!("reading workload file {}", path.display()))?;
+    serde_json::from_slice(&bytes)
+        .with_context(|| format!("parsing workload file {}", path.display()))
+}
+
+/// How often to poll [`ResearchSession::is_complete`] while a benchmark
+/// query runs.
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Replay every query in `workload` back-to-back - not concurrently, since a
+/// benchmark run should measure one query's cost at a time rather than have
+/// queries contend with each other over `BrowserManager`'s shared page pool
+/// - returning a report of runtime/pages-visited/success per query.
+pub async fn run_workload(workload: &Workload, browser_manager: Arc<BrowserManager>) -> BenchReport {
+    let mut results = Vec::with_capacity(workload.queries.len());
+    let mut total_runtime = Duration::ZERO;
+
+    for query in &workload.queries {
+        let research = DeepResearch::new(browser_manager.clone(), 0.0, 2048);
+        let options = ResearchOptions {
+            max_pages: workload.max_steps,
+            ..Default::default()
+        };
+        let session = ResearchSession::new(research, query.clone(), Some(options));
+
+        let started = Instant::now();
+        let result = match session.start().await {
+            Ok(()) => {
+                while !session.is_complete().await {
+                    tokio::time::sleep(POLL_INTERVAL).await;
+                }
+                let pages_visited = session.results_count().await;
+                let error = session.last_error().await;
+                QueryResult {
+                    query: query.clone(),
+                    runtime_seconds: started.elapsed().as_secs(),
+                    pages_visited,
+                    total_steps: pages_visited,
+                    success: error.is_none(),
+                    error,
+                }
+            }
+            Err(e) => QueryResult {
+                query: query.clone(),
+                runtime_seconds: started.elapsed().as_secs(),
+                pages_visited: 0,
+                total_steps: 0,
+                success: false,
+                error: Some(e.to_string()),
+            },
+        };
+
+        total_runtime += started.elapsed();
+        results.push(result);
+    }
+
+    let passed = results.iter().filter(|r| r.success).count();
+    let failed = results.len() - passed;
+
+    BenchReport {
+        workload_name: workload.name.clone(),
+        results,
+        total_runtime_seconds: total_runtime.as_secs(),
+        passed,
+        failed,
+    }
+}
+
+/// POST a [`BenchReport`] as JSON to `endpoint`, for tracking regressions
+/// across runs in an external dashboard. Errors propagate rather than get
+/// swallowed, so a CI job driving `research_bench` fails loudly if the
+/// tracking endpoint is unreachable.
+pub async fn report_to_endpoint(report: &BenchReport, endpoint: &str) -> Result<()> {
+    let client = reqwest::Client::new();
+    client
+        .post(endpoint)
+        .json(report)
+        .send()
+        .await
+        .with_context(|| format!("POSTing bench report to {endpoint}"))?
+        .error_for_status()
+        .with_context(|| format!("bench report endpoint {endpoint} returned an error status"))?;
+    Ok(())
+}
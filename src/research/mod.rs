@@ -1,7 +1,21 @@
 //! Research session management with registry pattern
 
+pub mod bench;
+pub mod queue;
 pub mod registry;
 pub mod session;
+pub mod store;
+pub mod worker;
 
+pub use bench::{
+    BenchReport, QueryResult as BenchQueryResult, Workload, load_workload, report_to_endpoint,
+    run_workload,
+};
+pub use queue::{QueueFullError, QueuePermit, ResearchQueue};
 pub use registry::ResearchRegistry;
-pub use session::ResearchSession;
+pub use session::{ResearchEvent, ResearchOutput, ResearchSession};
+pub use store::{
+    FileResearchStore, InMemoryResearchStore, KvBackend, KvResearchStore, PersistedSession,
+    ResearchStore,
+};
+pub use worker::{WorkerControl, WorkerState};
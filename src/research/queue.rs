@@ -0,0 +1,177 @@
+//! Global admission control for concurrently-running research crawls.
+//!
+//! `BrowserResearchTool::execute`'s `RESEARCH` action used to spawn a
+//! background crawl for every call with no cap, so a client could launch
+//! unbounded parallel crawls and exhaust browser tabs and CPU. `ResearchQueue`
+//! bounds how many crawls actually run at once; requests beyond that park in
+//! a bounded waiting queue until a running slot frees up.
+//!
+//! When the waiting queue is already full, a newly-arriving request evicts a
+//! *randomly chosen* waiting entry rather than the oldest one: oldest-first
+//! eviction gives every waiter the same worst-case latency, but newest-first
+//! (or "don't evict, just reject") lets an attacker trivially starve the
+//! queue by flooding it with requests that each out-wait the next.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use rand::Rng;
+use tokio::sync::{Mutex, Notify, oneshot};
+
+/// Returned when a request cannot be admitted: either the waiting queue's
+/// capacity is configured to 0, or the request was parked and then evicted
+/// to make room for a newer one.
+#[derive(Debug, Clone, Copy)]
+pub struct QueueFullError {
+    /// Hint for how long the caller should back off before retrying.
+    pub retry_after_ms: u64,
+}
+
+struct Waiting {
+    notify: oneshot::Sender<()>,
+}
+
+struct Inner {
+    max_running: usize,
+    max_waiting: usize,
+    running: AtomicUsize,
+    waiting: Mutex<VecDeque<Waiting>>,
+    consumer_wake: Notify,
+}
+
+/// Held for the duration of one running research crawl; dropping it frees
+/// the running slot and wakes the consumer loop to admit the next waiter.
+pub struct QueuePermit {
+    inner: Arc<Inner>,
+}
+
+impl Drop for QueuePermit {
+    fn drop(&mut self) {
+        self.inner.running.fetch_sub(1, Ordering::SeqCst);
+        self.inner.consumer_wake.notify_one();
+    }
+}
+
+/// Bounded admission queue limiting how many research crawls run
+/// concurrently, with a single consumer loop draining waiters as running
+/// slots free up.
+#[derive(Clone)]
+pub struct ResearchQueue {
+    inner: Arc<Inner>,
+}
+
+impl ResearchQueue {
+    /// `max_running` caps simultaneously-running crawls; `max_waiting`
+    /// bounds how many requests can park before eviction kicks in.
+    pub fn new(max_running: usize, max_waiting: usize) -> Self {
+        let inner = Arc::new(Inner {
+            max_running: max_running.max(1),
+            max_waiting,
+            running: AtomicUsize::new(0),
+            waiting: Mutex::new(VecDeque::new()),
+            consumer_wake: Notify::new(),
+        });
+        Self::spawn_consumer(inner.clone());
+        Self { inner }
+    }
+
+    /// Default `max_running` to the host's available parallelism, the
+    /// natural cap for CPU/tab-bound crawl work.
+    pub fn with_default_parallelism(max_waiting: usize) -> Self {
+        let max_running = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(4);
+        Self::new(max_running, max_waiting)
+    }
+
+    /// Number of crawls currently running.
+    pub fn running_count(&self) -> usize {
+        self.inner.running.load(Ordering::SeqCst)
+    }
+
+    /// Number of requests currently parked, waiting for a running slot.
+    pub async fn waiting_depth(&self) -> usize {
+        self.inner.waiting.lock().await.len()
+    }
+
+    /// Admit one research task. Resolves immediately if a running slot is
+    /// free; otherwise parks the caller in the waiting queue (evicting a
+    /// random existing waiter if it's already full) until the consumer loop
+    /// grants it a slot.
+    pub async fn admit(&self) -> Result<QueuePermit, QueueFullError> {
+        if self.try_take_running_slot() {
+            return Ok(QueuePermit {
+                inner: self.inner.clone(),
+            });
+        }
+
+        let (tx, rx) = oneshot::channel();
+        {
+            let mut waiting = self.inner.waiting.lock().await;
+            if waiting.len() >= self.inner.max_waiting {
+                if self.inner.max_waiting == 0 {
+                    return Err(QueueFullError {
+                        retry_after_ms: 2_000,
+                    });
+                }
+                let evict_idx = rand::thread_rng().gen_range(0..waiting.len());
+                // Dropping the evicted entry's sender completes its
+                // receiver with an error, so that caller sees "refused"
+                // rather than hanging forever.
+                waiting.remove(evict_idx);
+            }
+            waiting.push_back(Waiting { notify: tx });
+        }
+        self.inner.consumer_wake.notify_one();
+
+        rx.await.map_err(|_| QueueFullError {
+            retry_after_ms: 2_000,
+        })?;
+        Ok(QueuePermit {
+            inner: self.inner.clone(),
+        })
+    }
+
+    fn try_take_running_slot(&self) -> bool {
+        self.inner
+            .running
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |running| {
+                (running < self.inner.max_running).then_some(running + 1)
+            })
+            .is_ok()
+    }
+
+    /// Single consumer loop: wakes whenever a slot frees or a new waiter
+    /// arrives, and drains the waiting queue as far as running capacity
+    /// allows.
+    fn spawn_consumer(inner: Arc<Inner>) {
+        tokio::spawn(async move {
+            loop {
+                inner.consumer_wake.notified().await;
+                loop {
+                    if inner.running.load(Ordering::SeqCst) >= inner.max_running {
+                        break;
+                    }
+                    let Some(waiter) = inner.waiting.lock().await.pop_front() else {
+                        break;
+                    };
+                    let acquired = inner
+                        .running
+                        .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |r| {
+                            (r < inner.max_running).then_some(r + 1)
+                        })
+                        .is_ok();
+                    if !acquired {
+                        inner.waiting.lock().await.push_front(waiter);
+                        break;
+                    }
+                    if waiter.notify.send(()).is_err() {
+                        // Caller gave up waiting; release the slot back.
+                        inner.running.fetch_sub(1, Ordering::SeqCst);
+                    }
+                }
+            }
+        });
+    }
+}
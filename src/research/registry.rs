@@ -1,20 +1,167 @@
 //! Research session registry with connection isolation
+//!
+//! Push-based progress (a `broadcast::Sender` per session, "replay
+//! accumulated state then follow live events") already exists on
+//! [`ResearchSession`] - see its `subscribe`/`ResearchEvent` - added when
+//! streaming replaced the old `progress: Vec<ResearchStep>` polling shape.
+//! [`ResearchRegistry::subscribe`] is the registry-level convenience over it
+//! for callers that only have a `(connection_id, session_id)` pair, the same
+//! entry point the dead, never-wired-in `ResearchSessionManager`
+//! (`session_manager.rs`) used to expose under this name.
+//!
+//! Nothing else reclaims a session whose client never came back for its
+//! result, so [`ResearchRegistry::with_store`] also spawns a background
+//! reaper (see the free function `spawn_reaper`) that periodically kills
+//! and removes any session that is both complete and has sat unread past
+//! [`DEFAULT_SESSION_TTL`], bounding memory/task growth for long-lived
+//! servers hosting many concurrent research queries.
 
-use super::session::ResearchSession;
-use crate::utils::{DeepResearch, ResearchOptions};
+use super::queue::{QueueFullError, QueuePermit, ResearchQueue};
+use super::session::{ResearchEvent, ResearchOutput, ResearchSession};
+use super::store::{InMemoryResearchStore, PersistedSession, ResearchStore};
+use super::worker::WorkerState;
+use crate::utils::{DeepResearch, ResearchOptions, ResearchResult, ToolMetrics};
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
-use tokio::sync::Mutex;
+use std::time::Duration;
+use tokio::sync::{Mutex, broadcast};
+
+/// Default cap on parked `RESEARCH` requests before admission starts
+/// evicting a random waiter to make room for new ones.
+const DEFAULT_MAX_WAITING: usize = 32;
+
+/// How often the background reaper (see [`spawn_reaper`])
+/// wakes up to sweep abandoned sessions.
+const REAP_INTERVAL: Duration = Duration::from_secs(30);
+
+/// A completed session nobody has `read` in this long is considered
+/// abandoned (client disconnected without a final `LIST`/`READ`) and gets
+/// reaped, so a long-lived MCP server hosting many connections doesn't leak
+/// a `JoinHandle` and `Arc<DeepResearch>` per forgotten session forever.
+const DEFAULT_SESSION_TTL: Duration = Duration::from_secs(10 * 60);
 
 /// Registry key: (connection_id, session_number)
 type RegistryKey = (String, u32);
 
 /// Registry for managing multiple research sessions
+///
+/// `sessions` holds the live, controllable handles (background task,
+/// cancellation) for this process. `store` persists a serializable snapshot
+/// of each session alongside it, so `list`/`cleanup_connection` can still
+/// surface sessions started by a prior process instance (as read-only,
+/// already-completed entries) - see [`super::store::ResearchStore`].
 #[derive(Clone)]
 pub struct ResearchRegistry {
     sessions: Arc<Mutex<HashMap<RegistryKey, Arc<ResearchSession>>>>,
+    store: Arc<dyn ResearchStore>,
+
+    /// Global admission queue bounding how many crawls run simultaneously
+    /// across every connection - see [`super::queue::ResearchQueue`].
+    queue: ResearchQueue,
+}
+
+/// Sweep every `REAP_INTERVAL`, killing and removing any session that is
+/// both complete and idle longer than `ttl` - see
+/// [`ResearchSession::idle_for`]. Spawned once per registry by
+/// [`ResearchRegistry::with_store`].
+fn spawn_reaper(
+    sessions: Arc<Mutex<HashMap<RegistryKey, Arc<ResearchSession>>>>,
+    store: Arc<dyn ResearchStore>,
+    ttl: Duration,
+) {
+    tokio::spawn(async move {
+        loop {
+            tokio::time::sleep(REAP_INTERVAL).await;
+
+            let mut to_remove = Vec::new();
+            for (key, session) in sessions.lock().await.iter() {
+                if session.is_complete().await && session.idle_for().await >= ttl {
+                    to_remove.push(key.clone());
+                }
+            }
+
+            if to_remove.is_empty() {
+                continue;
+            }
+
+            let mut sessions_guard = sessions.lock().await;
+            for key in &to_remove {
+                if let Some(session) = sessions_guard.remove(key) {
+                    let _ = session.kill().await;
+                }
+            }
+            let remaining = sessions_guard.len();
+            drop(sessions_guard);
+
+            ToolMetrics::global().set_live_research_sessions(remaining as u64);
+
+            for (connection_id, session_id) in &to_remove {
+                if let Err(e) = store.remove(connection_id, *session_id).await {
+                    log::warn!(
+                        "Failed to remove persisted research session {connection_id}:{session_id}: {e}"
+                    );
+                }
+            }
+            log::debug!("Reaped {} abandoned research session(s)", to_remove.len());
+        }
+    });
+}
+
+/// Mirror `session`'s progress to `store` after every
+/// [`ResearchEvent`] it emits (a new result, completion, or error), so the
+/// on-disk snapshot stays current as a crawl runs rather than only being
+/// written once at creation - see [`ResearchRegistry::create`]. Exits once
+/// the session completes or errors (its own final snapshot already written
+/// by that last iteration) or its event channel closes.
+///
+/// Uses [`ResearchSession::snapshot`] rather than [`ResearchSession::read`]
+/// so this doesn't itself keep the session looking "recently accessed" to
+/// the reaper (see [`spawn_reaper`]).
+fn spawn_persister(
+    session: Arc<ResearchSession>,
+    store: Arc<dyn ResearchStore>,
+    connection_id: String,
+    session_id: u32,
+) {
+    tokio::spawn(async move {
+        let (_backlog, mut receiver) = session.subscribe().await;
+        loop {
+            let event = match receiver.recv().await {
+                Ok(event) => Some(event),
+                Err(broadcast::error::RecvError::Lagged(_)) => None,
+                Err(broadcast::error::RecvError::Closed) => break,
+            };
+
+            let output = session.snapshot(session_id).await;
+            let last_error = session.last_error().await;
+            let snapshot = PersistedSession {
+                connection_id: connection_id.clone(),
+                info: SessionInfo {
+                    session: session_id,
+                    query: output.query,
+                    completed: output.completed,
+                    results_count: output.results.len(),
+                    state: session.worker_state().await,
+                    last_error,
+                },
+                results: output.results,
+            };
+            if let Err(e) = store.put(&connection_id, session_id, snapshot).await {
+                log::warn!(
+                    "Failed to persist research session {connection_id}:{session_id}: {e}"
+                );
+            }
+
+            if matches!(
+                event,
+                Some(ResearchEvent::Completed { .. }) | Some(ResearchEvent::Errored(_))
+            ) {
+                break;
+            }
+        }
+    });
 }
 
 /// List output showing all active research sessions
@@ -22,12 +169,18 @@ pub struct ResearchRegistry {
 pub struct ResearchListOutput {
     /// Connection ID
     pub connection_id: String,
-    
+
     /// Active sessions
     pub sessions: Vec<SessionInfo>,
-    
+
     /// Total count
     pub total: usize,
+
+    /// Crawls currently running (bounded by the admission queue).
+    pub running: usize,
+
+    /// Requests parked in the admission queue, waiting for a running slot.
+    pub queue_depth: usize,
 }
 
 /// Information about a single session
@@ -35,25 +188,77 @@ pub struct ResearchListOutput {
 pub struct SessionInfo {
     /// Session number
     pub session: u32,
-    
+
     /// Query being researched
     pub query: String,
-    
+
     /// Whether complete
     pub completed: bool,
-    
+
     /// Current results count
     pub results_count: usize,
+
+    /// Worker status (active/idle/paused/dead), mirroring a background task
+    /// manager's state column. Sessions read from `store` rather than a live
+    /// handle - i.e. started by a prior process instance - always report
+    /// [`WorkerState::Dead`], since there's no running task to pause/resume.
+    #[serde(default = "default_worker_state")]
+    pub state: WorkerState,
+
+    /// Most recent crawl error, if any. For sessions read from `store`
+    /// rather than a live handle, the error recorded in their last
+    /// persisted snapshot.
+    #[serde(default)]
+    pub last_error: Option<String>,
+}
+
+fn default_worker_state() -> WorkerState {
+    WorkerState::Dead
 }
 
 impl ResearchRegistry {
-    /// Create a new registry
+    /// Create a new registry backed by the default in-memory store
     pub fn new() -> Self {
+        Self::with_store(Arc::new(InMemoryResearchStore::new()))
+    }
+
+    /// Create a registry persisting sessions through `store` instead of the
+    /// default in-memory one (e.g. a [`super::store::KvResearchStore`]).
+    ///
+    /// Spawns the background reaper (see [`spawn_reaper`]) that sweeps
+    /// completed-but-abandoned sessions every [`REAP_INTERVAL`] using the
+    /// [`DEFAULT_SESSION_TTL`].
+    #[must_use]
+    pub fn with_store(store: Arc<dyn ResearchStore>) -> Self {
+        let sessions = Arc::new(Mutex::new(HashMap::new()));
+        spawn_reaper(sessions.clone(), store.clone(), DEFAULT_SESSION_TTL);
+
         Self {
-            sessions: Arc::new(Mutex::new(HashMap::new())),
+            sessions,
+            store,
+            queue: ResearchQueue::with_default_parallelism(DEFAULT_MAX_WAITING),
         }
     }
-    
+
+    /// Request a slot to actually run a crawl, parking in the bounded
+    /// admission queue if every running slot is taken. Callers should hold
+    /// the returned permit for the lifetime of the background crawl task
+    /// and drop it once the session completes, freeing the slot for the
+    /// next waiter.
+    pub async fn admit(&self) -> Result<QueuePermit, QueueFullError> {
+        self.queue.admit().await
+    }
+
+    /// Current admission queue depth, for `LIST` output.
+    pub async fn queue_depth(&self) -> usize {
+        self.queue.waiting_depth().await
+    }
+
+    /// Current number of actually-running crawls, for `LIST` output.
+    pub fn running_count(&self) -> usize {
+        self.queue.running_count()
+    }
+
     /// Create a new research session, replacing any existing one with same ID
     ///
     /// RESEARCH action always starts fresh research. If a session already exists
@@ -75,78 +280,204 @@ impl ResearchRegistry {
         }
 
         // Create new session
-        let session = Arc::new(ResearchSession::new(research, query, options));
+        let session = Arc::new(ResearchSession::new(research, query.clone(), options));
         sessions.insert(key, session.clone());
 
+        ToolMetrics::global().set_live_research_sessions(sessions.len() as u64);
+
+        let snapshot = PersistedSession {
+            connection_id: connection_id.to_string(),
+            info: SessionInfo {
+                session: session_id,
+                query,
+                completed: false,
+                results_count: 0,
+                state: WorkerState::Idle,
+                last_error: None,
+            },
+            results: Vec::new(),
+        };
+        if let Err(e) = self.store.put(connection_id, session_id, snapshot).await {
+            log::warn!("Failed to persist research session {connection_id}:{session_id}: {e}");
+        }
+
+        spawn_persister(session.clone(), self.store.clone(), connection_id.to_string(), session_id);
+
         session
     }
-    
+
     /// Get an existing session
-    pub async fn get(
-        &self,
-        connection_id: &str,
-        session_id: u32,
-    ) -> Option<Arc<ResearchSession>> {
+    pub async fn get(&self, connection_id: &str, session_id: u32) -> Option<Arc<ResearchSession>> {
         let key = (connection_id.to_string(), session_id);
         let sessions = self.sessions.lock().await;
         sessions.get(&key).cloned()
     }
-    
+
+    /// [`Self::get`] then [`ResearchSession::read`], falling back to
+    /// `store` if the session isn't live in this process - e.g. it
+    /// completed in a prior run that has since restarted (see
+    /// [`Self::restore`]). A session removed by [`spawn_reaper`] is not
+    /// found this way, since the reaper removes its snapshot too. Returns
+    /// `None` only if neither a live session nor a persisted snapshot
+    /// exists for this key.
+    pub async fn read_any(&self, connection_id: &str, session_id: u32) -> Option<ResearchOutput> {
+        if let Some(session) = self.get(connection_id, session_id).await {
+            return Some(session.read(session_id).await);
+        }
+
+        let persisted = self.store.get(connection_id, session_id).await.ok()??;
+        Some(ResearchOutput {
+            session: session_id,
+            query: persisted.info.query,
+            results: persisted.results,
+            completed: persisted.info.completed,
+            summary: format!(
+                "Research completed. {} results found.",
+                persisted.info.results_count
+            ),
+        })
+    }
+
+    /// Report how many completed sessions `store` already has on disk for
+    /// `connection_id`, so a caller can log what a restart recovered. Since
+    /// [`Self::read_any`] and [`Self::list`] both already read straight
+    /// through `store` for sessions that aren't live in this process, there
+    /// is no separate in-memory rehydration step needed for a *completed*
+    /// session to stay readable across a restart - only a still-running one
+    /// would need re-dispatching against a fresh `DeepResearch`, which is
+    /// deliberately left to the caller (re-dispatching means re-running the
+    /// crawl from scratch; this registry has no way to resume page-by-page).
+    pub async fn restore(&self, connection_id: &str) -> Result<usize> {
+        let recovered = self.store.list(connection_id).await?;
+        for session in &recovered {
+            log::info!(
+                "Recovered persisted research session {}:{} ({} results, completed={})",
+                connection_id,
+                session.info.session,
+                session.results.len(),
+                session.info.completed
+            );
+        }
+        Ok(recovered.len())
+    }
+
+    /// Registry-level convenience over [`ResearchSession::subscribe`]: a
+    /// snapshot of results accumulated so far plus a live receiver of
+    /// further [`ResearchEvent`]s, so a caller that only has
+    /// `(connection_id, session_id)` doesn't need to `get` first. Returns
+    /// `None` if no such
+    /// session exists.
+    pub async fn subscribe(
+        &self,
+        connection_id: &str,
+        session_id: u32,
+    ) -> Option<(Vec<ResearchResult>, broadcast::Receiver<ResearchEvent>)> {
+        let session = self.get(connection_id, session_id).await?;
+        Some(session.subscribe().await)
+    }
+
     /// Remove a session (after KILL)
-    pub async fn remove(&self, connection_id: &str, session_id: u32) -> Option<Arc<ResearchSession>> {
+    pub async fn remove(
+        &self,
+        connection_id: &str,
+        session_id: u32,
+    ) -> Option<Arc<ResearchSession>> {
         let key = (connection_id.to_string(), session_id);
         let mut sessions = self.sessions.lock().await;
-        sessions.remove(&key)
+        let removed = sessions.remove(&key);
+        ToolMetrics::global().set_live_research_sessions(sessions.len() as u64);
+
+        if let Err(e) = self.store.remove(connection_id, session_id).await {
+            log::warn!(
+                "Failed to remove persisted research session {connection_id}:{session_id}: {e}"
+            );
+        }
+
+        removed
     }
-    
+
     /// List all sessions for a connection
+    ///
+    /// Live sessions (this process) are read directly for up-to-the-moment
+    /// state; any session persisted by `store` that isn't currently live -
+    /// e.g. one started by a process that has since restarted - is also
+    /// included, read-only, from its last persisted snapshot.
     pub async fn list(&self, connection_id: &str) -> Result<ResearchListOutput> {
         let sessions_map = self.sessions.lock().await;
         let mut session_infos = Vec::new();
-        
+        let mut live_session_ids = std::collections::HashSet::new();
+
         for ((conn_id, session_num), session) in sessions_map.iter() {
             if conn_id == connection_id {
                 let completed = session.is_complete().await;
                 let results_count = session.results_count().await;
                 let output = session.read(*session_num).await;
-                
+                let state = session.worker_state().await;
+                let last_error = session.last_error().await;
+
+                live_session_ids.insert(*session_num);
                 session_infos.push(SessionInfo {
                     session: *session_num,
                     query: output.query,
                     completed,
                     results_count,
+                    state,
+                    last_error,
                 });
             }
         }
-        
+        drop(sessions_map);
+
+        for persisted in self.store.list(connection_id).await? {
+            if !live_session_ids.contains(&persisted.info.session) {
+                // No live task backs a store-only session, whatever state
+                // its last snapshot recorded.
+                session_infos.push(SessionInfo {
+                    state: WorkerState::Dead,
+                    ..persisted.info
+                });
+            }
+        }
+
         // Sort by session number
         session_infos.sort_by_key(|s| s.session);
-        
+
         let total = session_infos.len();
-        
+
         Ok(ResearchListOutput {
             connection_id: connection_id.to_string(),
             sessions: session_infos,
             total,
+            running: self.running_count(),
+            queue_depth: self.queue_depth().await,
         })
     }
-    
+
     /// Clean up completed sessions (optional maintenance)
     pub async fn cleanup_completed(&self, connection_id: &str) -> usize {
         let mut sessions = self.sessions.lock().await;
         let mut to_remove = Vec::new();
-        
+
         for ((conn_id, session_num), session) in sessions.iter() {
             if conn_id == connection_id && session.is_complete().await {
                 to_remove.push((conn_id.clone(), *session_num));
             }
         }
-        
+
         let count = to_remove.len();
         for key in to_remove {
             sessions.remove(&key);
+            if let Err(e) = self.store.remove(&key.0, key.1).await {
+                log::warn!(
+                    "Failed to remove persisted research session {}:{}: {e}",
+                    key.0,
+                    key.1
+                );
+            }
         }
-        
+
+        ToolMetrics::global().set_live_research_sessions(sessions.len() as u64);
+
         count
     }
 
@@ -178,6 +509,14 @@ impl ResearchRegistry {
             }
         }
 
+        ToolMetrics::global().set_live_research_sessions(sessions.len() as u64);
+
+        if let Err(e) = self.store.cleanup_connection(connection_id).await {
+            log::warn!(
+                "Failed to clean up persisted research sessions for connection {connection_id}: {e}"
+            );
+        }
+
         count
     }
 }
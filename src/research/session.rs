@@ -1,36 +1,99 @@
 //! Research session management
 
-use crate::utils::{DeepResearch, ResearchOptions, ResearchResult};
+use super::worker::{WorkerControl, WorkerState};
+use crate::utils::{DeepResearch, ResearchControl, ResearchOptions, ResearchResult};
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
-use std::sync::atomic::AtomicUsize;
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use std::sync::atomic::AtomicUsize;
+use std::time::{Duration, Instant};
+use tokio::sync::{Mutex, RwLock, broadcast, mpsc};
 use tokio::task::JoinHandle;
 
+/// How often the event watcher task falls back to polling `completed` when
+/// it isn't already woken by a pushed result. `DeepResearch::run_crawl`
+/// pushes each result onto `result_receiver` the instant it's appended (see
+/// `DeepResearch::with_result_sender`), so this interval only bounds how
+/// long completion/error detection can lag after the last result - not
+/// result latency itself.
+const EVENT_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Capacity of each session's event broadcast channel. Subscribers that
+/// fall this far behind the crawl miss the oldest events (`RecvError::Lagged`)
+/// but can always re-read the full accumulated state via [`ResearchSession::read`].
+const EVENT_CHANNEL_CAPACITY: usize = 256;
+
+/// Incremental update pushed to subscribers of a running research session.
+/// See [`ResearchSession::subscribe`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ResearchEvent {
+    /// One more page finished processing and was appended to the results.
+    ResultAppended(ResearchResult),
+    /// The crawl finished normally.
+    Completed { total_results: usize },
+    /// The crawl finished with an error. A crawl stopped via
+    /// [`ResearchSession::kill`] records no error and is reported as
+    /// `Completed`.
+    Errored(String),
+}
+
 /// Session state for an active research task
 #[derive(Clone)]
 pub struct ResearchSession {
     /// Underlying research engine
     research: Arc<DeepResearch>,
-    
+
     /// Shared results (updated in background)
     results: Arc<RwLock<Vec<ResearchResult>>>,
-    
+
     /// Total results counter
     total_results: Arc<AtomicUsize>,
-    
+
     /// Background task handle
     task_handle: Arc<RwLock<Option<JoinHandle<Result<()>>>>>,
-    
+
     /// Query being researched
     query: String,
-    
+
     /// Research options
     options: Option<ResearchOptions>,
-    
+
     /// Session completion flag
     completed: Arc<RwLock<bool>>,
+
+    /// Error message from the crawl, if it finished with one.
+    last_error: Arc<RwLock<Option<String>>>,
+
+    /// Broadcasts a [`ResearchEvent`] per appended result and on completion,
+    /// so callers can push progress (e.g. over SSE/WebSocket) instead of
+    /// polling `read`/`list`. See [`ResearchSession::subscribe`].
+    events: broadcast::Sender<ResearchEvent>,
+
+    /// Receiving half of the channel handed to `DeepResearch::with_result_sender`.
+    /// Taken (replaced with `None`) by [`Self::spawn_event_watcher`] the first
+    /// time it runs - wrapped so [`Self::new`] can construct the channel
+    /// before the background task that owns the receiver exists.
+    result_receiver: Arc<Mutex<Option<mpsc::UnboundedReceiver<ResearchResult>>>>,
+
+    /// Pause/throttle handle shared with the crawl's background task; see
+    /// [`ResearchSession::pause`]/[`ResearchSession::resume`]/
+    /// [`ResearchSession::set_tranquility`].
+    worker_control: WorkerControl,
+
+    /// Abort+deadline handle shared with the crawl's background task, every
+    /// page navigation, and `wait_for_element`; see [`ResearchSession::kill`].
+    abort_control: ResearchControl,
+
+    /// When this session was created, so [`Self::start`] can report its
+    /// total runtime to `ToolMetrics::observe_research_runtime` once the
+    /// crawl finishes.
+    started_at: Instant,
+
+    /// Last time a caller observed this session via [`Self::read`], so
+    /// [`super::registry::ResearchRegistry`]'s periodic reaper can tell an
+    /// abandoned-but-complete session (client disconnected, never read the
+    /// result) from one a client is still actively polling.
+    last_accessed: Arc<RwLock<Instant>>,
 }
 
 /// Output from research session
@@ -38,38 +101,114 @@ pub struct ResearchSession {
 pub struct ResearchOutput {
     /// Session number
     pub session: u32,
-    
+
     /// Query being researched
     pub query: String,
-    
+
     /// Current results
     pub results: Vec<ResearchResult>,
-    
+
     /// Whether research is complete
     pub completed: bool,
-    
+
     /// Progress summary
     pub summary: String,
 }
 
+/// Caps the session-wide deadline derived from `timeout_seconds * max_pages`
+/// (see [`ResearchSession::new`]) so a large `max_pages` can't leave a
+/// session effectively uncappable.
+const MAX_SESSION_BUDGET: Duration = Duration::from_secs(2 * 60 * 60);
+
 impl ResearchSession {
     /// Create a new research session
     pub fn new(research: DeepResearch, query: String, options: Option<ResearchOptions>) -> Self {
         let results = Arc::new(RwLock::new(Vec::new()));
         let total_results = Arc::new(AtomicUsize::new(0));
         let completed = Arc::new(RwLock::new(false));
-        
+        let (events, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+        let (result_tx, result_rx) = mpsc::unbounded_channel();
+        let worker_control = WorkerControl::new();
+
+        // A generous overall budget - `timeout_seconds` per page times
+        // `max_pages` - independent of each individual navigation's own
+        // timeout, so `abort_control`/`kill` still has a deadline to fall
+        // back on even if a caller never calls either explicitly.
+        let (timeout_seconds, max_pages) = options
+            .as_ref()
+            .map(|o| (o.timeout_seconds, o.max_pages))
+            .unwrap_or((60, 5));
+        let budget = Duration::from_secs(timeout_seconds.saturating_mul(max_pages.max(1) as u64))
+            .min(MAX_SESSION_BUDGET);
+        let abort_control = ResearchControl::new(budget);
+
         Self {
-            research: Arc::new(research),
+            research: Arc::new(
+                research
+                    .with_worker_control(worker_control.clone())
+                    .with_research_control(abort_control.clone())
+                    .with_result_sender(result_tx),
+            ),
             results,
             total_results,
             task_handle: Arc::new(RwLock::new(None)),
             query,
             options,
             completed,
+            last_error: Arc::new(RwLock::new(None)),
+            events,
+            result_receiver: Arc::new(Mutex::new(Some(result_rx))),
+            worker_control,
+            abort_control,
+            started_at: Instant::now(),
+            last_accessed: Arc::new(RwLock::new(Instant::now())),
         }
     }
-    
+
+    /// Pause the crawl: in-flight page fetches finish, but no new frontier
+    /// URL is admitted until [`Self::resume`]. Partial `results` and the
+    /// session's slot are retained.
+    pub fn pause(&self) {
+        self.worker_control.pause();
+    }
+
+    pub fn resume(&self) {
+        self.worker_control.resume();
+    }
+
+    /// Set how much proportional delay (0-10) the crawl inserts after each
+    /// page fetch; see [`WorkerControl::throttle_delay`].
+    pub fn set_tranquility(&self, level: u8) {
+        self.worker_control.set_tranquility(level);
+    }
+
+    pub fn tranquility(&self) -> u8 {
+        self.worker_control.tranquility()
+    }
+
+    /// Current worker status for `LIST` output, mirroring a background task
+    /// manager's state column. `run_crawl`'s bounded-concurrency scheduler
+    /// doesn't expose true per-task liveness, so `Idle` is approximated as
+    /// "not done, not paused, no page analyzed yet" (the search/seed phase
+    /// before the first result lands) rather than a precise between-fetches
+    /// signal.
+    pub async fn worker_state(&self) -> WorkerState {
+        if *self.completed.read().await {
+            WorkerState::Dead
+        } else if self.worker_control.is_paused() {
+            WorkerState::Paused
+        } else if self.results_count().await == 0 {
+            WorkerState::Idle
+        } else {
+            WorkerState::Active
+        }
+    }
+
+    /// Most recent crawl error, if it finished with one.
+    pub async fn last_error(&self) -> Option<String> {
+        self.last_error.read().await.clone()
+    }
+
     /// Start research in background
     pub async fn start(&self) -> Result<()> {
         let research = self.research.clone();
@@ -78,39 +217,178 @@ impl ResearchSession {
         let results = self.results.clone();
         let total_results = self.total_results.clone();
         let completed = self.completed.clone();
-        
+        let last_error = self.last_error.clone();
+        let started_at = self.started_at;
+
         let handle = tokio::spawn(async move {
-            match research.research(&query, options, results.clone(), total_results.clone()).await {
+            let outcome = research
+                .research(&query, options, results.clone(), total_results.clone())
+                .await;
+
+            crate::utils::ToolMetrics::global()
+                .observe_research_runtime(started_at.elapsed())
+                .await;
+
+            match outcome {
                 Ok(()) => {
                     let mut comp = completed.write().await;
                     *comp = true;
                     Ok(())
                 }
                 Err(e) => {
+                    *last_error.write().await = Some(e.to_string());
                     let mut comp = completed.write().await;
                     *comp = true;
                     Err(anyhow::anyhow!("Research error: {}", e))
                 }
             }
         });
-        
+
         let mut task = self.task_handle.write().await;
         *task = Some(handle);
-        
+        drop(task);
+
+        self.spawn_event_watcher();
+
         Ok(())
     }
-    
+
+    /// Turn each result `DeepResearch::run_crawl` pushes onto
+    /// `result_receiver` into a [`ResearchEvent`] on `self.events` as it
+    /// arrives, and fall back to polling `completed` so termination is
+    /// still detected once the crawl stops pushing. No-op if nobody has
+    /// called [`ResearchSession::subscribe`] yet - `broadcast::Sender::send`
+    /// only fails (harmlessly, ignored here) when there are zero receivers.
+    fn spawn_event_watcher(&self) {
+        let results = self.results.clone();
+        let completed = self.completed.clone();
+        let last_error = self.last_error.clone();
+        let events = self.events.clone();
+        let result_receiver = self.result_receiver.clone();
+
+        tokio::spawn(async move {
+            // Taken once per session - `start()` can only meaningfully run
+            // once, since a second call would spawn a second crawl task
+            // against the same `results`/`completed` state.
+            let mut receiver = result_receiver.lock().await.take();
+
+            loop {
+                match receiver.as_mut() {
+                    Some(rx) => {
+                        tokio::select! {
+                            maybe_result = rx.recv() => match maybe_result {
+                                Some(result) => {
+                                    crate::utils::ToolMetrics::global().incr_research_pages_visited();
+                                    let _ = events.send(ResearchEvent::ResultAppended(result));
+                                }
+                                // Sender dropped along with the `DeepResearch`
+                                // instance - nothing left to push, so fall
+                                // back to plain polling for completion.
+                                None => receiver = None,
+                            },
+                            () = tokio::time::sleep(EVENT_POLL_INTERVAL) => {}
+                        }
+                    }
+                    None => tokio::time::sleep(EVENT_POLL_INTERVAL).await,
+                }
+
+                if *completed.read().await {
+                    let current_len = results.read().await.len();
+                    let event = match last_error.read().await.clone() {
+                        Some(message) => ResearchEvent::Errored(message),
+                        None => ResearchEvent::Completed {
+                            total_results: current_len,
+                        },
+                    };
+                    let _ = events.send(event);
+                    break;
+                }
+            }
+        });
+    }
+
+    /// Subscribe to this session's progress: replays the results
+    /// accumulated so far, then returns a receiver for live
+    /// [`ResearchEvent`]s (new results, completion, or error) from here on.
+    pub async fn subscribe(&self) -> (Vec<ResearchResult>, broadcast::Receiver<ResearchEvent>) {
+        let receiver = self.events.subscribe();
+        let backlog = self.results.read().await.clone();
+        (backlog, receiver)
+    }
+
+    /// Drain results appended since `cursor` (an index previously returned by
+    /// this method, `0` on the first call), optionally blocking up to
+    /// `block_for` if nothing new has landed yet. Returns the newly
+    /// available results, the cursor to pass on the next call, and whether
+    /// the crawl has completed - the "stream once / stream next" pattern for
+    /// a client that wants incremental results instead of polling
+    /// [`Self::read`]'s full snapshot or busy-waiting on [`Self::is_complete`].
+    ///
+    /// Built on [`Self::subscribe`] rather than a dedicated cursor channel,
+    /// so it shares the same backlog-then-live semantics: a cursor far
+    /// enough behind just re-reads the accumulated `results` directly,
+    /// without needing to replay every broadcast event in between.
+    pub async fn stream_since(
+        &self,
+        cursor: usize,
+        block_for: Option<Duration>,
+    ) -> (Vec<ResearchResult>, usize, bool) {
+        let snapshot = |cursor: usize| async move {
+            let results = self.results.read().await;
+            let completed = *self.completed.read().await;
+            let start = cursor.min(results.len());
+            (results[start..].to_vec(), results.len(), completed)
+        };
+
+        let (fresh, next_cursor, completed) = snapshot(cursor).await;
+        if !fresh.is_empty() || completed || block_for.is_none() {
+            return (fresh, next_cursor, completed);
+        }
+
+        // Nothing new yet: wait for the next event (or the timeout) before
+        // re-reading, rather than returning an empty batch immediately.
+        let (_backlog, mut receiver) = self.subscribe().await;
+        let wait_for_event = async {
+            loop {
+                match receiver.recv().await {
+                    Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => break,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        };
+        match block_for {
+            Some(timeout) => {
+                let _ = tokio::time::timeout(timeout, wait_for_event).await;
+            }
+            None => wait_for_event.await,
+        }
+
+        snapshot(cursor).await
+    }
+
     /// Read current progress
     pub async fn read(&self, session_id: u32) -> ResearchOutput {
+        *self.last_accessed.write().await = Instant::now();
+        self.snapshot(session_id).await
+    }
+
+    /// Same output as [`Self::read`], but without counting as a caller
+    /// "observing" the session - used by
+    /// [`super::registry::ResearchRegistry::create`]'s background persister,
+    /// which snapshots the session on every event purely to keep its on-disk
+    /// copy current, not because a client asked for it. Using [`Self::read`]
+    /// there would reset [`Self::idle_for`] on every single result and
+    /// defeat the reaper.
+    pub(crate) async fn snapshot(&self, session_id: u32) -> ResearchOutput {
         let results = self.results.read().await.clone();
         let completed = *self.completed.read().await;
-        
+
         let summary = if completed {
             format!("Research completed. {} results found.", results.len())
         } else {
             format!("Research in progress. {} results so far.", results.len())
         };
-        
+
         ResearchOutput {
             session: session_id,
             query: self.query.clone(),
@@ -119,27 +397,39 @@ impl ResearchSession {
             summary,
         }
     }
-    
+
     /// Kill the research task
     pub async fn kill(&self) -> Result<()> {
+        // Trip the shared abort flag first so an in-progress navigation or
+        // `wait_for_element` bails out immediately, rather than only the
+        // background task itself getting interrupted at its next `await`.
+        self.abort_control.abort();
+
         let mut task = self.task_handle.write().await;
         if let Some(handle) = task.take() {
             handle.abort();
         }
-        
+
         let mut comp = self.completed.write().await;
         *comp = true;
-        
+
         Ok(())
     }
-    
+
     /// Check if research is complete
     pub async fn is_complete(&self) -> bool {
         *self.completed.read().await
     }
-    
+
     /// Get current results count
     pub async fn results_count(&self) -> usize {
         (*self.results.read().await).len()
     }
+
+    /// How long it's been since a caller last called [`Self::read`]. Used by
+    /// [`super::registry::ResearchRegistry`]'s reaper to find sessions
+    /// nobody is polling anymore.
+    pub async fn idle_for(&self) -> Duration {
+        self.last_accessed.read().await.elapsed()
+    }
 }
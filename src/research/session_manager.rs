@@ -2,22 +2,39 @@
 //!
 //! This module provides session management for long-running browser research tasks,
 //! allowing them to run in the background while clients poll for progress and results.
+//!
+//! Note: never wired into `ResearchRegistry`/`BrowserResearchTool`, which is the
+//! live, actually-exposed research session path (see [`crate::research::session`]
+//! and its push-based `ResearchEvent` streaming). Kept buildable and internally
+//! consistent, not revived.
 
+use crate::utils::{TaskController, TaskOutcome};
 use anyhow::Result;
 use dashmap::DashMap;
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
 use std::sync::{Arc, OnceLock};
 use std::time::{Duration, Instant};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
 use tokio::task::JoinHandle;
 use tokio_util::sync::CancellationToken;
 
 /// Maximum session age before automatic cleanup (5 minutes)
 const SESSION_TIMEOUT: Duration = Duration::from_secs(300);
 
+/// Default bound on how long [`ResearchSession::cancel`] and
+/// [`ResearchSessionManager::shutdown`] wait for their task to stop gracefully
+/// before forcing it - previously a bare `Duration::from_secs(5)` duplicated
+/// at each call site.
+const DEFAULT_GRACEFUL_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(5);
+
 /// Research session status
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum ResearchStatus {
+    /// Parked in [`ResearchSessionManager`]'s FIFO queue, waiting for a
+    /// concurrency permit to start running.
+    Queued,
     /// Research is currently running
     Running,
     /// Research completed successfully
@@ -29,6 +46,11 @@ pub enum ResearchStatus {
 }
 
 /// Progress step during research
+///
+/// Note: the live crawl path's adaptive politeness throttle
+/// ([`crate::utils::AdaptiveThrottle`]) accumulates its sleep time on
+/// `DeepResearch::accumulated_throttle_time` directly rather than a step
+/// list like this one, since this module isn't on that path.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ResearchStep {
     /// Unix timestamp in milliseconds
@@ -59,10 +81,19 @@ pub struct ResearchSession {
     pub error: Option<String>,
     /// Background task handle
     pub task_handle: Option<JoinHandle<()>>,
+    /// How long `cancel` waits for `task_handle` to stop gracefully before
+    /// forcing it via [`TaskController::terminate_all_async`].
+    pub shutdown_timeout: Duration,
+    /// Concurrency permit held while `status` is [`ResearchStatus::Running`];
+    /// `None` while [`ResearchStatus::Queued`]. Dropping it (via
+    /// [`ResearchSessionManager::release_session`]) frees the slot for the
+    /// next queued session.
+    permit: Option<OwnedSemaphorePermit>,
 }
 
 impl ResearchSession {
-    /// Create new research session
+    /// Create new research session, starting immediately (i.e. a permit was
+    /// already acquired by the caller - see [`ResearchSessionManager::create_session`]).
     pub fn new(session_id: String, query: String) -> Self {
         Self {
             session_id,
@@ -74,6 +105,17 @@ impl ResearchSession {
             total_results: Arc::new(std::sync::atomic::AtomicUsize::new(0)),
             error: None,
             task_handle: None,
+            shutdown_timeout: DEFAULT_GRACEFUL_SHUTDOWN_TIMEOUT,
+            permit: None,
+        }
+    }
+
+    /// Same as [`Self::new`], but with a configurable graceful-shutdown
+    /// timeout instead of [`DEFAULT_GRACEFUL_SHUTDOWN_TIMEOUT`].
+    pub fn with_shutdown_timeout(session_id: String, query: String, shutdown_timeout: Duration) -> Self {
+        Self {
+            shutdown_timeout,
+            ..Self::new(session_id, query)
         }
     }
 
@@ -98,36 +140,38 @@ impl ResearchSession {
     pub fn fail(&mut self, error: String) {
         self.status = ResearchStatus::Failed;
         self.error = Some(error);
+        // Terminal, same as `cancel` - free the concurrency slot.
+        self.permit = None;
     }
 
     /// Cancel the session and wait for task to stop
     ///
-    /// Attempts graceful cancellation by aborting the task and waiting for it
-    /// to complete. If the task doesn't complete within 5 seconds, logs a warning
-    /// but continues anyway.
+    /// Attempts graceful cancellation via [`TaskController::terminate_all_async`],
+    /// bounded by `self.shutdown_timeout`. If the task doesn't finish in time it
+    /// is forcibly aborted; either way `cancel` returns once it's settled.
     pub async fn cancel(&mut self) -> Result<()> {
         self.status = ResearchStatus::Cancelled;
+        // Free the concurrency slot immediately; a manager holding this
+        // session can additionally call `release_session` to promote the
+        // next queued session; `create_session` never promotes queued
+        // sessions, it only takes a free permit for the session it creates.
+        self.permit = None;
 
         if let Some(handle) = self.task_handle.take() {
-            // Abort the task
-            handle.abort();
-
-            // Wait for it to complete (with timeout) - same pattern as shutdown()
-            match tokio::time::timeout(Duration::from_secs(5), handle).await {
-                Ok(Ok(())) => {
+            let mut controller = TaskController::new();
+            controller.track("research_task", handle);
+            let reports = controller.terminate_all_async(self.shutdown_timeout).await;
+            match reports.first().map(|r| r.outcome) {
+                Some(TaskOutcome::FinishedCleanly) => {
                     log::info!("Research task cancelled gracefully");
                 }
-                Ok(Err(e)) if e.is_cancelled() => {
-                    // Expected - task was aborted
-                    log::info!("Research task cancelled via abort");
-                }
-                Ok(Err(e)) => {
-                    log::warn!("Research task exited with error during cancel: {}", e);
-                }
-                Err(_) => {
-                    log::warn!("Research task did not complete within 5s of abort");
-                    // Continue anyway - task will be dropped
+                Some(TaskOutcome::ForceAborted) => {
+                    log::warn!(
+                        "Research task did not complete within {:?} of abort",
+                        self.shutdown_timeout
+                    );
                 }
+                None => {}
             }
         }
 
@@ -148,8 +192,16 @@ impl ResearchSession {
 /// Global research session manager
 pub struct ResearchSessionManager {
     sessions: DashMap<String, Arc<tokio::sync::Mutex<ResearchSession>>>,
-    cleanup_token: CancellationToken,
-    cleanup_task: Arc<tokio::sync::Mutex<Option<JoinHandle<()>>>>,
+    cleanup_controller: tokio::sync::Mutex<TaskController>,
+    /// How long `shutdown` waits for the cleanup task to stop gracefully
+    /// before forcing it.
+    shutdown_timeout: Duration,
+    /// Caps how many sessions may be [`ResearchStatus::Running`] at once.
+    /// Sessions created while every permit is taken become
+    /// [`ResearchStatus::Queued`] and wait in `queue`.
+    concurrency: Arc<Semaphore>,
+    /// FIFO of queued session IDs, in arrival order.
+    queue: tokio::sync::Mutex<VecDeque<String>>,
 }
 
 impl ResearchSessionManager {
@@ -157,26 +209,101 @@ impl ResearchSessionManager {
     pub fn global() -> &'static Self {
         static INSTANCE: OnceLock<ResearchSessionManager> = OnceLock::new();
         INSTANCE.get_or_init(|| {
-            let token = CancellationToken::new();
-            let cleanup_handle = Self::spawn_cleanup_task(token.clone());
-            Self {
-                sessions: DashMap::new(),
-                cleanup_token: token,
-                cleanup_task: Arc::new(tokio::sync::Mutex::new(Some(cleanup_handle))),
-            }
+            let max_concurrency = std::thread::available_parallelism()
+                .map(|n| n.get())
+                .unwrap_or(4);
+            Self::with_concurrency_limit(max_concurrency)
         })
     }
 
-    /// Create new research session
+    /// Build a manager capping simultaneously-running sessions at
+    /// `max_concurrency`, instead of [`Self::global`]'s host-parallelism default.
+    pub fn with_concurrency_limit(max_concurrency: usize) -> Self {
+        let mut controller = TaskController::new();
+        let cleanup_handle = Self::spawn_cleanup_task(controller.token());
+        controller.track("research_session_cleanup", cleanup_handle);
+        Self {
+            sessions: DashMap::new(),
+            cleanup_controller: tokio::sync::Mutex::new(controller),
+            shutdown_timeout: DEFAULT_GRACEFUL_SHUTDOWN_TIMEOUT,
+            concurrency: Arc::new(Semaphore::new(max_concurrency.max(1))),
+            queue: tokio::sync::Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Create a new research session. Acquires a running permit immediately
+    /// if one is free; otherwise the session starts life as
+    /// [`ResearchStatus::Queued`] and is appended to the FIFO queue, to be
+    /// promoted to `Running` by a future [`Self::release_session`] call.
     pub async fn create_session(&self, session_id: String, query: String) -> Result<Arc<tokio::sync::Mutex<ResearchSession>>> {
-        let session = Arc::new(tokio::sync::Mutex::new(ResearchSession::new(
-            session_id.clone(),
-            query,
-        )));
+        let mut session = ResearchSession::new(session_id.clone(), query);
+
+        match self.concurrency.clone().try_acquire_owned() {
+            Ok(permit) => {
+                session.permit = Some(permit);
+            }
+            Err(_) => {
+                session.status = ResearchStatus::Queued;
+                self.queue.lock().await.push_back(session_id.clone());
+            }
+        }
+
+        let session = Arc::new(tokio::sync::Mutex::new(session));
         self.sessions.insert(session_id, session.clone());
         Ok(session)
     }
 
+    /// Current 1-based position of `session_id` in the waiting queue, or
+    /// `None` if it isn't queued (already running, or unknown).
+    pub async fn queue_position(&self, session_id: &str) -> Option<usize> {
+        self.queue
+            .lock()
+            .await
+            .iter()
+            .position(|id| id == session_id)
+            .map(|idx| idx + 1)
+    }
+
+    /// Release `session_id`'s running permit (a no-op if it never held one,
+    /// e.g. it was still queued) and promote the next queued session, if
+    /// any, to `Running`. Callers should invoke this once a session reaches
+    /// a terminal state (completed/failed/cancelled) so its slot isn't held
+    /// forever.
+    pub async fn release_session(&self, session_id: &str) -> Result<()> {
+        if let Ok(session_ref) = self.get_session(session_id).await {
+            session_ref.lock().await.permit = None;
+        }
+        self.promote_next_queued().await;
+        Ok(())
+    }
+
+    /// Pop the next queued session (skipping any that have since been
+    /// removed) and, if a permit is free, promote it to `Running`.
+    async fn promote_next_queued(&self) {
+        let mut queue = self.queue.lock().await;
+        while let Some(next_id) = queue.pop_front() {
+            let Some(session_ref) = self.sessions.get(&next_id).map(|e| e.value().clone()) else {
+                // Session was removed/cleaned up while queued - try the next one.
+                continue;
+            };
+
+            match self.concurrency.clone().try_acquire_owned() {
+                Ok(permit) => {
+                    let mut session = session_ref.lock().await;
+                    session.permit = Some(permit);
+                    session.status = ResearchStatus::Running;
+                    log::info!("Promoted queued research session {} to running", next_id);
+                }
+                Err(_) => {
+                    // No free permit yet (shouldn't normally happen right
+                    // after a release) - put it back at the front and stop.
+                    queue.push_front(next_id);
+                }
+            }
+            return;
+        }
+    }
+
     /// Get session by ID
     pub async fn get_session(&self, session_id: &str) -> Result<Arc<tokio::sync::Mutex<ResearchSession>>> {
         self.sessions
@@ -210,6 +337,12 @@ impl ResearchSessionManager {
                 }
             };
 
+            let queue_position = if session.status == ResearchStatus::Queued {
+                self.queue_position(&session.session_id).await
+            } else {
+                None
+            };
+
             sessions.push(serde_json::json!({
                 "session_id": session.session_id,
                 "query": session.query,
@@ -218,6 +351,7 @@ impl ResearchSessionManager {
                 "runtime_seconds": session.runtime_seconds(),
                 "pages_visited": session.progress.last().map(|p| p.pages_visited).unwrap_or(0),
                 "current_step": session.progress.last().map(|p| p.message.clone()).unwrap_or_default(),
+                "queue_position": queue_position,
             }));
         }
         sessions
@@ -278,25 +412,22 @@ impl ResearchSessionManager {
     }
 
     /// Shutdown cleanup task gracefully
+    ///
+    /// Cancels the shared token and waits for the cleanup task via
+    /// [`TaskController::terminate_all_async`], bounded by `self.shutdown_timeout`.
     pub async fn shutdown(&self) -> Result<()> {
-        self.cleanup_token.cancel();
-        
-        // Take the join handle and wait for task with timeout
-        let mut task_lock = self.cleanup_task.lock().await;
-        if let Some(handle) = task_lock.take() {
-            match tokio::time::timeout(Duration::from_secs(5), handle).await {
-                Ok(Ok(())) => {
-                    log::info!("Cleanup task stopped successfully");
-                }
-                Ok(Err(e)) => {
-                    log::warn!("Cleanup task panicked: {:?}", e);
-                }
-                Err(_) => {
-                    log::warn!("Cleanup task didn't stop within timeout");
-                }
+        let mut controller = self.cleanup_controller.lock().await;
+        let reports = controller.terminate_all_async(self.shutdown_timeout).await;
+        match reports.first().map(|r| r.outcome) {
+            Some(TaskOutcome::FinishedCleanly) => {
+                log::info!("Cleanup task stopped successfully");
             }
+            Some(TaskOutcome::ForceAborted) => {
+                log::warn!("Cleanup task didn't stop within {:?}", self.shutdown_timeout);
+            }
+            None => {}
         }
-        
+
         Ok(())
     }
 }
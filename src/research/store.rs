@@ -0,0 +1,334 @@
+//! Pluggable persistence for research session metadata and results
+//!
+//! `ResearchSession` itself holds a `JoinHandle` and a live `DeepResearch`
+//! handle, neither of which is serializable or meaningful after a restart -
+//! only its accumulated [`SessionInfo`] and [`ResearchResult`]s are. This
+//! module persists that serializable slice (a [`PersistedSession`]) behind a
+//! [`ResearchStore`] trait so `ResearchRegistry` can survive a process
+//! restart: `list` and `cleanup_connection` read through the store rather
+//! than scanning only the in-process map, so sessions from a prior process
+//! still show up (as read-only, already-completed entries) until explicitly
+//! cleaned up. [`InMemoryResearchStore`] is the default; [`KvResearchStore`]
+//! persists through any [`KvBackend`], keyed on `"{connection_id}:{session_id}"`.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+use super::registry::SessionInfo;
+use crate::utils::ResearchResult;
+
+/// Everything about a research session worth keeping after the process
+/// that ran it exits.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersistedSession {
+    pub connection_id: String,
+    pub info: SessionInfo,
+    pub results: Vec<ResearchResult>,
+}
+
+/// Storage backend for research session snapshots.
+///
+/// `connection_id`/`session_id` pairs are the same key `ResearchRegistry`
+/// already uses for its in-process map.
+#[async_trait]
+pub trait ResearchStore: Send + Sync {
+    async fn put(
+        &self,
+        connection_id: &str,
+        session_id: u32,
+        session: PersistedSession,
+    ) -> Result<()>;
+    async fn get(&self, connection_id: &str, session_id: u32) -> Result<Option<PersistedSession>>;
+    async fn remove(&self, connection_id: &str, session_id: u32) -> Result<()>;
+    async fn list(&self, connection_id: &str) -> Result<Vec<PersistedSession>>;
+    async fn cleanup_connection(&self, connection_id: &str) -> Result<usize>;
+}
+
+type StoreKey = (String, u32);
+
+/// Default `ResearchStore`: an in-process map, gone on restart. Behaviorally
+/// equivalent to `ResearchRegistry`'s map before this module existed.
+#[derive(Default)]
+pub struct InMemoryResearchStore {
+    sessions: Mutex<HashMap<StoreKey, PersistedSession>>,
+}
+
+impl InMemoryResearchStore {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl ResearchStore for InMemoryResearchStore {
+    async fn put(
+        &self,
+        connection_id: &str,
+        session_id: u32,
+        session: PersistedSession,
+    ) -> Result<()> {
+        self.sessions
+            .lock()
+            .await
+            .insert((connection_id.to_string(), session_id), session);
+        Ok(())
+    }
+
+    async fn get(&self, connection_id: &str, session_id: u32) -> Result<Option<PersistedSession>> {
+        Ok(self
+            .sessions
+            .lock()
+            .await
+            .get(&(connection_id.to_string(), session_id))
+            .cloned())
+    }
+
+    async fn remove(&self, connection_id: &str, session_id: u32) -> Result<()> {
+        self.sessions
+            .lock()
+            .await
+            .remove(&(connection_id.to_string(), session_id));
+        Ok(())
+    }
+
+    async fn list(&self, connection_id: &str) -> Result<Vec<PersistedSession>> {
+        Ok(self
+            .sessions
+            .lock()
+            .await
+            .iter()
+            .filter(|((conn_id, _), _)| conn_id == connection_id)
+            .map(|(_, session)| session.clone())
+            .collect())
+    }
+
+    async fn cleanup_connection(&self, connection_id: &str) -> Result<usize> {
+        let mut sessions = self.sessions.lock().await;
+        let before = sessions.len();
+        sessions.retain(|(conn_id, _), _| conn_id != connection_id);
+        Ok(before - sessions.len())
+    }
+}
+
+/// Minimal key-value interface a `KvResearchStore` persists through.
+///
+/// Implement this against whatever key-value store the deployment already
+/// runs (redis, sled, a cloud KV service, ...); `ResearchRegistry` only ever
+/// talks to the [`ResearchStore`] trait above, so swapping the backend here
+/// needs no registry changes.
+#[async_trait]
+pub trait KvBackend: Send + Sync {
+    async fn kv_get(&self, key: &str) -> Result<Option<String>>;
+    async fn kv_set(&self, key: &str, value: String) -> Result<()>;
+    async fn kv_delete(&self, key: &str) -> Result<()>;
+    async fn kv_scan_prefix(&self, prefix: &str) -> Result<Vec<String>>;
+}
+
+/// `ResearchStore` implementation backed by any [`KvBackend`], keyed on
+/// `"{connection_id}:{session_id}"` with JSON-serialized values.
+pub struct KvResearchStore<B: KvBackend> {
+    backend: Arc<B>,
+}
+
+impl<B: KvBackend> KvResearchStore<B> {
+    #[must_use]
+    pub fn new(backend: Arc<B>) -> Self {
+        Self { backend }
+    }
+
+    fn key(connection_id: &str, session_id: u32) -> String {
+        format!("{connection_id}:{session_id}")
+    }
+}
+
+#[async_trait]
+impl<B: KvBackend> ResearchStore for KvResearchStore<B> {
+    async fn put(
+        &self,
+        connection_id: &str,
+        session_id: u32,
+        session: PersistedSession,
+    ) -> Result<()> {
+        let value = serde_json::to_string(&session)?;
+        self.backend
+            .kv_set(&Self::key(connection_id, session_id), value)
+            .await
+    }
+
+    async fn get(&self, connection_id: &str, session_id: u32) -> Result<Option<PersistedSession>> {
+        match self
+            .backend
+            .kv_get(&Self::key(connection_id, session_id))
+            .await?
+        {
+            Some(value) => Ok(Some(serde_json::from_str(&value)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn remove(&self, connection_id: &str, session_id: u32) -> Result<()> {
+        self.backend
+            .kv_delete(&Self::key(connection_id, session_id))
+            .await
+    }
+
+    async fn list(&self, connection_id: &str) -> Result<Vec<PersistedSession>> {
+        let values = self
+            .backend
+            .kv_scan_prefix(&format!("{connection_id}:"))
+            .await?;
+        values
+            .into_iter()
+            .map(|value| serde_json::from_str(&value).map_err(anyhow::Error::from))
+            .collect()
+    }
+
+    async fn cleanup_connection(&self, connection_id: &str) -> Result<usize> {
+        let keys_prefix = format!("{connection_id}:");
+        let values = self.backend.kv_scan_prefix(&keys_prefix).await?;
+        let count = values.len();
+        // `kv_scan_prefix` returns values, not keys, so re-derive keys from
+        // the sessions they encode rather than requiring a second backend
+        // method.
+        for value in values {
+            let session: PersistedSession = serde_json::from_str(&value)?;
+            self.backend
+                .kv_delete(&format!("{keys_prefix}{}", session.info.session))
+                .await?;
+        }
+        Ok(count)
+    }
+}
+
+/// `ResearchStore` implementation that writes one JSON file per session
+/// under `dir`, so a `deep_research` invocation survives a process restart
+/// (or crash) and an MCP client can reconnect to it afterwards with the same
+/// `(connection_id, session_id)` pair - see [`ResearchRegistry::restore`].
+///
+/// Each session's path is a stable, deterministic function of its key (see
+/// [`Self::path_for`]), not an append-only log, so `put` is a full
+/// snapshot-replace rather than an incremental diff: `ResearchRegistry`
+/// calls it again after every new result and once more on completion,
+/// overwriting the previous snapshot each time. Writes go through a
+/// `.tmp` + rename so a crash mid-write can't leave a half-written,
+/// unparseable file behind (the same pattern `agent::session` uses for its
+/// checkpoints).
+///
+/// [`ResearchRegistry::restore`]: super::registry::ResearchRegistry::restore
+pub struct FileResearchStore {
+    dir: PathBuf,
+}
+
+impl FileResearchStore {
+    /// `dir` is created (including parents) if it doesn't already exist.
+    pub async fn new(dir: impl Into<PathBuf>) -> Result<Self> {
+        let dir = dir.into();
+        tokio::fs::create_dir_all(&dir)
+            .await
+            .with_context(|| format!("creating research session directory {}", dir.display()))?;
+        Ok(Self { dir })
+    }
+
+    fn path_for(&self, connection_id: &str, session_id: u32) -> PathBuf {
+        // `connection_id` can contain characters that aren't filesystem-safe
+        // on every platform, so it's hex-encoded rather than used verbatim;
+        // `session_id` stays decimal since it's already numeric.
+        let safe_connection_id = connection_id
+            .bytes()
+            .map(|b| format!("{b:02x}"))
+            .collect::<String>();
+        self.dir
+            .join(format!("{safe_connection_id}__{session_id}.json"))
+    }
+
+    /// All session file paths currently under `dir`, for [`Self::list_all`]
+    /// and cleanup.
+    async fn session_paths(&self) -> Result<Vec<PathBuf>> {
+        let mut entries = tokio::fs::read_dir(&self.dir).await?;
+        let mut paths = Vec::new();
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) == Some("json") {
+                paths.push(path);
+            }
+        }
+        Ok(paths)
+    }
+
+    async fn read_session(path: &Path) -> Result<PersistedSession> {
+        let bytes = tokio::fs::read(path).await?;
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+
+    /// Every persisted session across every connection, for
+    /// [`ResearchRegistry::restore`] at startup - unlike
+    /// [`ResearchStore::list`], which is scoped to one `connection_id`.
+    pub async fn list_all(&self) -> Result<Vec<PersistedSession>> {
+        let mut sessions = Vec::new();
+        for path in self.session_paths().await? {
+            sessions.push(Self::read_session(&path).await?);
+        }
+        Ok(sessions)
+    }
+}
+
+#[async_trait]
+impl ResearchStore for FileResearchStore {
+    async fn put(
+        &self,
+        connection_id: &str,
+        session_id: u32,
+        session: PersistedSession,
+    ) -> Result<()> {
+        let path = self.path_for(connection_id, session_id);
+        let tmp_path = path.with_extension("json.tmp");
+        tokio::fs::write(&tmp_path, serde_json::to_vec_pretty(&session)?).await?;
+        tokio::fs::rename(&tmp_path, &path).await?;
+        Ok(())
+    }
+
+    async fn get(&self, connection_id: &str, session_id: u32) -> Result<Option<PersistedSession>> {
+        let path = self.path_for(connection_id, session_id);
+        if !tokio::fs::try_exists(&path).await? {
+            return Ok(None);
+        }
+        Ok(Some(Self::read_session(&path).await?))
+    }
+
+    async fn remove(&self, connection_id: &str, session_id: u32) -> Result<()> {
+        let path = self.path_for(connection_id, session_id);
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn list(&self, connection_id: &str) -> Result<Vec<PersistedSession>> {
+        Ok(self
+            .list_all()
+            .await?
+            .into_iter()
+            .filter(|s| s.connection_id == connection_id)
+            .collect())
+    }
+
+    async fn cleanup_connection(&self, connection_id: &str) -> Result<usize> {
+        let mut count = 0;
+        for path in self.session_paths().await? {
+            let session = Self::read_session(&path).await?;
+            if session.connection_id == connection_id {
+                tokio::fs::remove_file(&path).await?;
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+}
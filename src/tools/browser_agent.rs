@@ -4,15 +4,14 @@
 //! Session management with connection isolation
 //! Timeout with background continuation
 
-use crate::agent::{Agent, AgentConfig, PromptConfig};
 use crate::agent::prompts::{AgentMessagePrompt, SystemPrompt};
 use crate::agent::registry::AgentRegistry;
+use crate::agent::{Agent, AgentConfig, PromptConfig};
 use crate::manager::BrowserManager;
 use crate::utils::AgentState;
 use kodegen_mcp_schema::browser::{
-    BrowserAgentAction, BrowserAgentArgs, BrowserAgentOutput,
-    BrowserAgentStepInfo, BROWSER_AGENT, BROWSER_NAVIGATE,
-    AgentPrompts,
+    AgentPrompts, BROWSER_AGENT, BROWSER_NAVIGATE, BrowserAgentAction, BrowserAgentArgs,
+    BrowserAgentOutput, BrowserAgentStepInfo,
 };
 use kodegen_mcp_schema::{McpError, Tool, ToolExecutionContext, ToolResponse};
 use serde_json::json;
@@ -26,7 +25,7 @@ use tokio::sync::{Mutex, OnceCell};
 
 #[derive(Clone)]
 pub struct BrowserAgentTool {
-    _browser_manager: Arc<BrowserManager>,
+    browser_manager: Arc<BrowserManager>,
     server_url: String,
     registry: Arc<OnceCell<AgentRegistry>>,
 }
@@ -34,15 +33,25 @@ pub struct BrowserAgentTool {
 impl BrowserAgentTool {
     pub fn new(browser_manager: Arc<BrowserManager>, server_url: String) -> Self {
         Self {
-            _browser_manager: browser_manager,
+            browser_manager,
             server_url,
             registry: Arc::new(OnceCell::new()),
         }
     }
-    
+
+    /// Reaper parameters for [`Self::get_registry`]'s lazily-created
+    /// [`AgentRegistry`]. Not (yet) exposed via `Config` - agent sessions
+    /// don't have a config-driven knob analogous to `BrowserConfig`'s
+    /// `idle_timeout_secs` today, so these mirror `AgentSession`'s own
+    /// hardcoded `QUIESCENCE_WINDOW` rather than staying silently disabled.
+    const REAPER_SCAN_INTERVAL: Duration = Duration::from_secs(300);
+    const REAPER_IDLE_TTL: Duration = Duration::from_secs(1800);
+
     pub async fn get_registry(&self) -> AgentRegistry {
         self.registry
-            .get_or_init(|| async { AgentRegistry::new() })
+            .get_or_init(|| async {
+                AgentRegistry::with_reaper(Self::REAPER_SCAN_INTERVAL, Self::REAPER_IDLE_TTL)
+            })
             .await
             .clone()
     }
@@ -80,18 +89,18 @@ impl Tool for BrowserAgentTool {
     ) -> Result<ToolResponse<BrowserAgentOutput>, McpError> {
         let registry = self.get_registry().await;
         let connection_id = ctx.connection_id().unwrap_or("default");
-        
+
         match args.action {
             BrowserAgentAction::Prompt => {
                 // Validate task
                 let task = args.task.ok_or_else(|| {
                     McpError::invalid_arguments("task is required for PROMPT action")
                 })?;
-                
+
                 if task.trim().is_empty() {
                     return Err(McpError::invalid_arguments("Agent task cannot be empty"));
                 }
-                
+
                 // Create loopback MCP client
                 let (mcp_client, _connection) = kodegen_mcp_client::create_streamable_client(
                     &self.server_url,
@@ -99,12 +108,55 @@ impl Tool for BrowserAgentTool {
                 )
                 .await
                 .map_err(|e| {
-                    McpError::Other(anyhow::anyhow!(
-                        "Failed to create loopback client: {}",
-                        e
-                    ))
+                    McpError::Other(anyhow::anyhow!("Failed to create loopback client: {}", e))
                 })?;
-                
+
+                // Create agent configuration
+                let prompts = PromptConfig {
+                    system_prompt: SystemPrompt::new(),
+                    agent_prompt: AgentMessagePrompt::new(),
+                };
+
+                let agent_state = Arc::new(Mutex::new(AgentState::new()));
+
+                let config = AgentConfig {
+                    temperature: args.temperature,
+                    max_tokens: args.max_tokens,
+                    vision_timeout_secs: args.vision_timeout_secs,
+                    llm_timeout_secs: args.llm_timeout_secs,
+                    ..AgentConfig::default()
+                };
+
+                // Resource-blocking profile (see `AgentConfig::block_resource_types`).
+                // `BrowserAgentArgs` has no field to set this from yet, so
+                // `config.block_resource_types` is always empty here today;
+                // this call is a no-op until the external schema grows one.
+                if !config.block_resource_types.is_empty() {
+                    self.browser_manager
+                        .request_interceptor()
+                        .set_rules(crate::utils::block_resource_types(
+                            &config.block_resource_types,
+                        ))
+                        .await;
+                }
+
+                // Authenticated-session cookie profile (see
+                // `AgentConfig::cookie_profile`), made active before the
+                // start-URL navigation below so `navigate.rs`'s existing
+                // active-profile handling installs its cookies on that
+                // first load - the same mechanism `browser_cookies`'
+                // LOAD_PROFILE action and `ResearchOptions::cookie_profile`
+                // already use. `BrowserAgentArgs` has no field to set this
+                // from yet, so `config.cookie_profile` is always `None`
+                // here today; this call is a no-op until the external
+                // schema grows one.
+                if config.cookie_profile.is_some() {
+                    self.browser_manager
+                        .cookie_profiles()
+                        .set_active(config.cookie_profile.clone())
+                        .await;
+                }
+
                 // Navigate to start URL if provided
                 if let Some(url) = &args.start_url {
                     mcp_client
@@ -117,26 +169,65 @@ impl Tool for BrowserAgentTool {
                         )
                         .await
                         .map_err(|e| {
-                            McpError::Other(anyhow::anyhow!("Failed to navigate to start URL: {}", e))
+                            McpError::Other(anyhow::anyhow!(
+                                "Failed to navigate to start URL: {}",
+                                e
+                            ))
                         })?;
                 }
-                
-                // Create agent configuration
-                let prompts = PromptConfig {
-                    system_prompt: SystemPrompt::new(),
-                    agent_prompt: AgentMessagePrompt::new(),
-                };
-                
-                let agent_state = Arc::new(Mutex::new(AgentState::new()));
-                
-                let config = AgentConfig {
-                    temperature: args.temperature,
-                    max_tokens: args.max_tokens,
-                    vision_timeout_secs: args.vision_timeout_secs,
-                    llm_timeout_secs: args.llm_timeout_secs,
-                };
-                
-                let agent = Agent::new(
+
+                // Virtual WebAuthn authenticator (see
+                // `AgentConfig::webauthn_authenticator`), provisioned on
+                // this connection's current page so the agent can satisfy a
+                // passkey/2FA prompt it hits later in the task without a
+                // real security key. `BrowserAgentArgs` has no field to set
+                // this from yet, so `config.webauthn_authenticator` is
+                // always `None` here today; this block is a no-op until
+                // the external schema grows one.
+                if let Some(authenticator_config) = &config.webauthn_authenticator {
+                    if let Ok(browser_arc) =
+                        self.browser_manager.get_or_launch_for(connection_id).await
+                    {
+                        let browser_guard = browser_arc.lock().await;
+                        if let Some(wrapper) = browser_guard.as_ref() {
+                            if let Ok(page) = crate::browser::get_current_page(wrapper).await {
+                                if crate::browser::webauthn::enable(&page).await.is_ok() {
+                                    if let Ok(authenticator_id) =
+                                        crate::browser::webauthn::add_authenticator(
+                                            &page,
+                                            authenticator_config,
+                                        )
+                                        .await
+                                    {
+                                        for credential in &config.webauthn_credentials {
+                                            let _ = crate::browser::webauthn::add_credential(
+                                                &page,
+                                                &authenticator_id,
+                                                credential,
+                                            )
+                                            .await;
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // Default to local Candle inference; an OpenAI-compatible
+                // hosted model can be selected per-task without touching the
+                // step loop or action execution.
+                let action_provider: Arc<dyn crate::agent::ActionProvider> =
+                    match args.openai_base_url.as_deref() {
+                        Some(base_url) => crate::agent::OpenAiActionProvider::new(
+                            base_url.to_string(),
+                            args.openai_api_key.clone().unwrap_or_default(),
+                            args.openai_model.clone().unwrap_or_else(|| "gpt-4o".to_string()),
+                        ),
+                        None => crate::agent::CandleActionProvider::new(),
+                    };
+
+                let agent = Agent::new_with_provider(
                     &task,
                     args.additional_info.as_deref().unwrap_or(""),
                     Arc::new(mcp_client),
@@ -144,18 +235,25 @@ impl Tool for BrowserAgentTool {
                     args.max_actions_per_step as usize,
                     agent_state,
                     config,
+                    action_provider,
                 )
                 .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to create agent: {}", e)))?;
-                
+
                 // Find or create session
                 let session = registry
-                    .find_or_create(connection_id, args.agent, agent, task.clone(), args.max_steps as usize)
+                    .find_or_create(
+                        connection_id,
+                        args.agent,
+                        agent,
+                        task.clone(),
+                        args.max_steps as usize,
+                    )
                     .await
                     .map_err(McpError::Other)?;
-                
+
                 // Start agent in background (Agent.run is internally async)
                 session.start().await.map_err(McpError::Other)?;
-                
+
                 // Fire-and-forget: return immediately
                 if args.await_completion_ms == 0 {
                     let output = BrowserAgentOutput {
@@ -164,16 +262,17 @@ impl Tool for BrowserAgentTool {
                         steps_taken: 0,
                         completed: false,
                         error: None,
-                        summary: "Agent started in background. Use READ to check progress.".to_string(),
+                        summary: "Agent started in background. Use READ to check progress."
+                            .to_string(),
                         history: vec![],
                     };
-                    
+
                     return Ok(ToolResponse::new(
                         "Agent started in background. Use READ to check progress.",
                         output,
                     ));
                 }
-                
+
                 // Wait with timeout
                 let timeout_duration = Duration::from_millis(args.await_completion_ms);
                 let wait_result = tokio::time::timeout(timeout_duration, async {
@@ -186,17 +285,24 @@ impl Tool for BrowserAgentTool {
                     }
                 })
                 .await;
-                
+
                 // Read current state (whether timed out or completed)
                 let session_output = session.read(args.agent).await;
-                
-                // Convert to output format using schema types
+
+                // Convert to output format using schema types. Each
+                // `step.output.diagnostics` carries console messages/uncaught
+                // exceptions observed during that step (see
+                // `AgentOutput::diagnostics`), but `BrowserAgentStepInfo` -
+                // defined in the external `kodegen_mcp_schema` crate - has no
+                // field for them, so they don't reach the MCP response yet.
                 let history: Vec<BrowserAgentStepInfo> = session_output
                     .history
                     .steps
                     .iter()
                     .map(|step| {
-                        let actions: Vec<String> = step.output.action
+                        let actions: Vec<String> = step
+                            .output
+                            .action
                             .iter()
                             .map(|a| a.action.clone())
                             .collect();
@@ -209,7 +315,7 @@ impl Tool for BrowserAgentTool {
                         }
                     })
                     .collect();
-                
+
                 let display = if wait_result.is_ok() {
                     session_output.summary.clone()
                 } else {
@@ -219,7 +325,7 @@ impl Tool for BrowserAgentTool {
                         session_output.history.steps.len()
                     )
                 };
-                
+
                 let output = BrowserAgentOutput {
                     agent: args.agent,
                     task: session_output.task,
@@ -229,32 +335,32 @@ impl Tool for BrowserAgentTool {
                     summary: session_output.summary.clone(),
                     history,
                 };
-                
+
                 Ok(ToolResponse::new(display, output))
             }
-            
+
             BrowserAgentAction::Read => {
                 // Get existing session
                 let session = registry
                     .get(connection_id, args.agent)
                     .await
                     .ok_or_else(|| {
-                        McpError::invalid_arguments(format!(
-                            "Agent {} not found",
-                            args.agent
-                        ))
+                        McpError::invalid_arguments(format!("Agent {} not found", args.agent))
                     })?;
-                
+
                 // Read current state
                 let session_output = session.read(args.agent).await;
-                
-                // Convert to output format using schema types
+
+                // Convert to output format using schema types (see the
+                // PROMPT branch above for why `diagnostics` isn't included).
                 let history: Vec<BrowserAgentStepInfo> = session_output
                     .history
                     .steps
                     .iter()
                     .map(|step| {
-                        let actions: Vec<String> = step.output.action
+                        let actions: Vec<String> = step
+                            .output
+                            .action
                             .iter()
                             .map(|a| a.action.clone())
                             .collect();
@@ -267,7 +373,7 @@ impl Tool for BrowserAgentTool {
                         }
                     })
                     .collect();
-                
+
                 let output = BrowserAgentOutput {
                     agent: args.agent,
                     task: session_output.task.clone(),
@@ -277,28 +383,32 @@ impl Tool for BrowserAgentTool {
                     summary: session_output.summary.clone(),
                     history,
                 };
-                
+
                 Ok(ToolResponse::new(session_output.summary, output))
             }
-            
+
             BrowserAgentAction::Kill => {
                 // Get existing session
                 let session = registry
                     .get(connection_id, args.agent)
                     .await
                     .ok_or_else(|| {
-                        McpError::invalid_arguments(format!(
-                            "Agent {} not found",
-                            args.agent
-                        ))
+                        McpError::invalid_arguments(format!("Agent {} not found", args.agent))
                     })?;
-                
+
                 // Kill the session
                 session.kill().await.map_err(McpError::Other)?;
-                
+
                 // Remove from registry
                 registry.remove(connection_id, args.agent).await;
-                
+
+                // If this was the connection's last agent, give back its
+                // pooled browser lease (see `BrowserManager::acquire_for`)
+                // so another connection can use the slot.
+                if registry.list(connection_id).await.unwrap_or_default().is_empty() {
+                    self.browser_manager.release_for(connection_id).await;
+                }
+
                 let message = format!("Agent {} terminated", args.agent);
                 let output = BrowserAgentOutput {
                     agent: args.agent,
@@ -309,10 +419,9 @@ impl Tool for BrowserAgentTool {
                     summary: message.clone(),
                     history: vec![],
                 };
-                
+
                 Ok(ToolResponse::new(message, output))
             }
         }
     }
-
 }
@@ -0,0 +1,360 @@
+//! Cookie management tool - read, set, and clear cookies for the current page
+//!
+//! Exposes the CDP Network domain's cookie surface so a login flow can run
+//! once (via `browser_navigate`/`browser_click`/`browser_type_text`) and a
+//! later call can persist or restore the resulting session cookies,
+//! unblocking any login-gated research or agent run.
+//!
+//! `BrowserCookiesArgs`/`CookieInfo` (external `kodegen_mcp_schema` crate)
+//! have no `same_site` field yet, so ADD always leaves `SetCookieParams`'
+//! `same_site` unset (CDP defaults to `Lax`). GET_ALL/GET_NAMED always read
+//! the current page's cookies rather than an arbitrary URL's (no separate
+//! `url` arg), but do narrow by `domain` when it's supplied, the same
+//! optional field ADD/DELETE already accept.
+
+use std::sync::Arc;
+
+use chromiumoxide::cdp::browser_protocol::network::{
+    ClearBrowserCookiesParams, Cookie, DeleteCookiesParams, GetCookiesParams, SetCookieParams,
+};
+use kodegen_mcp_schema::browser::{
+    BROWSER_COOKIES, BrowserCookiesAction, BrowserCookiesArgs, BrowserCookiesOutput, CookieInfo,
+    CookiesPrompts,
+};
+use kodegen_mcp_schema::{McpError, Tool, ToolExecutionContext, ToolResponse};
+use tracing::warn;
+
+use crate::manager::BrowserManager;
+
+fn to_cookie_info(cookie: Cookie) -> CookieInfo {
+    CookieInfo {
+        name: cookie.name,
+        value: cookie.value,
+        domain: cookie.domain,
+        path: cookie.path,
+        expires: cookie.expires,
+        http_only: cookie.http_only,
+        secure: cookie.secure,
+    }
+}
+
+#[derive(Clone)]
+pub struct BrowserCookiesTool {
+    manager: Arc<BrowserManager>,
+}
+
+impl BrowserCookiesTool {
+    pub fn new(manager: Arc<BrowserManager>) -> Self {
+        Self { manager }
+    }
+}
+
+impl Tool for BrowserCookiesTool {
+    type Args = BrowserCookiesArgs;
+    type Prompts = CookiesPrompts;
+
+    fn name() -> &'static str {
+        BROWSER_COOKIES
+    }
+
+    fn description() -> &'static str {
+        "Read and manage cookies for the current page.\\n\\n\
+         Actions:\\n\
+         - GET_ALL: List every cookie visible to the current page\\n\
+         - GET_NAMED: List cookies matching `name`\\n\
+         - ADD: Set a cookie (name, value required; domain, path, expires, http_only, secure optional)\\n\
+         - DELETE: Delete cookies matching `name` (domain/path optional, narrows the match)\\n\
+         - DELETE_ALL: Clear every cookie in the browser\\n\
+         - SAVE_PROFILE: Snapshot the current cookie jar under `profile_name`\\n\
+         - LOAD_PROFILE: Install `profile_name`'s saved cookies on the current page, and make it \
+         the active profile future navigations (including background research) install automatically\\n\\n\
+         Example: browser_cookies({\\\"action\\\": \\\"ADD\\\", \\\"name\\\": \\\"session\\\", \\\"value\\\": \\\"abc123\\\"})\\n\
+         Example: browser_cookies({\\\"action\\\": \\\"SAVE_PROFILE\\\", \\\"profile_name\\\": \\\"logged-in\\\"})"
+    }
+
+    fn read_only() -> bool {
+        false // ADD/DELETE/DELETE_ALL mutate cookie state
+    }
+
+    fn destructive() -> bool {
+        true // DELETE_ALL clears every cookie in the browser
+    }
+
+    fn open_world() -> bool {
+        false
+    }
+
+    async fn execute(
+        &self,
+        args: Self::Args,
+        _ctx: ToolExecutionContext,
+    ) -> Result<ToolResponse<BrowserCookiesOutput>, McpError> {
+        let browser_arc = self
+            .manager
+            .get_or_launch()
+            .await
+            .map_err(|e| McpError::Other(anyhow::anyhow!("Browser error: {}", e)))?;
+
+        let browser_guard = browser_arc.lock().await;
+        let wrapper = browser_guard.as_ref().ok_or_else(|| {
+            McpError::Other(anyhow::anyhow!(
+                "Browser not available. This is an internal error - please report it."
+            ))
+        })?;
+
+        let page = crate::browser::get_current_page(wrapper)
+            .await
+            .map_err(|e| {
+                McpError::Other(anyhow::anyhow!(
+                    "Failed to get page. Did you call browser_navigate first? Error: {}",
+                    e
+                ))
+            })?;
+
+        match args.action {
+            BrowserCookiesAction::GetAll => {
+                let cookies = page
+                    .execute(GetCookiesParams::default())
+                    .await
+                    .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to get cookies: {}", e)))?
+                    .cookies
+                    .iter()
+                    .filter(|c| args.domain.as_deref().is_none_or(|d| c.domain == d))
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                let count = cookies.len();
+                let output = BrowserCookiesOutput {
+                    success: true,
+                    cookies: cookies.into_iter().map(to_cookie_info).collect(),
+                    count,
+                };
+                Ok(ToolResponse::new(
+                    format!("\x1b[36mCookies: GET_ALL\x1b[0m\n Count: {}", count),
+                    output,
+                ))
+            }
+            BrowserCookiesAction::GetNamed => {
+                let name = args
+                    .name
+                    .ok_or_else(|| McpError::invalid_arguments("name is required for GET_NAMED"))?;
+
+                let cookies = page
+                    .execute(GetCookiesParams::default())
+                    .await
+                    .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to get cookies: {}", e)))?
+                    .cookies
+                    .iter()
+                    .filter(|c| c.name == name)
+                    .filter(|c| args.domain.as_deref().is_none_or(|d| c.domain == d))
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                let count = cookies.len();
+                let output = BrowserCookiesOutput {
+                    success: true,
+                    cookies: cookies.into_iter().map(to_cookie_info).collect(),
+                    count,
+                };
+                Ok(ToolResponse::new(
+                    format!(
+                        "\x1b[36mCookies: GET_NAMED {}\x1b[0m\n Count: {}",
+                        name, count
+                    ),
+                    output,
+                ))
+            }
+            BrowserCookiesAction::Add => {
+                let name = args
+                    .name
+                    .ok_or_else(|| McpError::invalid_arguments("name is required for ADD"))?;
+                let value = args
+                    .value
+                    .ok_or_else(|| McpError::invalid_arguments("value is required for ADD"))?;
+
+                let current_url = page.url().await.ok().flatten();
+
+                let mut builder = SetCookieParams::builder().name(name.clone()).value(value);
+                if let Some(url) = &current_url {
+                    builder = builder.url(url.clone());
+                }
+                if let Some(domain) = args.domain {
+                    builder = builder.domain(domain);
+                }
+                if let Some(path) = args.path {
+                    builder = builder.path(path);
+                }
+                if let Some(http_only) = args.http_only {
+                    builder = builder.http_only(http_only);
+                }
+                if let Some(secure) = args.secure {
+                    builder = builder.secure(secure);
+                }
+                if let Some(expires) = args.expires {
+                    builder = builder.expires(expires);
+                }
+
+                let params = builder
+                    .build()
+                    .map_err(|e| McpError::invalid_arguments(format!("Invalid cookie: {}", e)))?;
+
+                page.execute(params)
+                    .await
+                    .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to set cookie: {}", e)))?;
+
+                let output = BrowserCookiesOutput {
+                    success: true,
+                    cookies: vec![],
+                    count: 0,
+                };
+                Ok(ToolResponse::new(
+                    format!("\x1b[36mCookies: ADD {}\x1b[0m", name),
+                    output,
+                ))
+            }
+            BrowserCookiesAction::Delete => {
+                let name = args
+                    .name
+                    .ok_or_else(|| McpError::invalid_arguments("name is required for DELETE"))?;
+
+                let mut builder = DeleteCookiesParams::builder().name(name.clone());
+                if let Some(url) = page.url().await.ok().flatten() {
+                    builder = builder.url(url);
+                }
+                if let Some(domain) = args.domain {
+                    builder = builder.domain(domain);
+                }
+                if let Some(path) = args.path {
+                    builder = builder.path(path);
+                }
+
+                let params = builder.build().map_err(|e| {
+                    McpError::invalid_arguments(format!("Invalid delete request: {}", e))
+                })?;
+
+                page.execute(params).await.map_err(|e| {
+                    McpError::Other(anyhow::anyhow!("Failed to delete cookie: {}", e))
+                })?;
+
+                let output = BrowserCookiesOutput {
+                    success: true,
+                    cookies: vec![],
+                    count: 0,
+                };
+                Ok(ToolResponse::new(
+                    format!("\x1b[36mCookies: DELETE {}\x1b[0m", name),
+                    output,
+                ))
+            }
+            BrowserCookiesAction::DeleteAll => {
+                page.execute(ClearBrowserCookiesParams::default())
+                    .await
+                    .map_err(|e| {
+                        McpError::Other(anyhow::anyhow!("Failed to clear cookies: {}", e))
+                    })?;
+
+                let output = BrowserCookiesOutput {
+                    success: true,
+                    cookies: vec![],
+                    count: 0,
+                };
+                Ok(ToolResponse::new(
+                    "\x1b[36mCookies: DELETE_ALL\x1b[0m",
+                    output,
+                ))
+            }
+            BrowserCookiesAction::SaveProfile => {
+                let profile_name = args.profile_name.ok_or_else(|| {
+                    McpError::invalid_arguments("profile_name is required for SAVE_PROFILE")
+                })?;
+
+                let cookies: Vec<CookieInfo> = page
+                    .execute(GetCookiesParams::default())
+                    .await
+                    .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to get cookies: {}", e)))?
+                    .cookies
+                    .iter()
+                    .cloned()
+                    .map(to_cookie_info)
+                    .collect();
+
+                let count = cookies.len();
+                self.manager
+                    .cookie_profiles()
+                    .save(profile_name.clone(), cookies.clone())
+                    .await;
+
+                let output = BrowserCookiesOutput {
+                    success: true,
+                    cookies,
+                    count,
+                };
+                Ok(ToolResponse::new(
+                    format!(
+                        "\x1b[36mCookies: SAVE_PROFILE {}\x1b[0m\n Count: {}",
+                        profile_name, count
+                    ),
+                    output,
+                ))
+            }
+            BrowserCookiesAction::LoadProfile => {
+                let profile_name = args.profile_name.ok_or_else(|| {
+                    McpError::invalid_arguments("profile_name is required for LOAD_PROFILE")
+                })?;
+
+                let profiles = self.manager.cookie_profiles();
+                let cookies = profiles.get(&profile_name).await.ok_or_else(|| {
+                    McpError::invalid_arguments(format!(
+                        "No cookie profile named '{}'",
+                        profile_name
+                    ))
+                })?;
+
+                // Make this the profile future navigations install
+                // automatically (see `BrowserNavigateTool::navigate_and_capture_page`).
+                profiles.set_active(Some(profile_name.clone())).await;
+
+                // Also apply immediately to the current page, so a call
+                // right after LOAD_PROFILE sees the session without a
+                // fresh navigation.
+                let current_url = page.url().await.ok().flatten();
+                for cookie in &cookies {
+                    let mut builder = SetCookieParams::builder()
+                        .name(cookie.name.clone())
+                        .value(cookie.value.clone())
+                        .domain(cookie.domain.clone())
+                        .path(cookie.path.clone())
+                        .expires(cookie.expires)
+                        .http_only(cookie.http_only)
+                        .secure(cookie.secure);
+                    if let Some(url) = &current_url {
+                        builder = builder.url(url.clone());
+                    }
+
+                    match builder.build() {
+                        Ok(params) => {
+                            if let Err(e) = page.execute(params).await {
+                                warn!("Failed to install cookie '{}': {}", cookie.name, e);
+                            }
+                        }
+                        Err(e) => warn!("Invalid saved cookie '{}': {}", cookie.name, e),
+                    }
+                }
+
+                let count = cookies.len();
+                let output = BrowserCookiesOutput {
+                    success: true,
+                    cookies,
+                    count,
+                };
+                Ok(ToolResponse::new(
+                    format!(
+                        "\x1b[36mCookies: LOAD_PROFILE {}\x1b[0m\n Count: {}",
+                        profile_name, count
+                    ),
+                    output,
+                ))
+            }
+        }
+    }
+}
@@ -0,0 +1,122 @@
+//! Recursive site-discovery crawl tool - structured link discovery
+//!
+//! Breadth-first from `start_url`, recording `{url, title, status, depth,
+//! outlinks}` per page instead of summarizing content (see
+//! `browser_research` for the AI-summarization counterpart). Useful for
+//! sitemap generation or feeding discovered URLs into `browser_extract_text`.
+
+use std::sync::Arc;
+
+use kodegen_mcp_schema::browser::{
+    BROWSER_CRAWL, BrowserCrawlArgs, BrowserCrawlOutput, CrawlPrompts, CrawledPage,
+};
+use kodegen_mcp_schema::{McpError, Tool, ToolExecutionContext, ToolResponse};
+use regex::Regex;
+
+use crate::manager::BrowserManager;
+use crate::utils::{CrawlOptions, SiteCrawler};
+
+#[derive(Clone)]
+pub struct BrowserCrawlTool {
+    manager: Arc<BrowserManager>,
+}
+
+impl BrowserCrawlTool {
+    pub fn new(manager: Arc<BrowserManager>) -> Self {
+        Self { manager }
+    }
+}
+
+impl Tool for BrowserCrawlTool {
+    type Args = BrowserCrawlArgs;
+    type Prompts = CrawlPrompts;
+
+    fn name() -> &'static str {
+        BROWSER_CRAWL
+    }
+
+    fn description() -> &'static str {
+        "Breadth-first crawl from a seed URL, recording each page's title, status, depth, and \
+         outgoing links.\\n\\n\
+         Args: start_url (required), max_depth (default 2), max_pages (default 20), \
+         same_origin_only (default true), include/exclude URL regex filters (optional).\\n\\n\
+         Example: browser_crawl({\\\"start_url\\\": \\\"https://example.com\\\", \\\"max_depth\\\": 2, \\\"max_pages\\\": 20})"
+    }
+
+    fn read_only() -> bool {
+        false // Navigates the shared browser across multiple pages
+    }
+
+    fn destructive() -> bool {
+        false
+    }
+
+    fn open_world() -> bool {
+        true // Accesses external URLs
+    }
+
+    async fn execute(
+        &self,
+        args: Self::Args,
+        _ctx: ToolExecutionContext,
+    ) -> Result<ToolResponse<BrowserCrawlOutput>, McpError> {
+        if !args.start_url.starts_with("http://") && !args.start_url.starts_with("https://") {
+            return Err(McpError::invalid_arguments(
+                "start_url must start with http:// or https://",
+            ));
+        }
+
+        let include_pattern = args
+            .include
+            .as_deref()
+            .map(Regex::new)
+            .transpose()
+            .map_err(|e| McpError::invalid_arguments(format!("Invalid include regex: {}", e)))?;
+        let exclude_pattern = args
+            .exclude
+            .as_deref()
+            .map(Regex::new)
+            .transpose()
+            .map_err(|e| McpError::invalid_arguments(format!("Invalid exclude regex: {}", e)))?;
+
+        let options = CrawlOptions {
+            max_depth: args.max_depth.map_or(2, |d| d as usize),
+            max_pages: args.max_pages.map_or(20, |p| p as usize),
+            same_origin_only: args.same_origin_only.unwrap_or(true),
+            include_pattern,
+            exclude_pattern,
+            ..CrawlOptions::default()
+        };
+
+        let crawler = SiteCrawler::new(self.manager.clone());
+        let pages = crawler
+            .crawl(&args.start_url, &options)
+            .await
+            .map_err(|e| McpError::Other(anyhow::anyhow!("Crawl failed: {}", e)))?;
+
+        let summary = format!(
+            "\x1b[36mCrawl: {}\x1b[0m\n Pages: {} · Max depth: {}",
+            args.start_url,
+            pages.len(),
+            options.max_depth
+        );
+
+        let output = BrowserCrawlOutput {
+            success: true,
+            start_url: args.start_url,
+            pages_crawled: pages.len(),
+            pages: pages
+                .into_iter()
+                .map(|p| CrawledPage {
+                    url: p.url,
+                    title: p.title,
+                    status: p.status,
+                    depth: p.depth as u32,
+                    outlinks: p.outlinks,
+                })
+                .collect(),
+        };
+
+        Ok(ToolResponse::new(summary, output))
+    }
+}
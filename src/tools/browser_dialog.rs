@@ -0,0 +1,119 @@
+//! JS dialog (`alert`/`confirm`/`prompt`/`beforeunload`) control tool
+//!
+//! Pairs with `crate::utils::DialogWatcher`, which keeps the current page's
+//! dialog blocked open until this tool resolves it (or the watcher's own
+//! auto-dismiss timeout fires). Lets a login or agent flow that triggers a
+//! `confirm()`/`prompt()` mid-navigation continue instead of hanging.
+
+use std::sync::Arc;
+
+use kodegen_mcp_schema::browser::{
+    BROWSER_DIALOG, BrowserDialogAction, BrowserDialogArgs, BrowserDialogOutput, DialogPrompts,
+};
+use kodegen_mcp_schema::{McpError, Tool, ToolExecutionContext, ToolResponse};
+
+use crate::manager::BrowserManager;
+
+#[derive(Clone)]
+pub struct BrowserDialogTool {
+    manager: Arc<BrowserManager>,
+}
+
+impl BrowserDialogTool {
+    pub fn new(manager: Arc<BrowserManager>) -> Self {
+        Self { manager }
+    }
+}
+
+impl Tool for BrowserDialogTool {
+    type Args = BrowserDialogArgs;
+    type Prompts = DialogPrompts;
+
+    fn name() -> &'static str {
+        BROWSER_DIALOG
+    }
+
+    fn description() -> &'static str {
+        "Inspect and resolve the current page's open JS dialog \
+         (alert/confirm/prompt/beforeunload).\\n\\n\
+         Actions:\\n\
+         - GET: Return the pending dialog's message/type, if any is open\\n\
+         - ACCEPT: Accept the dialog (OK on a prompt needs `text`)\\n\
+         - DISMISS: Dismiss the dialog (Cancel)\\n\\n\
+         An unhandled dialog blocks the page, so call this as soon as a prior tool call \
+         reports one is open. Unhandled dialogs auto-dismiss after 30s.\\n\\n\
+         Example: browser_dialog({\\\"action\\\": \\\"ACCEPT\\\"})"
+    }
+
+    fn read_only() -> bool {
+        false // ACCEPT/DISMISS mutate page state (unblocks the renderer)
+    }
+
+    fn destructive() -> bool {
+        false
+    }
+
+    fn open_world() -> bool {
+        false
+    }
+
+    async fn execute(
+        &self,
+        args: Self::Args,
+        _ctx: ToolExecutionContext,
+    ) -> Result<ToolResponse<BrowserDialogOutput>, McpError> {
+        let watcher = self.manager.dialog_watcher();
+
+        match args.action {
+            BrowserDialogAction::Get => {
+                let pending = watcher.pending().await;
+                let summary = match &pending {
+                    Some(d) => format!("\x1b[36mDialog: {}\x1b[0m\n {}", d.dialog_type, d.message),
+                    None => "\x1b[36mDialog: none open\x1b[0m".to_string(),
+                };
+                let output = BrowserDialogOutput {
+                    success: true,
+                    handled: false,
+                    message: pending.as_ref().map(|d| d.message.clone()),
+                    dialog_type: pending.as_ref().map(|d| d.dialog_type.clone()),
+                    default_prompt: pending.and_then(|d| d.default_prompt),
+                };
+                Ok(ToolResponse::new(summary, output))
+            }
+            BrowserDialogAction::Accept => {
+                let pending = watcher.pending().await;
+                let handled = watcher.resolve(true, args.text.clone()).await;
+                let output = BrowserDialogOutput {
+                    success: handled,
+                    handled,
+                    message: pending.as_ref().map(|d| d.message.clone()),
+                    dialog_type: pending.as_ref().map(|d| d.dialog_type.clone()),
+                    default_prompt: pending.and_then(|d| d.default_prompt),
+                };
+                let summary = if handled {
+                    "\x1b[36mDialog: ACCEPT\x1b[0m".to_string()
+                } else {
+                    "\x1b[36mDialog: ACCEPT\x1b[0m\n No dialog was open".to_string()
+                };
+                Ok(ToolResponse::new(summary, output))
+            }
+            BrowserDialogAction::Dismiss => {
+                let pending = watcher.pending().await;
+                let handled = watcher.resolve(false, None).await;
+                let output = BrowserDialogOutput {
+                    success: handled,
+                    handled,
+                    message: pending.as_ref().map(|d| d.message.clone()),
+                    dialog_type: pending.as_ref().map(|d| d.dialog_type.clone()),
+                    default_prompt: pending.and_then(|d| d.default_prompt),
+                };
+                let summary = if handled {
+                    "\x1b[36mDialog: DISMISS\x1b[0m".to_string()
+                } else {
+                    "\x1b[36mDialog: DISMISS\x1b[0m\n No dialog was open".to_string()
+                };
+                Ok(ToolResponse::new(summary, output))
+            }
+        }
+    }
+}
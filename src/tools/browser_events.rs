@@ -0,0 +1,71 @@
+//! `browser_events` tool - drains buffered navigation/network/console/DOM
+//! signals since the last drain
+//!
+//! Backs the agent's event-driven step loop (see
+//! `agent::core::process_step`): instead of paying for a fresh screenshot
+//! every step, the agent drains this first and only re-observes the page
+//! when the drained events cross a material-change threshold.
+
+use std::sync::Arc;
+
+use kodegen_mcp_schema::browser::{
+    BROWSER_EVENTS, BrowserEventsArgs, BrowserEventsOutput, EventsPrompts,
+};
+use kodegen_mcp_schema::{McpError, Tool, ToolExecutionContext, ToolResponse};
+
+use crate::manager::BrowserManager;
+use crate::utils::BrowserEvent;
+
+#[derive(Clone)]
+pub struct BrowserEventsTool {
+    manager: Arc<BrowserManager>,
+}
+
+impl BrowserEventsTool {
+    pub fn new(manager: Arc<BrowserManager>) -> Self {
+        Self { manager }
+    }
+}
+
+impl Tool for BrowserEventsTool {
+    type Args = BrowserEventsArgs;
+    type Prompts = EventsPrompts;
+
+    fn name() -> &'static str {
+        BROWSER_EVENTS
+    }
+
+    fn description() -> &'static str {
+        "Drain buffered navigation/network-error/console-error/DOM-update events captured \
+         since the last call. Empties the buffer on every call.\\n\\n\
+         Example: browser_events({})"
+    }
+
+    fn read_only() -> bool {
+        true
+    }
+
+    async fn execute(
+        &self,
+        _args: Self::Args,
+        _ctx: ToolExecutionContext,
+    ) -> Result<ToolResponse<BrowserEventsOutput>, McpError> {
+        let events = self.manager.event_tracker().drain().await;
+        let event_json: Vec<serde_json::Value> = events
+            .iter()
+            .map(|e| serde_json::to_value(e).unwrap_or(serde_json::Value::Null))
+            .collect();
+        let navigated = events
+            .iter()
+            .any(|e| matches!(e, BrowserEvent::Navigated { .. }));
+
+        let summary = format!("\x1b[36mEvents: {} drained\x1b[0m", events.len());
+        let output = BrowserEventsOutput {
+            success: true,
+            count: events.len(),
+            navigated,
+            events: event_json,
+        };
+        Ok(ToolResponse::new(summary, output))
+    }
+}
@@ -0,0 +1,312 @@
+//! Batch form-fill tool - fills and submits a whole form in one call
+//!
+//! `BrowserTypeTextTool` only handles one field per call, so a login or
+//! signup form takes many round-trips and re-runs the
+//! scroll/focus/clear/type sequence for every field. This reuses that same
+//! per-field sequence but drives an ordered list of fields and an optional
+//! submit, returning a per-field result so a caller can recover from a
+//! partial fill instead of having the whole form fail on one bad selector.
+
+use std::sync::Arc;
+
+use kodegen_mcp_schema::browser::{
+    BROWSER_FILL_FORM, BrowserFillFormArgs, BrowserFillFormOutput, FieldKind, FillFormPrompts,
+    FormFieldResult,
+};
+use kodegen_mcp_schema::{McpError, Tool, ToolExecutionContext, ToolResponse};
+
+use crate::manager::BrowserManager;
+use crate::utils::validate_interaction_timeout;
+
+#[derive(Clone)]
+pub struct BrowserFillFormTool {
+    manager: Arc<BrowserManager>,
+}
+
+impl BrowserFillFormTool {
+    pub fn new(manager: Arc<BrowserManager>) -> Self {
+        Self { manager }
+    }
+}
+
+impl Tool for BrowserFillFormTool {
+    type Args = BrowserFillFormArgs;
+    type Prompts = FillFormPrompts;
+
+    fn name() -> &'static str {
+        BROWSER_FILL_FORM
+    }
+
+    fn description() -> &'static str {
+        "Fill and submit a whole form in one call instead of one browser_type_text per field.\\n\\n\
+         `fields` is an ordered list of {selector, value, field_kind}. `field_kind` is one of \
+         text, textarea, select, checkbox, radio, file. `checkbox`/`radio` take value \\\"true\\\" \
+         or \\\"false\\\". `select` matches `value` against an option's value or visible text. \
+         `submit_selector` (optional) is clicked after all fields fill, or `requestSubmit()`-ed \
+         if it resolves to a <form>.\\n\\n\
+         Each field fills independently and reports its own success/error, so a bad selector in \
+         one field doesn't block the rest.\\n\\n\
+         Example: browser_fill_form({\\\"fields\\\": [\
+         {\\\"selector\\\": \\\"#email\\\", \\\"value\\\": \\\"user@test.local\\\", \\\"field_kind\\\": \\\"text\\\"}, \
+         {\\\"selector\\\": \\\"#password\\\", \\\"value\\\": \\\"hunter2\\\", \\\"field_kind\\\": \\\"text\\\"}\
+         ], \\\"submit_selector\\\": \\\"#login-button\\\"})"
+    }
+
+    fn read_only() -> bool {
+        false // Fills and optionally submits the form
+    }
+
+    async fn execute(
+        &self,
+        args: Self::Args,
+        ctx: ToolExecutionContext,
+    ) -> Result<ToolResponse<BrowserFillFormOutput>, McpError> {
+        if args.fields.is_empty() {
+            return Err(McpError::invalid_arguments("fields cannot be empty"));
+        }
+
+        let connection_id = ctx.connection_id().unwrap_or("default");
+        let page = self.manager.get_current_page(connection_id).await.ok_or_else(|| {
+            McpError::Other(anyhow::anyhow!(
+                "No page available. You must call browser_navigate first to load a page."
+            ))
+        })?;
+
+        let timeout = validate_interaction_timeout(args.timeout_ms, 5000)?;
+
+        let mut results = Vec::with_capacity(args.fields.len());
+        for field in &args.fields {
+            let result = fill_field(
+                &page,
+                &field.selector,
+                &field.value,
+                field.field_kind,
+                timeout,
+            )
+            .await;
+            results.push(match result {
+                Ok(()) => FormFieldResult {
+                    selector: field.selector.clone(),
+                    success: true,
+                    error: None,
+                },
+                Err(e) => FormFieldResult {
+                    selector: field.selector.clone(),
+                    success: false,
+                    error: Some(e.to_string()),
+                },
+            });
+        }
+
+        let fields_ok = results.iter().filter(|r| r.success).count();
+        let all_ok = fields_ok == results.len();
+
+        let mut submitted = false;
+        let mut navigated = false;
+        if all_ok {
+            if let Some(submit_selector) = &args.submit_selector {
+                navigated = submit_form(&page, submit_selector, timeout).await?;
+                submitted = true;
+            }
+        }
+
+        let summary = format!(
+            "\x1b[33m\u{f11d} Fill Form\x1b[0m\n\
+             \u{f129} Fields: {}/{} · Submitted: {}{}",
+            fields_ok,
+            results.len(),
+            submitted,
+            if navigated { " · Navigated" } else { "" }
+        );
+
+        let output = BrowserFillFormOutput {
+            success: all_ok,
+            fields: results,
+            submitted,
+            message: if all_ok {
+                format!(
+                    "Filled {} fields{}",
+                    fields_ok,
+                    if navigated {
+                        " and navigated after submit"
+                    } else {
+                        ""
+                    }
+                )
+            } else {
+                format!(
+                    "Filled {}/{} fields; see per-field errors",
+                    fields_ok,
+                    args.fields.len()
+                )
+            },
+        };
+
+        Ok(ToolResponse::new(summary, output))
+    }
+}
+
+/// Wait for, focus, and fill a single field the same way
+/// `BrowserTypeTextTool` does, branching on `kind` for how the value is
+/// applied once focused.
+async fn fill_field(
+    page: &chromiumoxide::Page,
+    selector: &str,
+    value: &str,
+    kind: FieldKind,
+    timeout: std::time::Duration,
+) -> anyhow::Result<()> {
+    let element = crate::utils::wait_for_element(
+        page,
+        selector,
+        timeout,
+        None,
+        crate::utils::WaitCondition::Visible,
+    )
+    .await
+    .map_err(|e| anyhow::anyhow!("Element not found for selector '{}'. {}", selector, e))?;
+
+    element
+        .scroll_into_view()
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to scroll element into view: {}", e))?;
+
+    if kind != FieldKind::File {
+        let point = element
+            .clickable_point()
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to get clickable point: {}", e))?;
+        page.click(point)
+            .await
+            .map_err(|e| anyhow::anyhow!("Click to focus failed: {}", e))?;
+    }
+
+    // `value` is the only user-controlled piece interpolated below; it goes
+    // through `serde_json::to_string` so it lands as a properly escaped JS
+    // string literal, same escaping guarantee `json!()` gives the CDP
+    // `CallArgument` path used in `scroll.rs`/`browser_storage.rs`.
+    let escaped_value = serde_json::to_string(value)?;
+
+    match kind {
+        FieldKind::Text | FieldKind::Textarea => {
+            element
+                .call_js_fn("function() { this.value = ''; }", false)
+                .await
+                .map_err(|e| anyhow::anyhow!("Failed to clear field: {}", e))?;
+            element
+                .type_str(value)
+                .await
+                .map_err(|e| anyhow::anyhow!("Failed to type text: {}", e))?;
+        }
+        FieldKind::Select => {
+            let js = format!(
+                "function() {{ \
+                   const val = {escaped_value}; \
+                   for (const opt of this.options) {{ \
+                     if (opt.value === val || opt.textContent.trim() === val) {{ \
+                       this.value = opt.value; break; \
+                     }} \
+                   }} \
+                   this.dispatchEvent(new Event('change', {{ bubbles: true }})); \
+                 }}"
+            );
+            element
+                .call_js_fn(&js, false)
+                .await
+                .map_err(|e| anyhow::anyhow!("Failed to select option: {}", e))?;
+        }
+        FieldKind::Checkbox | FieldKind::Radio => {
+            let checked = value.trim().eq_ignore_ascii_case("true");
+            let js = format!(
+                "function() {{ \
+                   this.checked = {checked}; \
+                   this.dispatchEvent(new Event('change', {{ bubbles: true }})); \
+                 }}"
+            );
+            element
+                .call_js_fn(&js, false)
+                .await
+                .map_err(|e| anyhow::anyhow!("Failed to toggle checkbox/radio: {}", e))?;
+        }
+        FieldKind::File => {
+            element
+                .set_file_input_files(vec![value.to_string()])
+                .await
+                .map_err(|e| anyhow::anyhow!("Failed to set file input: {}", e))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Submit the form: click `submit_selector` unless it resolves to a `<form>`
+/// element itself, in which case call `requestSubmit()` on it directly.
+/// Returns whether a post-submit navigation was observed.
+///
+/// `BrowserFillFormArgs` (from `kodegen_mcp_schema`) has no
+/// `wait_for_navigation` field like `BrowserClickArgs` does, so there's no
+/// way for a caller to opt in the way `BrowserClickTool` lets one.
+/// Submitting a form commonly does navigate, though, so this waits for one
+/// anyway - bounded by `timeout` via `tokio::time::timeout` rather than
+/// `BrowserClickTool`'s unbounded `page.wait_for_navigation().await`, since
+/// here there's no explicit caller signal that a navigation is actually
+/// coming. A form that submits via `fetch`/XHR without navigating just
+/// times out this wait and `submit_form` returns `false`, not an error.
+async fn submit_form(
+    page: &chromiumoxide::Page,
+    submit_selector: &str,
+    timeout: std::time::Duration,
+) -> Result<bool, McpError> {
+    let element = crate::utils::wait_for_element(
+        page,
+        submit_selector,
+        timeout,
+        None,
+        crate::utils::WaitCondition::Clickable,
+    )
+    .await
+    .map_err(|e| {
+            McpError::Other(anyhow::anyhow!(
+                "Submit element not found for selector '{}'. {}",
+                submit_selector,
+                e
+            ))
+        })?;
+
+    element.scroll_into_view().await.map_err(|e| {
+        McpError::Other(anyhow::anyhow!(
+            "Failed to scroll submit element into view: {}",
+            e
+        ))
+    })?;
+
+    let tag_name: Option<String> = element
+        .call_js_fn("function() { return this.tagName; }", false)
+        .await
+        .ok()
+        .and_then(|v| v.result.value)
+        .and_then(|val| val.as_str().map(|s| s.to_uppercase()));
+
+    if tag_name.as_deref() == Some("FORM") {
+        element
+            .call_js_fn("function() { this.requestSubmit(); }", false)
+            .await
+            .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to submit form: {}", e)))?;
+    } else {
+        let point = element.clickable_point().await.map_err(|e| {
+            McpError::Other(anyhow::anyhow!(
+                "Failed to get clickable point for submit: {}",
+                e
+            ))
+        })?;
+        page.click(point)
+            .await
+            .map_err(|e| McpError::Other(anyhow::anyhow!("Submit click failed: {}", e)))?;
+    }
+
+    let navigated = tokio::time::timeout(timeout, page.wait_for_navigation())
+        .await
+        .is_ok_and(|r| r.is_ok());
+
+    Ok(navigated)
+}
@@ -0,0 +1,91 @@
+//! Browser history tool - goes back/forward in the current tab's session history
+//!
+//! Multi-step flows (search → result → back to search, wizard steps) need
+//! history navigation that doesn't re-fetch the page from scratch the way
+//! re-running `browser_navigate` with the previous URL would (losing any
+//! client-side state a plain reload wouldn't restore).
+
+use std::sync::Arc;
+
+use kodegen_mcp_schema::browser::{
+    BROWSER_HISTORY, BrowserHistoryArgs, BrowserHistoryDirection, BrowserHistoryOutput,
+    HistoryPrompts,
+};
+use kodegen_mcp_schema::{McpError, Tool, ToolExecutionContext, ToolResponse};
+
+use crate::manager::BrowserManager;
+
+#[derive(Clone)]
+pub struct BrowserHistoryTool {
+    manager: Arc<BrowserManager>,
+}
+
+impl BrowserHistoryTool {
+    pub fn new(manager: Arc<BrowserManager>) -> Self {
+        Self { manager }
+    }
+}
+
+impl Tool for BrowserHistoryTool {
+    type Args = BrowserHistoryArgs;
+    type Prompts = HistoryPrompts;
+
+    fn name() -> &'static str {
+        BROWSER_HISTORY
+    }
+
+    fn description() -> &'static str {
+        "Navigate the current tab's session history.\\n\\n\
+         `direction`: BACK or FORWARD.\\n\\n\
+         Example: browser_history({\\\"direction\\\": \\\"BACK\\\"})"
+    }
+
+    fn read_only() -> bool {
+        false // Changes the loaded page
+    }
+
+    async fn execute(
+        &self,
+        args: Self::Args,
+        ctx: ToolExecutionContext,
+    ) -> Result<ToolResponse<BrowserHistoryOutput>, McpError> {
+        let connection_id = ctx.connection_id().unwrap_or("default");
+        let page = self.manager.get_current_page(connection_id).await.ok_or_else(|| {
+            McpError::Other(anyhow::anyhow!(
+                "No page available. You must call browser_navigate first to load a page."
+            ))
+        })?;
+
+        // chromiumoxide exposes no typed "go back"/"go forward" call; drive
+        // `history.back()`/`history.forward()` directly, same as
+        // `browser_storage` falls back to raw JS for things the CDP client
+        // doesn't wrap.
+        let script = match args.direction {
+            BrowserHistoryDirection::Back => "window.history.back()",
+            BrowserHistoryDirection::Forward => "window.history.forward()",
+        };
+        page.evaluate(script).await.map_err(|e| {
+            McpError::Other(anyhow::anyhow!("Failed to navigate history: {}", e))
+        })?;
+
+        page.wait_for_navigation().await.map_err(|e| {
+            McpError::Other(anyhow::anyhow!(
+                "Failed to wait for page load after history navigation: {}",
+                e
+            ))
+        })?;
+
+        let url = page
+            .url()
+            .await
+            .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to get URL: {}", e)))?
+            .unwrap_or_default();
+
+        let summary = format!("\x1b[36mHistory: {:?} → {}\x1b[0m", args.direction, url);
+        let output = BrowserHistoryOutput {
+            success: true,
+            url,
+        };
+        Ok(ToolResponse::new(summary, output))
+    }
+}
@@ -0,0 +1,103 @@
+//! Browser hover tool - moves the mouse over an element without clicking
+//!
+//! Needed for hover-revealed menus and tooltips that `browser_click` can't
+//! exercise: moving the pointer onto the element is itself the trigger,
+//! clicking would dismiss or navigate away from whatever the hover reveals.
+
+use std::sync::Arc;
+
+use kodegen_mcp_schema::browser::{BROWSER_HOVER, BrowserHoverArgs, BrowserHoverOutput, HoverPrompts};
+use kodegen_mcp_schema::{McpError, Tool, ToolExecutionContext, ToolResponse};
+
+use crate::manager::BrowserManager;
+use crate::utils::validate_interaction_timeout;
+
+#[derive(Clone)]
+pub struct BrowserHoverTool {
+    manager: Arc<BrowserManager>,
+}
+
+impl BrowserHoverTool {
+    pub fn new(manager: Arc<BrowserManager>) -> Self {
+        Self { manager }
+    }
+}
+
+impl Tool for BrowserHoverTool {
+    type Args = BrowserHoverArgs;
+    type Prompts = HoverPrompts;
+
+    fn name() -> &'static str {
+        BROWSER_HOVER
+    }
+
+    fn description() -> &'static str {
+        "Move the mouse over an element to trigger hover menus/tooltips, without clicking.\\n\\n\
+         Example: browser_hover({\\\"selector\\\": \\\"#nav-menu\\\"})"
+    }
+
+    fn read_only() -> bool {
+        false // Can trigger hover-revealed UI state changes
+    }
+
+    async fn execute(
+        &self,
+        args: Self::Args,
+        ctx: ToolExecutionContext,
+    ) -> Result<ToolResponse<BrowserHoverOutput>, McpError> {
+        if args.selector.trim().is_empty() {
+            return Err(McpError::invalid_arguments("Selector cannot be empty"));
+        }
+
+        let connection_id = ctx.connection_id().unwrap_or("default");
+        let page = self.manager.get_current_page(connection_id).await.ok_or_else(|| {
+            McpError::Other(anyhow::anyhow!(
+                "No page available. You must call browser_navigate first to load a page."
+            ))
+        })?;
+
+        let timeout = validate_interaction_timeout(args.timeout_ms, 5000)?;
+        let element = crate::utils::wait_for_element(
+            &page,
+            &args.selector,
+            timeout,
+            None,
+            crate::utils::WaitCondition::Visible,
+        )
+            .await
+            .map_err(|e| {
+                McpError::Other(anyhow::anyhow!(
+                    "Element not found for selector '{}'. {}",
+                    args.selector,
+                    e
+                ))
+            })?;
+
+        element.scroll_into_view().await.map_err(|e| {
+            McpError::Other(anyhow::anyhow!("Failed to scroll element into view: {}", e))
+        })?;
+
+        let point = element.clickable_point().await.map_err(|e| {
+            McpError::Other(anyhow::anyhow!(
+                "Failed to get hoverable point for selector '{}'. Error: {}",
+                args.selector,
+                e
+            ))
+        })?;
+
+        page.move_mouse(point).await.map_err(|e| {
+            McpError::Other(anyhow::anyhow!(
+                "Failed to move mouse to selector '{}'. Error: {}",
+                args.selector,
+                e
+            ))
+        })?;
+
+        let summary = format!("\x1b[33m  Hover: {}\x1b[0m", args.selector);
+        let output = BrowserHoverOutput {
+            success: true,
+            selector: args.selector,
+        };
+        Ok(ToolResponse::new(summary, output))
+    }
+}
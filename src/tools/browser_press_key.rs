@@ -0,0 +1,142 @@
+//! Standalone key-press tool - sends special keys and modifier chords
+//!
+//! `BrowserTypeTextTool` injects literal characters via `type_str`, with no
+//! way to press Enter to submit a search, Tab between fields, or send
+//! Ctrl+A to select existing text. This dispatches a WebDriver-style key
+//! sequence (`{Enter}`, `{Tab}`, `{Ctrl+A}`, ...) via CDP
+//! `Input.dispatchKeyEvent`, optionally focusing a selector first.
+
+use std::sync::Arc;
+
+use kodegen_mcp_schema::browser::{
+    BROWSER_PRESS_KEY, BrowserPressKeyArgs, BrowserPressKeyOutput, PressKeyPrompts,
+};
+use kodegen_mcp_schema::{McpError, Tool, ToolExecutionContext, ToolResponse};
+
+use crate::manager::BrowserManager;
+use crate::utils::{
+    KeySegment, dispatch_key_token, parse_key_sequence, validate_interaction_timeout,
+};
+
+#[derive(Clone)]
+pub struct BrowserPressKeyTool {
+    manager: Arc<BrowserManager>,
+}
+
+impl BrowserPressKeyTool {
+    pub fn new(manager: Arc<BrowserManager>) -> Self {
+        Self { manager }
+    }
+}
+
+impl Tool for BrowserPressKeyTool {
+    type Args = BrowserPressKeyArgs;
+    type Prompts = PressKeyPrompts;
+
+    fn name() -> &'static str {
+        BROWSER_PRESS_KEY
+    }
+
+    fn description() -> &'static str {
+        "Send key presses to the page: special keys ({Enter}, {Tab}, {Backspace}, {Escape}, \
+         arrow keys, ...) and modifier chords ({Ctrl+A}, {Shift+Tab}, ...). Literal runs between \
+         tokens are typed as characters.\\n\\n\
+         `selector` (optional) focuses that element first; otherwise keys go to whatever \
+         currently has focus.\\n\\n\
+         Example: browser_press_key({\\\"selector\\\": \\\"#search\\\", \\\"keys\\\": \\\"rust lang{Enter}\\\"})\\n\
+         Example: browser_press_key({\\\"keys\\\": \\\"{Ctrl+A}{Backspace}\\\"})"
+    }
+
+    fn read_only() -> bool {
+        false // Key presses change page/form state
+    }
+
+    async fn execute(
+        &self,
+        args: Self::Args,
+        ctx: ToolExecutionContext,
+    ) -> Result<ToolResponse<BrowserPressKeyOutput>, McpError> {
+        let connection_id = ctx.connection_id().unwrap_or("default");
+        let page = self.manager.get_current_page(connection_id).await.ok_or_else(|| {
+            McpError::Other(anyhow::anyhow!(
+                "No page available. You must call browser_navigate first to load a page."
+            ))
+        })?;
+
+        let focused_element = if let Some(selector) = &args.selector {
+            let timeout = validate_interaction_timeout(args.timeout_ms, 5000)?;
+            let element = crate::utils::wait_for_element(
+                &page,
+                selector,
+                timeout,
+                None,
+                crate::utils::WaitCondition::Visible,
+            )
+                .await
+                .map_err(|e| {
+                    McpError::Other(anyhow::anyhow!(
+                        "Element not found for selector '{}'. {}",
+                        selector,
+                        e
+                    ))
+                })?;
+            element.scroll_into_view().await.map_err(|e| {
+                McpError::Other(anyhow::anyhow!("Failed to scroll element into view: {}", e))
+            })?;
+            let point = element.clickable_point().await.map_err(|e| {
+                McpError::Other(anyhow::anyhow!("Failed to get clickable point: {}", e))
+            })?;
+            page.click(point)
+                .await
+                .map_err(|e| McpError::Other(anyhow::anyhow!("Click to focus failed: {}", e)))?;
+            Some(element)
+        } else {
+            None
+        };
+
+        for segment in parse_key_sequence(&args.keys) {
+            match segment {
+                KeySegment::Literal(text) => {
+                    if let Some(element) = &focused_element {
+                        element.type_str(&text).await.map_err(|e| {
+                            McpError::Other(anyhow::anyhow!("Failed to type text: {}", e))
+                        })?;
+                    } else {
+                        for ch in text.chars() {
+                            let token = crate::utils::KeyToken {
+                                modifiers: vec![],
+                                key: ch.to_string(),
+                            };
+                            dispatch_key_token(&page, &token).await.map_err(|e| {
+                                McpError::Other(anyhow::anyhow!(
+                                    "Failed to type character '{}': {}",
+                                    ch,
+                                    e
+                                ))
+                            })?;
+                        }
+                    }
+                }
+                KeySegment::Key(token) => {
+                    dispatch_key_token(&page, &token).await.map_err(|e| {
+                        McpError::Other(anyhow::anyhow!(
+                            "Failed to send key '{}': {}",
+                            token.key,
+                            e
+                        ))
+                    })?;
+                }
+            }
+        }
+
+        let summary = format!("\x1b[33m\u{f11d} Press Key: {}\x1b[0m", args.keys);
+
+        let output = BrowserPressKeyOutput {
+            success: true,
+            keys: args.keys.clone(),
+            message: format!("Sent key sequence: {}", args.keys),
+        };
+
+        Ok(ToolResponse::new(summary, output))
+    }
+}
@@ -0,0 +1,164 @@
+//! PDF rendering of the current page via CDP `Page.printToPDF`.
+//!
+//! There's no `BrowserPrintPdfTool` here the way `BrowserScrollTool` or
+//! `BrowserUploadFileTool` look - a new MCP tool needs a `{Name}Args`/
+//! `{Name}Output`/`{Name}Prompts` triple and a `BROWSER_PRINT_PDF` constant
+//! registered in the external `kodegen_mcp_schema` crate (see every other
+//! file in this directory), and that crate lives outside this repo and
+//! can't be extended from here. [`print_page_to_pdf`] is the real
+//! mechanism the tool would call - once the schema grows the types, a
+//! `BrowserPrintPdfTool::execute` wiring them up to `get_current_page` is a
+//! few lines on top of this: [`print_page_to_pdf_base64`] already produces
+//! the `BrowserScreenshotOutput::base64`-shaped string such an output would
+//! carry, and [`save_page_pdf`] covers writing it to a path instead. All
+//! four functions (including [`render_html_to_pdf_base64`]) are re-exported
+//! from `crate::tools`, so they're reachable today by any caller embedding
+//! this crate directly, even without the MCP tool wiring.
+
+use anyhow::Context;
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use chromiumoxide::Page;
+use chromiumoxide_cdp::cdp::browser_protocol::page::PrintToPdfParams;
+
+use crate::manager::BrowserManager;
+
+/// Mirrors the parameter set WebDriver's "Print Page" command exposes.
+/// `page_ranges` is passed straight through to CDP, which already accepts
+/// WebDriver's `"1-3,5"` syntax natively - no local parsing needed.
+#[derive(Debug, Clone)]
+pub struct PdfOptions {
+    pub paper_width_in: f64,
+    pub paper_height_in: f64,
+    pub margin_top_in: f64,
+    pub margin_bottom_in: f64,
+    pub margin_left_in: f64,
+    pub margin_right_in: f64,
+    pub landscape: bool,
+    pub scale: f64,
+    pub background: bool,
+    pub page_ranges: Option<String>,
+}
+
+impl Default for PdfOptions {
+    /// US Letter, 1-inch margins, portrait, no scaling - matches Chrome's
+    /// own print-to-PDF defaults.
+    fn default() -> Self {
+        Self {
+            paper_width_in: 8.5,
+            paper_height_in: 11.0,
+            margin_top_in: 1.0,
+            margin_bottom_in: 1.0,
+            margin_left_in: 1.0,
+            margin_right_in: 1.0,
+            landscape: false,
+            scale: 1.0,
+            background: false,
+            page_ranges: None,
+        }
+    }
+}
+
+/// Render `page` to PDF and return the raw bytes, ready to write to disk or
+/// base64-encode into a JSON response.
+pub async fn print_page_to_pdf(page: &Page, options: &PdfOptions) -> anyhow::Result<Vec<u8>> {
+    let mut builder = PrintToPdfParams::builder()
+        .landscape(options.landscape)
+        .print_background(options.background)
+        .scale(options.scale)
+        .paper_width(options.paper_width_in)
+        .paper_height(options.paper_height_in)
+        .margin_top(options.margin_top_in)
+        .margin_bottom(options.margin_bottom_in)
+        .margin_left(options.margin_left_in)
+        .margin_right(options.margin_right_in);
+    if let Some(page_ranges) = &options.page_ranges {
+        builder = builder.page_ranges(page_ranges.clone());
+    }
+    let params = builder.build();
+    let bytes = page.pdf(params).await?;
+    Ok(bytes)
+}
+
+/// [`print_page_to_pdf`], base64-encoded - the shape `BrowserScreenshotOutput::base64`
+/// already expects, so a future `BrowserPrintPdfTool::execute` can assign
+/// this straight into its own output's `base64` field with `format: "pdf"`.
+pub async fn print_page_to_pdf_base64(
+    page: &Page,
+    options: &PdfOptions,
+) -> anyhow::Result<String> {
+    let bytes = print_page_to_pdf(page, options).await?;
+    Ok(BASE64.encode(bytes))
+}
+
+/// [`print_page_to_pdf`], written to `path` instead of (or in addition to)
+/// being returned inline - mirrors `BrowserScreenshotOutput::path` being set
+/// when a caller wants the file on disk rather than paying for a base64
+/// round-trip through the MCP response.
+pub async fn save_page_pdf(
+    page: &Page,
+    options: &PdfOptions,
+    path: &std::path::Path,
+) -> anyhow::Result<Vec<u8>> {
+    let bytes = print_page_to_pdf(page, options).await?;
+    tokio::fs::write(path, &bytes).await?;
+    Ok(bytes)
+}
+
+/// Percent-encode `input` for embedding in a `data:` URL - same minimal,
+/// no-external-dependency approach `search_engines::percent_encode` uses for
+/// query strings.
+fn percent_encode(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for byte in input.as_bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(*byte as char);
+            }
+            _ => out.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    out
+}
+
+/// Render `html` to PDF without an already-open page to print from: opens a
+/// throwaway tab to a `data:text/html` URL holding `html`, prints it, and
+/// closes the tab. Used for documents generated from in-memory
+/// markup - e.g. a research report's markdown summary rendered to HTML -
+/// rather than whatever's currently loaded in the browser; see
+/// `browser_research`'s module doc for the motivating caller. No MCP tool
+/// reaches this yet - that still needs a `format` field on
+/// `BrowserResearchArgs`/`BrowserResearchOutput` in the external
+/// `kodegen_mcp_schema` crate - but it's re-exported from `crate::tools`
+/// alongside [`print_page_to_pdf`], so it's already callable by any caller
+/// embedding this crate directly, the same reachability
+/// `AgentConfig::webauthn_authenticator` has. The tab is opened directly via
+/// `wrapper.browser().new_page` rather than `BrowserManager::open_tab`, so
+/// it's never added to the tab registry and needs no `close_tab` - just
+/// `page.close()` once printing is done.
+pub async fn render_html_to_pdf_base64(
+    browser_manager: &BrowserManager,
+    connection_id: &str,
+    html: &str,
+    options: &PdfOptions,
+) -> anyhow::Result<String> {
+    let data_url = format!("data:text/html;charset=utf-8,{}", percent_encode(html));
+
+    let browser_arc = browser_manager.get_or_launch_for(connection_id).await?;
+    let browser_guard = browser_arc.lock().await;
+    let wrapper = browser_guard
+        .as_ref()
+        .context("Browser not available after launch")?;
+    let page = wrapper
+        .browser()
+        .new_page(data_url.as_str())
+        .await
+        .context("Failed to open throwaway tab for PDF rendering")?;
+    drop(browser_guard);
+
+    let result = print_page_to_pdf_base64(&page, options).await;
+    if let Err(e) = page.close().await {
+        tracing::warn!("Failed to close throwaway PDF-rendering tab: {}", e);
+    }
+    result
+}
@@ -3,13 +3,32 @@
 //! Action-based interface: EXEC/READ/LIST/KILL
 //! Session management with connection isolation
 //! Timeout with background continuation
+//!
+//! `ResearchSession::pause`/`resume`/`set_tranquility` (see
+//! [`crate::research::worker`]) and `ResearchSession::stream_since` are fully
+//! wired into the crawl scheduler but not yet reachable here as
+//! `PAUSE`/`RESUME`/`SET`/`STREAM` actions - that requires new
+//! `BrowserResearchAction` variants in `kodegen_mcp_schema`, which this crate
+//! only consumes. `LIST` already surfaces each session's resulting worker
+//! state and last error below.
+//!
+//! Same schema-crate limit blocks a `format` argument (`markdown` default,
+//! `json`, `pdf`) on `READ`: `BrowserResearchArgs` has no such field, and
+//! `BrowserResearchOutput::summary` is already a plain `String`, not a
+//! `Content` blob a base64 PDF could ride in. The rendering half a `pdf`
+//! variant would need is real and ready regardless -
+//! [`crate::tools::browser_print_pdf::render_html_to_pdf_base64`] turns a
+//! research summary's markdown (rendered to HTML first) into PDF bytes via
+//! the same `Page.printToPDF` call `browser_print_pdf` already makes for a
+//! live page, just against a throwaway `data:` tab instead - `json` would be
+//! a one-line change (serialize `session_output` instead of formatting
+//! `display`) once there's a field to select it with.
 
 use crate::research::ResearchRegistry;
 use crate::utils::{DeepResearch, ResearchOptions};
 use kodegen_mcp_schema::browser::{
-    BrowserResearchAction, BrowserResearchArgs, BrowserResearchOutput,
-    ResearchSource, BROWSER_RESEARCH,
-    ResearchPrompts,
+    BROWSER_RESEARCH, BrowserResearchAction, BrowserResearchArgs, BrowserResearchOutput,
+    ResearchPrompts, ResearchSource,
 };
 use kodegen_mcp_schema::{McpError, Tool, ToolExecutionContext, ToolResponse};
 use std::sync::Arc;
@@ -33,7 +52,7 @@ impl BrowserResearchTool {
             registry: Arc::new(OnceCell::new()),
         }
     }
-    
+
     pub async fn get_registry(&self) -> ResearchRegistry {
         self.registry
             .get_or_init(|| async { ResearchRegistry::new() })
@@ -83,18 +102,20 @@ impl Tool for BrowserResearchTool {
     ) -> Result<ToolResponse<BrowserResearchOutput>, McpError> {
         let registry = self.get_registry().await;
         let connection_id = ctx.connection_id().unwrap_or("default");
-        
+
         match args.action {
             BrowserResearchAction::Research => {
                 // Validate query
                 let query = args.query.ok_or_else(|| {
                     McpError::invalid_arguments("query is required for RESEARCH action")
                 })?;
-                
+
                 if query.trim().is_empty() {
-                    return Err(McpError::invalid_arguments("Research query cannot be empty"));
+                    return Err(McpError::invalid_arguments(
+                        "Research query cannot be empty",
+                    ));
                 }
-                
+
                 // Build research options
                 let options = Some(ResearchOptions {
                     max_pages: args.max_pages,
@@ -104,23 +125,65 @@ impl Tool for BrowserResearchTool {
                     extract_tables: args.extract_tables,
                     extract_images: args.extract_images,
                     timeout_seconds: args.timeout_seconds,
+                    requests_per_second_per_host: args
+                        .requests_per_second_per_host
+                        .unwrap_or(ResearchOptions::default().requests_per_second_per_host),
+                    host_burst_capacity: args
+                        .host_burst_capacity
+                        .unwrap_or(ResearchOptions::default().host_burst_capacity),
+                    extra_headers: args.extra_headers.clone(),
+                    basic_auth: args
+                        .basic_auth_username
+                        .clone()
+                        .zip(args.basic_auth_password.clone()),
+                    block_patterns: args.block_patterns.clone(),
+                    cookie_profile: args.cookie_profile.clone(),
+                    ..Default::default()
                 });
-                
+
                 // Create DeepResearch instance
                 let research = DeepResearch::new(
                     self.browser_manager.clone(),
                     args.temperature,
                     args.max_tokens,
                 );
-                
+
                 // Create new session (always fresh for RESEARCH action)
                 let session = registry
-                    .create(connection_id, args.session, research, query.clone(), options)
+                    .create(
+                        connection_id,
+                        args.session,
+                        research,
+                        query.clone(),
+                        options,
+                    )
                     .await;
-                
+
+                // Bound how many crawls actually run at once; park (or, if
+                // the wait queue is full, get bumped by a random evictee)
+                // until a running slot is free rather than firing off an
+                // unbounded number of background crawls.
+                let permit = registry.admit().await.map_err(|e| {
+                    McpError::Other(anyhow::anyhow!(
+                        "Research queue is full, retry after {}ms",
+                        e.retry_after_ms
+                    ))
+                })?;
+
                 // Start research in background
                 session.start().await.map_err(McpError::Other)?;
-                
+
+                // Hold the permit until the crawl finishes so the running
+                // count reflects actual in-flight work, then release it so
+                // the admission queue can admit the next waiter.
+                let release_session = session.clone();
+                tokio::spawn(async move {
+                    while !release_session.is_complete().await {
+                        tokio::time::sleep(Duration::from_millis(250)).await;
+                    }
+                    drop(permit);
+                });
+
                 // Fire-and-forget: return immediately
                 if args.await_completion_ms == 0 {
                     let output = BrowserResearchOutput {
@@ -135,13 +198,13 @@ impl Tool for BrowserResearchTool {
                         sources: vec![],
                         error: None,
                     };
-                    
+
                     return Ok(ToolResponse::new(
                         "Research started in background. Use READ to check progress.",
                         output,
                     ));
                 }
-                
+
                 // Wait with timeout
                 let timeout_duration = Duration::from_millis(args.await_completion_ms);
                 let wait_result = tokio::time::timeout(timeout_duration, async {
@@ -154,10 +217,10 @@ impl Tool for BrowserResearchTool {
                     }
                 })
                 .await;
-                
+
                 // Read current state (whether timed out or completed)
                 let session_output = session.read(args.session).await;
-                
+
                 // Convert to output format using schema types
                 let sources: Vec<ResearchSource> = session_output
                     .results
@@ -168,7 +231,7 @@ impl Tool for BrowserResearchTool {
                         summary: Some(r.summary.clone()),
                     })
                     .collect();
-                
+
                 let display = if wait_result.is_ok() {
                     session_output.summary.clone()
                 } else {
@@ -178,38 +241,50 @@ impl Tool for BrowserResearchTool {
                         session_output.results.len()
                     )
                 };
-                
+
                 let output = BrowserResearchOutput {
                     session: args.session,
-                    status: if session_output.completed { "completed" } else { "running" }.to_string(),
+                    status: if session_output.completed {
+                        "completed"
+                    } else {
+                        "running"
+                    }
+                    .to_string(),
                     query: session_output.query,
                     pages_analyzed: session_output.results.len(),
                     max_pages: args.max_pages,
                     completed: session_output.completed,
-                    summary: if session_output.completed { Some(session_output.summary.clone()) } else { None },
+                    summary: if session_output.completed {
+                        Some(session_output.summary.clone())
+                    } else {
+                        None
+                    },
                     key_findings: None,
                     sources,
                     error: None,
                 };
-                
+
                 Ok(ToolResponse::new(display, output))
             }
-            
+
             BrowserResearchAction::Read => {
-                // Get existing session
-                let session = registry
-                    .get(connection_id, args.session)
-                    .await
-                    .ok_or_else(|| {
-                        McpError::invalid_arguments(format!(
-                            "Research session {} not found",
-                            args.session
-                        ))
-                    })?;
-                
-                // Read current state
-                let session_output = session.read(args.session).await;
-                
+                // Falls back to the persisted snapshot (see
+                // `ResearchRegistry::read_any`) when the session isn't live
+                // in this process - e.g. it survived a restart - so a
+                // client can still retrieve a prior `deep_research`
+                // invocation's result. A reaped or cleaned-up session is
+                // not found: both remove its snapshot from the store.
+                let session_output =
+                    registry
+                        .read_any(connection_id, args.session)
+                        .await
+                        .ok_or_else(|| {
+                            McpError::invalid_arguments(format!(
+                                "Research session {} not found",
+                                args.session
+                            ))
+                        })?;
+
                 // Opportunistic cleanup if session completed
                 if session_output.completed {
                     let registry_clone = registry.clone();
@@ -218,14 +293,14 @@ impl Tool for BrowserResearchTool {
                         let cleaned = registry_clone.cleanup_completed(&conn_id).await;
                         if cleaned > 0 {
                             tracing::info!(
-                                "Cleaned up {} completed session(s) for connection {}", 
-                                cleaned, 
+                                "Cleaned up {} completed session(s) for connection {}",
+                                cleaned,
                                 conn_id
                             );
                         }
                     });
                 }
-                
+
                 // Convert to output format using schema types
                 let sources: Vec<ResearchSource> = session_output
                     .results
@@ -236,47 +311,73 @@ impl Tool for BrowserResearchTool {
                         summary: Some(r.summary.clone()),
                     })
                     .collect();
-                
+
                 let output = BrowserResearchOutput {
                     session: args.session,
-                    status: if session_output.completed { "completed" } else { "running" }.to_string(),
+                    status: if session_output.completed {
+                        "completed"
+                    } else {
+                        "running"
+                    }
+                    .to_string(),
                     query: session_output.query.clone(),
                     pages_analyzed: session_output.results.len(),
                     max_pages: args.max_pages,
                     completed: session_output.completed,
-                    summary: if session_output.completed { Some(session_output.summary.clone()) } else { None },
+                    summary: if session_output.completed {
+                        Some(session_output.summary.clone())
+                    } else {
+                        None
+                    },
                     key_findings: None,
                     sources,
                     error: None,
                 };
-                
+
                 Ok(ToolResponse::new(session_output.summary, output))
             }
-            
+
             BrowserResearchAction::List => {
                 // List all sessions for this connection
                 let list_output = registry
                     .list(connection_id)
                     .await
                     .map_err(McpError::Other)?;
-                
+
                 // Build display string with session info
                 let display = if list_output.sessions.is_empty() {
-                    format!("No active research sessions for connection {}", list_output.connection_id)
+                    format!(
+                        "No active research sessions for connection {}",
+                        list_output.connection_id
+                    )
                 } else {
-                    let sessions_info: Vec<String> = list_output.sessions.iter()
-                        .map(|s| format!(
-                            "Session {}: query='{}', completed={}, results={}",
-                            s.session, s.query, s.completed, s.results_count
-                        ))
+                    let sessions_info: Vec<String> = list_output
+                        .sessions
+                        .iter()
+                        .map(|s| {
+                            format!(
+                                "Session {}: query='{}', completed={}, results={}, state={:?}{}",
+                                s.session,
+                                s.query,
+                                s.completed,
+                                s.results_count,
+                                s.state,
+                                s.last_error
+                                    .as_deref()
+                                    .map(|e| format!(", last_error='{e}'"))
+                                    .unwrap_or_default()
+                            )
+                        })
                         .collect();
                     format!(
-                        "Active research sessions for connection {}:\n{}",
+                        "Active research sessions for connection {}:\n{}\n\n{} crawl(s) running, {} queued",
                         list_output.connection_id,
-                        sessions_info.join("\n")
+                        sessions_info.join("\n"),
+                        list_output.running,
+                        list_output.queue_depth
                     )
                 };
-                
+
                 let output = BrowserResearchOutput {
                     session: args.session,
                     status: "list".to_string(),
@@ -289,10 +390,10 @@ impl Tool for BrowserResearchTool {
                     sources: vec![],
                     error: None,
                 };
-                
+
                 Ok(ToolResponse::new(display, output))
             }
-            
+
             BrowserResearchAction::Kill => {
                 // Get existing session
                 let session = registry
@@ -304,13 +405,13 @@ impl Tool for BrowserResearchTool {
                             args.session
                         ))
                     })?;
-                
+
                 // Kill the session
                 session.kill().await.map_err(McpError::Other)?;
-                
+
                 // Remove from registry
                 registry.remove(connection_id, args.session).await;
-                
+
                 let message = format!("Research session {} terminated", args.session);
                 let output = BrowserResearchOutput {
                     session: args.session,
@@ -324,7 +425,7 @@ impl Tool for BrowserResearchTool {
                     sources: vec![],
                     error: None,
                 };
-                
+
                 Ok(ToolResponse::new(message, output))
             }
         }
@@ -0,0 +1,131 @@
+//! Browser select tool - chooses an `<option>` in a `<select>` element
+//!
+//! Dropdowns can't be driven through `browser_click`/`browser_type_text`:
+//! clicking a native `<select>` opens a platform popup CDP can't see inside.
+//! This sets the element's `value` directly (matching by option `value` or
+//! visible label) and fires a `change` event, same as a user picking an
+//! option would.
+
+use std::sync::Arc;
+
+use kodegen_mcp_schema::browser::{BROWSER_SELECT, BrowserSelectArgs, BrowserSelectOutput, SelectPrompts};
+use kodegen_mcp_schema::{McpError, Tool, ToolExecutionContext, ToolResponse};
+
+use crate::manager::BrowserManager;
+use crate::utils::validate_interaction_timeout;
+
+#[derive(Clone)]
+pub struct BrowserSelectTool {
+    manager: Arc<BrowserManager>,
+}
+
+impl BrowserSelectTool {
+    pub fn new(manager: Arc<BrowserManager>) -> Self {
+        Self { manager }
+    }
+}
+
+impl Tool for BrowserSelectTool {
+    type Args = BrowserSelectArgs;
+    type Prompts = SelectPrompts;
+
+    fn name() -> &'static str {
+        BROWSER_SELECT
+    }
+
+    fn description() -> &'static str {
+        "Select an option in a `<select>` dropdown by matching `value` or visible label.\\n\\n\
+         Example: browser_select({\\\"selector\\\": \\\"#country\\\", \\\"value\\\": \\\"US\\\"})\\n\
+         Example: browser_select({\\\"selector\\\": \\\"#country\\\", \\\"label\\\": \\\"United States\\\"})"
+    }
+
+    fn read_only() -> bool {
+        false // Changes form state
+    }
+
+    async fn execute(
+        &self,
+        args: Self::Args,
+        ctx: ToolExecutionContext,
+    ) -> Result<ToolResponse<BrowserSelectOutput>, McpError> {
+        if args.selector.trim().is_empty() {
+            return Err(McpError::invalid_arguments("Selector cannot be empty"));
+        }
+        let Some(value) = args.value.clone().or_else(|| args.label.clone()) else {
+            return Err(McpError::invalid_arguments(
+                "Either 'value' or 'label' is required",
+            ));
+        };
+        let by_label = args.value.is_none();
+
+        let connection_id = ctx.connection_id().unwrap_or("default");
+        let page = self.manager.get_current_page(connection_id).await.ok_or_else(|| {
+            McpError::Other(anyhow::anyhow!(
+                "No page available. You must call browser_navigate first to load a page."
+            ))
+        })?;
+
+        let timeout = validate_interaction_timeout(args.timeout_ms, 5000)?;
+        let element = crate::utils::wait_for_element(
+            &page,
+            &args.selector,
+            timeout,
+            None,
+            crate::utils::WaitCondition::Clickable,
+        )
+            .await
+            .map_err(|e| {
+                McpError::Other(anyhow::anyhow!(
+                    "Element not found for selector '{}'. {}",
+                    args.selector,
+                    e
+                ))
+            })?;
+
+        // `value`/`by_label` are embedded as JSON literals (not interpolated as
+        // raw strings) so quotes or backslashes in the match target can't break
+        // out of the generated function body - same concern `browser_storage`
+        // solves with a real CDP call argument, but `Element::call_js_fn` only
+        // takes a source string.
+        let match_value_json = serde_json::to_string(&value)
+            .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to encode value: {}", e)))?;
+        let js = format!(
+            "function() {{ \
+                 const matchValue = {match_value_json}; \
+                 const opts = Array.from(this.options); \
+                 const opt = {by_label} \
+                     ? opts.find(o => o.text === matchValue) \
+                     : opts.find(o => o.value === matchValue); \
+                 if (!opt) return false; \
+                 this.value = opt.value; \
+                 this.dispatchEvent(new Event('change', {{ bubbles: true }})); \
+                 return true; \
+             }}"
+        );
+        let selected: bool = element
+            .call_js_fn(&js, false)
+            .await
+            .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to select option: {}", e)))?
+            .result
+            .value
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+        if !selected {
+            return Err(McpError::Other(anyhow::anyhow!(
+                "No option matching {} '{}' found in '{}'",
+                if by_label { "label" } else { "value" },
+                value,
+                args.selector
+            )));
+        }
+
+        let summary = format!("\x1b[33m  Select: {} → {}\x1b[0m", args.selector, value);
+        let output = BrowserSelectOutput {
+            success: true,
+            selector: args.selector,
+            value,
+        };
+        Ok(ToolResponse::new(summary, output))
+    }
+}
@@ -0,0 +1,121 @@
+//! `browser_set_headers` tool - custom headers, basic-auth credentials, and
+//! request-blocking rules for authenticated/filtered crawling
+//!
+//! Populates `BrowserManager`'s shared [`crate::utils::NetworkOverrides`],
+//! consulted by every subsequent navigation (`browser_navigate`,
+//! `browser_research`, `start_browser_research`). Each field is independent:
+//! a call that only sets `block_patterns` leaves previously configured
+//! headers/auth untouched.
+
+use std::sync::Arc;
+
+use kodegen_mcp_schema::browser::{
+    BROWSER_SET_HEADERS, BrowserSetHeadersArgs, BrowserSetHeadersOutput, SetHeadersPrompts,
+};
+use kodegen_mcp_schema::{McpError, Tool, ToolExecutionContext, ToolResponse};
+
+use crate::manager::BrowserManager;
+use crate::utils::BasicAuth;
+
+#[derive(Clone)]
+pub struct BrowserSetHeadersTool {
+    manager: Arc<BrowserManager>,
+}
+
+impl BrowserSetHeadersTool {
+    pub fn new(manager: Arc<BrowserManager>) -> Self {
+        Self { manager }
+    }
+}
+
+impl Tool for BrowserSetHeadersTool {
+    type Args = BrowserSetHeadersArgs;
+    type Prompts = SetHeadersPrompts;
+
+    fn name() -> &'static str {
+        BROWSER_SET_HEADERS
+    }
+
+    fn description() -> &'static str {
+        "Configure custom headers, HTTP basic-auth credentials, and request-blocking rules \
+         applied to every navigation (browser_navigate, browser_research, start_browser_research).\\n\\n\
+         - `headers`: replaces the custom headers sent with every request (e.g. Authorization, Cookie)\\n\
+         - `auth_origin` + `auth_username` + `auth_password`: stores basic-auth credentials answered \
+         for `Fetch.authRequired` challenges from that origin (e.g. \\\"https://internal.example.com\\\")\\n\
+         - `block_patterns`: replaces the list of URL substrings whose requests get blocked (e.g. to \
+         skip images/ads during extraction)\\n\\n\
+         Fields left unset leave the corresponding store untouched.\\n\\n\
+         Example: browser_set_headers({\\\"headers\\\": {\\\"Authorization\\\": \\\"Bearer abc123\\\"}})"
+    }
+
+    fn read_only() -> bool {
+        false // Mutates shared navigation overrides
+    }
+
+    fn destructive() -> bool {
+        false
+    }
+
+    fn open_world() -> bool {
+        false
+    }
+
+    async fn execute(
+        &self,
+        args: Self::Args,
+        _ctx: ToolExecutionContext,
+    ) -> Result<ToolResponse<BrowserSetHeadersOutput>, McpError> {
+        let overrides = self.manager.network_overrides();
+
+        let headers_count = if let Some(headers) = args.headers {
+            let count = headers.len();
+            overrides.set_headers(headers).await;
+            Some(count)
+        } else {
+            None
+        };
+
+        let auth_configured = if let Some(origin) = args.auth_origin.clone() {
+            let username = args.auth_username.ok_or_else(|| {
+                McpError::invalid_arguments("auth_username is required when auth_origin is set")
+            })?;
+            let password = args.auth_password.ok_or_else(|| {
+                McpError::invalid_arguments("auth_password is required when auth_origin is set")
+            })?;
+            overrides
+                .set_auth(origin, Some(BasicAuth { username, password }))
+                .await;
+            true
+        } else {
+            false
+        };
+
+        let block_patterns_count = if let Some(patterns) = args.block_patterns {
+            let count = patterns.len();
+            overrides.set_block_patterns(patterns).await;
+            Some(count)
+        } else {
+            None
+        };
+
+        let summary = format!(
+            "\x1b[36mSet Headers\x1b[0m\n Headers: {} · Auth: {} · Block patterns: {}",
+            headers_count.map_or_else(|| "unchanged".to_string(), |n| n.to_string()),
+            if auth_configured {
+                "configured"
+            } else {
+                "unchanged"
+            },
+            block_patterns_count.map_or_else(|| "unchanged".to_string(), |n| n.to_string()),
+        );
+
+        let output = BrowserSetHeadersOutput {
+            success: true,
+            headers_count,
+            auth_configured,
+            block_patterns_count,
+        };
+
+        Ok(ToolResponse::new(summary, output))
+    }
+}
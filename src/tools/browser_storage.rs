@@ -0,0 +1,247 @@
+//! `localStorage`/`sessionStorage` access for the current page's origin
+//!
+//! Complements `browser_cookies`: some login flows persist session state in
+//! Web Storage rather than (or in addition to) cookies, so a research or
+//! agent run that logs in once needs a way to read or seed it back.
+
+use std::sync::Arc;
+
+use chromiumoxide_cdp::cdp::js_protocol::runtime::{CallArgument, CallFunctionOnParams};
+use kodegen_mcp_schema::browser::{
+    BROWSER_STORAGE, BrowserStorageAction, BrowserStorageArea, BrowserStorageArgs,
+    BrowserStorageOutput, StorageEntry, StoragePrompts,
+};
+use kodegen_mcp_schema::{McpError, Tool, ToolExecutionContext, ToolResponse};
+use serde_json::{Value, json};
+
+use crate::manager::BrowserManager;
+
+/// `storage` is never user-controlled (it's picked from a closed enum), so
+/// it's safe to interpolate directly into the function body. Anything
+/// user-controlled (keys, values) is passed as a real CDP call argument
+/// instead - same split as `scroll.rs`'s `CallFunctionOnParams` usage.
+fn storage_object(area: BrowserStorageArea) -> &'static str {
+    match area {
+        BrowserStorageArea::Local => "window.localStorage",
+        BrowserStorageArea::Session => "window.sessionStorage",
+    }
+}
+
+#[derive(Clone)]
+pub struct BrowserStorageTool {
+    manager: Arc<BrowserManager>,
+}
+
+impl BrowserStorageTool {
+    pub fn new(manager: Arc<BrowserManager>) -> Self {
+        Self { manager }
+    }
+}
+
+impl Tool for BrowserStorageTool {
+    type Args = BrowserStorageArgs;
+    type Prompts = StoragePrompts;
+
+    fn name() -> &'static str {
+        BROWSER_STORAGE
+    }
+
+    fn description() -> &'static str {
+        "Read and write localStorage/sessionStorage for the current page's origin.\\n\\n\
+         Actions: GET_ALL, GET (requires `key`), SET (requires `key` and `value`), \
+         REMOVE (requires `key`), CLEAR.\\n\
+         `area` selects `local` (default) or `session` storage.\\n\\n\
+         Example: browser_storage({\\\"action\\\": \\\"SET\\\", \\\"key\\\": \\\"token\\\", \\\"value\\\": \\\"abc123\\\"})"
+    }
+
+    fn read_only() -> bool {
+        false // SET/REMOVE/CLEAR mutate storage
+    }
+
+    fn destructive() -> bool {
+        false
+    }
+
+    fn open_world() -> bool {
+        false
+    }
+
+    async fn execute(
+        &self,
+        args: Self::Args,
+        _ctx: ToolExecutionContext,
+    ) -> Result<ToolResponse<BrowserStorageOutput>, McpError> {
+        let browser_arc = self
+            .manager
+            .get_or_launch()
+            .await
+            .map_err(|e| McpError::Other(anyhow::anyhow!("Browser error: {}", e)))?;
+
+        let browser_guard = browser_arc.lock().await;
+        let wrapper = browser_guard.as_ref().ok_or_else(|| {
+            McpError::Other(anyhow::anyhow!(
+                "Browser not available. This is an internal error - please report it."
+            ))
+        })?;
+
+        let page = crate::browser::get_current_page(wrapper)
+            .await
+            .map_err(|e| {
+                McpError::Other(anyhow::anyhow!(
+                    "Failed to get page. Did you call browser_navigate first? Error: {}",
+                    e
+                ))
+            })?;
+
+        let storage = storage_object(args.area.unwrap_or(BrowserStorageArea::Local));
+
+        match args.action {
+            BrowserStorageAction::GetAll => {
+                let call = CallFunctionOnParams::builder()
+                    .function_declaration(format!(
+                        "() => {{ const s = {storage}; const out = {{}}; \
+                         for (let i = 0; i < s.length; i++) {{ const k = s.key(i); out[k] = s.getItem(k); }} \
+                         return out; }}"
+                    ))
+                    .build()
+                    .map_err(|e| {
+                        McpError::Other(anyhow::anyhow!("Failed to build storage call: {}", e))
+                    })?;
+
+                let value = page.evaluate_function(call).await.map_err(|e| {
+                    McpError::Other(anyhow::anyhow!("Failed to read storage: {}", e))
+                })?;
+                let map: std::collections::HashMap<String, String> =
+                    value.into_value().map_err(|e| {
+                        McpError::Other(anyhow::anyhow!("Failed to parse storage: {}", e))
+                    })?;
+
+                let entries: Vec<StorageEntry> = map
+                    .into_iter()
+                    .map(|(key, value)| StorageEntry { key, value })
+                    .collect();
+                let count = entries.len();
+                let output = BrowserStorageOutput {
+                    success: true,
+                    entries,
+                    value: None,
+                };
+                Ok(ToolResponse::new(
+                    format!("\x1b[36mStorage: GET_ALL\x1b[0m\n Entries: {}", count),
+                    output,
+                ))
+            }
+            BrowserStorageAction::Get => {
+                let key = args
+                    .key
+                    .ok_or_else(|| McpError::invalid_arguments("key is required for GET"))?;
+
+                let call = CallFunctionOnParams::builder()
+                    .function_declaration(format!("(key) => {storage}.getItem(key)"))
+                    .argument(CallArgument::builder().value(json!(key)).build())
+                    .build()
+                    .map_err(|e| {
+                        McpError::Other(anyhow::anyhow!("Failed to build storage call: {}", e))
+                    })?;
+
+                let value = page.evaluate_function(call).await.map_err(|e| {
+                    McpError::Other(anyhow::anyhow!("Failed to read storage: {}", e))
+                })?;
+                let value_str = match value.into_value().map_err(|e| {
+                    McpError::Other(anyhow::anyhow!("Failed to parse storage: {}", e))
+                })? {
+                    Value::String(s) => Some(s),
+                    Value::Null => None,
+                    other => Some(other.to_string()),
+                };
+
+                let output = BrowserStorageOutput {
+                    success: true,
+                    entries: vec![],
+                    value: value_str,
+                };
+                Ok(ToolResponse::new(
+                    format!("\x1b[36mStorage: GET {}\x1b[0m", key),
+                    output,
+                ))
+            }
+            BrowserStorageAction::Set => {
+                let key = args
+                    .key
+                    .ok_or_else(|| McpError::invalid_arguments("key is required for SET"))?;
+                let value = args
+                    .value
+                    .ok_or_else(|| McpError::invalid_arguments("value is required for SET"))?;
+
+                let call = CallFunctionOnParams::builder()
+                    .function_declaration(format!("(key, value) => {storage}.setItem(key, value)"))
+                    .argument(CallArgument::builder().value(json!(key)).build())
+                    .argument(CallArgument::builder().value(json!(value)).build())
+                    .build()
+                    .map_err(|e| {
+                        McpError::Other(anyhow::anyhow!("Failed to build storage call: {}", e))
+                    })?;
+
+                page.evaluate_function(call).await.map_err(|e| {
+                    McpError::Other(anyhow::anyhow!("Failed to write storage: {}", e))
+                })?;
+
+                let output = BrowserStorageOutput {
+                    success: true,
+                    entries: vec![],
+                    value: None,
+                };
+                Ok(ToolResponse::new(
+                    format!("\x1b[36mStorage: SET {}\x1b[0m", key),
+                    output,
+                ))
+            }
+            BrowserStorageAction::Remove => {
+                let key = args
+                    .key
+                    .ok_or_else(|| McpError::invalid_arguments("key is required for REMOVE"))?;
+
+                let call = CallFunctionOnParams::builder()
+                    .function_declaration(format!("(key) => {storage}.removeItem(key)"))
+                    .argument(CallArgument::builder().value(json!(key)).build())
+                    .build()
+                    .map_err(|e| {
+                        McpError::Other(anyhow::anyhow!("Failed to build storage call: {}", e))
+                    })?;
+
+                page.evaluate_function(call).await.map_err(|e| {
+                    McpError::Other(anyhow::anyhow!("Failed to remove storage key: {}", e))
+                })?;
+
+                let output = BrowserStorageOutput {
+                    success: true,
+                    entries: vec![],
+                    value: None,
+                };
+                Ok(ToolResponse::new(
+                    format!("\x1b[36mStorage: REMOVE {}\x1b[0m", key),
+                    output,
+                ))
+            }
+            BrowserStorageAction::Clear => {
+                let call = CallFunctionOnParams::builder()
+                    .function_declaration(format!("() => {storage}.clear()"))
+                    .build()
+                    .map_err(|e| {
+                        McpError::Other(anyhow::anyhow!("Failed to build storage call: {}", e))
+                    })?;
+
+                page.evaluate_function(call).await.map_err(|e| {
+                    McpError::Other(anyhow::anyhow!("Failed to clear storage: {}", e))
+                })?;
+
+                let output = BrowserStorageOutput {
+                    success: true,
+                    entries: vec![],
+                    value: None,
+                };
+                Ok(ToolResponse::new("\x1b[36mStorage: CLEAR\x1b[0m", output))
+            }
+        }
+    }
+}
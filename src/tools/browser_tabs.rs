@@ -0,0 +1,143 @@
+//! Browser tabs tool - open/switch/close/list named tabs
+//!
+//! `BrowserManager`'s named tab registry (`open_tab`/`switch_tab`/
+//! `close_tab`/`list_tabs`) has existed since the multi-tab registry
+//! replaced the single `current_page` model, but nothing exposed it over
+//! MCP - every tool still only ever saw whatever tab `browser_navigate`
+//! last made active. This is the control surface for multi-tab flows
+//! (compare two pages side by side, open a link in a new tab and return).
+
+use std::sync::Arc;
+
+use kodegen_mcp_schema::browser::{
+    BROWSER_TABS, BrowserTabsAction, BrowserTabsArgs, BrowserTabsOutput, TabsPrompts,
+};
+use kodegen_mcp_schema::{McpError, Tool, ToolExecutionContext, ToolResponse};
+
+use crate::manager::BrowserManager;
+
+#[derive(Clone)]
+pub struct BrowserTabsTool {
+    manager: Arc<BrowserManager>,
+}
+
+impl BrowserTabsTool {
+    pub fn new(manager: Arc<BrowserManager>) -> Self {
+        Self { manager }
+    }
+}
+
+impl Tool for BrowserTabsTool {
+    type Args = BrowserTabsArgs;
+    type Prompts = TabsPrompts;
+
+    fn name() -> &'static str {
+        BROWSER_TABS
+    }
+
+    fn description() -> &'static str {
+        "Manage named browser tabs.\\n\\n\
+         Actions: OPEN (requires `name` and `url`), SWITCH (requires `name`), \
+         CLOSE (requires `name`), LIST.\\n\\n\
+         Example: browser_tabs({\\\"action\\\": \\\"OPEN\\\", \\\"name\\\": \\\"docs\\\", \\\"url\\\": \\\"https://doc.rust-lang.org\\\"})\\n\
+         Example: browser_tabs({\\\"action\\\": \\\"SWITCH\\\", \\\"name\\\": \\\"docs\\\"})"
+    }
+
+    fn read_only() -> bool {
+        false // OPEN/SWITCH/CLOSE all mutate which tab is active
+    }
+
+    fn open_world() -> bool {
+        true // OPEN navigates to an external URL
+    }
+
+    async fn execute(
+        &self,
+        args: Self::Args,
+        ctx: ToolExecutionContext,
+    ) -> Result<ToolResponse<BrowserTabsOutput>, McpError> {
+        let connection_id = ctx.connection_id().unwrap_or("default");
+
+        match args.action {
+            BrowserTabsAction::Open => {
+                let name = args
+                    .name
+                    .ok_or_else(|| McpError::invalid_arguments("name is required for OPEN"))?;
+                let url = args
+                    .url
+                    .ok_or_else(|| McpError::invalid_arguments("url is required for OPEN"))?;
+                self.manager
+                    .open_tab(connection_id, name.clone(), &url)
+                    .await
+                    .map_err(|e| {
+                        McpError::Other(anyhow::anyhow!("Failed to open tab '{}': {}", name, e))
+                    })?;
+
+                Ok(ToolResponse::new(
+                    format!("\x1b[36mTabs: OPEN {} → {}\x1b[0m", name, url),
+                    BrowserTabsOutput {
+                        success: true,
+                        tabs: self.manager.list_tabs(connection_id).await,
+                        active: Some(name),
+                    },
+                ))
+            }
+            BrowserTabsAction::Switch => {
+                let name = args
+                    .name
+                    .ok_or_else(|| McpError::invalid_arguments("name is required for SWITCH"))?;
+                self.manager
+                    .switch_tab(connection_id, &name)
+                    .await
+                    .map_err(|e| {
+                        McpError::Other(anyhow::anyhow!(
+                            "Failed to switch to tab '{}': {}",
+                            name,
+                            e
+                        ))
+                    })?;
+
+                Ok(ToolResponse::new(
+                    format!("\x1b[36mTabs: SWITCH {}\x1b[0m", name),
+                    BrowserTabsOutput {
+                        success: true,
+                        tabs: self.manager.list_tabs(connection_id).await,
+                        active: Some(name),
+                    },
+                ))
+            }
+            BrowserTabsAction::Close => {
+                let name = args
+                    .name
+                    .ok_or_else(|| McpError::invalid_arguments("name is required for CLOSE"))?;
+                self.manager
+                    .close_tab(connection_id, &name)
+                    .await
+                    .map_err(|e| {
+                        McpError::Other(anyhow::anyhow!("Failed to close tab '{}': {}", name, e))
+                    })?;
+
+                let tabs = self.manager.list_tabs(connection_id).await;
+                Ok(ToolResponse::new(
+                    format!("\x1b[36mTabs: CLOSE {}\x1b[0m", name),
+                    BrowserTabsOutput {
+                        success: true,
+                        active: self.manager.active_tab_name(connection_id).await,
+                        tabs,
+                    },
+                ))
+            }
+            BrowserTabsAction::List => {
+                let tabs = self.manager.list_tabs(connection_id).await;
+                Ok(ToolResponse::new(
+                    format!("\x1b[36mTabs: LIST ({})\x1b[0m", tabs.len()),
+                    BrowserTabsOutput {
+                        success: true,
+                        active: self.manager.active_tab_name(connection_id).await,
+                        tabs,
+                    },
+                ))
+            }
+        }
+    }
+}
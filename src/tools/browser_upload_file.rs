@@ -0,0 +1,128 @@
+//! Browser upload-file tool - sets files on a file input without a native OS dialog
+//!
+//! `<input type="file">` can't be driven through `browser_type_text`
+//! (typing into it opens a real OS file picker CDP has no access to). This
+//! uses `DOM.setFileInputFiles` to attach local file paths directly, the
+//! same mechanism WebDriver's "File Upload" command uses.
+
+use std::sync::Arc;
+
+use chromiumoxide::cdp::browser_protocol::dom::SetFileInputFilesParams;
+use kodegen_mcp_schema::browser::{
+    BROWSER_UPLOAD_FILE, BrowserUploadFileArgs, BrowserUploadFileOutput, UploadFilePrompts,
+};
+use kodegen_mcp_schema::{McpError, Tool, ToolExecutionContext, ToolResponse};
+
+use crate::manager::BrowserManager;
+use crate::utils::validate_interaction_timeout;
+
+#[derive(Clone)]
+pub struct BrowserUploadFileTool {
+    manager: Arc<BrowserManager>,
+}
+
+impl BrowserUploadFileTool {
+    pub fn new(manager: Arc<BrowserManager>) -> Self {
+        Self { manager }
+    }
+}
+
+impl Tool for BrowserUploadFileTool {
+    type Args = BrowserUploadFileArgs;
+    type Prompts = UploadFilePrompts;
+
+    fn name() -> &'static str {
+        BROWSER_UPLOAD_FILE
+    }
+
+    fn description() -> &'static str {
+        "Attach local file(s) to a `<input type=\\\"file\\\">` element, bypassing the native \
+         OS file picker.\\n\\n\
+         Example: browser_upload_file({\\\"selector\\\": \\\"input[type=file]\\\", \\\"paths\\\": [\\\"/tmp/resume.pdf\\\"]})"
+    }
+
+    fn read_only() -> bool {
+        false // Changes form state
+    }
+
+    async fn execute(
+        &self,
+        args: Self::Args,
+        ctx: ToolExecutionContext,
+    ) -> Result<ToolResponse<BrowserUploadFileOutput>, McpError> {
+        if args.selector.trim().is_empty() {
+            return Err(McpError::invalid_arguments("Selector cannot be empty"));
+        }
+        if args.paths.is_empty() {
+            return Err(McpError::invalid_arguments(
+                "At least one path is required",
+            ));
+        }
+        for path in &args.paths {
+            if !std::path::Path::new(path).is_file() {
+                return Err(McpError::invalid_arguments(format!(
+                    "File does not exist: {}",
+                    path
+                )));
+            }
+        }
+
+        let connection_id = ctx.connection_id().unwrap_or("default");
+        let page = self.manager.get_current_page(connection_id).await.ok_or_else(|| {
+            McpError::Other(anyhow::anyhow!(
+                "No page available. You must call browser_navigate first to load a page."
+            ))
+        })?;
+
+        let timeout = validate_interaction_timeout(args.timeout_ms, 5000)?;
+        // File inputs are frequently styled `display:none`/zero-size with a
+        // visible custom control triggering them, so only DOM presence is
+        // required here.
+        let element = crate::utils::wait_for_element(
+            &page,
+            &args.selector,
+            timeout,
+            None,
+            crate::utils::WaitCondition::Present,
+        )
+            .await
+            .map_err(|e| {
+                McpError::Other(anyhow::anyhow!(
+                    "Element not found for selector '{}'. {}",
+                    args.selector,
+                    e
+                ))
+            })?;
+
+        let backend_node_id = element.backend_node_id();
+        page.execute(
+            SetFileInputFilesParams::builder()
+                .files(args.paths.clone())
+                .backend_node_id(backend_node_id)
+                .build()
+                .map_err(|e| {
+                    McpError::Other(anyhow::anyhow!("Failed to build upload params: {}", e))
+                })?,
+        )
+        .await
+        .map_err(|e| {
+            McpError::Other(anyhow::anyhow!(
+                "Failed to set files on selector '{}'. Error: {}",
+                args.selector,
+                e
+            ))
+        })?;
+
+        let summary = format!(
+            "\x1b[33m  Upload: {} → {} file(s)\x1b[0m",
+            args.selector,
+            args.paths.len()
+        );
+        let output = BrowserUploadFileOutput {
+            success: true,
+            selector: args.selector,
+            paths: args.paths,
+        };
+        Ok(ToolResponse::new(summary, output))
+    }
+}
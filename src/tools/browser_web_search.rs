@@ -1,10 +1,17 @@
 //! `browser_web_search` MCP tool implementation
 //!
 //! Performs web searches and returns structured results with titles, URLs, and snippets.
+//!
+//! Only fetches the first page of fused results today. Paging further
+//! (`crate::web_search::SearchCursor` / `search_paginated`) is implemented
+//! and usable as a library call, but isn't wired up here yet: that needs a
+//! `limit`/`offset`/`page` field on `WebSearchArgs` and a `next_offset`/
+//! `has_more` field on `WebSearchOutput`, both defined in the external
+//! `kodegen_mcp_schema` crate, which isn't part of this tree.
 
 use kodegen_mcp_schema::browser::{BROWSER_WEB_SEARCH, WebSearchPrompts};
 use kodegen_mcp_schema::citescrape::{WebSearchArgs, WebSearchOutput};
-use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse, McpError};
+use kodegen_mcp_schema::{McpError, Tool, ToolExecutionContext, ToolResponse};
 
 // =============================================================================
 // Tool Struct
@@ -62,7 +69,13 @@ impl Tool for BrowserWebSearchTool {
         true
     }
 
-    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<WebSearchOutput>, McpError> {
+    async fn execute(
+        &self,
+        args: Self::Args,
+        ctx: ToolExecutionContext,
+    ) -> Result<ToolResponse<WebSearchOutput>, McpError> {
+        let started_at = std::time::Instant::now();
+
         // Validate query is not empty
         if args.query.trim().is_empty() {
             return Err(McpError::invalid_arguments("Search query cannot be empty"));
@@ -70,11 +83,24 @@ impl Tool for BrowserWebSearchTool {
 
         // Get global browser manager
         let browser_manager = crate::BrowserManager::global();
+        let connection_id = ctx.connection_id().unwrap_or("default");
 
         // Perform search
-        let results = crate::web_search::search_with_manager(&browser_manager, args.query)
-            .await
-            .map_err(McpError::Other)?;
+        let search_result =
+            crate::web_search::search_with_manager(&browser_manager, connection_id, args.query)
+                .await;
+
+        // `record_invocation` takes the global counters' lock and does a
+        // handful of atomic stores inline here on the response path; there's
+        // no bounded channel/background-writer split to decouple it behind,
+        // since (unlike a per-call disk write) updating in-memory atomics
+        // under an uncontended tokio `Mutex` doesn't block on I/O and isn't
+        // a cost worth introducing a drop policy to shed.
+        crate::utils::ToolMetrics::global()
+            .record_invocation(Self::name(), search_result.is_ok(), started_at.elapsed())
+            .await;
+
+        let results = search_result.map_err(McpError::Other)?;
 
         // Terminal summary
         let summary = if results.results.is_empty() {
@@ -83,8 +109,7 @@ impl Tool for BrowserWebSearchTool {
                 results.query
             )
         } else {
-            let first_title = results.results.first()
-                .map_or("none", |r| r.title.as_str());
+            let first_title = results.results.first().map_or("none", |r| r.title.as_str());
 
             format!(
                 "\x1b[36mWeb Search: {}\x1b[0m\n Results: {} · Top: {}",
@@ -99,14 +124,16 @@ impl Tool for BrowserWebSearchTool {
             success: true,
             query: results.query,
             results_count: results.results.len(),
-            results: results.results.into_iter().map(|r| {
-                kodegen_mcp_schema::citescrape::WebSearchResultItem {
+            results: results
+                .results
+                .into_iter()
+                .map(|r| kodegen_mcp_schema::citescrape::WebSearchResultItem {
                     rank: r.rank as u32,
                     title: r.title,
                     url: r.url,
                     snippet: Some(r.snippet),
-                }
-            }).collect(),
+                })
+                .collect(),
         };
 
         Ok(ToolResponse::new(summary, output))
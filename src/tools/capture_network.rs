@@ -0,0 +1,217 @@
+//! Network response capture tool - records matching XHR/fetch responses
+//!
+//! `BrowserExtractTextTool`'s SPA fallback only ever sees rendered HTML, so
+//! JSON payloads a page fetches after load (and never renders to visible
+//! text) are invisible to it. This tool subscribes to the CDP Network
+//! domain for a bounded time window and returns the status, headers, and
+//! body of every response whose URL matches a regex (and, optionally,
+//! whose MIME type contains a given substring) - giving the agent direct
+//! access to the API data a page actually consumed.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chromiumoxide::cdp::browser_protocol::network::{
+    EnableParams, EventLoadingFinished, EventResponseReceived, GetResponseBodyParams, Response,
+};
+use futures::StreamExt;
+use kodegen_mcp_schema::browser::{
+    BROWSER_CAPTURE_NETWORK, BrowserCaptureNetworkArgs, BrowserCaptureNetworkOutput,
+    CaptureNetworkPrompts, CapturedNetworkResponse,
+};
+use kodegen_mcp_schema::{McpError, Tool, ToolExecutionContext, ToolResponse};
+use regex::Regex;
+use tokio::sync::Mutex;
+use tracing::warn;
+
+use crate::manager::BrowserManager;
+
+/// Default capture window when the caller doesn't specify `duration_ms`.
+const DEFAULT_CAPTURE_DURATION_MS: u64 = 5000;
+
+#[derive(Clone)]
+pub struct BrowserCaptureNetworkTool {
+    manager: Arc<BrowserManager>,
+}
+
+impl BrowserCaptureNetworkTool {
+    pub fn new(manager: Arc<BrowserManager>) -> Self {
+        Self { manager }
+    }
+}
+
+impl Tool for BrowserCaptureNetworkTool {
+    type Args = BrowserCaptureNetworkArgs;
+    type Prompts = CaptureNetworkPrompts;
+
+    fn name() -> &'static str {
+        BROWSER_CAPTURE_NETWORK
+    }
+
+    fn description() -> &'static str {
+        "Capture network responses (XHR/fetch) matching a URL regex for a bounded time window.\\n\\n\
+         Returns status, headers, and body for each matching response - useful for SPA pages whose \
+         content arrives as JSON that never becomes visible text.\\n\\n\
+         Example: browser_capture_network({\\\"url_pattern\\\": \\\"/api/.*\\\", \\\"duration_ms\\\": 5000})"
+    }
+
+    fn read_only() -> bool {
+        true // Observes network traffic, doesn't modify browser state
+    }
+
+    fn open_world() -> bool {
+        true // Inspects responses from external URLs
+    }
+
+    async fn execute(
+        &self,
+        args: Self::Args,
+        _ctx: ToolExecutionContext,
+    ) -> Result<ToolResponse<BrowserCaptureNetworkOutput>, McpError> {
+        let url_regex = Regex::new(&args.url_pattern).map_err(|e| {
+            McpError::invalid_arguments(format!("Invalid url_pattern regex: {}", e))
+        })?;
+        let duration =
+            Duration::from_millis(args.duration_ms.unwrap_or(DEFAULT_CAPTURE_DURATION_MS));
+
+        let browser_arc = self
+            .manager
+            .get_or_launch()
+            .await
+            .map_err(|e| McpError::Other(anyhow::anyhow!("Browser error: {}", e)))?;
+
+        let browser_guard = browser_arc.lock().await;
+        let wrapper = browser_guard.as_ref().ok_or_else(|| {
+            McpError::Other(anyhow::anyhow!(
+                "Browser not available. This is an internal error - please report it."
+            ))
+        })?;
+
+        let page = crate::browser::get_current_page(wrapper)
+            .await
+            .map_err(|e| {
+                McpError::Other(anyhow::anyhow!(
+                    "Failed to get page. Did you call browser_navigate first? Error: {}",
+                    e
+                ))
+            })?;
+
+        page.execute(EnableParams::default()).await.map_err(|e| {
+            McpError::Other(anyhow::anyhow!("Failed to enable network domain: {}", e))
+        })?;
+
+        let mut response_events = page
+            .event_listener::<EventResponseReceived>()
+            .await
+            .map_err(|e| {
+                McpError::Other(anyhow::anyhow!(
+                    "Failed to subscribe to network responses: {}",
+                    e
+                ))
+            })?;
+        let mut finished_events = page
+            .event_listener::<EventLoadingFinished>()
+            .await
+            .map_err(|e| {
+                McpError::Other(anyhow::anyhow!(
+                    "Failed to subscribe to loading-finished events: {}",
+                    e
+                ))
+            })?;
+
+        // Responses arrive before their body is fully buffered; bodies are
+        // only safe to fetch once the matching EventLoadingFinished arrives,
+        // so matched responses wait here keyed by request id until then.
+        let pending: Arc<Mutex<HashMap<String, Response>>> = Arc::new(Mutex::new(HashMap::new()));
+        let captured: Arc<Mutex<Vec<CapturedNetworkResponse>>> = Arc::new(Mutex::new(Vec::new()));
+
+        let mime_filter = args.mime_filter.clone();
+        let pending_for_responses = Arc::clone(&pending);
+        let response_task = tokio::spawn(async move {
+            while let Some(event) = response_events.next().await {
+                let response = &event.response;
+                if !url_regex.is_match(&response.url) {
+                    continue;
+                }
+                if let Some(mime_filter) = &mime_filter {
+                    if !response.mime_type.contains(mime_filter.as_str()) {
+                        continue;
+                    }
+                }
+                pending_for_responses
+                    .lock()
+                    .await
+                    .insert(event.request_id.to_string(), response.clone());
+            }
+        });
+
+        let page_for_finished = page.clone();
+        let captured_for_finished = Arc::clone(&captured);
+        let finished_task = tokio::spawn(async move {
+            while let Some(event) = finished_events.next().await {
+                let request_id = event.request_id.to_string();
+                let response = pending.lock().await.remove(&request_id);
+                let Some(response) = response else {
+                    continue;
+                };
+
+                let body = match page_for_finished
+                    .execute(GetResponseBodyParams::new(event.request_id.clone()))
+                    .await
+                {
+                    Ok(result) => Some(result.body.clone()),
+                    Err(e) => {
+                        warn!("Failed to fetch response body for {}: {}", response.url, e);
+                        None
+                    }
+                };
+
+                let headers = response
+                    .headers
+                    .inner()
+                    .iter()
+                    .map(|(k, v)| (k.clone(), v.as_str().unwrap_or_default().to_string()))
+                    .collect();
+
+                captured_for_finished
+                    .lock()
+                    .await
+                    .push(CapturedNetworkResponse {
+                        url: response.url.clone(),
+                        status: response.status as u16,
+                        mime_type: response.mime_type.clone(),
+                        headers,
+                        body,
+                    });
+            }
+        });
+
+        tokio::time::sleep(duration).await;
+        response_task.abort();
+        finished_task.abort();
+        // Wait for cancellation to actually land so each task's Arc clone is
+        // dropped before we try to reclaim sole ownership below.
+        let _ = response_task.await;
+        let _ = finished_task.await;
+
+        let responses = Arc::try_unwrap(captured)
+            .map(|mutex| mutex.into_inner())
+            .unwrap_or_default();
+
+        let summary = format!(
+            "\x1b[36m Network Capture: {}\x1b[0m\n Matched: {} responses · Window: {}ms",
+            args.url_pattern,
+            responses.len(),
+            duration.as_millis()
+        );
+
+        let output = BrowserCaptureNetworkOutput {
+            success: true,
+            count: responses.len(),
+            responses,
+        };
+
+        Ok(ToolResponse::new(summary, output))
+    }
+}
@@ -2,30 +2,32 @@
 
 use chromiumoxide::Page;
 use kodegen_mcp_schema::browser::{
-    BrowserClickArgs, BrowserClickOutput, BROWSER_CLICK,
-    ClickPrompts,
+    BROWSER_CLICK, BrowserClickArgs, BrowserClickOutput, ClickPrompts,
 };
-use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse, McpError};
+use kodegen_mcp_schema::{McpError, Tool, ToolExecutionContext, ToolResponse};
 use std::sync::Arc;
 
 use crate::manager::BrowserManager;
 use crate::utils::validate_interaction_timeout;
 
 /// Query the page for clickable elements and format as hints
-/// 
+///
 /// This helps the agent learn what selectors are actually available
 /// when its guess fails.
 async fn get_clickable_element_hints(page: &Page) -> String {
     // Try to find clickable elements
-    let clickables = match page.find_elements("button, a, [role='button'], input[type='submit'], input[type='button']").await {
+    let clickables = match page
+        .find_elements("button, a, [role='button'], input[type='submit'], input[type='button']")
+        .await
+    {
         Ok(elements) => elements,
         Err(_) => return String::new(),
     };
-    
+
     if clickables.is_empty() {
         return "No clickable elements found on page.".to_string();
     }
-    
+
     let mut hints = Vec::new();
     for (i, el) in clickables.iter().take(15).enumerate() {
         // Try to get identifying attributes
@@ -36,42 +38,69 @@ async fn get_clickable_element_hints(page: &Page) -> String {
         let href = el.attribute("href").await.ok().flatten();
         let role = el.attribute("role").await.ok().flatten();
         // Get tag name via JavaScript since chromiumoxide Element doesn't expose it directly
-        let tag: Option<String> = el.call_js_fn("function() { return this.tagName; }", false)
+        let tag: Option<String> = el
+            .call_js_fn("function() { return this.tagName; }", false)
             .await
             .ok()
             .and_then(|v| v.result.value)
             .and_then(|val| val.as_str().map(|s| s.to_lowercase()));
-        
+
         let mut selector_hints = Vec::new();
-        
+
         if let Some(id) = &id
-            && !id.is_empty() {
+            && !id.is_empty()
+        {
             selector_hints.push(format!("#{}", id));
         }
         if let Some(name) = &name
-            && !name.is_empty() {
+            && !name.is_empty()
+        {
             selector_hints.push(format!("[name='{}']", name));
         }
-        
+        // Elements with neither an id nor a name (the common case for
+        // unstyled-by-attribute links/buttons) are still targetable by
+        // their visible text via `crate::utils::SelectorStrategy::Text`.
+        if selector_hints.is_empty()
+            && let Some(text) = &text
+        {
+            let trimmed = text.trim();
+            if !trimmed.is_empty() {
+                selector_hints.push(
+                    crate::utils::SelectorStrategy::Text(trimmed.to_string())
+                        .to_selector_string(),
+                );
+            }
+        }
+
         // Build description
         let tag_str = tag.unwrap_or_else(|| "element".to_string());
-        let text_preview = text.map(|t| {
-            let trimmed = t.trim();
-            if trimmed.len() > 20 {
-                format!(" \"{}...\"", &trimmed[..20])
-            } else if !trimmed.is_empty() {
-                format!(" \"{}\"", trimmed)
-            } else {
-                String::new()
-            }
-        }).unwrap_or_default();
-        let href_preview = href.map(|h| format!(" href=\"{}\"", if h.len() > 30 { &h[..30] } else { &h })).unwrap_or_default();
+        let text_preview = text
+            .map(|t| {
+                let trimmed = t.trim();
+                if trimmed.len() > 20 {
+                    format!(" \"{}...\"", &trimmed[..20])
+                } else if !trimmed.is_empty() {
+                    format!(" \"{}\"", trimmed)
+                } else {
+                    String::new()
+                }
+            })
+            .unwrap_or_default();
+        let href_preview = href
+            .map(|h| format!(" href=\"{}\"", if h.len() > 30 { &h[..30] } else { &h }))
+            .unwrap_or_default();
         let role_str = role.map(|r| format!(" role={}", r)).unwrap_or_default();
-        let class_preview = class.map(|c| {
-            let first_class = c.split_whitespace().next().unwrap_or("");
-            if first_class.is_empty() { String::new() } else { format!(" .{}", first_class) }
-        }).unwrap_or_default();
-        
+        let class_preview = class
+            .map(|c| {
+                let first_class = c.split_whitespace().next().unwrap_or("");
+                if first_class.is_empty() {
+                    String::new()
+                } else {
+                    format!(" .{}", first_class)
+                }
+            })
+            .unwrap_or_default();
+
         if !selector_hints.is_empty() {
             hints.push(format!(
                 "  {}. <{}{}{}{}{}> → {}",
@@ -85,11 +114,12 @@ async fn get_clickable_element_hints(page: &Page) -> String {
             ));
         }
     }
-    
+
     if hints.is_empty() {
-        return "Clickable elements found but no usable selectors (missing id/name attributes).".to_string();
+        return "Clickable elements found but no usable selectors (missing id/name attributes)."
+            .to_string();
     }
-    
+
     format!("Available clickable elements:\n{}", hints.join("\n"))
 }
 
@@ -123,16 +153,22 @@ impl Tool for BrowserClickTool {
         false // Clicking changes page state
     }
 
-    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<BrowserClickOutput>, McpError> {
+    async fn execute(
+        &self,
+        args: Self::Args,
+        ctx: ToolExecutionContext,
+    ) -> Result<ToolResponse<BrowserClickOutput>, McpError> {
         // Validate selector not empty
         if args.selector.trim().is_empty() {
             return Err(McpError::invalid_arguments("Selector cannot be empty"));
         }
 
+        let connection_id = ctx.connection_id().unwrap_or("default");
+
         // Get or create browser instance
         let browser_arc = self
             .manager
-            .get_or_launch()
+            .get_or_launch_for(connection_id)
             .await
             .map_err(|e| McpError::Other(anyhow::anyhow!("Browser error: {}", e)))?;
 
@@ -153,9 +189,19 @@ impl Tool for BrowserClickTool {
                 ))
             })?;
 
-        // Find element with polling (waits for SPAs to render)
+        // Find element with polling (waits for SPAs to render). `selector`
+        // accepts the `xpath=`/`text=`/`re=` prefix convention in addition
+        // to plain CSS - see `crate::utils::SelectorStrategy`.
         let timeout = validate_interaction_timeout(args.timeout_ms, 5000)?;
-        let element = match crate::utils::wait_for_element(&page, &args.selector, timeout).await {
+        let element = match crate::utils::resolve_selector(
+            &page,
+            &args.selector,
+            timeout,
+            None,
+            crate::utils::WaitCondition::Clickable,
+        )
+        .await
+        {
             Ok(el) => el,
             Err(e) => {
                 // Element not found - get DOM hints to help the agent try a better selector
@@ -193,20 +239,98 @@ impl Tool for BrowserClickTool {
             ))
         })?;
 
-        page.click(point).await.map_err(|e| {
-            McpError::Other(anyhow::anyhow!(
-                "Click failed for selector '{}'. \
-                 Possible causes: (1) Element is obscured by another element, \
-                 (2) Element is disabled, \
-                 (3) Page changed after finding element. \
-                 Error: {}",
-                args.selector,
-                e
-            ))
-        })?;
+        // Layered click strategy: a coordinate click is cheap and correct
+        // for the common case, but a CDP coordinate click essentially
+        // always returns `Ok` even when the coordinate lands on an overlay
+        // (sticky header, modal backdrop) or a different node than the one
+        // we resolved - `Err` alone can't detect that failure mode. Compare
+        // `document.elementFromPoint` at the click coordinate against the
+        // resolved element (or one of its descendants, e.g. an icon/span
+        // inside a button) to tell whether it actually landed, and only
+        // then fall back to progressively more forceful JS-dispatched
+        // clicks rather than surfacing "click failed" for a case the agent
+        // can't do anything about from a bare CSS selector.
+        let landed_check = format!(
+            "function() {{ \
+                 const hit = document.elementFromPoint({}, {}); \
+                 return hit === this || this.contains(hit); \
+             }}",
+            point.x, point.y
+        );
+        let coordinate_click_landed = page.click(point).await.is_ok()
+            && element
+                .call_js_fn(&landed_check, false)
+                .await
+                .ok()
+                .and_then(|v| v.result.value)
+                .and_then(|val| val.as_bool())
+                .unwrap_or(false);
+
+        let strategy = if coordinate_click_landed {
+            "coordinate click"
+        } else if element
+            .call_js_fn("function() { this.click(); }", false)
+            .await
+            .is_ok()
+        {
+            "synthetic click()"
+        } else {
+            element
+                .call_js_fn(
+                    "function() { \
+                         for (const type of ['mousedown', 'mouseup', 'click']) { \
+                             this.dispatchEvent(new MouseEvent(type, { bubbles: true, cancelable: true, view: window })); \
+                         } \
+                     }",
+                    false,
+                )
+                .await
+                .map_err(|e| {
+                    McpError::Other(anyhow::anyhow!(
+                        "Click failed for selector '{}' after trying a coordinate click, a \
+                         synthetic click(), and a dispatched MouseEvent sequence. \
+                         Possible causes: (1) Element is obscured by another element, \
+                         (2) Element is disabled, \
+                         (3) Page changed after finding element. \
+                         Error: {}",
+                        args.selector,
+                        e
+                    ))
+                })?;
+            "dispatched MouseEvent sequence"
+        };
+
+        // A click can trigger a native `alert`/`confirm`/`beforeunload` dialog
+        // (e.g. a "Delete?" confirm on a destructive button), which blocks
+        // the renderer - and, in turn, `wait_for_navigation` below - until
+        // something answers it. `crate::utils::DialogWatcher` is already
+        // subscribed for this page (see `navigate::finish_navigation`) and
+        // auto-dismisses after 30s on its own, but that's a poor default
+        // for a tool call that should return promptly. Poll briefly for a
+        // dialog the click just opened and resolve it immediately instead of
+        // waiting out that timeout. `BrowserClickArgs` has no `on_dialog`
+        // policy field yet (use `browser_dialog` for ACCEPT with prompt
+        // text), so dismiss is the only safe default here - the same choice
+        // `DialogWatcher`'s own timeout makes.
+        let dialog_watcher = self.manager.dialog_watcher();
+        let mut dialog_note = String::new();
+        for _ in 0..10 {
+            if let Some(dialog) = dialog_watcher.pending().await {
+                dialog_watcher.resolve(false, None).await;
+                dialog_note = format!(
+                    " · Dialog: {} \"{}\" (auto-dismissed)",
+                    dialog.dialog_type, dialog.message
+                );
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        }
 
         // Wait for navigation if requested (for submit buttons, links, etc.)
-        if args.wait_for_navigation.unwrap_or(false) {
+        if !dialog_note.is_empty() {
+            // A `beforeunload`/`confirm` dialog dismissal typically cancels
+            // the navigation it was guarding, so there's nothing to wait for.
+        } else if args.wait_for_navigation.unwrap_or(false) {
             page.wait_for_navigation().await.map_err(|e| {
                 McpError::Other(anyhow::anyhow!(
                     "Navigation after click failed for selector '{}'. Error: {}",
@@ -219,16 +343,22 @@ impl Tool for BrowserClickTool {
         // Terminal summary
         let summary = format!(
             "\x1b[33m  Click: {}\x1b[0m\n \
-              Element: {} · Action: clicked",
-            args.selector,
-            args.selector
+              Element: {} · Action: clicked ({}){}",
+            args.selector, args.selector, strategy, dialog_note
         );
 
-        // Build typed output
+        // Build typed output. `BrowserClickOutput` (from `kodegen_mcp_schema`)
+        // has no dedicated field for which strategy won or for a dialog
+        // encountered mid-click, so both are folded into `message` instead -
+        // the same workaround this crate uses elsewhere for schema fields
+        // that crate doesn't expose yet.
         let output = BrowserClickOutput {
             success: true,
             selector: args.selector,
-            message: "Element clicked successfully".to_string(),
+            message: format!(
+                "Element clicked successfully via {}{}",
+                strategy, dialog_note
+            ),
         };
 
         Ok(ToolResponse::new(summary, output))
@@ -1,10 +1,9 @@
 //! Browser extract text tool - gets page or element text content
 
 use kodegen_mcp_schema::browser::{
-    BrowserExtractTextArgs, BrowserExtractTextOutput, BROWSER_EXTRACT_TEXT,
-    ExtractTextPrompts,
+    BROWSER_EXTRACT_TEXT, BrowserExtractTextArgs, BrowserExtractTextOutput, ExtractTextPrompts,
 };
-use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse, McpError};
+use kodegen_mcp_schema::{McpError, Tool, ToolExecutionContext, ToolResponse};
 use std::sync::Arc;
 
 use crate::manager::BrowserManager;
@@ -40,7 +39,11 @@ impl Tool for BrowserExtractTextTool {
         true // Extraction doesn't modify page
     }
 
-    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<BrowserExtractTextOutput>, McpError> {
+    async fn execute(
+        &self,
+        args: Self::Args,
+        _ctx: ToolExecutionContext,
+    ) -> Result<ToolResponse<BrowserExtractTextOutput>, McpError> {
         // Get or create browser instance
         let browser_arc = self
             .manager
@@ -114,14 +117,12 @@ impl Tool for BrowserExtractTextTool {
                 })?;
 
             // Use citescrape pattern: into_value() without type param, then match
-            let text_value = eval_result
-                .into_value()
-                .map_err(|e| {
-                    McpError::Other(anyhow::anyhow!(
-                        "Failed to parse result from JavaScript. Error: {}",
-                        e
-                    ))
-                })?;
+            let text_value = eval_result.into_value().map_err(|e| {
+                McpError::Other(anyhow::anyhow!(
+                    "Failed to parse result from JavaScript. Error: {}",
+                    e
+                ))
+            })?;
 
             // Extract string from serde_json::Value
             let initial_text = if let serde_json::Value::String(text) = text_value {
@@ -134,15 +135,9 @@ impl Tool for BrowserExtractTextTool {
             // Use citescrape's approach: get rendered HTML and convert to text
             if initial_text.trim().is_empty() {
                 // Get HTML content (includes JavaScript-rendered DOM)
-                let html = page
-                    .content()
-                    .await
-                    .map_err(|e| {
-                        McpError::Other(anyhow::anyhow!(
-                            "Failed to get HTML content. Error: {}",
-                            e
-                        ))
-                    })?;
+                let html = page.content().await.map_err(|e| {
+                    McpError::Other(anyhow::anyhow!("Failed to get HTML content. Error: {}", e))
+                })?;
 
                 // Convert HTML to markdown/text (removes tags, keeps content)
                 // This is citescrape's proven fallback for SPAs
@@ -164,9 +159,7 @@ impl Tool for BrowserExtractTextTool {
 
         let summary = format!(
             "\x1b[36mExtract Text: {}\x1b[0m\n Characters: {} Â· Preview: {}",
-            selector_display,
-            text_len,
-            preview
+            selector_display, text_len, preview
         );
 
         // Build typed output
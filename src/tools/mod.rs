@@ -1,16 +1,49 @@
 //! Browser automation tool implementations
 
 mod browser_agent;
+mod browser_cookies;
+mod browser_crawl;
+mod browser_dialog;
+mod browser_events;
+mod browser_fill_form;
+mod browser_history;
+mod browser_hover;
+mod browser_press_key;
+mod browser_print_pdf;
 mod browser_research;
+mod browser_select;
+mod browser_set_headers;
+mod browser_storage;
+mod browser_tabs;
+mod browser_upload_file;
+mod capture_network;
 mod click;
 mod extract_text;
-mod navigate;
+pub(crate) mod navigate;
 mod screenshot;
 mod scroll;
 mod type_text;
 
 pub use browser_agent::BrowserAgentTool;
+pub use browser_cookies::BrowserCookiesTool;
+pub use browser_crawl::BrowserCrawlTool;
+pub use browser_dialog::BrowserDialogTool;
+pub use browser_events::BrowserEventsTool;
+pub use browser_fill_form::BrowserFillFormTool;
+pub use browser_history::BrowserHistoryTool;
+pub use browser_hover::BrowserHoverTool;
+pub use browser_press_key::BrowserPressKeyTool;
+pub use browser_print_pdf::{
+    PdfOptions, print_page_to_pdf, print_page_to_pdf_base64, render_html_to_pdf_base64,
+    save_page_pdf,
+};
 pub use browser_research::BrowserResearchTool;
+pub use browser_select::BrowserSelectTool;
+pub use browser_set_headers::BrowserSetHeadersTool;
+pub use browser_storage::BrowserStorageTool;
+pub use browser_tabs::BrowserTabsTool;
+pub use browser_upload_file::BrowserUploadFileTool;
+pub use capture_network::BrowserCaptureNetworkTool;
 pub use click::BrowserClickTool;
 pub use extract_text::BrowserExtractTextTool;
 pub use navigate::BrowserNavigateTool;
@@ -1,36 +1,71 @@
 //! Browser navigation tool - loads URLs and waits for page ready
 
 use kodegen_mcp_schema::browser::{
-    BrowserNavigateArgs, BrowserNavigateOutput, BROWSER_NAVIGATE,
-    NavigatePrompts,
+    BROWSER_NAVIGATE, BrowserNavigateArgs, BrowserNavigateOutput, NavigatePrompts,
 };
-use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse, McpError};
+use kodegen_mcp_schema::{McpError, Tool, ToolExecutionContext, ToolResponse};
 // Removed serde_json::{json, Value} - no longer needed after conversion to typed NavigationResult
+use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
+
+use chromiumoxide::cdp::browser_protocol::fetch::{
+    AuthChallengeResponse, AuthChallengeResponseResponse, ContinueRequestParams,
+    ContinueWithAuthParams, EnableParams as FetchEnableParams, EventAuthRequired,
+    EventRequestPaused, FailRequestParams, FulfillRequestParams, HeaderEntry,
+};
+use chromiumoxide::cdp::browser_protocol::network::{
+    EnableParams as NetworkEnableParams, ErrorReason, EventLoadingFinished,
+    EventResponseReceived, Headers, SetCookieParams, SetExtraHttpHeadersParams,
+};
+use futures::StreamExt;
 
 use crate::manager::BrowserManager;
 use crate::utils::validate_navigation_timeout;
 
+/// Marker prefix on the error message returned when the per-origin
+/// governor's 429/503 check bails out a navigation, so callers with access
+/// to the internal `UtilsError` type (like `DeepResearch`) can recognize a
+/// throttle rather than treating it as a generic navigation failure and
+/// recover the `retry_after` the governor computed. `McpError` itself is
+/// defined in the external `kodegen_mcp_schema` crate, so there's no
+/// structured variant to attach this to here - the prefix is followed by
+/// the retry-after in milliseconds, e.g. `"RATE_LIMITED:4200 ..."`.
+pub(crate) const RATE_LIMITED_MARKER: &str = "RATE_LIMITED:";
+
 /// Internal navigation result returned by navigate_and_capture_page()
-/// 
+///
 /// This is NOT exposed via MCP schema (use BrowserNavigateOutput for that).
 /// Contains additional metadata for internal logic: requested_url, redirected, message.
 #[derive(Debug, Clone)]
 pub(crate) struct NavigationResult {
     /// Whether navigation succeeded
     pub success: bool,
-    
+
     /// Final URL after navigation (may differ from requested_url due to redirects)
     pub url: String,
-    
+
     /// Originally requested URL (before any redirects)
     pub requested_url: String,
-    
+
     /// Whether the final URL differs from requested URL
     pub redirected: bool,
-    
+
     /// Human-readable message describing the navigation
     pub message: String,
+
+    /// HTTP status of the top-level document response, if it arrived
+    /// within the capture window (see the `EventResponseReceived` listener
+    /// in `finish_navigation`). `None` if no response was observed
+    /// in time - callers that need a status for reporting (e.g. a crawl
+    /// tool) should treat that as "unknown", not as a failure.
+    pub status: Option<u16>,
+
+    /// `document.title` read right after `wait_for_navigation` completes.
+    /// `None` if the `evaluate` call itself failed, or for a fire-and-forget
+    /// (`timeout_ms: 0`) navigation, which returns before the page loads -
+    /// a page with no `<title>` still yields `Some("")`.
+    pub title: Option<String>,
 }
 
 #[derive(Clone)]
@@ -44,12 +79,27 @@ impl BrowserNavigateTool {
     }
 
     /// Internal method that returns both Page handle and result JSON
-    /// 
+    ///
     /// Used by deep_research to capture specific page in parallel execution.
     /// External MCP callers use execute() which discards Page handle.
     pub(crate) async fn navigate_and_capture_page(
         &self,
         args: BrowserNavigateArgs,
+        control: Option<&crate::utils::ResearchControl>,
+    ) -> Result<(chromiumoxide::Page, NavigationResult), McpError> {
+        self.navigate_and_capture_page_for("default", args, control)
+            .await
+    }
+
+    /// Same as [`Self::navigate_and_capture_page`], but resolves the
+    /// browser instance from `connection_id`'s pool lease (see
+    /// [`crate::manager::BrowserManager::get_or_launch_for`]) so concurrent
+    /// agent sessions each navigate their own instance.
+    pub(crate) async fn navigate_and_capture_page_for(
+        &self,
+        connection_id: &str,
+        args: BrowserNavigateArgs,
+        control: Option<&crate::utils::ResearchControl>,
     ) -> Result<(chromiumoxide::Page, NavigationResult), McpError> {
         // Validate URL protocol
         if !args.url.starts_with("http://") && !args.url.starts_with("https://") {
@@ -58,10 +108,22 @@ impl BrowserNavigateTool {
             ));
         }
 
+        // Reject disallowed/private-network hosts before a page is even
+        // created (see `crate::utils::NavigationPolicy`). Re-checked below
+        // against the landing URL, since a public URL can redirect into an
+        // internal one.
+        if let Some(host) = crate::utils::url_utils::host_of(&args.url) {
+            if let Err(reason) = self.manager.navigation_policy().check_host(&host).await {
+                return Err(McpError::invalid_arguments(format!(
+                    "Navigation blocked: {reason}"
+                )));
+            }
+        }
+
         // Get or create browser instance
         let browser_arc = self
             .manager
-            .get_or_launch()
+            .get_or_launch_for(connection_id)
             .await
             .map_err(|e| McpError::Other(anyhow::anyhow!("Browser error: {}", e)))?;
 
@@ -91,8 +153,228 @@ impl BrowserNavigateTool {
             .await
             .map_err(McpError::Other)?;
 
+        // Drop the wrapper lease's lock before the (potentially 30s)
+        // navigation below - it only guards the wrapper slot itself (for
+        // health-check/relaunch), not individual pages, so holding it this
+        // long would otherwise serialize every concurrent call sharing
+        // `connection_id`.
+        drop(browser_guard);
+
+        self.finish_navigation(page, args, control).await
+    }
+
+    /// Same as [`Self::navigate_and_capture_page_for`], but checks the tab
+    /// out of `connection_id`'s [`crate::browser::TabPool`] instead of
+    /// closing every other page first, so `DeepResearch`'s crawl scheduler
+    /// can run several of these concurrently against one browser instance.
+    /// The returned `Page` must be given back via
+    /// `TabPool::acquire_page`/[`crate::browser::PooledTab::release`] by the
+    /// caller once it's done with it - see `DeepResearch::process_url_with_links`.
+    pub(crate) async fn navigate_and_capture_page_pooled(
+        &self,
+        connection_id: &str,
+        args: BrowserNavigateArgs,
+        control: Option<&crate::utils::ResearchControl>,
+    ) -> Result<(crate::browser::PooledTab, NavigationResult), McpError> {
+        if !args.url.starts_with("http://") && !args.url.starts_with("https://") {
+            return Err(McpError::invalid_arguments(
+                "URL must start with http:// or https://",
+            ));
+        }
+
+        if let Some(host) = crate::utils::url_utils::host_of(&args.url) {
+            if let Err(reason) = self.manager.navigation_policy().check_host(&host).await {
+                return Err(McpError::invalid_arguments(format!(
+                    "Navigation blocked: {reason}"
+                )));
+            }
+        }
+
+        let browser_arc = self
+            .manager
+            .get_or_launch_for(connection_id)
+            .await
+            .map_err(|e| McpError::Other(anyhow::anyhow!("Browser error: {}", e)))?;
+
+        let browser_guard = browser_arc.lock().await;
+        let wrapper = browser_guard.as_ref().ok_or_else(|| {
+            McpError::Other(anyhow::anyhow!(
+                "Browser not available. This is an internal error - please report it."
+            ))
+        })?;
+
+        let tab_pool = self.manager.tab_pool_for(connection_id).await;
+        let mut tab = tab_pool
+            .acquire_page(wrapper)
+            .await
+            .map_err(McpError::Other)?;
+
+        drop(browser_guard);
+
+        let page = tab.page.take().ok_or_else(|| {
+            McpError::Other(anyhow::anyhow!(
+                "Pooled tab had no page - this is an internal error, please report it."
+            ))
+        })?;
+
+        let (page, result) = self.finish_navigation(page, args, control).await?;
+        tab.page = Some(page);
+        Ok((tab, result))
+    }
+
+    /// Everything after a page exists: diagnostics/dialog/event subscriptions,
+    /// stored headers/cookies/interception, the actual `goto` plus
+    /// `wait_for_navigation`, and the post-landing navigation-policy
+    /// re-check. Shared by [`Self::navigate_and_capture_page_for`] (single
+    /// page) and [`Self::navigate_and_capture_page_pooled`] (tab pool) -
+    /// the two differ only in how `page` was obtained.
+    async fn finish_navigation(
+        &self,
+        page: chromiumoxide::Page,
+        args: BrowserNavigateArgs,
+        control: Option<&crate::utils::ResearchControl>,
+    ) -> Result<(chromiumoxide::Page, NavigationResult), McpError> {
+        // Subscribe console/exception diagnostics before navigating so
+        // nothing the page logs during load is missed. Best-effort: a
+        // subscription failure shouldn't block navigation.
+        if let Err(e) = self.manager.diagnostics().subscribe(&page).await {
+            tracing::warn!("Failed to subscribe page diagnostics: {}", e);
+        }
+        if let Err(e) = self.manager.dialog_watcher().subscribe(&page).await {
+            tracing::warn!("Failed to subscribe dialog watcher: {}", e);
+        }
+        if let Err(e) = self.manager.event_tracker().subscribe(&page).await {
+            tracing::warn!("Failed to subscribe event tracker: {}", e);
+        }
+
+        // Custom headers (Authorization, Cookie, API tokens, ...) configured
+        // via `browser_set_headers` or `ResearchOptions`. Best-effort, same
+        // as diagnostics/dialog_watcher above - a page that doesn't need
+        // them shouldn't fail to load because of this.
+        let overrides = self.manager.network_overrides();
+        let custom_headers = overrides.headers().await;
+        if !custom_headers.is_empty() {
+            let headers_value = serde_json::Value::Object(
+                custom_headers
+                    .into_iter()
+                    .map(|(k, v)| (k, serde_json::Value::String(v)))
+                    .collect(),
+            );
+            if let Err(e) = page
+                .execute(SetExtraHttpHeadersParams::new(Headers::new(headers_value)))
+                .await
+            {
+                tracing::warn!("Failed to set extra HTTP headers: {}", e);
+            }
+        }
+
+        // Install the active cookie profile (if any), set via
+        // `browser_cookies`' LOAD_PROFILE action or `ResearchOptions::cookie_profile`,
+        // so a session captured once is already present before the first
+        // navigation of a standalone call or a background research run.
+        // Best-effort, same as the blocks above - a bad saved cookie
+        // shouldn't block navigation.
+        if let Some(cookies) = self.manager.cookie_profiles().active_cookies().await {
+            for cookie in &cookies {
+                let builder = SetCookieParams::builder()
+                    .name(cookie.name.clone())
+                    .value(cookie.value.clone())
+                    .domain(cookie.domain.clone())
+                    .path(cookie.path.clone())
+                    .expires(cookie.expires)
+                    .http_only(cookie.http_only)
+                    .secure(cookie.secure);
+                match builder.build() {
+                    Ok(params) => {
+                        if let Err(e) = page.execute(params).await {
+                            tracing::warn!("Failed to install cookie '{}': {}", cookie.name, e);
+                        }
+                    }
+                    Err(e) => tracing::warn!("Invalid saved cookie '{}': {}", cookie.name, e),
+                }
+            }
+        }
+
+        // Request interception: only pay for `Fetch.enable` when there's
+        // stored auth, block rules, or interceptor rules to act on, since it
+        // adds a round-trip for every request the page makes.
+        if overrides.needs_fetch_domain().await
+            || self.manager.request_interceptor().has_rules().await
+        {
+            if let Err(e) = self.subscribe_fetch_interception(&page).await {
+                tracing::warn!("Failed to enable request interception: {}", e);
+            }
+        }
+
+        // Network-domain capture: only the status/mime/size half (the
+        // decision half - url, resource type, blocked - is recorded
+        // straight from the Fetch-domain loop above). See
+        // `NetworkOverrides::set_capture_enabled`.
+        if overrides.is_capture_enabled().await {
+            if let Err(e) = self.subscribe_network_capture(&page).await {
+                tracing::warn!("Failed to enable network capture: {}", e);
+            }
+        }
+
+        // Per-origin pacing: wait for a free token (and out any active
+        // throttle backoff) before spending a navigation on this origin.
+        let origin = crate::utils::url_utils::origin_of(&args.url);
+        if let Some(origin) = &origin {
+            self.manager.origin_governor().acquire(origin).await;
+        }
+
+        // `timeout_ms: 0` means wait indefinitely - rather than actually
+        // blocking forever (which would collide with the MCP transport's own
+        // ~30s call timeout), treat it as fire-and-
+        // forget: kick off `goto`/`wait_for_navigation` in a detached task
+        // against this same page and return immediately. The caller gets
+        // the `Page` back right away and can poll it (or re-navigate,
+        // screenshot, etc.) whenever it's ready, instead of the call itself
+        // hanging until the transport kills it.
+        let timeout = match validate_navigation_timeout(args.timeout_ms, 30000)? {
+            Some(timeout) => timeout,
+            None => {
+                let url = args.url.clone();
+                let background_page = page.clone();
+                tokio::spawn(async move {
+                    if let Err(e) = background_page.goto(&url).await {
+                        tracing::warn!("Fire-and-forget navigation to {url} failed: {e}");
+                        return;
+                    }
+                    if let Err(e) = background_page.wait_for_navigation().await {
+                        tracing::warn!(
+                            "Fire-and-forget navigation to {url} did not finish loading: {e}"
+                        );
+                    }
+                });
+
+                let result = NavigationResult {
+                    success: true,
+                    url: args.url.clone(),
+                    requested_url: args.url.clone(),
+                    redirected: false,
+                    message: format!(
+                        "Navigation to {} started in the background (timeout_ms: 0); poll this tab for its eventual state.",
+                        args.url
+                    ),
+                    status: None,
+                    title: None,
+                };
+                return Ok((page, result));
+            }
+        };
+
+        // Subscribe to the top-level document response before navigating,
+        // so a 429/503 can be caught as it arrives (same pattern as
+        // `search_engines::goto_checking_throttle`).
+        let mut responses = page
+            .event_listener::<EventResponseReceived>()
+            .await
+            .map_err(|e| {
+                McpError::Other(anyhow::anyhow!("Failed to subscribe to responses: {}", e))
+            })?;
+
         // Navigate to URL
-        let timeout = validate_navigation_timeout(args.timeout_ms, 30000)?;
         tokio::time::timeout(timeout, page.goto(&args.url))
             .await
             .map_err(|_| {
@@ -116,21 +398,75 @@ impl BrowserNavigateTool {
                     e
                 ))
             })?;
-        
+
+        // The document-level response is the first to arrive; later
+        // sub-resource responses (images, scripts) aren't relevant here.
+        let mut status: Option<u16> = None;
+        if let Ok(Some(event)) =
+            tokio::time::timeout(Duration::from_secs(3), responses.next()).await
+        {
+            let response_status = event.response.status;
+            status = u16::try_from(response_status).ok();
+
+            // Close most of the DNS-rebinding TOCTOU gap the pre-navigation
+            // `check_host` above can't: that check resolves `host` through
+            // Rust's own resolver, not Chrome's, so a name server that hands
+            // back a different answer moments later can slip a private
+            // address past it. This checks the IP CDP reports Chrome
+            // actually connected to for the real response - see
+            // `NavigationPolicy::disallowed_ip_reason`'s doc comment.
+            if let Some(remote_ip) = event
+                .response
+                .remote_ip_address
+                .as_deref()
+                .and_then(|ip| ip.parse::<std::net::IpAddr>().ok())
+                && let Some(host) = crate::utils::url_utils::host_of(&args.url)
+                && let Some(reason) = self
+                    .manager
+                    .navigation_policy()
+                    .reject_if_disallowed_connected_ip(&host, remote_ip)
+            {
+                return Err(McpError::invalid_arguments(format!(
+                    "Navigation blocked: {reason}"
+                )));
+            }
+
+            if response_status == 429 || response_status == 503 {
+                if let Some(origin) = &origin {
+                    let retry_after = self.manager.origin_governor().note_throttled(origin).await;
+                    return Err(McpError::Other(anyhow::anyhow!(
+                        "{RATE_LIMITED_MARKER}{} {} responded {} for {}; back off {:?}",
+                        retry_after.as_millis(),
+                        origin,
+                        response_status,
+                        args.url,
+                        retry_after
+                    )));
+                }
+            } else if let Some(origin) = &origin {
+                self.manager.origin_governor().note_success(origin).await;
+            }
+        }
+
         // Wait for page lifecycle to complete
         // Pattern from web_search/search.rs - wait_for_navigation ensures page is fully loaded
-        page.wait_for_navigation()
-            .await
-            .map_err(|e| {
-                McpError::Other(anyhow::anyhow!(
-                    "Failed to wait for page load completion: {}",
-                    e
-                ))
-            })?;
+        page.wait_for_navigation().await.map_err(|e| {
+            McpError::Other(anyhow::anyhow!(
+                "Failed to wait for page load completion: {}",
+                e
+            ))
+        })?;
 
         // Wait for selector if specified
         if let Some(selector) = &args.wait_for_selector {
-            crate::utils::wait_for_element(&page, selector, timeout).await?;
+            crate::utils::wait_for_element(
+                &page,
+                selector,
+                timeout,
+                control,
+                crate::utils::WaitCondition::Present,
+            )
+            .await?;
         }
 
         // Get final URL (may differ from requested due to redirects)
@@ -140,17 +476,288 @@ impl BrowserNavigateTool {
             .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to get URL: {}", e)))?
             .unwrap_or_else(|| args.url.clone());
 
+        // Re-check the policy against where we actually landed - a host
+        // allowed (or not yet known to be private) at request time can
+        // still have redirected into a disallowed one.
+        if let Some(host) = crate::utils::url_utils::host_of(&final_url) {
+            if let Err(reason) = self.manager.navigation_policy().check_host(&host).await {
+                return Err(McpError::invalid_arguments(format!(
+                    "Navigation blocked after redirect: {reason}"
+                )));
+            }
+        }
+
+        // Best-effort: a page that errors on this trivial evaluate (e.g.
+        // already navigating away) shouldn't fail a navigation that
+        // otherwise succeeded.
+        let title = match page.evaluate("document.title").await {
+            Ok(value) => value.into_value::<String>().ok(),
+            Err(e) => {
+                tracing::warn!("Failed to read document.title: {}", e);
+                None
+            }
+        };
+
         let result = NavigationResult {
             success: true,
             url: final_url.clone(),
             requested_url: args.url.clone(),
             redirected: final_url != args.url,
             message: format!("Navigated to {}", final_url),
+            status,
+            title,
         };
 
         // Return BOTH page and JSON (new behavior for parallel execution)
         Ok((page, result))
     }
+
+    /// Enable the CDP `Fetch` domain with auth handling and spawn a
+    /// background task answering `authRequired` challenges (with stored
+    /// per-origin [`crate::utils::BasicAuth`] credentials) and deciding
+    /// whether each paused request should continue, be blocked, or be
+    /// answered with a synthetic response. [`crate::utils::RequestInterceptor`]
+    /// rules are consulted first (richer glob/resource-type matching,
+    /// mocking, header/URL rewrite); if none match, falls back to
+    /// [`crate::utils::NetworkOverrides`] URL-substring blocking. The task
+    /// ends on its own once the page's event streams close (navigation
+    /// away, page close).
+    async fn subscribe_fetch_interception(&self, page: &chromiumoxide::Page) -> anyhow::Result<()> {
+        page.execute(
+            FetchEnableParams::builder()
+                .handle_auth_requests(true)
+                .build(),
+        )
+        .await?;
+
+        let mut paused_events = page.event_listener::<EventRequestPaused>().await?;
+        let mut auth_events = page.event_listener::<EventAuthRequired>().await?;
+
+        let overrides = self.manager.network_overrides();
+        let interceptor = self.manager.request_interceptor();
+        let page_for_paused = page.clone();
+        let overrides_for_paused = Arc::clone(&overrides);
+        let interceptor_for_paused = Arc::clone(&interceptor);
+        tokio::spawn(async move {
+            while let Some(event) = paused_events.next().await {
+                let resource_type = format!("{:?}", event.resource_type);
+                let rule = interceptor_for_paused
+                    .rule_for(&event.request.url, &resource_type)
+                    .await;
+                let override_blocked = overrides_for_paused.is_blocked(&event.request.url).await;
+
+                let blocked = matches!(
+                    rule,
+                    Some(crate::utils::InterceptRule {
+                        action: crate::utils::InterceptAction::Block,
+                        ..
+                    })
+                ) || (rule.is_none() && override_blocked);
+                overrides_for_paused
+                    .record_decision(event.request.url.clone(), resource_type, blocked)
+                    .await;
+
+                let outcome = match rule {
+                    Some(crate::utils::InterceptRule {
+                        action: crate::utils::InterceptAction::Block,
+                        ..
+                    }) => fail_request(&page_for_paused, &event).await,
+                    Some(crate::utils::InterceptRule {
+                        action:
+                            crate::utils::InterceptAction::Mock {
+                                status,
+                                content_type,
+                                body,
+                            },
+                        ..
+                    }) => fulfill_request(&page_for_paused, &event, status, &content_type, &body).await,
+                    Some(crate::utils::InterceptRule {
+                        action:
+                            crate::utils::InterceptAction::Continue {
+                                rewrite_headers,
+                                rewrite_url,
+                            },
+                        ..
+                    }) => {
+                        continue_request(&page_for_paused, &event, &rewrite_headers, rewrite_url.as_deref())
+                            .await
+                    }
+                    None if override_blocked => fail_request(&page_for_paused, &event).await,
+                    None => continue_request(&page_for_paused, &event, &HashMap::new(), None).await,
+                };
+                if let Err(e) = outcome {
+                    tracing::warn!("Failed to resolve intercepted request: {}", e);
+                }
+            }
+        });
+
+        let page_for_auth = page.clone();
+        tokio::spawn(async move {
+            while let Some(event) = auth_events.next().await {
+                let credentials = overrides.auth_for(&event.auth_challenge.origin).await;
+                let response_builder = match &credentials {
+                    Some(auth) => AuthChallengeResponse::builder()
+                        .response(AuthChallengeResponseResponse::ProvideCredentials)
+                        .username(auth.username.clone())
+                        .password(auth.password.clone()),
+                    None => AuthChallengeResponse::builder()
+                        .response(AuthChallengeResponseResponse::Default),
+                };
+                let auth_challenge_response = match response_builder.build() {
+                    Ok(response) => response,
+                    Err(e) => {
+                        tracing::warn!("Failed to build auth challenge response: {}", e);
+                        continue;
+                    }
+                };
+                let params = match ContinueWithAuthParams::builder()
+                    .request_id(event.request_id.clone())
+                    .auth_challenge_response(auth_challenge_response)
+                    .build()
+                {
+                    Ok(params) => params,
+                    Err(e) => {
+                        tracing::warn!("Failed to build auth response params: {}", e);
+                        continue;
+                    }
+                };
+                if let Err(e) = page_for_auth.execute(params).await {
+                    tracing::warn!("Failed to answer auth challenge: {}", e);
+                }
+            }
+        });
+
+        Ok(())
+    }
+
+    /// Enable the CDP `Network` domain and spawn a background task that
+    /// merges each response's status/mime type/byte size into
+    /// [`crate::utils::NetworkOverrides`]' capture log for the page's whole
+    /// lifetime - the same `EventResponseReceived` +
+    /// `EventLoadingFinished` pairing `BrowserCaptureNetworkTool` uses for
+    /// its bounded capture window (responses arrive before their size is
+    /// final, so wait for `EventLoadingFinished` before recording), just
+    /// unbounded in time and without fetching bodies. Only called when
+    /// [`crate::utils::NetworkOverrides::is_capture_enabled`] is true.
+    async fn subscribe_network_capture(&self, page: &chromiumoxide::Page) -> anyhow::Result<()> {
+        page.execute(NetworkEnableParams::default()).await?;
+
+        let mut responses = page.event_listener::<EventResponseReceived>().await?;
+        let mut finished_events = page.event_listener::<EventLoadingFinished>().await?;
+
+        let pending: Arc<tokio::sync::Mutex<HashMap<String, (String, u16, String)>>> =
+            Arc::new(tokio::sync::Mutex::new(HashMap::new()));
+
+        let pending_for_responses = Arc::clone(&pending);
+        tokio::spawn(async move {
+            while let Some(event) = responses.next().await {
+                let response = &event.response;
+                pending_for_responses.lock().await.insert(
+                    event.request_id.to_string(),
+                    (
+                        response.url.clone(),
+                        response.status as u16,
+                        response.mime_type.clone(),
+                    ),
+                );
+            }
+        });
+
+        let overrides = self.manager.network_overrides();
+        tokio::spawn(async move {
+            while let Some(event) = finished_events.next().await {
+                let request_id = event.request_id.to_string();
+                let Some((url, status, mime_type)) = pending.lock().await.remove(&request_id)
+                else {
+                    continue;
+                };
+                overrides
+                    .record_response(&url, status, mime_type, Some(event.encoded_data_length as u64))
+                    .await;
+            }
+        });
+
+        Ok(())
+    }
+}
+
+/// Fail a paused request outright (`Fetch.failRequest`), used for both
+/// `NetworkOverrides` block-pattern matches and `InterceptAction::Block`.
+async fn fail_request(
+    page: &chromiumoxide::Page,
+    event: &EventRequestPaused,
+) -> anyhow::Result<()> {
+    let params = FailRequestParams::builder()
+        .request_id(event.request_id.clone())
+        .error_reason(ErrorReason::BlockedByClient)
+        .build()
+        .map_err(|e| anyhow::anyhow!("Failed to build blocked-request response: {}", e))?;
+    page.execute(params).await?;
+    Ok(())
+}
+
+/// Answer a paused request with a synthetic response (`Fetch.fulfillRequest`)
+/// for `InterceptAction::Mock`, skipping the network entirely.
+async fn fulfill_request(
+    page: &chromiumoxide::Page,
+    event: &EventRequestPaused,
+    status: u16,
+    content_type: &str,
+    body: &str,
+) -> anyhow::Result<()> {
+    let content_type_header = HeaderEntry::builder()
+        .name("Content-Type")
+        .value(content_type)
+        .build()
+        .map_err(|e| anyhow::anyhow!("Failed to build mock response header: {}", e))?;
+    let params = FulfillRequestParams::builder()
+        .request_id(event.request_id.clone())
+        .response_code(i64::from(status))
+        .response_headers(vec![content_type_header])
+        .body(base64::Engine::encode(
+            &base64::engine::general_purpose::STANDARD,
+            body,
+        ))
+        .build()
+        .map_err(|e| anyhow::anyhow!("Failed to build mock response: {}", e))?;
+    page.execute(params).await?;
+    Ok(())
+}
+
+/// Let a paused request through (`Fetch.continueRequest`), for
+/// `InterceptAction::Continue` and the default no-rule-matched path.
+/// `rewrite_headers` replaces the request's headers entirely when
+/// non-empty; `rewrite_url` redirects the request to a different URL when
+/// set.
+async fn continue_request(
+    page: &chromiumoxide::Page,
+    event: &EventRequestPaused,
+    rewrite_headers: &HashMap<String, String>,
+    rewrite_url: Option<&str>,
+) -> anyhow::Result<()> {
+    if rewrite_headers.is_empty() && rewrite_url.is_none() {
+        page.execute(ContinueRequestParams::new(event.request_id.clone()))
+            .await?;
+        return Ok(());
+    }
+
+    let mut builder = ContinueRequestParams::builder().request_id(event.request_id.clone());
+    if let Some(url) = rewrite_url {
+        builder = builder.url(url);
+    }
+    if !rewrite_headers.is_empty() {
+        let headers = rewrite_headers
+            .iter()
+            .map(|(name, value)| HeaderEntry::builder().name(name).value(value).build())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| anyhow::anyhow!("Failed to build rewritten request headers: {}", e))?;
+        builder = builder.headers(headers);
+    }
+    let params = builder
+        .build()
+        .map_err(|e| anyhow::anyhow!("Failed to build continue-request params: {}", e))?;
+    page.execute(params).await?;
+    Ok(())
 }
 
 impl Tool for BrowserNavigateTool {
@@ -176,13 +783,21 @@ impl Tool for BrowserNavigateTool {
         true // Accesses external URLs
     }
 
-    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<BrowserNavigateOutput>, McpError> {
+    async fn execute(
+        &self,
+        args: Self::Args,
+        ctx: ToolExecutionContext,
+    ) -> Result<ToolResponse<BrowserNavigateOutput>, McpError> {
         // Store timeout before moving args
         let timeout_ms = args.timeout_ms.unwrap_or(30000);
-        
+
+        let connection_id = ctx.connection_id().unwrap_or("default");
+
         // Capture page handle to ensure cleanup (CRITICAL: don't use _page)
-        let (page, result) = self.navigate_and_capture_page(args).await?;
-        
+        let (page, result) = self
+            .navigate_and_capture_page_for(connection_id, args, None)
+            .await?;
+
         // Extract data from typed result
         let final_url = result.url;
         let redirected = result.redirected;
@@ -196,26 +811,26 @@ impl Tool for BrowserNavigateTool {
             format!(
                 "\x1b[36mNavigate: {}\x1b[0m\n\
                   Redirected: {} → {} · Timeout: {}ms",
-                final_url,
-                requested_url,
-                final_url,
-                timeout_ms
+                final_url, requested_url, final_url, timeout_ms
             )
         } else {
             format!(
                 "\x1b[36mNavigate: {}\x1b[0m\n\
                   Timeout: {}ms",
-                final_url,
-                timeout_ms
+                final_url, timeout_ms
             )
         };
 
-        // Build typed output
+        // Build typed output. Console messages and thrown exceptions during
+        // this navigation aren't duplicated onto the output here - they're
+        // already captured by the page-wide `PageDiagnostics` subscription
+        // set up in `navigate_and_capture_page_for` and drained per agent
+        // step (see `AgentOutput::diagnostics`).
         let output = BrowserNavigateOutput {
             success: result.success,
             url: final_url,
-            title: None,
-            status_code: None,
+            title: result.title,
+            status_code: result.status,
         };
 
         // CRITICAL FIX: Close page before returning to prevent memory leak
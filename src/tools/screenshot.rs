@@ -1,17 +1,162 @@
 //! Browser screenshot tool - captures page or element as base64 image
+//!
+//! `BrowserScreenshotArgs` has no `full_page` field to request a capture of
+//! the whole scrollable document rather than just the viewport - that's a
+//! new field on a type owned by the external `kodegen_mcp_schema` crate
+//! (see `browser_print_pdf`'s module doc for why this repo can't add one).
+//! [`capture_full_page_screenshot`] is the real mechanism `execute` would
+//! call once `args.full_page` exists: it's a `Page.captureScreenshot`
+//! clipped to the full document size with `capture_beyond_viewport` set,
+//! where the viewport path below takes an unclipped capture.
+//! Likewise for JPEG `quality` and an arbitrary `clip` rect - WebDriver's
+//! `ScreenshotOptions` surface - see [`ScreenshotClip`] and
+//! [`capture_screenshot_with_options`].
 
 use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use chromiumoxide::Page;
 use chromiumoxide::page::ScreenshotParams;
-use chromiumoxide_cdp::cdp::browser_protocol::page::CaptureScreenshotFormat;
+use chromiumoxide_cdp::cdp::browser_protocol::page::{CaptureScreenshotFormat, Viewport};
 use kodegen_mcp_schema::browser::{
-    BrowserScreenshotArgs, BrowserScreenshotOutput, BROWSER_SCREENSHOT,
-    ScreenshotPrompts,
+    BROWSER_SCREENSHOT, BrowserScreenshotArgs, BrowserScreenshotOutput, ScreenshotPrompts,
 };
-use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse, McpError};
+use kodegen_mcp_schema::{McpError, Tool, ToolExecutionContext, ToolResponse};
 use std::sync::Arc;
 
 use crate::manager::BrowserManager;
 
+/// Above this, [`capture_full_page_screenshot`] refuses the capture rather
+/// than risk OOMing on the PNG/JPEG encode of a multi-hundred-megapixel
+/// image - an infinite-scroll page can otherwise report a scroll height in
+/// the hundreds of thousands of pixels.
+const MAX_FULL_PAGE_PIXELS: u64 = 40_000_000;
+
+/// Full scrollable document size, read the same way as the viewport
+/// dimensions in [`BrowserScreenshotTool::execute`] - `evaluate`, not a CDP
+/// `Page.getLayoutMetrics` round trip, since both already pay for one
+/// `evaluate` call per screenshot and a second is cheaper than a second
+/// round-trip type to wire up.
+async fn full_page_content_size(page: &Page) -> anyhow::Result<(u32, u32)> {
+    let result = page
+        .evaluate(
+            "(() => ({ width: document.documentElement.scrollWidth, \
+                        height: document.documentElement.scrollHeight }))()",
+        )
+        .await?;
+    let width = result
+        .value()
+        .and_then(|v| v.get("width"))
+        .and_then(|w| w.as_u64())
+        .unwrap_or(0) as u32;
+    let height = result
+        .value()
+        .and_then(|v| v.get("height"))
+        .and_then(|h| h.as_u64())
+        .unwrap_or(0) as u32;
+    Ok((width, height))
+}
+
+/// Captures the entire scrollable document rather than just the viewport,
+/// by clipping the screenshot to [`full_page_content_size`] with
+/// `capture_beyond_viewport` set so CDP renders content currently outside
+/// the viewport instead of clamping to it. Returns the image bytes and the
+/// real content dimensions it was clipped to, for
+/// `BrowserScreenshotOutput::width/height`. Rejects pathologically tall/wide
+/// pages per [`MAX_FULL_PAGE_PIXELS`] rather than risk an OOM.
+pub async fn capture_full_page_screenshot(
+    page: &Page,
+    format: CaptureScreenshotFormat,
+) -> anyhow::Result<(Vec<u8>, u32, u32)> {
+    let (width, height) = full_page_content_size(page).await?;
+    let pixels = u64::from(width) * u64::from(height);
+    if pixels > MAX_FULL_PAGE_PIXELS {
+        anyhow::bail!(
+            "full-page capture would be {}x{} ({} px), over the {} px limit",
+            width,
+            height,
+            pixels,
+            MAX_FULL_PAGE_PIXELS
+        );
+    }
+    let clip = Viewport::builder()
+        .x(0.0)
+        .y(0.0)
+        .width(f64::from(width))
+        .height(f64::from(height))
+        .scale(1.0)
+        .build()
+        .map_err(|e| anyhow::anyhow!("failed to build clip viewport: {}", e))?;
+    let params = ScreenshotParams::builder()
+        .format(format)
+        .clip(clip)
+        .capture_beyond_viewport(true)
+        .build();
+    let image_data = page.screenshot(params).await?;
+    Ok((image_data, width, height))
+}
+
+/// A rectangular region to crop a screenshot to, in CSS pixels relative to
+/// the top-left of the viewport - the shape WebDriver's `ScreenshotOptions`
+/// exposes as `clip: {x, y, width, height}`, plus the device-scale `scale`
+/// CDP's own `Page.captureScreenshot` wants alongside it.
+#[derive(Debug, Clone, Copy)]
+pub struct ScreenshotClip {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+    pub scale: f64,
+}
+
+/// Captures a screenshot honoring an optional JPEG `quality` (0-100) and an
+/// optional [`ScreenshotClip`] region, on top of the plain
+/// format/selector-only path in [`BrowserScreenshotTool::execute`].
+/// `quality` is rejected outside `0..=100`, and for any format other than
+/// JPEG, since CDP silently ignores it for PNG and a caller who set it
+/// probably expects an error instead. `clip` is rejected if its width or
+/// height isn't positive - a zero-area capture isn't a usable image.
+pub async fn capture_screenshot_with_options(
+    page: &Page,
+    format: CaptureScreenshotFormat,
+    quality: Option<u8>,
+    clip: Option<ScreenshotClip>,
+) -> anyhow::Result<Vec<u8>> {
+    if let Some(quality) = quality {
+        if !matches!(format, CaptureScreenshotFormat::Jpeg) {
+            anyhow::bail!("quality is only honored for jpeg screenshots");
+        }
+        if quality > 100 {
+            anyhow::bail!("quality must be between 0 and 100, got {}", quality);
+        }
+    }
+
+    let mut builder = ScreenshotParams::builder().format(format);
+    if let Some(quality) = quality {
+        builder = builder.quality(i64::from(quality));
+    }
+    if let Some(clip) = clip {
+        if clip.width <= 0.0 || clip.height <= 0.0 {
+            anyhow::bail!(
+                "clip width and height must be positive, got {}x{}",
+                clip.width,
+                clip.height
+            );
+        }
+        let viewport = Viewport::builder()
+            .x(clip.x)
+            .y(clip.y)
+            .width(clip.width)
+            .height(clip.height)
+            .scale(clip.scale)
+            .build()
+            .map_err(|e| anyhow::anyhow!("failed to build clip viewport: {}", e))?;
+        builder = builder.clip(viewport);
+    }
+
+    let params = builder.build();
+    let image_data = page.screenshot(params).await?;
+    Ok(image_data)
+}
+
 #[derive(Clone)]
 pub struct BrowserScreenshotTool {
     manager: Arc<BrowserManager>,
@@ -42,7 +187,11 @@ impl Tool for BrowserScreenshotTool {
         true // Screenshots don't modify browser state
     }
 
-    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<BrowserScreenshotOutput>, McpError> {
+    async fn execute(
+        &self,
+        args: Self::Args,
+        _ctx: ToolExecutionContext,
+    ) -> Result<ToolResponse<BrowserScreenshotOutput>, McpError> {
         // Get browser instance
         let browser_arc = self
             .manager
@@ -85,10 +234,7 @@ impl Tool for BrowserScreenshotTool {
             .evaluate("(() => ({ width: window.innerWidth, height: window.innerHeight }))()")
             .await
             .map_err(|e| {
-                McpError::Other(anyhow::anyhow!(
-                    "Failed to get viewport dimensions: {}",
-                    e
-                ))
+                McpError::Other(anyhow::anyhow!("Failed to get viewport dimensions: {}", e))
             })?;
 
         let viewport_width = viewport_result
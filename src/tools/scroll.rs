@@ -2,10 +2,9 @@
 
 use chromiumoxide_cdp::cdp::js_protocol::runtime::{CallArgument, CallFunctionOnParams};
 use kodegen_mcp_schema::browser::{
-    BrowserScrollArgs, BrowserScrollOutput, BROWSER_SCROLL,
-    ScrollPrompts,
+    BROWSER_SCROLL, BrowserScrollArgs, BrowserScrollOutput, ScrollPrompts,
 };
-use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse, McpError};
+use kodegen_mcp_schema::{McpError, Tool, ToolExecutionContext, ToolResponse};
 use serde_json::json;
 use std::sync::Arc;
 use tracing::warn;
@@ -42,11 +41,17 @@ impl Tool for BrowserScrollTool {
         false // Scrolling changes viewport state
     }
 
-    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<BrowserScrollOutput>, McpError> {
+    async fn execute(
+        &self,
+        args: Self::Args,
+        ctx: ToolExecutionContext,
+    ) -> Result<ToolResponse<BrowserScrollOutput>, McpError> {
+        let connection_id = ctx.connection_id().unwrap_or("default");
+
         // Get browser instance
         let browser_arc = self
             .manager
-            .get_or_launch()
+            .get_or_launch_for(connection_id)
             .await
             .map_err(|e| McpError::Other(anyhow::anyhow!("Browser error: {}", e)))?;
 
@@ -70,12 +75,18 @@ impl Tool for BrowserScrollTool {
         // Perform scroll
         if let Some(selector) = &args.selector {
             // Find element first (validates existence)
+            // `crate::browser::resolve_frame_chain` can already scope a
+            // `Page` to a nested iframe's document, but `BrowserScrollArgs`
+            // (defined in the external `kodegen_mcp_schema` crate) has no
+            // `frame` field to carry a selector chain here, so this still
+            // only looks in the top-level document until that schema grows
+            // one.
             let element = page.find_element(selector).await.map_err(|e| {
                 McpError::Other(anyhow::anyhow!(
                     "Element not found for selector '{}'. \
                      Verify: (1) Selector syntax is valid CSS, \
                      (2) Element exists on current page, \
-                     (3) Element is not in an iframe (unsupported). \
+                     (3) Element is not in an iframe (unsupported - no `frame` field yet). \
                      Error: {}",
                     selector,
                     e
@@ -68,7 +68,9 @@ impl Tool for StartBrowserResearchTool {
     async fn execute(&self, args: Self::Args) -> Result<Value, McpError> {
         // Validate query
         if args.query.trim().is_empty() {
-            return Err(McpError::invalid_arguments("Research query cannot be empty"));
+            return Err(McpError::invalid_arguments(
+                "Research query cannot be empty",
+            ));
         }
 
         // Generate unique session ID
@@ -78,11 +80,12 @@ impl Tool for StartBrowserResearchTool {
         let manager = ResearchSessionManager::global();
 
         // Create session
-        let session_ref = manager.create_session(session_id.clone(), args.query.clone())
+        let session_ref = manager
+            .create_session(session_id.clone(), args.query.clone())
             .await
-            .map_err(|e| McpError::Other(anyhow::anyhow!(
-                "Failed to create research session: {}", e
-            )))?;
+            .map_err(|e| {
+                McpError::Other(anyhow::anyhow!("Failed to create research session: {}", e))
+            })?;
 
         // Clone session ref for background task
         let session_ref_bg = Arc::clone(&session_ref);
@@ -97,6 +100,20 @@ impl Tool for StartBrowserResearchTool {
             extract_tables: args.extract_tables,
             extract_images: args.extract_images,
             timeout_seconds: args.timeout_seconds,
+            requests_per_second_per_host: args
+                .requests_per_second_per_host
+                .unwrap_or(ResearchOptions::default().requests_per_second_per_host),
+            host_burst_capacity: args
+                .host_burst_capacity
+                .unwrap_or(ResearchOptions::default().host_burst_capacity),
+            extra_headers: args.extra_headers.clone(),
+            basic_auth: args
+                .basic_auth_username
+                .clone()
+                .zip(args.basic_auth_password.clone()),
+            block_patterns: args.block_patterns.clone(),
+            cookie_profile: args.cookie_profile.clone(),
+            ..Default::default()
         });
 
         // Clone Arc pointers for background task (matches search pattern)
@@ -111,14 +128,13 @@ impl Tool for StartBrowserResearchTool {
             let browser_manager = crate::BrowserManager::global();
 
             // Create DeepResearch instance
-            let research = DeepResearch::new(
-                browser_manager,
-                args.temperature,
-                args.max_tokens,
-            );
+            let research = DeepResearch::new(browser_manager, args.temperature, args.max_tokens);
 
             // Run research (incremental streaming pattern)
-            match research.research(&query, options, results, total_results.clone()).await {
+            match research
+                .research(&query, options, results, total_results.clone())
+                .await
+            {
                 Ok(()) => {
                     // Research completed successfully
                     let mut session = session_ref_bg.lock().await;
@@ -126,7 +142,7 @@ impl Tool for StartBrowserResearchTool {
                     session.status = crate::research::ResearchStatus::Completed;
                     session.add_progress(
                         format!("Research completed - {} pages analyzed", count),
-                        count
+                        count,
                     );
                     // Result building moved to get_research_result.rs
                 }
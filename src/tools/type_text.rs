@@ -2,30 +2,32 @@
 
 use chromiumoxide::Page;
 use kodegen_mcp_schema::browser::{
-    BrowserTypeTextArgs, BrowserTypeOutput, BROWSER_TYPE_TEXT,
-    TypeTextPrompts,
+    BROWSER_TYPE_TEXT, BrowserTypeOutput, BrowserTypeTextArgs, TypeTextPrompts,
 };
-use kodegen_mcp_schema::{Tool, ToolExecutionContext, ToolResponse, McpError};
+use kodegen_mcp_schema::{McpError, Tool, ToolExecutionContext, ToolResponse};
 use std::sync::Arc;
 
 use crate::manager::BrowserManager;
 use crate::utils::validate_interaction_timeout;
 
 /// Query the page for available input elements and format as hints
-/// 
+///
 /// This helps the agent learn what selectors are actually available
 /// when its guess fails.
 async fn get_input_element_hints(page: &Page) -> String {
     // Try to find input elements
-    let inputs = match page.find_elements("input, textarea, [contenteditable='true']").await {
+    let inputs = match page
+        .find_elements("input, textarea, [contenteditable='true']")
+        .await
+    {
         Ok(elements) => elements,
         Err(_) => return String::new(),
     };
-    
+
     if inputs.is_empty() {
         return "No input elements found on page.".to_string();
     }
-    
+
     let mut hints = Vec::new();
     for (i, el) in inputs.iter().take(10).enumerate() {
         // Try to get identifying attributes
@@ -34,26 +36,36 @@ async fn get_input_element_hints(page: &Page) -> String {
         let class = el.attribute("class").await.ok().flatten();
         let placeholder = el.attribute("placeholder").await.ok().flatten();
         let input_type = el.attribute("type").await.ok().flatten();
-        
+
         let mut selector_hints = Vec::new();
-        
+
         if let Some(id) = id
-            && !id.is_empty() {
+            && !id.is_empty()
+        {
             selector_hints.push(format!("#{}", id));
         }
         if let Some(name) = name
-            && !name.is_empty() {
+            && !name.is_empty()
+        {
             selector_hints.push(format!("input[name='{}']", name));
         }
-        
+
         // Build description
         let type_str = input_type.unwrap_or_else(|| "text".to_string());
-        let placeholder_str = placeholder.map(|p| format!(" placeholder=\"{}\"", p)).unwrap_or_default();
-        let class_preview = class.map(|c| {
-            let first_class = c.split_whitespace().next().unwrap_or("");
-            if first_class.is_empty() { String::new() } else { format!(" .{}", first_class) }
-        }).unwrap_or_default();
-        
+        let placeholder_str = placeholder
+            .map(|p| format!(" placeholder=\"{}\"", p))
+            .unwrap_or_default();
+        let class_preview = class
+            .map(|c| {
+                let first_class = c.split_whitespace().next().unwrap_or("");
+                if first_class.is_empty() {
+                    String::new()
+                } else {
+                    format!(" .{}", first_class)
+                }
+            })
+            .unwrap_or_default();
+
         if !selector_hints.is_empty() {
             hints.push(format!(
                 "  {}. [{}{}{}] → {}",
@@ -65,11 +77,12 @@ async fn get_input_element_hints(page: &Page) -> String {
             ));
         }
     }
-    
+
     if hints.is_empty() {
-        return "Input elements found but no usable selectors (missing id/name attributes).".to_string();
+        return "Input elements found but no usable selectors (missing id/name attributes)."
+            .to_string();
     }
-    
+
     format!("Available input elements:\n{}", hints.join("\n"))
 }
 
@@ -103,26 +116,36 @@ impl Tool for BrowserTypeTextTool {
         false // Typing changes page state
     }
 
-    async fn execute(&self, args: Self::Args, _ctx: ToolExecutionContext) -> Result<ToolResponse<BrowserTypeOutput>, McpError> {
+    async fn execute(
+        &self,
+        args: Self::Args,
+        ctx: ToolExecutionContext,
+    ) -> Result<ToolResponse<BrowserTypeOutput>, McpError> {
         // Validate selector
         if args.selector.trim().is_empty() {
             return Err(McpError::invalid_arguments("Selector cannot be empty"));
         }
 
+        let connection_id = ctx.connection_id().unwrap_or("default");
+
         // Get current page from manager (set by browser_navigate)
-        let page = self
-            .manager
-            .get_current_page()
-            .await
-            .ok_or_else(|| {
-                McpError::Other(anyhow::anyhow!(
-                    "No page available. You must call browser_navigate first to load a page."
-                ))
-            })?;
+        let page = self.manager.get_current_page(connection_id).await.ok_or_else(|| {
+            McpError::Other(anyhow::anyhow!(
+                "No page available. You must call browser_navigate first to load a page."
+            ))
+        })?;
 
         // Find element with polling (waits for SPAs to render)
         let timeout = validate_interaction_timeout(args.timeout_ms, 5000)?;
-        let element = match crate::utils::wait_for_element(&page, &args.selector, timeout).await {
+        let element = match crate::utils::wait_for_element(
+            &page,
+            &args.selector,
+            timeout,
+            None,
+            crate::utils::WaitCondition::Visible,
+        )
+        .await
+        {
             Ok(el) => el,
             Err(e) => {
                 // Element not found - get DOM hints to help the agent try a better selector
@@ -190,18 +213,49 @@ impl Tool for BrowserTypeTextTool {
                 })?;
         }
 
-        // Type text
-        element.type_str(&args.text).await.map_err(|e| {
-            McpError::Other(anyhow::anyhow!(
-                "Type text failed for selector '{}'. \
-                 Possible causes: (1) Element lost focus during typing, \
-                 (2) Element is not a text input field, \
-                 (3) Field has input restrictions or validation. \
-                 Error: {}",
-                args.selector,
-                e
-            ))
-        })?;
+        // Type text, or - if the caller opted into key-sequence mode -
+        // interpret `{Token}` / `{Mod+Token}` tokens as real key presses
+        // (Enter, Tab, Ctrl+A, ...) with literal runs between them still
+        // going through `type_str`.
+        if args.parse_keys.unwrap_or(false) {
+            for segment in crate::utils::parse_key_sequence(&args.text) {
+                match segment {
+                    crate::utils::KeySegment::Literal(text) => {
+                        element.type_str(&text).await.map_err(|e| {
+                            McpError::Other(anyhow::anyhow!(
+                                "Type text failed for selector '{}'. Error: {}",
+                                args.selector,
+                                e
+                            ))
+                        })?;
+                    }
+                    crate::utils::KeySegment::Key(token) => {
+                        crate::utils::dispatch_key_token(&page, &token)
+                            .await
+                            .map_err(|e| {
+                                McpError::Other(anyhow::anyhow!(
+                                    "Failed to send key '{}' for selector '{}'. Error: {}",
+                                    token.key,
+                                    args.selector,
+                                    e
+                                ))
+                            })?;
+                    }
+                }
+            }
+        } else {
+            element.type_str(&args.text).await.map_err(|e| {
+                McpError::Other(anyhow::anyhow!(
+                    "Type text failed for selector '{}'. \
+                     Possible causes: (1) Element lost focus during typing, \
+                     (2) Element is not a text input field, \
+                     (3) Field has input restrictions or validation. \
+                     Error: {}",
+                    args.selector,
+                    e
+                ))
+            })?;
+        }
 
         let text_len = args.text.len();
 
@@ -209,9 +263,7 @@ impl Tool for BrowserTypeTextTool {
         let summary = format!(
             "\x1b[33m\u{f11d} Type Text: {}\x1b[0m\n\
              \u{f129} Element: {} · Characters: {}",
-            args.selector,
-            args.selector,
-            text_len
+            args.selector, args.selector, text_len
         );
 
         // Build typed output
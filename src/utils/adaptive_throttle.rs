@@ -0,0 +1,75 @@
+//! Response-time-aware politeness throttle for a single research crawl.
+//!
+//! Distinct from [`crate::utils::HostRateLimiter`], which enforces a fixed
+//! configured rate via a token bucket regardless of how long each page
+//! actually takes: this one watches a rolling window of recent per-page
+//! durations for a host and only sleeps the difference needed to keep the
+//! *effective* rate under a target. A burst of fast responses gets slowed
+//! down; a run of slow ones already meets the target on their own and incurs
+//! no extra wait - unlike a fixed per-page sleep, which wastes time on pages
+//! that were already polite enough.
+
+use std::collections::{HashMap, VecDeque};
+use std::time::Duration;
+
+use tokio::sync::Mutex;
+
+/// How many recent page durations feed the rolling average per host.
+const WINDOW_LEN: usize = 8;
+
+#[derive(Default)]
+struct HostWindow {
+    recent_durations: VecDeque<Duration>,
+}
+
+/// Per-host rolling-average throttle, shared across every concurrently
+/// in-flight task of one research crawl.
+#[derive(Default)]
+pub struct AdaptiveThrottle {
+    hosts: Mutex<HashMap<String, HostWindow>>,
+}
+
+impl AdaptiveThrottle {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sleep just enough, if any, to keep `host`'s effective rate under
+    /// `target_pages_per_sec` given its rolling average page duration so
+    /// far. The first visit to a host has no history yet and is never
+    /// delayed. Returns the duration actually slept, so a caller can
+    /// accumulate it for progress reporting.
+    pub async fn throttle(&self, host: &str, target_pages_per_sec: f64) -> Duration {
+        let min_interval = Duration::from_secs_f64(1.0 / target_pages_per_sec.max(0.001));
+
+        let sleep_for = {
+            let hosts = self.hosts.lock().await;
+            match hosts.get(host) {
+                Some(window) if !window.recent_durations.is_empty() => {
+                    let avg = window.recent_durations.iter().sum::<Duration>()
+                        / window.recent_durations.len() as u32;
+                    min_interval.saturating_sub(avg)
+                }
+                _ => Duration::ZERO,
+            }
+        };
+
+        if sleep_for > Duration::ZERO {
+            tokio::time::sleep(sleep_for).await;
+        }
+        sleep_for
+    }
+
+    /// Record how long a just-completed page visit to `host` took, feeding
+    /// the rolling average used by future `throttle` calls. Oldest entry is
+    /// dropped once the window reaches [`WINDOW_LEN`].
+    pub async fn record(&self, host: &str, duration: Duration) {
+        let mut hosts = self.hosts.lock().await;
+        let window = hosts.entry(host.to_string()).or_default();
+        if window.recent_durations.len() == WINDOW_LEN {
+            window.recent_durations.pop_front();
+        }
+        window.recent_durations.push_back(duration);
+    }
+}
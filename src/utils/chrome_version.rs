@@ -0,0 +1,79 @@
+//! Detect the real launched Chrome/Chromium version so the spoofed UA
+//! (see `constants::CHROME_USER_AGENT`) doesn't drift from the binary
+//! actually being controlled over CDP.
+//!
+//! The static `CHROME_USER_AGENT` constant is manually bumped on a
+//! quarterly schedule and only ever matches whatever version happened to
+//! be current when someone last updated it. Running the discovered Chrome
+//! binary with `--version` gives the real installed version at launch
+//! time, which this module splices into the same UA template so the
+//! reported Chrome build always matches the real one.
+
+use std::path::Path;
+use std::process::Command;
+use std::sync::OnceLock;
+
+use tracing::warn;
+
+use super::constants::CHROME_USER_AGENT;
+
+/// Cached for the lifetime of the process: the detected Chrome binary
+/// doesn't change between browser launches within one run.
+static DETECTED_USER_AGENT: OnceLock<String> = OnceLock::new();
+
+/// Run `{chrome_path} --version` and pull out the dotted version number
+/// (e.g. `"Google Chrome 131.0.6778.85"` -> `"131.0.6778.85"`).
+fn detect_version(chrome_path: &Path) -> Option<String> {
+    let output = Command::new(chrome_path).arg("--version").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    stdout
+        .split_whitespace()
+        .find(|token| token.chars().filter(|c| *c == '.').count() >= 3)
+        .map(|s| s.to_string())
+}
+
+/// Splice `version` into `CHROME_USER_AGENT`'s `Chrome/<version>` segment,
+/// leaving the rest of the template (platform, WebKit/Safari tokens)
+/// untouched.
+fn user_agent_for_version(version: &str) -> Option<String> {
+    let prefix = "Chrome/";
+    let start = CHROME_USER_AGENT.find(prefix)? + prefix.len();
+    let rest = &CHROME_USER_AGENT[start..];
+    let end = start + rest.find(' ')?;
+    Some(format!(
+        "{}{}{}",
+        &CHROME_USER_AGENT[..start],
+        version,
+        &CHROME_USER_AGENT[end..]
+    ))
+}
+
+/// The user-agent string to launch Chrome with: the real installed
+/// version spliced into the UA template when detection succeeds, falling
+/// back to the static `CHROME_USER_AGENT` constant otherwise. Detected
+/// once per process and cached for subsequent launches.
+pub fn chrome_user_agent(chrome_path: &Path) -> &'static str {
+    DETECTED_USER_AGENT.get_or_init(|| {
+        let detected = detect_version(chrome_path).and_then(|version| {
+            let ua = user_agent_for_version(&version)?;
+            Some((version, ua))
+        });
+
+        match detected {
+            Some((version, ua)) => {
+                tracing::debug!("Detected Chrome version {} for user-agent", version);
+                ua
+            }
+            None => {
+                warn!(
+                    "Failed to detect Chrome version from {}, falling back to static CHROME_USER_AGENT",
+                    chrome_path.display()
+                );
+                CHROME_USER_AGENT.to_string()
+            }
+        }
+    })
+}
@@ -0,0 +1,188 @@
+//! Named cookie-jar snapshots, so a session captured once (e.g. after an
+//! interactive login via `browser_cookies`) can be reapplied on a later
+//! navigation - including the very first navigation of a background
+//! `start_browser_research` run, via `ResearchOptions::cookie_profile`.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use kodegen_mcp_schema::browser::CookieInfo;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+/// On-disk representation of one profile for [`CookieProfileStore::with_dir`] -
+/// the name is stored alongside the cookies (rather than relying solely on
+/// the hex-encoded filename) so [`CookieProfileStore::with_dir`] can recover
+/// the original caller-chosen name on load.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SavedProfile {
+    name: String,
+    cookies: Vec<CookieInfo>,
+}
+
+/// Store of named cookie-jar snapshots plus which one (if any) should be
+/// installed on every new page before it navigates.
+///
+/// In-memory only via [`Self::new`] until [`Self::hydrate`] (or
+/// [`Self::with_dir`]) is called - profiles are gone on restart until then,
+/// same tradeoff `research::store::InMemoryResearchStore` makes. Once
+/// hydrated, every subsequent [`Self::save`] mirrors through to one JSON
+/// file per profile under that directory (see
+/// [`crate::research::store::FileResearchStore`] for the same
+/// write-through-plus-rename pattern), so an authenticated research session
+/// survives a process restart. `dir` is a `OnceLock` rather than a plain
+/// field so [`Self::hydrate`] can upgrade an already-constructed,
+/// already-shared (`Arc`'d) in-memory store in place - `BrowserManager`
+/// builds `cookie_profiles` synchronously in its constructor but only
+/// learns whether persistence was configured once it's handed a `Config`,
+/// so the disk-backed load has to happen after the fact rather than at
+/// construction.
+#[derive(Default)]
+pub struct CookieProfileStore {
+    profiles: Mutex<HashMap<String, Vec<CookieInfo>>>,
+    active_profile: Mutex<Option<String>>,
+    dir: std::sync::OnceLock<PathBuf>,
+}
+
+impl CookieProfileStore {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Disk-backed variant of [`Self::new`]: loads every profile already
+    /// saved under `dir` (if any), then mirrors every subsequent
+    /// [`Self::save`] back to it. `dir` is created (including parents) if it
+    /// doesn't already exist.
+    pub async fn with_dir(dir: impl Into<PathBuf>) -> Result<Self> {
+        let store = Self::default();
+        store.hydrate(dir).await?;
+        Ok(store)
+    }
+
+    /// Load every profile already saved under `dir` into this store and
+    /// start mirroring every subsequent [`Self::save`] back to it - the
+    /// same effect [`Self::with_dir`] has on a freshly constructed store,
+    /// but usable on one that's already in use (and possibly already
+    /// `Arc`'d out to callers). `dir` is created (including parents) if it
+    /// doesn't already exist. A no-op if this store was already hydrated
+    /// with a directory.
+    pub async fn hydrate(&self, dir: impl Into<PathBuf>) -> Result<()> {
+        if self.dir.get().is_some() {
+            return Ok(());
+        }
+
+        let dir = dir.into();
+        tokio::fs::create_dir_all(&dir)
+            .await
+            .with_context(|| format!("creating cookie profile directory {}", dir.display()))?;
+        restrict_to_owner(&dir)
+            .await
+            .with_context(|| format!("restricting permissions on {}", dir.display()))?;
+
+        let mut loaded = HashMap::new();
+        let mut entries = tokio::fs::read_dir(&dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("json") {
+                continue;
+            }
+            let bytes = tokio::fs::read(&path).await?;
+            let saved: SavedProfile = serde_json::from_slice(&bytes)
+                .with_context(|| format!("parsing cookie profile {}", path.display()))?;
+            loaded.insert(saved.name, saved.cookies);
+        }
+
+        self.profiles.lock().await.extend(loaded);
+        // Another caller may have raced us into `hydrate` - whichever
+        // `dir` wins, the loaded profiles above are already merged in
+        // either way, so losing the race here is harmless.
+        let _ = self.dir.set(dir);
+        Ok(())
+    }
+
+    /// Profile name hex-encoded into a filesystem-safe filename, same
+    /// reasoning as `FileResearchStore::path_for` - a caller-chosen profile
+    /// name can contain characters that aren't valid in a path component on
+    /// every platform.
+    fn path_for(dir: &Path, name: &str) -> PathBuf {
+        let safe_name = name.bytes().map(|b| format!("{b:02x}")).collect::<String>();
+        dir.join(format!("{safe_name}.json"))
+    }
+
+    /// Save `cookies` under `name`, replacing any existing profile of that
+    /// name. Mirrored to disk first when [`Self::with_dir`] was used, so a
+    /// crash between the write and the in-memory insert still leaves the
+    /// saved file intact - matching `FileResearchStore::put`'s
+    /// write-then-commit order isn't necessary here since there's no
+    /// separate "committed" state to race, but the `.tmp` + rename on the
+    /// write itself still guards against a half-written file.
+    pub async fn save(&self, name: String, cookies: Vec<CookieInfo>) {
+        if let Some(dir) = self.dir.get() {
+            let path = Self::path_for(dir, &name);
+            let tmp_path = path.with_extension("json.tmp");
+            let saved = SavedProfile {
+                name: name.clone(),
+                cookies: cookies.clone(),
+            };
+            if let Ok(bytes) = serde_json::to_vec_pretty(&saved) {
+                if let Err(e) = tokio::fs::write(&tmp_path, bytes).await {
+                    tracing::warn!("Failed to write cookie profile '{}': {}", name, e);
+                } else if let Err(e) = restrict_to_owner(&tmp_path).await {
+                    tracing::warn!(
+                        "Failed to restrict permissions on cookie profile '{}': {}",
+                        name,
+                        e
+                    );
+                } else if let Err(e) = tokio::fs::rename(&tmp_path, &path).await {
+                    tracing::warn!("Failed to commit cookie profile '{}': {}", name, e);
+                }
+            }
+        }
+        self.profiles.lock().await.insert(name, cookies);
+    }
+
+    /// Cookies saved under `name`, if any.
+    pub async fn get(&self, name: &str) -> Option<Vec<CookieInfo>> {
+        self.profiles.lock().await.get(name).cloned()
+    }
+
+    /// Set (or clear, if `name` is `None`) which profile navigation should
+    /// install on every new page. Does not require the profile to already
+    /// exist - it's looked up lazily by [`Self::active_cookies`] on every
+    /// navigation, so it picks up a profile saved after this call too.
+    pub async fn set_active(&self, name: Option<String>) {
+        *self.active_profile.lock().await = name;
+    }
+
+    /// The active profile's cookies, if one is set and has been saved.
+    pub async fn active_cookies(&self) -> Option<Vec<CookieInfo>> {
+        let name = self.active_profile.lock().await.clone()?;
+        self.get(&name).await
+    }
+}
+
+/// Restrict `path` (a directory or a cookie-profile JSON/tmp file) to
+/// owner-only access - these hold live session cookies, so a
+/// world/group-readable profile directory or file would leak them to any
+/// other local user. Directories need the owner's execute bit to stay
+/// traversable (`0o700`); files get read/write only (`0o600`). No-op on
+/// non-Unix platforms, same split `examples/common::PortReaper` uses for
+/// OS-specific behaviour that has no portable equivalent.
+#[cfg(unix)]
+async fn restrict_to_owner(path: &Path) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    let mode = if tokio::fs::metadata(path).await?.is_dir() {
+        0o700
+    } else {
+        0o600
+    };
+    tokio::fs::set_permissions(path, std::fs::Permissions::from_mode(mode)).await?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+async fn restrict_to_owner(_path: &Path) -> Result<()> {
+    Ok(())
+}
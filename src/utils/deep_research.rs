@@ -1,25 +1,36 @@
 //! Deep research module - infrastructure for future use
 
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 // Workspace LLM infrastructure
 use kodegen_candle_agent::prelude::*;
 
+use futures::StreamExt as _;
+use futures::stream::FuturesUnordered;
 use serde::{Deserialize, Serialize};
-use tokio::sync::Mutex;
-use tracing::{debug, info, warn};
+use tokio::sync::{Mutex, Semaphore};
 use tokio::task::JoinSet;
-use tokio::sync::Semaphore;
+use tracing::{debug, info, warn};
 
+use crate::research::worker::WorkerControl;
+use crate::utils::AgentState;
+use crate::utils::ResearchControl;
 use crate::utils::errors::UtilsError;
 
 // Browser tool imports for direct library integration
-use kodegen_mcp_schema::browser::BrowserNavigateArgs;
 use crate::tools::BrowserNavigateTool;
+use kodegen_mcp_schema::browser::BrowserNavigateArgs;
 
 // Page metadata extraction
 use crate::page_extractor::{PageMetadata, extract_page_info};
 
+/// Maximum concurrent chunk-summarization LLM calls during a single
+/// map-reduce `summarize_content` pass. Kept small so summarization fan-out
+/// for one long page doesn't starve permits other research calls need from
+/// the global `SearchQueue` for page processing.
+const MAX_CONCURRENT_CHUNK_SUMMARIES: usize = 2;
+
 /// Research result containing extracted information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ResearchResult {
@@ -41,6 +52,108 @@ pub struct ResearchOptions {
     pub extract_tables: bool,
     pub extract_images: bool,
     pub timeout_seconds: u64,
+
+    /// Not read by `DeepResearch`: the global research queue
+    /// (`BrowserManager::search_queue`) is shared process-wide and sized
+    /// once at `BrowserManager` construction, so this value changes neither
+    /// its waiting buffer nor its in-flight cap.
+    pub queue_size: usize,
+
+    /// Maximum retry attempts per search engine when it responds with a
+    /// throttling status (429/503) or another transient error.
+    pub max_search_retries: u32,
+
+    /// Rotate the browser's user-agent per search/navigation request to
+    /// avoid fingerprinting on a single static UA. When `false`, falls back
+    /// to the default `CHROME_USER_AGENT` set at browser launch.
+    pub rotate_user_agent: bool,
+
+    /// Explicit user-agent pool to rotate through. `None` uses the built-in
+    /// curated set (`user_agents::DEFAULT_USER_AGENTS`).
+    pub user_agents: Option<Vec<String>>,
+
+    /// Maximum characters per map-reduce summarization chunk. Content longer
+    /// than this is split into overlapping chunks rather than truncated.
+    pub max_content_chars: usize,
+
+    /// Character overlap between consecutive summarization chunks, so a
+    /// fact straddling a chunk boundary still appears whole in at least one
+    /// chunk.
+    pub chunk_overlap: usize,
+
+    /// Whether to crawl with a bounded pool of concurrently-running tabs
+    /// (see `DeepResearch::run_crawl`). `false` forces a single tab at a
+    /// time regardless of `concurrency`.
+    pub parallel: bool,
+
+    /// Maximum number of URLs processed concurrently by this `research()`
+    /// call's local scheduler (on top of the process-wide `SearchQueue`
+    /// cap shared across all concurrent research calls).
+    pub concurrency: usize,
+
+    /// Skip the robots.txt/`<meta name="robots">`/`X-Robots-Tag` crawl-policy
+    /// checks in `process_url_with_links` entirely, including per-origin
+    /// `Crawl-delay`. Only meant for explicitly authorized scraping (e.g. a
+    /// site the caller owns or has permission to crawl unrestricted) - the
+    /// default honors robots.txt like any well-behaved crawler.
+    pub ignore_robots: bool,
+
+    /// Maximum requests per second to any single host, enforced as a
+    /// continuously-refilling token bucket (see
+    /// [`crate::utils::HostRateLimiter`]) independent of the process-wide
+    /// `OriginGovernor`. Keeps a background research crawl from hammering
+    /// one site even when `concurrency` admits several of its URLs at once.
+    pub requests_per_second_per_host: f64,
+
+    /// Burst capacity for `requests_per_second_per_host` - how many
+    /// back-to-back requests to a host can fire before per-second pacing
+    /// kicks in.
+    pub host_burst_capacity: u32,
+
+    /// Custom headers (e.g. `Authorization`, `Cookie`, an API token) sent
+    /// with every navigation this call makes. Applied to the shared
+    /// `BrowserManager::network_overrides` store at the start of
+    /// `research()`, the same store `browser_set_headers` populates, so a
+    /// background crawl can reach pages behind a login wall.
+    pub extra_headers: Option<std::collections::HashMap<String, String>>,
+
+    /// HTTP basic-auth credentials answered for `Fetch.authRequired`
+    /// challenges raised while crawling. Applies to every origin reached by
+    /// this call, since a single authenticated crawl target is the common
+    /// case - use `browser_set_headers` directly for finer per-origin
+    /// control.
+    pub basic_auth: Option<(String, String)>,
+
+    /// URL substrings whose requests get blocked during this call (e.g.
+    /// image/ad hosts), to skip fetching resources page extraction doesn't
+    /// need.
+    pub block_patterns: Option<Vec<String>>,
+
+    /// Name of a cookie jar saved via `browser_cookies`' SAVE_PROFILE action
+    /// to install before the first navigation, so a crawl starts with an
+    /// authenticated session already in place. Applied to the shared
+    /// `BrowserManager::cookie_profiles` store at the start of `research()`.
+    pub cookie_profile: Option<String>,
+
+    /// Target pages/sec per host for the adaptive politeness throttle (see
+    /// [`crate::utils::AdaptiveThrottle`]), on top of the fixed-rate
+    /// `requests_per_second_per_host` token bucket. `None` disables it -
+    /// only the token bucket paces requests. Unlike the token bucket, a page
+    /// whose own fetch already took longer than `1 / target` incurs no
+    /// extra sleep, since the rolling average already meets the target.
+    pub adaptive_throttle_target_pages_per_second: Option<f64>,
+
+    /// Accumulate CDP Fetch/Network-domain traffic for every page this call
+    /// navigates into the shared `BrowserManager::network_overrides` capture
+    /// log (see [`crate::utils::NetworkOverrides::set_capture_enabled`]), so
+    /// a caller can read back a [`crate::utils::NetworkSummary`] afterward -
+    /// XHR/fetch endpoints discovered, requests blocked, total bytes.
+    /// `false` by default, same opt-in reasoning as `block_patterns`: it
+    /// costs a `Fetch.enable` round-trip per request. There's no
+    /// `browser_research`/`start_browser_research` MCP argument wired to
+    /// this yet (see the field's doc comment), so today it's only reachable
+    /// by a direct library caller constructing its own `ResearchOptions`.
+    pub capture_network: bool,
 }
 
 impl Default for ResearchOptions {
@@ -53,6 +166,27 @@ impl Default for ResearchOptions {
             extract_tables: true,
             extract_images: false,
             timeout_seconds: 60,
+            queue_size: std::thread::available_parallelism()
+                .map(|n| n.get())
+                .unwrap_or(4),
+            max_search_retries: 3,
+            rotate_user_agent: true,
+            user_agents: None,
+            max_content_chars: 8000,
+            chunk_overlap: 500,
+            parallel: true,
+            concurrency: std::thread::available_parallelism()
+                .map(|n| n.get())
+                .unwrap_or(4),
+            ignore_robots: false,
+            requests_per_second_per_host: 1.0,
+            host_burst_capacity: 3,
+            extra_headers: None,
+            basic_auth: None,
+            block_patterns: None,
+            cookie_profile: None,
+            adaptive_throttle_target_pages_per_second: None,
+            capture_network: false,
         }
     }
 }
@@ -78,6 +212,49 @@ pub struct DeepResearch {
 
     /// Track visited URLs to avoid duplicates
     visited_urls: Arc<Mutex<Vec<String>>>,
+
+    /// Optional external stop flag checked by the crawl scheduler between
+    /// admission rounds (see `with_agent_state`).
+    agent_state: Option<Arc<Mutex<AgentState>>>,
+
+    /// Per-origin robots.txt rules, fetched and cached once per instance
+    /// (see `crate::utils::RobotsCache`).
+    robots_cache: Arc<crate::utils::RobotsCache>,
+
+    /// Per-origin `Crawl-delay` enforcement, shared across every
+    /// concurrently in-flight task in `run_crawl`.
+    crawl_delay_scheduler: Arc<crate::utils::CrawlDelayScheduler>,
+
+    /// Per-host politeness pacing, configured per call via
+    /// `ResearchOptions::requests_per_second_per_host`. Shared across every
+    /// concurrently in-flight task in `run_crawl`.
+    host_rate_limiter: Arc<crate::utils::HostRateLimiter>,
+
+    /// Per-host adaptive pacing on top of `host_rate_limiter`, configured
+    /// per call via `ResearchOptions::adaptive_throttle_target_pages_per_second`.
+    adaptive_throttle: Arc<crate::utils::AdaptiveThrottle>,
+
+    /// Total time this instance has spent sleeping in `adaptive_throttle`,
+    /// in milliseconds - there's no live per-step progress record to attach
+    /// this to (unlike the dead `research::session_manager::ResearchStep`),
+    /// so callers read it directly off the `DeepResearch` instance once a
+    /// crawl completes.
+    accumulated_throttle_ms: Arc<std::sync::atomic::AtomicU64>,
+
+    /// Optional pause/throttle handle, checked between frontier admissions
+    /// and applied as a post-fetch delay in `run_crawl` (see
+    /// `with_worker_control`).
+    worker_control: Option<WorkerControl>,
+
+    /// Optional abort+deadline handle shared with the owning session; see
+    /// [`crate::utils::ResearchControl`] and `with_research_control`.
+    control: Option<ResearchControl>,
+
+    /// Optional channel a result is pushed onto the instant it's appended to
+    /// `results` in `run_crawl`, so a caller like `ResearchSession` can turn
+    /// each one into a [`crate::research::session::ResearchEvent`] without
+    /// polling the shared `Vec`; see `with_result_sender`.
+    result_sender: Option<tokio::sync::mpsc::UnboundedSender<ResearchResult>>,
 }
 
 impl DeepResearch {
@@ -92,14 +269,74 @@ impl DeepResearch {
         temperature: f64,
         max_tokens: u64,
     ) -> Self {
+        let tls_trust_store = browser_manager.tls_trust_store();
         Self {
             browser_manager,
             temperature,
             max_tokens,
             visited_urls: Arc::new(Mutex::new(Vec::new())),
+            agent_state: None,
+            robots_cache: Arc::new(crate::utils::RobotsCache::new(tls_trust_store)),
+            crawl_delay_scheduler: Arc::new(crate::utils::CrawlDelayScheduler::new()),
+            host_rate_limiter: Arc::new(crate::utils::HostRateLimiter::new()),
+            adaptive_throttle: Arc::new(crate::utils::AdaptiveThrottle::new()),
+            accumulated_throttle_ms: Arc::new(std::sync::atomic::AtomicU64::new(0)),
+            worker_control: None,
+            control: None,
+            result_sender: None,
         }
     }
 
+    /// Total time this instance has spent in the adaptive throttle so far
+    /// (see `ResearchOptions::adaptive_throttle_target_pages_per_second`).
+    pub fn accumulated_throttle_time(&self) -> Duration {
+        Duration::from_millis(
+            self.accumulated_throttle_ms
+                .load(std::sync::atomic::Ordering::Relaxed),
+        )
+    }
+
+    /// Attach an `AgentState` stop flag so an in-progress crawl can be
+    /// cancelled externally; checked once per scheduler admission round.
+    #[must_use]
+    pub fn with_agent_state(mut self, agent_state: Arc<Mutex<AgentState>>) -> Self {
+        self.agent_state = Some(agent_state);
+        self
+    }
+
+    /// Attach a pause/throttle handle so an in-progress crawl can be paused,
+    /// resumed, or have its `tranquility` dialed up/down without losing
+    /// partial results; see [`WorkerControl`].
+    #[must_use]
+    pub fn with_worker_control(mut self, control: WorkerControl) -> Self {
+        self.worker_control = Some(control);
+        self
+    }
+
+    /// Attach a shared abort+deadline handle so `KILL`/`stop_research`
+    /// propagates into an in-progress navigation or element wait instead of
+    /// orphaning it until its own local timeout; see
+    /// [`crate::utils::ResearchControl`].
+    #[must_use]
+    pub fn with_research_control(mut self, control: ResearchControl) -> Self {
+        self.control = Some(control);
+        self
+    }
+
+    /// Attach a channel that receives a clone of each `ResearchResult` at
+    /// the moment it's pushed into the shared `results` vec in `run_crawl`,
+    /// so a subscriber gets it pushed rather than having to diff successive
+    /// reads of the vec. A send failing (no receiver left) is ignored - the
+    /// crawl itself never depends on anyone listening.
+    #[must_use]
+    pub fn with_result_sender(
+        mut self,
+        sender: tokio::sync::mpsc::UnboundedSender<ResearchResult>,
+    ) -> Self {
+        self.result_sender = Some(sender);
+        self
+    }
+
     /// Perform web research on a query (incremental streaming pattern)
     pub async fn research(
         &self,
@@ -108,127 +345,316 @@ impl DeepResearch {
         results: Arc<tokio::sync::RwLock<Vec<ResearchResult>>>,
         total_results: Arc<std::sync::atomic::AtomicUsize>,
     ) -> Result<(), UtilsError> {
+        use std::sync::atomic::Ordering;
+
         let options = options.unwrap_or_default();
 
+        // Apply this call's header/auth/block overrides to the shared
+        // navigation store (see `crate::utils::NetworkOverrides`) so every
+        // page this crawl loads - via `BrowserNavigateTool` - can reach
+        // sites behind a login wall. `None` leaves a previously configured
+        // store (e.g. from `browser_set_headers`) untouched.
+        let network_overrides = self.browser_manager.network_overrides();
+        if let Some(headers) = options.extra_headers.clone() {
+            network_overrides.set_headers(headers).await;
+        }
+        if let Some((username, password)) = options.basic_auth.clone() {
+            network_overrides
+                .set_default_auth(Some(crate::utils::BasicAuth { username, password }))
+                .await;
+        }
+        if let Some(patterns) = options.block_patterns.clone() {
+            network_overrides.set_block_patterns(patterns).await;
+        }
+        if let Some(profile) = options.cookie_profile.clone() {
+            self.browser_manager
+                .cookie_profiles()
+                .set_active(Some(profile))
+                .await;
+        }
+        network_overrides
+            .set_capture_enabled(options.capture_network)
+            .await;
+
         // Reset visited URLs
         let mut visited = self.visited_urls.lock().await;
         visited.clear();
         drop(visited);
 
-        // Search for query
+        // Search for query - these seed the frontier at depth 0
         let search_results = self.search_query(query, &options).await?;
+        let frontier: Vec<(String, usize)> = search_results
+            .into_iter()
+            .take(options.max_pages)
+            .map(|url| (url, 0))
+            .collect();
 
-        // Process each search result in parallel with semaphore-controlled concurrency
-        let semaphore = Arc::new(Semaphore::new(3)); // 3 concurrent URLs max
-        let mut join_set = JoinSet::new();
+        self.run_crawl(frontier, &options, results, total_results)
+            .await
+    }
 
-        // Spawn parallel task for each URL
-        for url in search_results.iter().take(options.max_pages) {
-            let url = url.clone();
-            let options = options.clone();
-            let results = Arc::clone(&results);
-            let total_results = Arc::clone(&total_results);
-            let semaphore = Arc::clone(&semaphore);
-            let research = self.clone(); // All fields are Clone via Arc or Copy
+    /// Drive the bounded-concurrency crawl scheduler over `frontier`.
+    ///
+    /// Unlike the earlier wave-based design, admission is continuous: as
+    /// soon as a task completes and reports its discovered links, a new
+    /// task is admitted immediately rather than waiting for the rest of
+    /// the current depth to finish. This keeps the local `concurrency`
+    /// permits (and the process-wide `SearchQueue` permits underneath them)
+    /// saturated even when some pages are much slower than others.
+    ///
+    /// Cancellation: `self.agent_state`, if set, is polled once per
+    /// admission round; a stop request drains in-flight tasks and returns
+    /// early without spawning any more.
+    async fn run_crawl(
+        &self,
+        mut frontier: Vec<(String, usize)>,
+        options: &ResearchOptions,
+        results: Arc<tokio::sync::RwLock<Vec<ResearchResult>>>,
+        total_results: Arc<std::sync::atomic::AtomicUsize>,
+    ) -> Result<(), UtilsError> {
+        use std::sync::atomic::Ordering;
 
-            join_set.spawn(async move {
-                // Acquire semaphore permit (blocks if 3 tasks already running)
-                let _permit = semaphore
-                    .acquire()
-                    .await
-                    .map_err(|e| UtilsError::UnexpectedError(format!("Semaphore error: {}", e)))?;
-
-                // Process URL (duplicate checking now atomic via Change 3)
-                match research.process_url(&url, &options).await {
-                    Ok(result) => {
-                        // Append result immediately (incremental streaming - UNCHANGED)
-                        {
-                            let mut results_guard = results.write().await;
-                            results_guard.push(result);
-                        }
-                        // Update counter atomically (UNCHANGED)
-                        total_results.fetch_add(1, std::sync::atomic::Ordering::Release);
-                        Ok(())
-                    }
-                    Err(e) => {
-                        // Log error and continue (UNCHANGED behavior)
-                        warn!("Error processing URL {}: {}", url, e);
-                        Err(e)
+        // Global queue shared by every concurrent research() call, not just
+        // this one - bounds process-wide in-flight page loads.
+        let search_queue = self.browser_manager.search_queue();
+
+        // Per-call cap on top of the process-wide queue. `parallel: false`
+        // forces strictly sequential processing regardless of `concurrency`.
+        let local_permits = if options.parallel {
+            options.concurrency.max(1)
+        } else {
+            1
+        };
+        let local_semaphore = Arc::new(Semaphore::new(local_permits));
+
+        let mut in_flight = FuturesUnordered::new();
+        let mut stop_error: Option<UtilsError> = None;
+
+        loop {
+            if stop_error.is_none() {
+                if let Some(agent_state) = &self.agent_state {
+                    if agent_state.lock().await.is_stop_requested() {
+                        debug!("Research crawl stopped via agent state");
+                        frontier.clear();
+                        stop_error = Some(UtilsError::from(crate::agent::AgentError::Stopped));
                     }
                 }
-                // Semaphore permit automatically released when _permit drops
-            });
-        }
+            }
 
-        // Wait for all parallel tasks to complete
-        while let Some(result) = join_set.join_next().await {
-            match result {
-                Ok(Ok(())) => {
-                    // URL processed successfully
+            // A paused session holds its slot and partial results but admits
+            // no new frontier URLs until resumed; in-flight tasks still run
+            // to completion below.
+            if let Some(control) = &self.worker_control {
+                control.wait_while_paused().await;
+            }
+
+            // Admit as many frontier URLs as local permits allow, unless
+            // we're draining after a stop/fatal error or have hit max_pages.
+            while stop_error.is_none()
+                && !frontier.is_empty()
+                && total_results.load(Ordering::Acquire) < options.max_pages
+            {
+                let Ok(local_permit) = Arc::clone(&local_semaphore).try_acquire_owned() else {
+                    break;
+                };
+
+                let (url, depth) = frontier.remove(0);
+                let options = options.clone();
+                let results = Arc::clone(&results);
+                let total_results = Arc::clone(&total_results);
+                let search_queue = Arc::clone(&search_queue);
+                let research = self.clone(); // All fields are Clone via Arc or Copy
+                let worker_control = self.worker_control.clone();
+
+                in_flight.push(tokio::spawn(async move {
+                    let _local_permit = local_permit;
+
+                    // Acquire a permit from the global, admission-controlled
+                    // queue (blocks if the process-wide in-flight cap is hit,
+                    // fails fast with a retry-after if randomly evicted from
+                    // an already-full waiting buffer).
+                    let _permit = search_queue.acquire().await?;
+
+                    let started = std::time::Instant::now();
+
+                    // Process URL (duplicate checking is atomic via visited_urls)
+                    let outcome = match research.process_url_with_links(&url, &options).await {
+                        Ok((maybe_result, links)) => {
+                            // `None` means the page was excluded from the
+                            // corpus (robots.txt disallow, or `noindex` via
+                            // `<meta name="robots">`/`X-Robots-Tag`) - its
+                            // links may still have been discovered, unless
+                            // `nofollow` suppressed them too.
+                            if let Some(result) = maybe_result {
+                                if let Some(tx) = &research.result_sender {
+                                    let _ = tx.send(result.clone());
+                                }
+                                let mut results_guard = results.write().await;
+                                results_guard.push(result);
+                                total_results.fetch_add(1, Ordering::Release);
+                            }
+                            Ok((depth, links))
+                        }
+                        Err(e) => {
+                            warn!("Error processing URL {}: {}", url, e);
+                            Err(e)
+                        }
+                    };
+
+                    // Dial load on the target site(s) down by sleeping
+                    // proportionally to how long this fetch took; a no-op at
+                    // the default tranquility of 0.
+                    if let Some(worker_control) = &worker_control {
+                        let delay = worker_control.throttle_delay(started.elapsed());
+                        if !delay.is_zero() {
+                            tokio::time::sleep(delay).await;
+                        }
+                    }
+
+                    outcome
+                    // Queue permit automatically released when _permit drops
+                }));
+            }
+
+            let Some(joined) = in_flight.next().await else {
+                // Nothing in flight and nothing left to admit - done.
+                break;
+            };
+
+            match joined {
+                Ok(Ok((depth, links))) => {
+                    if stop_error.is_none()
+                        && options.include_links
+                        && depth + 1 <= options.max_depth
+                        && total_results.load(Ordering::Acquire) < options.max_pages
+                    {
+                        for link in links {
+                            frontier.push((link, depth + 1));
+                        }
+                    }
                 }
                 Ok(Err(_e)) => {
-                    // URL processing error (already logged in task)
+                    // URL processing error (already logged in task) - not
+                    // fatal to the overall crawl, just skip this URL's links.
                 }
                 Err(e) => {
-                    // Task panic - log it
                     warn!("Research task panicked: {}", e);
                 }
             }
         }
 
+        if let Some(e) = stop_error {
+            return Err(e);
+        }
+
         Ok(())
     }
 
-    /// Search for query using web_search module directly
-    ///
-    /// Calls local web_search which provides DuckDuckGo search
-    /// with kromekover stealth, retries, and structured result parsing.
+    /// Search for query, dispatching to the engine(s) named by
+    /// `ResearchOptions::search_engine`.
     ///
     /// # Arguments
     /// * `query` - Search query string
-    /// * `options` - Research options (currently unused, web_search has sensible defaults)
+    /// * `options` - Research options; `search_engine` selects one engine
+    ///   (e.g. `"google"`) or a comma-separated list (`"google,duckduckgo"`)
+    ///   to query concurrently
     ///
     /// # Returns
-    /// Vector of URLs from search results (up to 10)
+    /// Deduplicated vector of URLs, merged across engines with Reciprocal
+    /// Rank Fusion (see [`crate::utils::fuse_rrf`]) so URLs multiple engines
+    /// agree on are ranked ahead of single-engine hits
     ///
     /// # Direct Integration
-    /// This method calls web_search directly (same package) instead of via MCP.
-    /// Benefits:
+    /// Engines call into browser automation directly (same package) instead
+    /// of via MCP. Benefits:
     /// - Faster (no IPC overhead)
     /// - Simpler (no serialization/deserialization)
     /// - More reliable (no network/process dependencies)
     async fn search_query(
         &self,
         query: &str,
-        _options: &ResearchOptions,
+        options: &ResearchOptions,
     ) -> Result<Vec<String>, UtilsError> {
-        debug!("Searching DuckDuckGo via web_search (direct): {}", query);
+        let engines = crate::utils::search_engines::resolve_engines(&options.search_engine);
+        debug!(
+            "Searching via {} engine(s) for query: {}",
+            engines.len(),
+            query
+        );
 
-        // Call web_search directly (same package, no MCP needed)
-        let search_results = crate::web_search::search_with_manager(&self.browser_manager, query)
-            .await
-            .map_err(|e| UtilsError::BrowserError(e.to_string()))?;
+        let max_retries = options.max_search_retries;
+        let ua_pool = options.rotate_user_agent.then(|| {
+            crate::utils::UserAgentPool::new(
+                options.user_agents.clone().unwrap_or_default(),
+                crate::utils::UserAgentSelection::Random,
+            )
+        });
 
-        // Extract URLs from SearchResults
-        let urls: Vec<String> = search_results.results.iter()
-            .map(|r| r.url.clone())
+        let mut in_flight = FuturesUnordered::new();
+        for engine in engines {
+            let manager = self.browser_manager.clone();
+            let query = query.to_string();
+            let user_agent = ua_pool.as_ref().map(|pool| pool.pick().to_string());
+            in_flight.push(async move {
+                let name = engine.name();
+                let result = crate::utils::search_engines::retry_search(
+                    || engine.search(&manager, &query, user_agent.as_deref(), 0),
+                    max_retries,
+                )
+                .await;
+                (name, result)
+            });
+        }
+
+        // Collect each engine's ranked hit list as it finishes - whichever
+        // engine responds first is consumed first rather than waiting in
+        // spawn order - then fuse them into one consensus ranking below.
+        let mut per_engine = Vec::new();
+        while let Some((name, result)) = in_flight.next().await {
+            match result {
+                Ok(hits) => {
+                    info!(
+                        "{} engine found {} hits for query: {}",
+                        name,
+                        hits.len(),
+                        query
+                    );
+                    per_engine.push(hits);
+                }
+                Err(e) => {
+                    warn!("{} engine failed for query '{}': {}", name, query, e);
+                }
+            }
+        }
+
+        let urls: Vec<String> = crate::utils::fuse_rrf(per_engine)
+            .into_iter()
+            .map(|hit| hit.url)
             .collect();
 
         if urls.is_empty() {
-            warn!("web_search returned no results for query: {}", query);
-        } else {
-            info!("web_search found {} URLs for query: {}", urls.len(), query);
+            warn!("No engines returned results for query: {}", query);
         }
 
         Ok(urls)
     }
 
-    /// Process a URL and extract content
-    async fn process_url(
+    /// Process a URL, extract content, and (when `include_links` is set and
+    /// the page isn't `nofollow`) discover same-registrable-domain links to
+    /// feed the BFS frontier.
+    ///
+    /// Returns `(None, links)` when the page is excluded from the research
+    /// corpus - either robots.txt disallows it (in which case it's never
+    /// navigated to at all, and `links` is empty) or it declares itself
+    /// `noindex` via `<meta name="robots">`/`X-Robots-Tag` (navigated and
+    /// link-discovered as normal, just not added to the corpus). Link
+    /// discovery is a no-op (empty vec) when `options.include_links` is
+    /// false or the page is `nofollow`.
+    async fn process_url_with_links(
         &self,
         url: &str,
         options: &ResearchOptions,
-    ) -> Result<ResearchResult, UtilsError> {
+    ) -> Result<(Option<ResearchResult>, Vec<String>), UtilsError> {
         // Check if already visited and mark atomically (prevents race conditions)
         {
             let mut visited = self.visited_urls.lock().await;
@@ -239,99 +665,340 @@ impl DeepResearch {
             visited.push(url.to_string());
         } // Lock released here
 
+        // 0. CRAWL POLICY: robots.txt Disallow + per-origin Crawl-delay
+        let mut crawl_delay = None;
+        let x_robots_tag = if options.ignore_robots {
+            None
+        } else {
+            let rules = self.robots_cache.rules_for(url).await;
+            let path = crate::utils::url_utils::path_of(url);
+            if !rules.is_allowed(&path) {
+                debug!("Skipping {} - disallowed by robots.txt", url);
+                return Ok((None, Vec::new()));
+            }
+            crawl_delay = rules.crawl_delay;
+            if let Some(delay) = rules.crawl_delay
+                && let Some(origin) = crate::utils::url_utils::origin_of(url)
+            {
+                self.crawl_delay_scheduler
+                    .wait_if_needed(&origin, delay)
+                    .await;
+            }
+            self.robots_cache.fetch_x_robots_tag(url).await
+        };
+
+        // 0.5 PACING: per-host token bucket, floored by robots.txt
+        // Crawl-delay when one was discovered above.
+        let host = crate::utils::url_utils::host_of(url);
+        if let Some(host) = &host {
+            self.host_rate_limiter
+                .acquire(
+                    host,
+                    options.requests_per_second_per_host,
+                    options.host_burst_capacity,
+                    crawl_delay,
+                )
+                .await;
+
+            if let Some(target) = options.adaptive_throttle_target_pages_per_second {
+                let slept = self.adaptive_throttle.throttle(host, target).await;
+                if slept > Duration::ZERO {
+                    self.accumulated_throttle_ms
+                        .fetch_add(slept.as_millis() as u64, std::sync::atomic::Ordering::Relaxed);
+                }
+            }
+        }
+
         // 1. NAVIGATE AND CAPTURE PAGE HANDLE
         debug!("Navigating to {} and capturing page handle", url);
-        
+
         let nav_tool = BrowserNavigateTool::new(self.browser_manager.clone());
         let nav_args = BrowserNavigateArgs {
             url: url.to_string(),
             wait_for_selector: None,
             timeout_ms: Some(options.timeout_seconds * 1000),
         };
-        
-        // Call internal method to get BOTH page and result
-        let (page, nav_result) = nav_tool
-            .navigate_and_capture_page(nav_args)
-            .await
-            .map_err(|e| UtilsError::BrowserError(e.to_string()))?;
-
-        // Parse final URL from result
-        let final_url = nav_result
-            .get("url")
-            .and_then(|v| v.as_str())
-            .unwrap_or(url)
-            .to_string();
-
-        // 2. EXTRACT PAGE INFO - uses captured page
-        debug!("Extracting page info from captured page");
-        let page_info = extract_page_info(page.clone())
+
+        let nav_started_at = Instant::now();
+
+        // Check the tab out of the "default" connection's bounded pool
+        // (see `crate::browser::TabPool`) instead of the single-page model,
+        // so concurrent in-flight tasks in `run_crawl` get real parallel
+        // page loads rather than serializing on one shared page.
+        let (mut tab, nav_result) = nav_tool
+            .navigate_and_capture_page_pooled("default", nav_args, self.control.as_ref())
             .await
-            .map_err(|e| UtilsError::BrowserError(e.to_string()))?;
+            .map_err(|e| navigation_error_to_utils_error(&e.to_string()))?;
+
+        if let Some(host) = &host
+            && options.adaptive_throttle_target_pages_per_second.is_some()
+        {
+            self.adaptive_throttle
+                .record(host, nav_started_at.elapsed())
+                .await;
+        }
+
+        let final_url = nav_result.url;
+
+        let page = tab.page.clone().ok_or_else(|| {
+            UtilsError::BrowserError("Pooled tab had no page after navigation".into())
+        })?;
+
+        // Everything past this point only borrows the captured page, so run
+        // it in its own scope and release the tab back to the pool
+        // afterwards regardless of outcome (`?`/`return` inside this block
+        // only exit the block, not `process_url_with_links`).
+        let outcome: Result<(Option<ResearchResult>, Vec<String>), UtilsError> = async {
+            // 2. EXTRACT PAGE INFO - uses captured page
+            debug!("Extracting page info from captured page");
+            let page_info = extract_page_info(page.clone())
+                .await
+                .map_err(|e| UtilsError::BrowserError(e.to_string()))?;
+
+            let title = page_info.title;
+
+            // 3. EXTRACT CONTENT DIRECTLY FROM CAPTURED PAGE
+            debug!("Extracting content from captured page");
+
+            // Extract text using JavaScript evaluation on captured page
+            // This ensures we extract from the correct page in parallel execution
+            let eval_result = page
+                .evaluate("document.body.innerText")
+                .await
+                .map_err(|e| UtilsError::BrowserError(format!("Failed to extract text: {}", e)))?;
+
+            // Parse result value
+            let text_value = eval_result.into_value().map_err(|e| {
+                UtilsError::BrowserError(format!("Failed to parse text result: {}", e))
+            })?;
+
+            // Extract string from Value, with fallback for SPAs
+            let content = if let serde_json::Value::String(text) = text_value {
+                text
+            } else {
+                // Fallback: get HTML and convert to text (for SPAs where innerText is empty)
+                let html = page.content().await.map_err(|e| {
+                    UtilsError::BrowserError(format!("Failed to get HTML content: {}", e))
+                })?;
+                html2md::parse_html(&html)
+            };
+
+            // A 2xx response can still be a CAPTCHA/"unusual traffic"
+            // interstitial - HTTP-status throttle detection alone misses this,
+            // so engage the same per-origin backoff the governor uses for a
+            // 429/503 and drop this page rather than summarizing a block page.
+            if crate::utils::is_block_page(&title, &content) {
+                if let Some(origin) = crate::utils::url_utils::origin_of(&final_url) {
+                    let retry_after = self
+                        .browser_manager
+                        .origin_governor()
+                        .note_throttled(&origin)
+                        .await;
+                    warn!(
+                        "Block page detected for {} - backing off {} for {:?}",
+                        final_url, origin, retry_after
+                    );
+                }
+                return Err(UtilsError::RateLimited {
+                    retry_after: std::time::Duration::from_secs(10),
+                });
+            }
+
+            // 4. CRAWL POLICY: <meta name="robots"> + X-Robots-Tag noindex/nofollow
+            let (noindex, nofollow) = if options.ignore_robots {
+                (false, false)
+            } else {
+                let meta_content = self.read_meta_robots(&page).await;
+                let (meta_noindex, meta_nofollow) = meta_content
+                    .as_deref()
+                    .map(crate::utils::parse_robots_directives)
+                    .unwrap_or_default();
+                let (header_noindex, header_nofollow) = x_robots_tag
+                    .as_deref()
+                    .map(crate::utils::parse_robots_directives)
+                    .unwrap_or_default();
+                (
+                    meta_noindex || header_noindex,
+                    meta_nofollow || header_nofollow,
+                )
+            };
+
+            // 5. DISCOVER LINKS FOR BFS EXPANSION (only when requested and allowed)
+            let links = if options.include_links && !nofollow {
+                self.discover_links(&page, &final_url).await
+            } else {
+                Vec::new()
+            };
+
+            if noindex {
+                debug!("Excluding {} from corpus - noindex", final_url);
+                return Ok((None, links));
+            }
+
+            // 6. GENERATE SUMMARY WITH CANDLEFLUENTAI (map-reduce over long content)
+            let summary = self.summarize_content(&title, &content, options).await?;
 
-        let title = page_info.title;
+            let result = ResearchResult {
+                url: final_url.clone(),
+                title,
+                content,
+                summary,
+                timestamp: chrono::Utc::now(),
+                metadata: page_info.metadata,
+            };
 
-        // 3. EXTRACT CONTENT DIRECTLY FROM CAPTURED PAGE
-        debug!("Extracting content from captured page");
-        
-        // Extract text using JavaScript evaluation on captured page
-        // This ensures we extract from the correct page in parallel execution
+            Ok((Some(result), links))
+        }
+        .await;
+
+        tab.release().await;
+        outcome
+    }
+
+    /// Read `<meta name="robots">`'s `content` attribute, if present
+    /// (case-insensitive on the `name` attribute, matching how browsers
+    /// treat it). `None` on any evaluation failure or if the tag is absent.
+    async fn read_meta_robots(&self, page: &chromiumoxide::Page) -> Option<String> {
         let eval_result = page
-            .evaluate("document.body.innerText")
+            .evaluate(
+                r#"document.querySelector('meta[name="robots" i]')?.getAttribute('content') ?? null"#,
+            )
             .await
-            .map_err(|e| UtilsError::BrowserError(format!("Failed to extract text: {}", e)))?;
+            .ok()?;
+        match eval_result.into_value() {
+            Ok(serde_json::Value::String(content)) => Some(content),
+            _ => None,
+        }
+    }
 
-        // Parse result value
-        let text_value = eval_result
-            .into_value()
-            .map_err(|e| UtilsError::BrowserError(format!("Failed to parse text result: {}", e)))?;
+    /// Parse anchor hrefs from the captured page, resolve them against
+    /// `final_url`, and keep only those on the same registrable domain.
+    async fn discover_links(&self, page: &chromiumoxide::Page, final_url: &str) -> Vec<String> {
+        let eval_result = match page
+            .evaluate(
+                "Array.from(document.querySelectorAll('a[href]')).map(a => a.getAttribute('href'))",
+            )
+            .await
+        {
+            Ok(r) => r,
+            Err(e) => {
+                warn!("Failed to read links from {}: {}", final_url, e);
+                return Vec::new();
+            }
+        };
 
-        // Extract string from Value, with fallback for SPAs
-        let content = if let serde_json::Value::String(text) = text_value {
-            text
-        } else {
-            // Fallback: get HTML and convert to text (for SPAs where innerText is empty)
-            let html = page
-                .content()
-                .await
-                .map_err(|e| UtilsError::BrowserError(format!("Failed to get HTML content: {}", e)))?;
-            html2md::parse_html(&html)
+        let hrefs: Vec<Option<String>> = match eval_result.into_value() {
+            Ok(v) => v,
+            Err(e) => {
+                warn!("Failed to parse links from {}: {}", final_url, e);
+                return Vec::new();
+            }
         };
 
-        // 4. GENERATE SUMMARY WITH CANDLEFLUENTAI
-        let summary = self.summarize_content(&title, &content).await?;
-
-        Ok(ResearchResult {
-            url: final_url,
-            title,
-            content,
-            summary,
-            timestamp: chrono::Utc::now(),
-            metadata: page_info.metadata,
-        })
+        let mut seen = std::collections::HashSet::new();
+        let mut links = Vec::new();
+        for href in hrefs.into_iter().flatten() {
+            if let Some(resolved) = crate::utils::url_utils::resolve_relative(final_url, &href)
+                && crate::utils::url_utils::same_registrable_domain(final_url, &resolved)
+                && seen.insert(resolved.clone())
+            {
+                links.push(resolved);
+            }
+        }
+
+        links
+    }
+
+    /// Summarize content, splitting into overlapping map-reduce chunks
+    /// instead of truncating when it exceeds `options.max_content_chars`.
+    ///
+    /// Short content is summarized directly. Long content is split into
+    /// overlapping chunks (map step, bounded by
+    /// [`MAX_CONCURRENT_CHUNK_SUMMARIES`] so fan-out here doesn't starve
+    /// permits needed for page processing), then the concatenated partial
+    /// summaries are summarized again (reduce step) to produce the final
+    /// summary. This keeps full-document coverage instead of dropping
+    /// everything past a fixed char cutoff.
+    async fn summarize_content(
+        &self,
+        title: &str,
+        content: &str,
+        options: &ResearchOptions,
+    ) -> Result<String, UtilsError> {
+        let chars: Vec<char> = content.chars().collect();
+        if chars.len() <= options.max_content_chars {
+            return self.summarize_chunk(title, content).await;
+        }
+
+        // MAP: summarize each overlapping chunk independently.
+        let stride = options
+            .max_content_chars
+            .saturating_sub(options.chunk_overlap)
+            .max(1);
+        let semaphore = Arc::new(Semaphore::new(MAX_CONCURRENT_CHUNK_SUMMARIES));
+        let mut join_set = JoinSet::new();
+        let mut chunk_index = 0usize;
+        let mut start = 0usize;
+        loop {
+            let end = (start + options.max_content_chars).min(chars.len());
+            let chunk: String = chars[start..end].iter().collect();
+            let this = self.clone();
+            let title = title.to_string();
+            let semaphore = Arc::clone(&semaphore);
+            let index = chunk_index;
+            join_set.spawn(async move {
+                let _permit = semaphore
+                    .acquire_owned()
+                    .await
+                    .map_err(|e| UtilsError::UnexpectedError(format!("Semaphore closed: {}", e)))?;
+                this.summarize_chunk(&title, &chunk)
+                    .await
+                    .map(|s| (index, s))
+            });
+
+            if end == chars.len() {
+                break;
+            }
+            chunk_index += 1;
+            start += stride;
+        }
+
+        let mut partials = Vec::with_capacity(chunk_index + 1);
+        while let Some(joined) = join_set.join_next().await {
+            let (index, summary) = joined.map_err(|e| {
+                UtilsError::UnexpectedError(format!("Chunk summarization task panicked: {}", e))
+            })??;
+            partials.push((index, summary));
+        }
+        partials.sort_by_key(|(index, _)| *index);
+
+        info!(
+            "Map-reduce summarization: {} chunks of content ({} chars)",
+            partials.len(),
+            chars.len()
+        );
+
+        // REDUCE: summarize the concatenated partial summaries.
+        let combined = partials
+            .into_iter()
+            .map(|(_, summary)| summary)
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        self.summarize_chunk(title, &combined).await
     }
 
-    /// Summarize content using CandleFluentAi streaming
+    /// Summarize a single chunk of content using CandleFluentAi streaming.
     ///
     /// Creates an LLM agent on-demand with configured temperature and max_tokens.
     /// Streams response in real-time for better perceived performance.
     ///
     /// # Pattern Reference
     /// Based on: packages/tools-candle-agent/examples/fluent_builder.rs:58-90
-    async fn summarize_content(&self, title: &str, content: &str) -> Result<String, UtilsError> {
-        // Truncate content if too long (avoid context overflow)
-        // Use char-based truncation to prevent UTF-8 boundary panics
-        let max_content_chars = 8000;
-        let truncated_content = if content.chars().count() > max_content_chars {
-            let truncated: String = content.chars().take(max_content_chars).collect();
-            format!("{}... [content truncated]", truncated)
-        } else {
-            content.to_string()
-        };
-
+    async fn summarize_chunk(&self, title: &str, content: &str) -> Result<String, UtilsError> {
         // Build prompt
         let prompt = format!(
             "Please summarize the following webpage content.\n\nTitle: '{}'\n\nContent:\n{}",
-            title, truncated_content
+            title, content
         );
 
         // Create streaming agent with CandleFluentAi builder
@@ -384,3 +1051,24 @@ impl DeepResearch {
         Ok(summary)
     }
 }
+
+/// Recover a throttle signal from `BrowserNavigateTool::navigate_and_capture_page`'s
+/// error message, if present (see `crate::tools::navigate::RATE_LIMITED_MARKER`),
+/// mapping it to the structured `UtilsError::RateLimited` the rest of this
+/// module already uses for search-engine throttling - anything else becomes
+/// a generic `UtilsError::BrowserError` as before.
+fn navigation_error_to_utils_error(message: &str) -> UtilsError {
+    let Some(rest) = message.strip_prefix(crate::tools::navigate::RATE_LIMITED_MARKER) else {
+        return UtilsError::BrowserError(message.to_string());
+    };
+
+    let millis = rest
+        .split(|c: char| !c.is_ascii_digit())
+        .next()
+        .and_then(|digits| digits.parse::<u64>().ok())
+        .unwrap_or(5000);
+
+    UtilsError::RateLimited {
+        retry_after: std::time::Duration::from_millis(millis),
+    }
+}
@@ -0,0 +1,140 @@
+//! Per-page CDP diagnostics: console messages and uncaught exceptions
+//!
+//! Callers driving a page through a tool call (MCP or direct) only ever see
+//! that tool's return value, so a page that logs a warning or throws an
+//! uncaught exception mid-action leaves the caller blind to it. This module
+//! subscribes to the CDP Runtime domain's `consoleAPICalled` and
+//! `exceptionThrown` events for a page and buffers them in a bounded ring
+//! buffer that can be drained independently of any single tool call.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use chromiumoxide::Page;
+use chromiumoxide::cdp::browser_protocol::runtime::{
+    ConsoleApiCalledType, EventConsoleApiCalled, EventExceptionThrown,
+};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+use tracing::debug;
+
+/// Maximum diagnostics retained before the oldest are dropped, protecting
+/// against unbounded growth on pages that log continuously.
+const MAX_BUFFERED_DIAGNOSTICS: usize = 200;
+
+/// Severity of a captured page diagnostic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiagnosticLevel {
+    Log,
+    Info,
+    Warning,
+    Error,
+}
+
+/// A single console message or uncaught exception captured from a page.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PageDiagnostic {
+    pub level: DiagnosticLevel,
+    pub text: String,
+    pub source_url: Option<String>,
+    pub line: Option<i64>,
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+}
+
+/// Bounded ring buffer of diagnostics captured from a page's CDP event
+/// streams. Shared process-wide on `BrowserManager` so it keeps capturing
+/// across page recreation (each navigation re-subscribes to the fresh page).
+#[derive(Default)]
+pub struct PageDiagnostics {
+    buffer: Mutex<VecDeque<PageDiagnostic>>,
+}
+
+impl PageDiagnostics {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    /// Subscribe to `page`'s console and exception events, pushing each
+    /// into the ring buffer until the page's event streams close.
+    ///
+    /// Safe to call again after navigation recreates the page; the old
+    /// page's listener tasks simply end when its streams close.
+    pub async fn subscribe(
+        self: &Arc<Self>,
+        page: &Page,
+    ) -> Result<(), chromiumoxide::error::CdpError> {
+        let mut console_events = page.event_listener::<EventConsoleApiCalled>().await?;
+        let this = Arc::clone(self);
+        tokio::spawn(async move {
+            while let Some(event) = console_events.next().await {
+                let level = match event.r#type {
+                    ConsoleApiCalledType::Error => DiagnosticLevel::Error,
+                    ConsoleApiCalledType::Warning => DiagnosticLevel::Warning,
+                    ConsoleApiCalledType::Info => DiagnosticLevel::Info,
+                    _ => DiagnosticLevel::Log,
+                };
+                let text = event
+                    .args
+                    .iter()
+                    .filter_map(|arg| {
+                        arg.value
+                            .as_ref()
+                            .map(|v| v.to_string())
+                            .or_else(|| arg.description.clone())
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                this.push(PageDiagnostic {
+                    level,
+                    text,
+                    source_url: None,
+                    line: None,
+                    timestamp: chrono::Utc::now(),
+                })
+                .await;
+            }
+            debug!("Console diagnostics listener ended (page navigated or closed)");
+        });
+
+        let mut exception_events = page.event_listener::<EventExceptionThrown>().await?;
+        let this = Arc::clone(self);
+        tokio::spawn(async move {
+            while let Some(event) = exception_events.next().await {
+                let details = event.exception_details;
+                let text = details
+                    .exception
+                    .as_ref()
+                    .and_then(|e| e.description.clone())
+                    .unwrap_or(details.text);
+                this.push(PageDiagnostic {
+                    level: DiagnosticLevel::Error,
+                    text,
+                    source_url: details.url,
+                    line: Some(details.line_number),
+                    timestamp: chrono::Utc::now(),
+                })
+                .await;
+            }
+            debug!("Exception diagnostics listener ended (page navigated or closed)");
+        });
+
+        Ok(())
+    }
+
+    async fn push(&self, diagnostic: PageDiagnostic) {
+        let mut buffer = self.buffer.lock().await;
+        if buffer.len() >= MAX_BUFFERED_DIAGNOSTICS {
+            buffer.pop_front();
+        }
+        buffer.push_back(diagnostic);
+    }
+
+    /// Drain all diagnostics buffered since the last drain, emptying the
+    /// buffer for whatever accumulates next.
+    pub async fn drain(&self) -> Vec<PageDiagnostic> {
+        let mut buffer = self.buffer.lock().await;
+        buffer.drain(..).collect()
+    }
+}
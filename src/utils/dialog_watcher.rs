@@ -0,0 +1,134 @@
+//! Per-page JS dialog (`alert`/`confirm`/`prompt`/`beforeunload`) handling
+//!
+//! A CDP-driven page blocks the renderer on any of these dialogs until
+//! `Page.handleJavaScriptDialog` is called, but they can open at any point
+//! during an unrelated tool call (e.g. a `browser_click` triggering a
+//! `confirm()`). Mirrors `PageDiagnostics`: subscribed once per page
+//! alongside it in `BrowserNavigateTool::finish_navigation`, shared
+//! process-wide on `BrowserManager` so `browser_dialog` can observe and
+//! resolve whatever's currently open.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chromiumoxide::Page;
+use chromiumoxide::cdp::browser_protocol::page::{
+    EventJavascriptDialogOpening, HandleJavaScriptDialogParams,
+};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use tokio::sync::{Mutex, oneshot};
+use tracing::warn;
+
+/// How long to wait for `browser_dialog` to resolve an open dialog before
+/// falling back to dismissing it, so an unhandled dialog doesn't hang the
+/// page (and every subsequent navigation on it) forever.
+const AUTO_DISMISS_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Snapshot of the currently open dialog, if any.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingDialog {
+    pub message: String,
+    /// `"alert"`, `"confirm"`, `"prompt"`, or `"beforeunload"`.
+    pub dialog_type: String,
+    /// `prompt()`'s default value, if the dialog is a prompt.
+    pub default_prompt: Option<String>,
+}
+
+#[derive(Default)]
+struct Inner {
+    pending: Option<PendingDialog>,
+    resolver: Option<oneshot::Sender<(bool, Option<String>)>>,
+}
+
+/// Shared per-page dialog state. See the module doc comment for the
+/// subscribe-once, resolve-from-anywhere design.
+#[derive(Default)]
+pub struct DialogWatcher {
+    inner: Mutex<Inner>,
+}
+
+impl DialogWatcher {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    /// Subscribe to `page`'s `Page.javascriptDialogOpening` events. Each
+    /// dialog is held open until `resolve` is called (from `browser_dialog`)
+    /// or `AUTO_DISMISS_TIMEOUT` elapses, whichever comes first.
+    ///
+    /// Safe to call again after navigation recreates the page, matching
+    /// `PageDiagnostics::subscribe`.
+    pub async fn subscribe(
+        self: &Arc<Self>,
+        page: &Page,
+    ) -> Result<(), chromiumoxide::error::CdpError> {
+        let mut dialog_events = page
+            .event_listener::<EventJavascriptDialogOpening>()
+            .await?;
+        let this = Arc::clone(self);
+        let page = page.clone();
+        tokio::spawn(async move {
+            while let Some(event) = dialog_events.next().await {
+                let (tx, rx) = oneshot::channel();
+                {
+                    let mut inner = this.inner.lock().await;
+                    inner.pending = Some(PendingDialog {
+                        message: event.message.clone(),
+                        dialog_type: format!("{:?}", event.r#type).to_lowercase(),
+                        default_prompt: event.default_prompt.clone(),
+                    });
+                    inner.resolver = Some(tx);
+                }
+
+                let (accept, prompt_text) = tokio::time::timeout(AUTO_DISMISS_TIMEOUT, rx)
+                    .await
+                    .ok()
+                    .and_then(Result::ok)
+                    .unwrap_or_else(|| {
+                        warn!(
+                            "Dialog left unhandled for {:?}, auto-dismissing",
+                            AUTO_DISMISS_TIMEOUT
+                        );
+                        (false, None)
+                    });
+
+                let mut builder = HandleJavaScriptDialogParams::builder().accept(accept);
+                if let Some(text) = prompt_text {
+                    builder = builder.prompt_text(text);
+                }
+                match builder.build() {
+                    Ok(params) => {
+                        if let Err(e) = page.execute(params).await {
+                            warn!("Failed to handle JS dialog: {}", e);
+                        }
+                    }
+                    Err(e) => warn!("Failed to build dialog response params: {}", e),
+                }
+
+                let mut inner = this.inner.lock().await;
+                inner.pending = None;
+                inner.resolver = None;
+            }
+        });
+        Ok(())
+    }
+
+    /// The currently open dialog's info, if any.
+    pub async fn pending(&self) -> Option<PendingDialog> {
+        self.inner.lock().await.pending.clone()
+    }
+
+    /// Resolve the currently open dialog. Returns `false` if nothing was
+    /// pending (already handled, auto-dismissed, or none ever opened).
+    pub async fn resolve(&self, accept: bool, prompt_text: Option<String>) -> bool {
+        let mut inner = self.inner.lock().await;
+        match inner.resolver.take() {
+            Some(tx) => {
+                let _ = tx.send((accept, prompt_text));
+                true
+            }
+            None => false,
+        }
+    }
+}
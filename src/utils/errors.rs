@@ -35,6 +35,12 @@ pub enum UtilsError {
 
     #[error("Unexpected error: {0}")]
     UnexpectedError(String),
+
+    #[error("Research queue full, evicted pending request; retry after {retry_after:?}")]
+    QueueOverflow { retry_after: std::time::Duration },
+
+    #[error("Rate limited by upstream service; retry after {retry_after:?}")]
+    RateLimited { retry_after: std::time::Duration },
 }
 
 /// Implement From<BrowserError> for UtilsError
@@ -0,0 +1,152 @@
+//! Per-page CDP event tracking for the agent's event-driven step loop
+//!
+//! `AgentInner::process_step` used to call `browser_extract_text` +
+//! `browser_screenshot` on every step, even when nothing on the page had
+//! changed since the last one. This subscribes to the CDP signals that
+//! actually indicate something worth re-observing - navigation,
+//! network errors, console errors, and gross DOM updates - and buffers them
+//! in a bounded ring buffer (same shape as [`super::PageDiagnostics`]) that
+//! the agent drains once per step via `browser_events` instead of paying
+//! for a fresh screenshot unconditionally.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use chromiumoxide::Page;
+use chromiumoxide::cdp::browser_protocol::dom::EventDocumentUpdated;
+use chromiumoxide::cdp::browser_protocol::network::EventResponseReceived;
+use chromiumoxide::cdp::browser_protocol::page::EventFrameNavigated;
+use chromiumoxide::cdp::browser_protocol::runtime::{ConsoleApiCalledType, EventConsoleApiCalled};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+use tracing::debug;
+
+/// Maximum events retained before the oldest are dropped, same bound as
+/// [`super::PageDiagnostics`] for the same reason: pages that churn
+/// continuously (polling XHRs, chatty console) shouldn't grow this unbounded.
+const MAX_BUFFERED_EVENTS: usize = 200;
+
+/// A single CDP signal material to whether the agent should re-observe the
+/// page. Deliberately coarse - this drives a re-screenshot decision, not a
+/// detailed diff.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum BrowserEvent {
+    /// A new top-level document loaded. Also the signal that invalidates
+    /// any cached `[data-mcp-index]` element mapping, since indices are
+    /// only valid for the document they were computed against.
+    Navigated { url: String },
+    /// A sub-resource or document response came back with an error status.
+    NetworkError { url: String, status: u16 },
+    /// `console.error(...)` was called.
+    ConsoleError { text: String },
+    /// The DOM tree was invalidated/rebuilt (CDP `DOM.documentUpdated`) -
+    /// a coarse proxy for "something mutated enough to matter" since CDP
+    /// has no cheap fine-grained mutation-observer equivalent.
+    DomUpdated,
+}
+
+/// Bounded ring buffer of [`BrowserEvent`]s captured from a page's CDP event
+/// streams. Shared process-wide on `BrowserManager`, re-subscribed on every
+/// navigation (same lifecycle as [`super::PageDiagnostics`]).
+#[derive(Default)]
+pub struct EventTracker {
+    buffer: Mutex<VecDeque<BrowserEvent>>,
+}
+
+impl EventTracker {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    /// Subscribe to `page`'s navigation, network, console, and DOM update
+    /// events, pushing each into the ring buffer until the page's event
+    /// streams close. Safe to call again after navigation recreates the
+    /// page.
+    pub async fn subscribe(self: &Arc<Self>, page: &Page) -> Result<(), chromiumoxide::error::CdpError> {
+        let mut nav_events = page.event_listener::<EventFrameNavigated>().await?;
+        let this = Arc::clone(self);
+        tokio::spawn(async move {
+            while let Some(event) = nav_events.next().await {
+                // Only the top-level frame's navigation invalidates cached
+                // element indices - a nested iframe renavigating doesn't.
+                if event.frame.parent_id.is_none() {
+                    this.push(BrowserEvent::Navigated {
+                        url: event.frame.url.clone(),
+                    })
+                    .await;
+                }
+            }
+            debug!("Navigation event listener ended (page navigated or closed)");
+        });
+
+        let mut response_events = page.event_listener::<EventResponseReceived>().await?;
+        let this = Arc::clone(self);
+        tokio::spawn(async move {
+            while let Some(event) = response_events.next().await {
+                let status = event.response.status;
+                if status >= 400
+                    && let Ok(status) = u16::try_from(status)
+                {
+                    this.push(BrowserEvent::NetworkError {
+                        url: event.response.url.clone(),
+                        status,
+                    })
+                    .await;
+                }
+            }
+            debug!("Network event listener ended (page navigated or closed)");
+        });
+
+        let mut console_events = page.event_listener::<EventConsoleApiCalled>().await?;
+        let this = Arc::clone(self);
+        tokio::spawn(async move {
+            while let Some(event) = console_events.next().await {
+                if event.r#type != ConsoleApiCalledType::Error {
+                    continue;
+                }
+                let text = event
+                    .args
+                    .iter()
+                    .filter_map(|arg| {
+                        arg.value
+                            .as_ref()
+                            .map(|v| v.to_string())
+                            .or_else(|| arg.description.clone())
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                this.push(BrowserEvent::ConsoleError { text }).await;
+            }
+            debug!("Console event listener ended (page navigated or closed)");
+        });
+
+        let mut document_events = page.event_listener::<EventDocumentUpdated>().await?;
+        let this = Arc::clone(self);
+        tokio::spawn(async move {
+            while document_events.next().await.is_some() {
+                this.push(BrowserEvent::DomUpdated).await;
+            }
+            debug!("DOM update event listener ended (page navigated or closed)");
+        });
+
+        Ok(())
+    }
+
+    async fn push(&self, event: BrowserEvent) {
+        let mut buffer = self.buffer.lock().await;
+        if buffer.len() >= MAX_BUFFERED_EVENTS {
+            buffer.pop_front();
+        }
+        buffer.push_back(event);
+    }
+
+    /// Drain all events buffered since the last drain, emptying the buffer
+    /// for whatever accumulates next. The buffer stays bounded without it:
+    /// `push` drops the oldest event once [`MAX_BUFFERED_EVENTS`] are held.
+    pub async fn drain(&self) -> Vec<BrowserEvent> {
+        let mut buffer = self.buffer.lock().await;
+        buffer.drain(..).collect()
+    }
+}
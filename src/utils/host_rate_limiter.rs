@@ -0,0 +1,106 @@
+//! Per-host token-bucket rate limiting for a single research crawl.
+//!
+//! Distinct from [`crate::utils::OriginGovernor`], which is process-wide and
+//! configured once at `BrowserManager` construction: this one is owned by a
+//! single `DeepResearch` instance and its rate/burst come from
+//! `ResearchOptions` on every call, so one research session's pacing doesn't
+//! affect another's and a caller can tune it per request.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use tokio::sync::Mutex;
+use tracing::debug;
+
+struct Bucket {
+    tokens: f64,
+    capacity: f64,
+    refill_per_sec: f64,
+    last_refill: Instant,
+    last_dispatch: Instant,
+}
+
+/// Per-host token bucket, lazily created on first use. `acquire` never
+/// rejects a caller - it sleeps out whatever wait is needed, since a
+/// background research crawl should slow down against a host rather than
+/// abort.
+#[derive(Default)]
+pub struct HostRateLimiter {
+    buckets: Mutex<HashMap<String, Bucket>>,
+}
+
+impl HostRateLimiter {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wait until `host` has a free token, then consume one.
+    ///
+    /// `requests_per_second`/`burst_capacity` size the bucket (re-applied on
+    /// every call, in case `ResearchOptions` differs between research()
+    /// calls sharing this instance). `min_interval`, when set, is an
+    /// additional floor on the gap since this host's last dispatched
+    /// request - e.g. a robots.txt `Crawl-delay` stricter than the
+    /// configured rate still gets honored even when a token is available.
+    pub async fn acquire(
+        &self,
+        host: &str,
+        requests_per_second: f64,
+        burst_capacity: u32,
+        min_interval: Option<Duration>,
+    ) {
+        let capacity = f64::from(burst_capacity.max(1));
+        let refill_per_sec = requests_per_second.max(0.001);
+
+        loop {
+            let wait = {
+                let mut buckets = self.buckets.lock().await;
+                let now = Instant::now();
+                let bucket = buckets.entry(host.to_string()).or_insert_with(|| Bucket {
+                    tokens: capacity,
+                    capacity,
+                    refill_per_sec,
+                    last_refill: now,
+                    // Far enough in the past that a host's first request
+                    // is never delayed by `min_interval`.
+                    last_dispatch: now - Duration::from_secs(3600),
+                });
+                bucket.capacity = capacity;
+                bucket.refill_per_sec = refill_per_sec;
+
+                let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+                bucket.tokens =
+                    (bucket.tokens + elapsed * bucket.refill_per_sec).min(bucket.capacity);
+                bucket.last_refill = now;
+
+                let rate_wait = (bucket.tokens < 1.0).then(|| {
+                    Duration::from_secs_f64((1.0 - bucket.tokens) / bucket.refill_per_sec)
+                });
+                let delay_wait = min_interval.and_then(|min_interval| {
+                    min_interval.checked_sub(now.duration_since(bucket.last_dispatch))
+                });
+
+                match rate_wait.into_iter().chain(delay_wait).max() {
+                    Some(wait) => Some(wait),
+                    None => {
+                        bucket.tokens -= 1.0;
+                        bucket.last_dispatch = now;
+                        None
+                    }
+                }
+            };
+
+            match wait {
+                None => return,
+                Some(wait) => {
+                    debug!(
+                        "Host rate limit: sleeping {:?} before next request to {}",
+                        wait, host
+                    );
+                    tokio::time::sleep(wait).await;
+                }
+            }
+        }
+    }
+}
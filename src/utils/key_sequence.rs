@@ -0,0 +1,249 @@
+//! Parsing and CDP dispatch for WebDriver-style key sequences
+//!
+//! `BrowserTypeTextTool` and `browser_press_key` both need to turn a string
+//! like `"hello{Enter}"` or `"{Ctrl+A}"` into real key events rather than
+//! literal characters - `element.type_str` only inserts characters, it has
+//! no notion of "press Enter" or "hold Ctrl". Tokens are written the same
+//! way WebDriver's `ElementSendKeys` spec does: a literal run of characters,
+//! or a `{Name}` / `{Mod+Name}` token. Dispatched via CDP
+//! `Input.dispatchKeyEvent`, which targets whatever element currently has
+//! focus - no element handle needed, unlike `type_str`/`call_js_fn`.
+
+use chromiumoxide::Page;
+use chromiumoxide::cdp::browser_protocol::input::{DispatchKeyEventParams, DispatchKeyEventType};
+
+/// One piece of a parsed key sequence: either literal text (typed via
+/// `type_str`) or a named key, optionally held under modifiers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeySegment {
+    Literal(String),
+    Key(KeyToken),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyToken {
+    pub modifiers: Vec<Modifier>,
+    /// The key name as written in a `{...}` token, e.g. `"Enter"` or `"A"`.
+    pub key: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Modifier {
+    Alt,
+    Ctrl,
+    Meta,
+    Shift,
+}
+
+impl Modifier {
+    /// CDP's `Input.dispatchKeyEvent` modifiers bitmask.
+    fn bit(self) -> u32 {
+        match self {
+            Modifier::Alt => 1,
+            Modifier::Ctrl => 2,
+            Modifier::Meta => 4,
+            Modifier::Shift => 8,
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "ctrl" | "control" => Some(Modifier::Ctrl),
+            "alt" => Some(Modifier::Alt),
+            "meta" | "cmd" | "command" => Some(Modifier::Meta),
+            "shift" => Some(Modifier::Shift),
+            _ => None,
+        }
+    }
+}
+
+fn modifiers_bitmask(modifiers: &[Modifier]) -> u32 {
+    modifiers.iter().fold(0, |acc, m| acc | m.bit())
+}
+
+/// Known non-printable keys and the CDP `key`/`code`/`windowsVirtualKeyCode`
+/// triple they dispatch as. Extend as new `{Name}` tokens are needed.
+fn named_key(name: &str) -> Option<(&'static str, &'static str, i64)> {
+    match name.to_ascii_lowercase().as_str() {
+        "enter" | "return" => Some(("Enter", "Enter", 13)),
+        "tab" => Some(("Tab", "Tab", 9)),
+        "backspace" => Some(("Backspace", "Backspace", 8)),
+        "delete" | "del" => Some(("Delete", "Delete", 46)),
+        "escape" | "esc" => Some(("Escape", "Escape", 27)),
+        "space" => Some((" ", "Space", 32)),
+        "arrowup" | "up" => Some(("ArrowUp", "ArrowUp", 38)),
+        "arrowdown" | "down" => Some(("ArrowDown", "ArrowDown", 40)),
+        "arrowleft" | "left" => Some(("ArrowLeft", "ArrowLeft", 37)),
+        "arrowright" | "right" => Some(("ArrowRight", "ArrowRight", 39)),
+        "home" => Some(("Home", "Home", 36)),
+        "end" => Some(("End", "End", 35)),
+        "pageup" => Some(("PageUp", "PageUp", 33)),
+        "pagedown" => Some(("PageDown", "PageDown", 34)),
+        _ => None,
+    }
+}
+
+/// Resolve a single key name (inside a `{...}` token, after stripping any
+/// modifiers) to the `key`/`code`/`windowsVirtualKeyCode` triple CDP needs.
+/// Falls back to treating a single printable character as itself, so
+/// `{Ctrl+A}` works without needing "A" in `named_key`.
+fn resolve_key(name: &str) -> (String, String, i64) {
+    if let Some((key, code, vk)) = named_key(name) {
+        return (key.to_string(), code.to_string(), vk);
+    }
+    if let Some(ch) = name.chars().next().filter(|_| name.chars().count() == 1) {
+        let upper = ch.to_ascii_uppercase();
+        let code = if upper.is_ascii_alphabetic() {
+            format!("Key{upper}")
+        } else if upper.is_ascii_digit() {
+            format!("Digit{upper}")
+        } else {
+            upper.to_string()
+        };
+        return (ch.to_string(), code, upper as i64);
+    }
+    (name.to_string(), name.to_string(), 0)
+}
+
+/// Parse a WebDriver-style key sequence into literal runs and `{...}`
+/// tokens. A token's body is split on `+`; every part but the last must
+/// name a modifier (`{Ctrl+A}`), the last part is the key itself.
+pub fn parse_key_sequence(input: &str) -> Vec<KeySegment> {
+    let mut segments = Vec::new();
+    let mut literal = String::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == '{' {
+            let mut token = String::new();
+            let mut closed = false;
+            for c2 in chars.by_ref() {
+                if c2 == '}' {
+                    closed = true;
+                    break;
+                }
+                token.push(c2);
+            }
+            if !closed || token.is_empty() {
+                // Unterminated or empty `{...}` - treat literally.
+                literal.push('{');
+                literal.push_str(&token);
+                if closed {
+                    literal.push('}');
+                }
+                continue;
+            }
+
+            if !literal.is_empty() {
+                segments.push(KeySegment::Literal(std::mem::take(&mut literal)));
+            }
+
+            let mut parts: Vec<&str> = token.split('+').map(str::trim).collect();
+            let key = parts.pop().unwrap_or_default().to_string();
+            let modifiers = parts
+                .iter()
+                .filter_map(|p| Modifier::from_name(p))
+                .collect();
+            segments.push(KeySegment::Key(KeyToken { modifiers, key }));
+        } else {
+            literal.push(c);
+        }
+    }
+
+    if !literal.is_empty() {
+        segments.push(KeySegment::Literal(literal));
+    }
+
+    segments
+}
+
+/// Dispatch one key token via `Input.dispatchKeyEvent`: press and hold each
+/// modifier, press+release the inner key with the modifier bitmask applied,
+/// then release the modifiers in reverse order.
+pub async fn dispatch_key_token(page: &Page, token: &KeyToken) -> anyhow::Result<()> {
+    let bitmask = modifiers_bitmask(&token.modifiers);
+    let (key, code, vk) = resolve_key(&token.key);
+
+    for modifier in &token.modifiers {
+        dispatch_raw(
+            page,
+            DispatchKeyEventType::RawKeyDown,
+            modifier_key(*modifier),
+            0,
+        )
+        .await?;
+    }
+
+    dispatch_keyed(
+        page,
+        DispatchKeyEventType::RawKeyDown,
+        &key,
+        &code,
+        vk,
+        bitmask,
+    )
+    .await?;
+    if key.chars().count() == 1 {
+        dispatch_keyed(page, DispatchKeyEventType::Char, &key, &code, vk, bitmask).await?;
+    }
+    dispatch_keyed(page, DispatchKeyEventType::KeyUp, &key, &code, vk, bitmask).await?;
+
+    for modifier in token.modifiers.iter().rev() {
+        dispatch_raw(
+            page,
+            DispatchKeyEventType::KeyUp,
+            modifier_key(*modifier),
+            0,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+fn modifier_key(modifier: Modifier) -> &'static str {
+    match modifier {
+        Modifier::Alt => "Alt",
+        Modifier::Ctrl => "Control",
+        Modifier::Meta => "Meta",
+        Modifier::Shift => "Shift",
+    }
+}
+
+async fn dispatch_raw(
+    page: &Page,
+    event_type: DispatchKeyEventType,
+    key: &str,
+    modifiers: u32,
+) -> anyhow::Result<()> {
+    let params = DispatchKeyEventParams::builder()
+        .r#type(event_type)
+        .key(key)
+        .code(key)
+        .modifiers(modifiers as i64)
+        .build()
+        .map_err(|e| anyhow::anyhow!("Failed to build key event for '{key}': {e}"))?;
+    page.execute(params).await?;
+    Ok(())
+}
+
+async fn dispatch_keyed(
+    page: &Page,
+    event_type: DispatchKeyEventType,
+    key: &str,
+    code: &str,
+    windows_virtual_key_code: i64,
+    modifiers: u32,
+) -> anyhow::Result<()> {
+    let params = DispatchKeyEventParams::builder()
+        .r#type(event_type)
+        .key(key)
+        .code(code)
+        .windows_virtual_key_code(windows_virtual_key_code)
+        .native_virtual_key_code(windows_virtual_key_code)
+        .modifiers(modifiers as i64)
+        .build()
+        .map_err(|e| anyhow::anyhow!("Failed to build key event for '{key}': {e}"))?;
+    page.execute(params).await?;
+    Ok(())
+}
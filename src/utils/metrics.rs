@@ -0,0 +1,419 @@
+//! Process-wide instrumentation exposed as Prometheus text exposition format
+//!
+//! Every tool invocation is recorded against a per-tool-name counter set
+//! (invocations, successes, failures) plus a latency histogram with fixed
+//! buckets, mirroring Prometheus's own `Histogram` shape so the rendered
+//! output needs no external scraping library. Browser-level state
+//! (live pages, active research sessions, relaunch count) is tracked
+//! separately as plain gauges since it isn't keyed by tool name.
+//!
+//! This intentionally hand-rolls exposition-format rendering rather than
+//! pulling in a metrics crate: the surface area here (counters + one
+//! histogram shape) is small enough that a dependency would cost more in
+//! API surface than it saves.
+//!
+//! Agent (`agents_active`, `agents_completed_total`, `agent_steps_total`)
+//! and research (`research_sessions_running`, `research_pages_visited_total`,
+//! `research_runtime_seconds`) series are recorded here too, incremented
+//! from `AgentRegistry`/`AgentSession`/`RecordingStepReporter` and
+//! `research::session::ResearchSession` at the points their underlying
+//! state already changes - there's no separate exporter for them, for the
+//! same no-extra-dependency reason as the rest of this module. There's
+//! also no `init_exporter`/scrape-server entry point here: as noted on
+//! `start_server_with_listener`, `ServerBuilder` doesn't yet expose a hook
+//! for mounting an extra HTTP route alongside the MCP router, so serving
+//! this text over `Config::metrics.bind_path` is deferred until that
+//! lands; until then, embedders can render it directly via
+//! [`ToolMetrics::render_prometheus`].
+//!
+//! There's no pluggable sink abstraction for pushing these series to a
+//! remote time-series store (InfluxDB/ClickHouse-style batched insert)
+//! either, and for the same reason as the hand-rolled rendering above: a
+//! batching client, its own retry/backpressure handling, and a trait to
+//! keep it swappable is a lot of surface for what a pull-based Prometheus
+//! scrape (once it has a route to scrape) already covers for free. An
+//! embedder that wants push-based telemetry today has to poll
+//! [`ToolMetrics::render_prometheus`] itself and forward it onward.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
+use tokio::sync::Mutex;
+
+/// Upper bounds (seconds) of the latency histogram buckets, in increasing
+/// order. The last bucket is implicitly `+Inf`.
+const LATENCY_BUCKETS_SECS: &[f64] = &[0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+
+/// Upper bounds (seconds) of the `research_runtime_seconds` histogram.
+/// Research runs are long-lived multi-page crawls rather than single tool
+/// calls, so this reaches much further out than `LATENCY_BUCKETS_SECS`.
+const RESEARCH_RUNTIME_BUCKETS_SECS: &[f64] =
+    &[5.0, 15.0, 30.0, 60.0, 120.0, 300.0, 600.0, 1800.0];
+
+/// A single unlabeled Prometheus histogram - same bucket/sum/count shape as
+/// `ToolCounters`' latency histogram, but not keyed by tool name.
+struct Histogram {
+    bucket_counts: Vec<AtomicU64>,
+    sum_secs: Mutex<f64>,
+    count: AtomicU64,
+}
+
+impl Histogram {
+    fn new(buckets: &[f64]) -> Self {
+        Self {
+            bucket_counts: buckets.iter().map(|_| AtomicU64::new(0)).collect(),
+            sum_secs: Mutex::new(0.0),
+            count: AtomicU64::new(0),
+        }
+    }
+
+    async fn observe(&self, buckets: &[f64], duration: Duration) {
+        let secs = duration.as_secs_f64();
+        for (bound, bucket) in buckets.iter().zip(self.bucket_counts.iter()) {
+            if secs <= *bound {
+                bucket.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+        *self.sum_secs.lock().await += secs;
+        self.count.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+struct ToolCounters {
+    invocations: AtomicU64,
+    successes: AtomicU64,
+    failures: AtomicU64,
+    /// One counter per entry in `LATENCY_BUCKETS_SECS`, each counting
+    /// observations `<=` that bucket's bound (cumulative, as Prometheus
+    /// histograms require).
+    bucket_counts: Vec<AtomicU64>,
+    sum_secs: Mutex<f64>,
+}
+
+impl ToolCounters {
+    fn new() -> Self {
+        Self {
+            invocations: AtomicU64::new(0),
+            successes: AtomicU64::new(0),
+            failures: AtomicU64::new(0),
+            bucket_counts: LATENCY_BUCKETS_SECS
+                .iter()
+                .map(|_| AtomicU64::new(0))
+                .collect(),
+            sum_secs: Mutex::new(0.0),
+        }
+    }
+}
+
+/// Process-wide counters and gauges for the tool server.
+///
+/// Access via [`ToolMetrics::global`]; there is one instance per process,
+/// shared by every tool invocation and by `BrowserManager`.
+///
+/// Everything here lives purely in memory and is read back on demand by
+/// [`ToolMetrics::render_prometheus`] - there's no backing file or writer,
+/// so there's nothing to flush and no persistence step a caller could wait
+/// on for a durability guarantee before a critical operation. A process
+/// restart loses all counters; that's an accepted tradeoff for a metrics
+/// surface that's meant to be scraped live, not one call sites need to
+/// synchronously confirm was written to disk.
+pub struct ToolMetrics {
+    /// Keyed by tool name (a fixed, compile-time-bounded set), not by call -
+    /// so unlike an ever-growing append-only log this never needs rotation:
+    /// there is no file here to close, rename, gzip, and reopen once it
+    /// crosses a size/age threshold, and these counters are never reset for
+    /// the life of the process (a full history, not a retained window).
+    tools: Mutex<HashMap<&'static str, Arc<ToolCounters>>>,
+    active_pages: AtomicU64,
+    live_research_sessions: AtomicU64,
+    relaunch_count: AtomicU64,
+
+    /// Agent sessions currently tracked by an `AgentRegistry` (not yet
+    /// removed via `remove`/`cleanup_completed`/the stale-session reaper).
+    agents_active: AtomicU64,
+    /// Agent sessions whose background task has finished, successfully or
+    /// not. See `AgentSession::start`.
+    agents_completed_total: AtomicU64,
+    /// Steps completed across every agent session. See
+    /// `RecordingStepReporter::on_step_complete`.
+    agent_steps_total: AtomicU64,
+    /// Pages visited across every research session. See
+    /// `research::session::ResearchSession::spawn_event_watcher`.
+    research_pages_visited_total: AtomicU64,
+    /// Wall-clock duration of completed research sessions.
+    research_runtime: Histogram,
+}
+
+static GLOBAL_METRICS: OnceLock<Arc<ToolMetrics>> = OnceLock::new();
+
+impl ToolMetrics {
+    /// Get the process-wide singleton.
+    #[must_use]
+    pub fn global() -> Arc<ToolMetrics> {
+        GLOBAL_METRICS
+            .get_or_init(|| Arc::new(ToolMetrics::new()))
+            .clone()
+    }
+
+    fn new() -> Self {
+        Self {
+            tools: Mutex::new(HashMap::new()),
+            active_pages: AtomicU64::new(0),
+            live_research_sessions: AtomicU64::new(0),
+            relaunch_count: AtomicU64::new(0),
+            agents_active: AtomicU64::new(0),
+            agents_completed_total: AtomicU64::new(0),
+            agent_steps_total: AtomicU64::new(0),
+            research_pages_visited_total: AtomicU64::new(0),
+            research_runtime: Histogram::new(RESEARCH_RUNTIME_BUCKETS_SECS),
+        }
+    }
+
+    /// Record one invocation of `tool_name`, its outcome, and how long it took.
+    ///
+    /// This only folds the call into `tool_name`'s aggregate counters and
+    /// latency histogram - it doesn't retain the call's arguments or
+    /// response anywhere, so there's no per-call JSONL (or any other) log
+    /// for a record/replay test harness to read back and diff against a
+    /// golden run. Building one would mean adding a separate opt-in
+    /// recorder that persists `(tool_name, args, response)` per call, since
+    /// today's aggregated-only counters have already discarded that
+    /// information by the time a caller could inspect them.
+    ///
+    /// Updates `counters` directly and unconditionally on every call - there
+    /// is no dirty flag, write-coalescing, or idle/burst-aware batching
+    /// here, because there's no periodic background writer in this module
+    /// at all for such a policy to govern; every invocation pays its own
+    /// (cheap, in-memory) atomic increments synchronously.
+    pub async fn record_invocation(
+        &self,
+        tool_name: &'static str,
+        success: bool,
+        duration: Duration,
+    ) {
+        let counters = {
+            let mut tools = self.tools.lock().await;
+            tools
+                .entry(tool_name)
+                .or_insert_with(|| Arc::new(ToolCounters::new()))
+                .clone()
+        };
+
+        counters.invocations.fetch_add(1, Ordering::Relaxed);
+        if success {
+            counters.successes.fetch_add(1, Ordering::Relaxed);
+        } else {
+            counters.failures.fetch_add(1, Ordering::Relaxed);
+        }
+
+        let secs = duration.as_secs_f64();
+        for (bound, bucket) in LATENCY_BUCKETS_SECS
+            .iter()
+            .zip(counters.bucket_counts.iter())
+        {
+            if secs <= *bound {
+                bucket.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+        *counters.sum_secs.lock().await += secs;
+    }
+
+    /// Set the number of currently open browser pages.
+    pub fn set_active_pages(&self, count: u64) {
+        self.active_pages.store(count, Ordering::Relaxed);
+    }
+
+    /// Set the number of research sessions currently in progress.
+    pub fn set_live_research_sessions(&self, count: u64) {
+        self.live_research_sessions.store(count, Ordering::Relaxed);
+    }
+
+    /// Increment the count of automatic browser relaunches (crash recovery
+    /// or, once `chunk2-7`-style monitoring lands, threshold-triggered
+    /// restarts).
+    pub fn incr_relaunch_count(&self) {
+        self.relaunch_count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// An agent session was inserted into an `AgentRegistry`.
+    pub fn incr_agents_active(&self) {
+        self.agents_active.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// An agent session was removed from an `AgentRegistry` (explicit KILL,
+    /// `cleanup_completed`, or the stale-session reaper).
+    pub fn decr_agents_active(&self) {
+        self.agents_active.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    /// An agent session's background task finished, successfully or not.
+    pub fn incr_agents_completed(&self) {
+        self.agents_completed_total.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// One agent step completed, across any session.
+    pub fn incr_agent_steps(&self) {
+        self.agent_steps_total.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// One page was visited by a research session.
+    pub fn incr_research_pages_visited(&self) {
+        self.research_pages_visited_total
+            .fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record how long a research session ran end to end.
+    pub async fn observe_research_runtime(&self, duration: Duration) {
+        self.research_runtime
+            .observe(RESEARCH_RUNTIME_BUCKETS_SECS, duration)
+            .await;
+    }
+
+    /// Render all counters and gauges in Prometheus text exposition format.
+    ///
+    /// Recomputed fresh from the live atomics on every call - the returned
+    /// `String` is never written to disk by this crate, so there's no log
+    /// artifact here that could be silently truncated or corrupted between
+    /// scrapes, and nothing for a streaming checksum/sidecar-file scheme to
+    /// protect. Integrity of a *stored* copy of this output is whichever
+    /// external scraper or file persists it.
+    pub async fn render_prometheus(&self) -> String {
+        let mut out = String::new();
+
+        out.push_str("# HELP browser_tool_invocations_total Tool invocations by tool name.\n");
+        out.push_str("# TYPE browser_tool_invocations_total counter\n");
+        out.push_str(
+            "# HELP browser_tool_successes_total Successful tool invocations by tool name.\n",
+        );
+        out.push_str("# TYPE browser_tool_successes_total counter\n");
+        out.push_str("# HELP browser_tool_failures_total Failed tool invocations by tool name.\n");
+        out.push_str("# TYPE browser_tool_failures_total counter\n");
+        out.push_str("# HELP browser_tool_duration_seconds Tool invocation latency.\n");
+        out.push_str("# TYPE browser_tool_duration_seconds histogram\n");
+
+        let tools = self.tools.lock().await;
+        for (name, counters) in tools.iter() {
+            out.push_str(&format!(
+                "browser_tool_invocations_total{{tool=\"{name}\"}} {}\n",
+                counters.invocations.load(Ordering::Relaxed)
+            ));
+            out.push_str(&format!(
+                "browser_tool_successes_total{{tool=\"{name}\"}} {}\n",
+                counters.successes.load(Ordering::Relaxed)
+            ));
+            out.push_str(&format!(
+                "browser_tool_failures_total{{tool=\"{name}\"}} {}\n",
+                counters.failures.load(Ordering::Relaxed)
+            ));
+
+            for (bound, bucket) in LATENCY_BUCKETS_SECS
+                .iter()
+                .zip(counters.bucket_counts.iter())
+            {
+                out.push_str(&format!(
+                    "browser_tool_duration_seconds_bucket{{tool=\"{name}\",le=\"{bound}\"}} {}\n",
+                    bucket.load(Ordering::Relaxed)
+                ));
+            }
+            out.push_str(&format!(
+                "browser_tool_duration_seconds_bucket{{tool=\"{name}\",le=\"+Inf\"}} {}\n",
+                counters.invocations.load(Ordering::Relaxed)
+            ));
+            out.push_str(&format!(
+                "browser_tool_duration_seconds_sum{{tool=\"{name}\"}} {}\n",
+                *counters.sum_secs.lock().await
+            ));
+            out.push_str(&format!(
+                "browser_tool_duration_seconds_count{{tool=\"{name}\"}} {}\n",
+                counters.invocations.load(Ordering::Relaxed)
+            ));
+        }
+        drop(tools);
+
+        out.push_str("# HELP browser_active_pages Currently open browser pages.\n");
+        out.push_str("# TYPE browser_active_pages gauge\n");
+        out.push_str(&format!(
+            "browser_active_pages {}\n",
+            self.active_pages.load(Ordering::Relaxed)
+        ));
+
+        out.push_str(
+            "# HELP browser_live_research_sessions Research sessions currently in progress.\n",
+        );
+        out.push_str("# TYPE browser_live_research_sessions gauge\n");
+        out.push_str(&format!(
+            "browser_live_research_sessions {}\n",
+            self.live_research_sessions.load(Ordering::Relaxed)
+        ));
+
+        out.push_str("# HELP browser_relaunch_count_total Automatic browser relaunches since process start.\n");
+        out.push_str("# TYPE browser_relaunch_count_total counter\n");
+        out.push_str(&format!(
+            "browser_relaunch_count_total {}\n",
+            self.relaunch_count.load(Ordering::Relaxed)
+        ));
+
+        out.push_str("# HELP agents_active Agent sessions currently tracked by an AgentRegistry.\n");
+        out.push_str("# TYPE agents_active gauge\n");
+        out.push_str(&format!(
+            "agents_active {}\n",
+            self.agents_active.load(Ordering::Relaxed)
+        ));
+
+        out.push_str("# HELP agents_completed_total Agent sessions whose background task has finished.\n");
+        out.push_str("# TYPE agents_completed_total counter\n");
+        out.push_str(&format!(
+            "agents_completed_total {}\n",
+            self.agents_completed_total.load(Ordering::Relaxed)
+        ));
+
+        out.push_str("# HELP agent_steps_total Steps completed across every agent session.\n");
+        out.push_str("# TYPE agent_steps_total counter\n");
+        out.push_str(&format!(
+            "agent_steps_total {}\n",
+            self.agent_steps_total.load(Ordering::Relaxed)
+        ));
+
+        out.push_str("# HELP research_sessions_running Research sessions currently in progress.\n");
+        out.push_str("# TYPE research_sessions_running gauge\n");
+        out.push_str(&format!(
+            "research_sessions_running {}\n",
+            self.live_research_sessions.load(Ordering::Relaxed)
+        ));
+
+        out.push_str("# HELP research_pages_visited_total Pages visited across every research session.\n");
+        out.push_str("# TYPE research_pages_visited_total counter\n");
+        out.push_str(&format!(
+            "research_pages_visited_total {}\n",
+            self.research_pages_visited_total.load(Ordering::Relaxed)
+        ));
+
+        out.push_str("# HELP research_runtime_seconds Wall-clock duration of completed research sessions.\n");
+        out.push_str("# TYPE research_runtime_seconds histogram\n");
+        for (bound, bucket) in RESEARCH_RUNTIME_BUCKETS_SECS
+            .iter()
+            .zip(self.research_runtime.bucket_counts.iter())
+        {
+            out.push_str(&format!(
+                "research_runtime_seconds_bucket{{le=\"{bound}\"}} {}\n",
+                bucket.load(Ordering::Relaxed)
+            ));
+        }
+        out.push_str(&format!(
+            "research_runtime_seconds_bucket{{le=\"+Inf\"}} {}\n",
+            self.research_runtime.count.load(Ordering::Relaxed)
+        ));
+        out.push_str(&format!(
+            "research_runtime_seconds_sum {}\n",
+            *self.research_runtime.sum_secs.lock().await
+        ));
+        out.push_str(&format!(
+            "research_runtime_seconds_count {}\n",
+            self.research_runtime.count.load(Ordering::Relaxed)
+        ));
+
+        out
+    }
+}
@@ -1,15 +1,68 @@
 // All browser utility modules - no feature gating
+mod adaptive_throttle;
 mod agent_state;
+mod chrome_version;
 pub mod constants;
+mod cookie_profiles;
 mod deep_research;
+mod diagnostics;
+mod dialog_watcher;
 mod errors;
+mod event_tracker;
+mod host_rate_limiter;
+mod key_sequence;
+mod metrics;
+mod navigation_guard;
+mod network_overrides;
+mod origin_governor;
+mod request_interception;
+mod research_control;
+mod robots;
+mod search_engines;
+mod search_queue;
+mod selector_strategy;
+mod site_crawl;
+mod task_controller;
 mod timeout;
+pub mod url_utils;
+mod user_agents;
 mod wait_for_element;
 
+pub use adaptive_throttle::AdaptiveThrottle;
 pub use agent_state::AgentState;
+pub use chrome_version::chrome_user_agent;
+pub use cookie_profiles::CookieProfileStore;
 pub use deep_research::{DeepResearch, ResearchOptions, ResearchResult};
+pub use diagnostics::{DiagnosticLevel, PageDiagnostic, PageDiagnostics};
+pub use dialog_watcher::{DialogWatcher, PendingDialog};
+pub use event_tracker::{BrowserEvent, EventTracker};
+pub use host_rate_limiter::HostRateLimiter;
+pub use key_sequence::{KeySegment, KeyToken, dispatch_key_token, parse_key_sequence};
+pub use metrics::ToolMetrics;
+pub use navigation_guard::NavigationPolicy;
+pub use network_overrides::{BasicAuth, CapturedEndpoint, NetworkOverrides, NetworkSummary};
+pub use origin_governor::{OriginGovernor, is_block_page};
+pub use request_interception::{
+    InterceptAction, InterceptRule, RequestInterceptor, block_resource_types,
+};
+pub use research_control::ResearchControl;
+pub use robots::{
+    CRAWLER_USER_AGENT_TOKEN, CrawlDelayScheduler, RobotsCache, RobotsRules,
+    parse_robots_directives, parse_robots_txt,
+};
+pub use search_engines::{
+    BraveEngine, DuckDuckGoEngine, GoogleEngine, SearchEngine, SearchHit, StartpageEngine,
+    fuse_rrf, resolve_engines, retry_search,
+};
+pub use search_queue::SearchQueue;
+pub use selector_strategy::{SelectorStrategy, resolve_selector};
+pub use site_crawl::{CrawlOptions, CrawlPageResult, SiteCrawler};
+pub use task_controller::{TaskController, TaskOutcome, TaskReport};
 pub use timeout::{validate_interaction_timeout, validate_navigation_timeout};
-pub use wait_for_element::wait_for_element;
+pub use user_agents::{UserAgentPool, UserAgentSelection};
+pub use wait_for_element::{
+    WaitCondition, wait_for_element, wait_for_element_via_binding, wait_for_element_with,
+};
 
 // /// Result type for utility functions
 // pub type UtilsResult<T> = Result<T, UtilsError>;
@@ -0,0 +1,176 @@
+//! Host allow/deny list and private-network (SSRF) guard applied before any
+//! navigation.
+//!
+//! Owned once by `BrowserManager` (config `browser.navigation_allowlist`/
+//! `navigation_denylist`/`block_private_navigation`) and consulted twice by
+//! `BrowserNavigateTool`: before a page is created for the requested URL
+//! (`navigate_and_capture_page_for`/`navigate_and_capture_page_pooled`), and
+//! again in `finish_navigation` against the landing `final_url` after
+//! `wait_for_navigation`, since a public URL can redirect into an internal one.
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use tokio::sync::Mutex;
+
+/// Configured host allow/deny rules plus the private-IP (SSRF) guard.
+pub struct NavigationPolicy {
+    /// If non-empty, only these hosts may be navigated to.
+    allowlist: Mutex<Vec<String>>,
+    /// Hosts rejected outright, checked before `allowlist`.
+    denylist: Mutex<Vec<String>>,
+    block_private_ips: AtomicBool,
+}
+
+impl NavigationPolicy {
+    #[must_use]
+    pub fn new(allowlist: Vec<String>, denylist: Vec<String>, block_private_ips: bool) -> Self {
+        Self {
+            allowlist: Mutex::new(allowlist.into_iter().map(|h| h.to_lowercase()).collect()),
+            denylist: Mutex::new(denylist.into_iter().map(|h| h.to_lowercase()).collect()),
+            block_private_ips: AtomicBool::new(block_private_ips),
+        }
+    }
+
+    /// Replace the host allowlist. Empty means "no restriction".
+    pub async fn set_allowlist(&self, hosts: Vec<String>) {
+        *self.allowlist.lock().await = hosts.into_iter().map(|h| h.to_lowercase()).collect();
+    }
+
+    /// Replace the host denylist.
+    pub async fn set_denylist(&self, hosts: Vec<String>) {
+        *self.denylist.lock().await = hosts.into_iter().map(|h| h.to_lowercase()).collect();
+    }
+
+    pub fn set_block_private_ips(&self, block: bool) {
+        self.block_private_ips.store(block, Ordering::Relaxed);
+    }
+
+    /// Check `host` (as extracted by `crate::utils::url_utils::host_of`)
+    /// against the allowlist, denylist, and - if enabled - the resolved IP's
+    /// private/loopback/link-local status. Returns a human-readable reason
+    /// if navigation to `host` should be rejected.
+    ///
+    /// DNS resolution failures are not treated as a rejection here - the
+    /// navigation itself will fail naturally (and more informatively) when
+    /// `page.goto` can't resolve the host either.
+    pub async fn check_host(&self, host: &str) -> Result<(), String> {
+        let host_lower = host.to_lowercase();
+
+        if self.denylist.lock().await.iter().any(|h| h == &host_lower) {
+            return Err(format!("host '{host}' is on the navigation denylist"));
+        }
+
+        {
+            let allowlist = self.allowlist.lock().await;
+            if !allowlist.is_empty() && !allowlist.iter().any(|h| h == &host_lower) {
+                return Err(format!("host '{host}' is not on the navigation allowlist"));
+            }
+        }
+
+        if self.block_private_ips.load(Ordering::Relaxed) {
+            if let Some(reason) = self.disallowed_ip_reason(&host_lower).await {
+                return Err(reason);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// `Some(reason)` if `host` is (or resolves to) a private, loopback, or
+    /// link-local address; `None` if it's public or couldn't be resolved.
+    ///
+    /// This resolves `host` itself, via Rust's own resolver
+    /// (`tokio::net::lookup_host`) - it is *not* the resolution Chrome
+    /// actually uses to connect. Chrome runs its own independent DNS
+    /// stack, so a malicious name server can legally hand back a public IP
+    /// here and a private one moments later when Chrome itself resolves the
+    /// same name for the real connection ("DNS rebinding"); a short TTL is
+    /// enough to win that race. There's no portable way to force Chrome's
+    /// resolver and this one to agree short of either routing all browser
+    /// traffic through a proxy this crate controls, or relaunching Chrome
+    /// per-navigation with a `--host-resolver-rules` pin - both bigger
+    /// changes than this guard's scope. [`Self::reject_if_disallowed_connected_ip`]
+    /// narrows the window instead of closing it: it checks the IP CDP
+    /// reports Chrome *actually* connected to, once the top-level document
+    /// response arrives, so a rebind that slips past this pre-navigation
+    /// check still gets caught before the page is treated as loaded (see
+    /// `BrowserNavigateTool::finish_navigation`).
+    async fn disallowed_ip_reason(&self, host: &str) -> Option<String> {
+        if host == "localhost" {
+            return Some(format!("host '{host}' is loopback"));
+        }
+
+        // A literal IP (e.g. `http://127.0.0.1/`) doesn't need DNS at all.
+        if let Ok(ip) = host.parse::<IpAddr>() {
+            return is_disallowed_ip(ip).then(|| format!("host '{host}' is a disallowed address"));
+        }
+
+        // `lookup_host` needs a port even though we only want the resolved
+        // IPs; `0` is never actually dialed.
+        let Ok(addrs) = tokio::net::lookup_host((host, 0)).await else {
+            return None;
+        };
+
+        for addr in addrs {
+            if is_disallowed_ip(addr.ip()) {
+                return Some(format!(
+                    "host '{host}' resolves to disallowed address {}",
+                    addr.ip()
+                ));
+            }
+        }
+
+        None
+    }
+
+    /// Check the IP address CDP reports Chrome actually connected to for a
+    /// response (e.g. `Network.Response.remote_ip_address`), independent of
+    /// [`Self::disallowed_ip_reason`]'s own DNS lookup - see that method's
+    /// doc comment for why the two can disagree. A no-op (returns `None`)
+    /// when private-IP blocking isn't enabled.
+    pub fn reject_if_disallowed_connected_ip(&self, host: &str, ip: IpAddr) -> Option<String> {
+        if !self.block_private_ips.load(Ordering::Relaxed) {
+            return None;
+        }
+        is_disallowed_ip(ip).then(|| {
+            format!(
+                "host '{host}' actually connected to disallowed address {ip} \
+                 (caught post-connection; DNS resolved a different, allowed \
+                 address moments earlier - see NavigationPolicy::disallowed_ip_reason)"
+            )
+        })
+    }
+}
+
+/// Private, loopback, link-local, unspecified, or broadcast - the address
+/// ranges that shouldn't be reachable from an agent-driven navigation
+/// regardless of which public hostname pointed at them.
+fn is_disallowed_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => is_disallowed_ipv4(v4),
+        IpAddr::V6(v6) => is_disallowed_ipv6(v6),
+    }
+}
+
+fn is_disallowed_ipv4(v4: Ipv4Addr) -> bool {
+    v4.is_private()
+        || v4.is_loopback()
+        || v4.is_link_local()
+        || v4.is_broadcast()
+        || v4.is_unspecified()
+        // 100.64.0.0/10 - carrier-grade NAT, not covered by `is_private`
+        || (v4.octets()[0] == 100 && (64..128).contains(&v4.octets()[1]))
+}
+
+fn is_disallowed_ipv6(v6: Ipv6Addr) -> bool {
+    if v6.is_loopback() || v6.is_unspecified() {
+        return true;
+    }
+    if let Some(mapped) = v6.to_ipv4_mapped() {
+        return is_disallowed_ipv4(mapped);
+    }
+    let first_segment = v6.segments()[0];
+    // fe80::/10 link-local, fc00::/7 unique-local
+    (first_segment & 0xffc0) == 0xfe80 || (first_segment & 0xfe00) == 0xfc00
+}
@@ -0,0 +1,248 @@
+//! Per-origin header/auth overrides and request-blocking rules for
+//! authenticated crawling.
+//!
+//! Shared across every navigation path the same way [`crate::utils::OriginGovernor`]
+//! is: owned once by `BrowserManager`, populated by `browser_set_headers` (or
+//! `ResearchOptions` for background research), and read by
+//! `navigate::finish_navigation` before every navigation.
+
+use std::collections::HashMap;
+
+use tokio::sync::Mutex;
+
+/// HTTP basic-auth credentials for one origin.
+#[derive(Debug, Clone)]
+pub struct BasicAuth {
+    pub username: String,
+    pub password: String,
+}
+
+/// One request observed while network capture is enabled (see
+/// [`NetworkOverrides::set_capture_enabled`]). `status`/`mime_type`/
+/// `encoded_data_length` start `None` and are filled in best-effort by a
+/// Network-domain response match keyed by URL (see
+/// `navigate::subscribe_network_capture`) - they stay `None` for a request
+/// the page never got a response for (blocked, aborted, still in flight
+/// when the crawl moved on).
+#[derive(Debug, Clone)]
+pub struct CapturedEndpoint {
+    pub url: String,
+    pub resource_type: String,
+    pub blocked: bool,
+    pub status: Option<u16>,
+    pub mime_type: Option<String>,
+    pub encoded_data_length: Option<u64>,
+}
+
+/// Aggregated view over the current capture log, built fresh from it on
+/// demand by [`NetworkOverrides::capture_summary`].
+#[derive(Debug, Clone, Default)]
+pub struct NetworkSummary {
+    pub request_count: usize,
+    pub blocked_count: usize,
+    pub total_bytes: u64,
+    /// Distinct, non-blocked `Xhr`/`Fetch` URLs seen - the API endpoints a
+    /// page's own JS fetched, as opposed to its document/script/image/style
+    /// requests. Capped at 20 so a chatty SPA doesn't flood a summary.
+    pub api_endpoints: Vec<String>,
+}
+
+/// Cap on the capture log so an unbounded crawl can't grow it forever -
+/// same rationale as `SearchQueue`'s bounded buffer, just for memory
+/// instead of concurrency.
+const MAX_CAPTURED_ENDPOINTS: usize = 2000;
+
+/// Store of custom headers, per-origin basic-auth credentials, URL-substring
+/// block rules, and (opt-in) network capture applied to every navigation.
+///
+/// Headers are injected via CDP `Network.setExtraHTTPHeaders` ahead of
+/// navigation; auth, blocking, and capture decisions all require the CDP
+/// `Fetch` domain to be enabled, since they need to intercept the request
+/// before it completes (`Fetch.authRequired` / `Fetch.requestPaused`).
+#[derive(Default)]
+pub struct NetworkOverrides {
+    headers: Mutex<HashMap<String, String>>,
+    auth: Mutex<HashMap<String, BasicAuth>>,
+    /// Fallback credentials answered when no entry in `auth` matches the
+    /// challenging origin - e.g. `ResearchOptions::basic_auth`, which names
+    /// credentials for "whatever site this crawl authenticates against"
+    /// rather than a specific known origin.
+    default_auth: Mutex<Option<BasicAuth>>,
+    block_patterns: Mutex<Vec<String>>,
+    /// Whether `navigate::finish_navigation` should subscribe the capture
+    /// listeners (see [`Self::set_capture_enabled`]) for the next page it
+    /// navigates. Checked once per navigation, same as `needs_fetch_domain`.
+    capture_enabled: Mutex<bool>,
+    /// Log fed by the Fetch-domain paused-request loop (decision: url,
+    /// resource type, blocked) and merged into by the Network-domain
+    /// response loop (status, mime type, byte size) while capture is
+    /// enabled. Cleared each time capture is (re-)enabled.
+    captured: Mutex<Vec<CapturedEndpoint>>,
+}
+
+impl NetworkOverrides {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace the custom headers sent with every subsequent navigation.
+    pub async fn set_headers(&self, headers: HashMap<String, String>) {
+        *self.headers.lock().await = headers;
+    }
+
+    /// Current custom headers, if any are configured.
+    pub async fn headers(&self) -> HashMap<String, String> {
+        self.headers.lock().await.clone()
+    }
+
+    /// Store (or clear, if `auth` is `None`) the basic-auth credentials used
+    /// to answer `Fetch.authRequired` challenges for `origin`.
+    pub async fn set_auth(&self, origin: String, auth: Option<BasicAuth>) {
+        let mut guard = self.auth.lock().await;
+        match auth {
+            Some(auth) => {
+                guard.insert(origin, auth);
+            }
+            None => {
+                guard.remove(&origin);
+            }
+        }
+    }
+
+    /// Credentials to answer an auth challenge from `origin`: an exact
+    /// per-origin match if one is stored, otherwise the fallback
+    /// `default_auth`.
+    pub async fn auth_for(&self, origin: &str) -> Option<BasicAuth> {
+        if let Some(auth) = self.auth.lock().await.get(origin).cloned() {
+            return Some(auth);
+        }
+        self.default_auth.lock().await.clone()
+    }
+
+    /// Store (or clear) the fallback credentials returned by `auth_for` when
+    /// no per-origin entry matches. See [`Self::default_auth`] field docs.
+    pub async fn set_default_auth(&self, auth: Option<BasicAuth>) {
+        *self.default_auth.lock().await = auth;
+    }
+
+    /// Replace the set of URL substrings that cause a request to be
+    /// blocked (failed) rather than sent.
+    pub async fn set_block_patterns(&self, patterns: Vec<String>) {
+        *self.block_patterns.lock().await = patterns;
+    }
+
+    /// Whether `url` matches any configured block pattern.
+    pub async fn is_blocked(&self, url: &str) -> bool {
+        self.block_patterns
+            .lock()
+            .await
+            .iter()
+            .any(|pattern| url.contains(pattern.as_str()))
+    }
+
+    /// Whether request interception (`Fetch.enable`) is needed at all for
+    /// the current configuration - skips the extra CDP round-trips on pages
+    /// that don't use auth or blocking.
+    pub async fn needs_fetch_domain(&self) -> bool {
+        !self.auth.lock().await.is_empty()
+            || self.default_auth.lock().await.is_some()
+            || !self.block_patterns.lock().await.is_empty()
+            || *self.capture_enabled.lock().await
+    }
+
+    /// Enable or disable network capture for subsequent navigations,
+    /// clearing the prior log on every transition to `true` so one crawl's
+    /// traffic doesn't bleed into the next call's summary. `false` by
+    /// default: capture adds a CDP `Fetch.enable` round-trip per request on
+    /// every page, same tradeoff `block_patterns`/the request interceptor
+    /// already make, so it's opt-in rather than always-on.
+    ///
+    /// Set via `ResearchOptions::capture_network` in `DeepResearch::research`
+    /// today - there's no `browser_research`/`start_browser_research` MCP
+    /// argument wired to it yet, since `BrowserResearchArgs`/
+    /// `StartBrowserResearchArgs` (from the external `kodegen_mcp_schema`
+    /// crate) have no such field. A direct library caller constructing its
+    /// own `ResearchOptions` can opt in today regardless.
+    pub async fn set_capture_enabled(&self, enabled: bool) {
+        *self.capture_enabled.lock().await = enabled;
+        if enabled {
+            self.captured.lock().await.clear();
+        }
+    }
+
+    /// Whether capture is currently enabled - checked once by
+    /// `navigate::finish_navigation` per page.
+    pub async fn is_capture_enabled(&self) -> bool {
+        *self.capture_enabled.lock().await
+    }
+
+    /// Record a Fetch-domain request-paused decision. No-op when capture is
+    /// disabled or the log has hit [`MAX_CAPTURED_ENDPOINTS`], so a long
+    /// crawl degrades to "summary stops growing" rather than unbounded
+    /// memory use.
+    pub(crate) async fn record_decision(&self, url: String, resource_type: String, blocked: bool) {
+        if !self.is_capture_enabled().await {
+            return;
+        }
+        let mut guard = self.captured.lock().await;
+        if guard.len() >= MAX_CAPTURED_ENDPOINTS {
+            return;
+        }
+        guard.push(CapturedEndpoint {
+            url,
+            resource_type,
+            blocked,
+            status: None,
+            mime_type: None,
+            encoded_data_length: None,
+        });
+    }
+
+    /// Merge a Network-domain response's status/mime/size into the most
+    /// recent still-unanswered capture entry for `url` - matched by URL
+    /// rather than request id, since the Fetch-domain id recorded in
+    /// `record_decision` and the Network-domain id this response arrives
+    /// under aren't the same id space. A page that requests the same URL
+    /// twice in flight can merge into either in-flight entry; acceptable for
+    /// a summary, not meant as a precise per-request audit log.
+    pub(crate) async fn record_response(
+        &self,
+        url: &str,
+        status: u16,
+        mime_type: String,
+        encoded_data_length: Option<u64>,
+    ) {
+        let mut guard = self.captured.lock().await;
+        if let Some(entry) = guard
+            .iter_mut()
+            .rev()
+            .find(|e| e.url == url && e.status.is_none())
+        {
+            entry.status = Some(status);
+            entry.mime_type = Some(mime_type);
+            entry.encoded_data_length = encoded_data_length;
+        }
+    }
+
+    /// Build a [`NetworkSummary`] from the current capture log.
+    pub async fn capture_summary(&self) -> NetworkSummary {
+        let guard = self.captured.lock().await;
+        let blocked_count = guard.iter().filter(|e| e.blocked).count();
+        let total_bytes = guard.iter().filter_map(|e| e.encoded_data_length).sum();
+        let mut api_endpoints: Vec<String> = guard
+            .iter()
+            .filter(|e| !e.blocked && matches!(e.resource_type.as_str(), "Xhr" | "Fetch"))
+            .map(|e| e.url.clone())
+            .collect();
+        api_endpoints.sort();
+        api_endpoints.dedup();
+        api_endpoints.truncate(20);
+        NetworkSummary {
+            request_count: guard.len(),
+            blocked_count,
+            total_bytes,
+            api_endpoints,
+        }
+    }
+}
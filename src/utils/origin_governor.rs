@@ -0,0 +1,175 @@
+//! Per-origin rate limiting and throttle backoff shared across every
+//! consumer of [`crate::BrowserManager`] - `browser_web_search`,
+//! `browser_research`, and direct `browser_navigate`/agent calls all
+//! funnel through the single governor `BrowserManager::origin_governor`
+//! returns, so a burst against one host from any of them is throttled the
+//! same way.
+//!
+//! Two independent mechanisms, both keyed by origin
+//! (`crate::utils::url_utils::origin_of`):
+//! - A continuously-refilling token bucket (same shape as
+//!   `web_search::RateLimiter`'s per-connection one), except [`OriginGovernor::acquire`]
+//!   *waits* for a token instead of rejecting the caller - a research crawl
+//!   should slow down against a host, not fail outright.
+//! - An exponential-backoff-with-jitter window, engaged by
+//!   [`OriginGovernor::note_throttled`] whenever a caller detects a 429/503
+//!   response or a CAPTCHA/block page for that origin. `acquire` also waits
+//!   out any active backoff window before granting a token.
+//!
+//! The global in-flight concurrency cap this crawler already enforces
+//! lives in [`crate::utils::SearchQueue`] - that's orthogonal to per-origin
+//! pacing, so it isn't duplicated here.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use rand::Rng;
+use tokio::sync::Mutex;
+
+/// Starting backoff window on the first throttle signal for an origin.
+const BASE_BACKOFF: Duration = Duration::from_secs(2);
+
+/// Backoff never grows past this, regardless of how many consecutive
+/// throttle signals an origin has produced.
+const MAX_BACKOFF: Duration = Duration::from_secs(120);
+
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+struct Backoff {
+    until: Instant,
+    /// Consecutive throttle signals without an intervening
+    /// [`OriginGovernor::note_success`], used to double the backoff window
+    /// each time (capped by `MAX_BACKOFF`).
+    streak: u32,
+}
+
+/// Per-origin token-bucket pacing plus throttle backoff. See the module
+/// doc comment for the split between the two.
+pub struct OriginGovernor {
+    buckets: Mutex<HashMap<String, Bucket>>,
+    backoffs: Mutex<HashMap<String, Backoff>>,
+    capacity: f64,
+    refill_per_sec: f64,
+}
+
+impl OriginGovernor {
+    /// `burst` tokens are available up front per origin, refilling
+    /// continuously at `requests_per_sec`.
+    #[must_use]
+    pub fn new(requests_per_sec: f64, burst: u32) -> Self {
+        Self {
+            buckets: Mutex::new(HashMap::new()),
+            backoffs: Mutex::new(HashMap::new()),
+            capacity: f64::from(burst.max(1)),
+            refill_per_sec: requests_per_sec.max(0.001),
+        }
+    }
+
+    /// Wait until `origin` has both a free token-bucket slot and no active
+    /// throttle backoff, then consume one token. Unlike
+    /// `web_search::RateLimiter::check`, this never rejects a caller - it
+    /// sleeps out whatever wait is needed, since a crawl should pace
+    /// itself against a host rather than abort.
+    pub async fn acquire(&self, origin: &str) {
+        loop {
+            let backoff_wait = {
+                let backoffs = self.backoffs.lock().await;
+                backoffs.get(origin).and_then(|b| {
+                    let now = Instant::now();
+                    (b.until > now).then(|| b.until - now)
+                })
+            };
+            if let Some(wait) = backoff_wait {
+                tokio::time::sleep(wait).await;
+                continue;
+            }
+
+            let token_wait = {
+                let mut buckets = self.buckets.lock().await;
+                let now = Instant::now();
+                let bucket = buckets.entry(origin.to_string()).or_insert_with(|| Bucket {
+                    tokens: self.capacity,
+                    last_refill: now,
+                });
+
+                let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+                bucket.tokens = (bucket.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+                bucket.last_refill = now;
+
+                if bucket.tokens >= 1.0 {
+                    bucket.tokens -= 1.0;
+                    None
+                } else {
+                    let deficit = 1.0 - bucket.tokens;
+                    Some(Duration::from_secs_f64(deficit / self.refill_per_sec))
+                }
+            };
+
+            match token_wait {
+                None => return,
+                Some(wait) => tokio::time::sleep(wait).await,
+            }
+        }
+    }
+
+    /// Engage (or extend) `origin`'s throttle backoff after a detected
+    /// 429/503 response or block page, doubling the window per consecutive
+    /// signal and adding up to 20% jitter so concurrent tasks hitting the
+    /// same origin don't all retry in lockstep. Returns the resulting
+    /// `retry_after`, for callers that want to surface it (e.g.
+    /// `UtilsError::RateLimited`) rather than just relying on `acquire` to
+    /// wait it out next time.
+    pub async fn note_throttled(&self, origin: &str) -> Duration {
+        let mut backoffs = self.backoffs.lock().await;
+        let streak = backoffs
+            .get(origin)
+            .map_or(0, |b| b.streak)
+            .saturating_add(1);
+        let base = BASE_BACKOFF
+            .saturating_mul(1u32 << (streak - 1).min(6))
+            .min(MAX_BACKOFF);
+        let jitter_frac = rand::thread_rng().gen_range(0.0..0.2);
+        let delay = base + Duration::from_secs_f64(base.as_secs_f64() * jitter_frac);
+
+        backoffs.insert(
+            origin.to_string(),
+            Backoff {
+                until: Instant::now() + delay,
+                streak,
+            },
+        );
+        delay
+    }
+
+    /// Clear any backoff streak for `origin` after a clean response -
+    /// otherwise a transient throttle would keep doubling an origin's
+    /// backoff window forever even once it recovers.
+    pub async fn note_success(&self, origin: &str) {
+        self.backoffs.lock().await.remove(origin);
+    }
+}
+
+/// Heuristic detection of a CAPTCHA/"unusual traffic" interstitial that
+/// returned a normal 2xx status - the kind of soft block that HTTP-status
+/// checking alone misses. False positives just mean an extra backoff
+/// delay, not a dropped result, so this errs toward recall over precision.
+#[must_use]
+pub fn is_block_page(title: &str, content: &str) -> bool {
+    const MARKERS: &[&str] = &[
+        "unusual traffic",
+        "are you a human",
+        "captcha",
+        "access denied",
+        "please verify you are a human",
+        "request blocked",
+        "rate limit exceeded",
+    ];
+    // Block interstitials put their message up top; scanning the whole
+    // page text isn't needed and would be wasteful for long articles.
+    let content_head: String = content.chars().take(2000).collect();
+    let haystack = format!("{} {}", title, content_head).to_ascii_lowercase();
+    MARKERS.iter().any(|marker| haystack.contains(marker))
+}
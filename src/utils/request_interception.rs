@@ -0,0 +1,147 @@
+//! URL-glob/resource-type request interception rules (block, mock, or
+//! rewrite) on top of the CDP `Fetch` domain.
+//!
+//! Sits alongside [`crate::utils::NetworkOverrides`] as a second, richer set
+//! of `Fetch.requestPaused` rules consulted by
+//! `navigate::subscribe_fetch_interception`: `NetworkOverrides` answers "is
+//! this URL blocked" and "what auth goes on this origin"; `RequestInterceptor`
+//! answers "what should happen to this specific request" via an ordered list
+//! of caller-supplied rules, each matching on a URL glob and/or resource
+//! type, producing one of [`InterceptAction::Block`] (fail the request),
+//! [`InterceptAction::Mock`] (answer with a synthetic response, skipping the
+//! network entirely), or [`InterceptAction::Continue`] (pass through,
+//! optionally rewriting headers or the URL).
+//!
+//! Shared the same way `NetworkOverrides` is - owned once by
+//! `BrowserManager`, populated via `AgentConfig::block_resource_types` at
+//! agent boot (see `crate::agent::AgentConfig`). There is no dedicated
+//! `browser_intercept` MCP tool to add or clear arbitrary rules at runtime:
+//! that needs a new `Tool`/`Args`/`Output` triple in the external
+//! `kodegen_mcp_schema` crate, which lives outside this repo and can't be
+//! extended from here.
+
+use std::collections::HashMap;
+
+use tokio::sync::Mutex;
+
+/// What to do with a paused request matching an [`InterceptRule`].
+#[derive(Debug, Clone)]
+pub enum InterceptAction {
+    /// Fail the request outright (ads/trackers/images, to speed up agent
+    /// runs without touching the network).
+    Block,
+    /// Answer with a synthetic response instead of letting the request
+    /// reach the network - mocks an API response for deterministic tests.
+    Mock {
+        status: u16,
+        content_type: String,
+        body: String,
+    },
+    /// Let the request through, optionally rewriting its headers and/or
+    /// URL first.
+    Continue {
+        rewrite_headers: HashMap<String, String>,
+        rewrite_url: Option<String>,
+    },
+}
+
+/// One interception rule: a glob over the request URL, an optional CDP
+/// resource type filter (`"Document"`, `"Image"`, `"Script"`, `"Fetch"`,
+/// ...; matched case-insensitively), and the action to take when both match.
+#[derive(Debug, Clone)]
+pub struct InterceptRule {
+    pub url_glob: String,
+    pub resource_type: Option<String>,
+    pub action: InterceptAction,
+}
+
+/// Whether `text` matches `pattern`, where `*` in `pattern` matches any run
+/// of characters (including none). No other glob syntax is supported - this
+/// mirrors the simple substring/prefix matching `NetworkOverrides` and
+/// [`crate::utils::robots::RobotsRules`] already use rather than pulling in
+/// a full glob crate for one wildcard.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut pi, mut ti) = (0, 0);
+    let mut star: Option<usize> = None;
+    let mut star_match = 0;
+
+    while ti < text.len() {
+        if pi < pattern.len() && pattern[pi] == '*' {
+            star = Some(pi);
+            star_match = ti;
+            pi += 1;
+        } else if pi < pattern.len() && pattern[pi] == text[ti] {
+            pi += 1;
+            ti += 1;
+        } else if let Some(star_pi) = star {
+            pi = star_pi + 1;
+            star_match += 1;
+            ti = star_match;
+        } else {
+            return false;
+        }
+    }
+    while pi < pattern.len() && pattern[pi] == '*' {
+        pi += 1;
+    }
+    pi == pattern.len()
+}
+
+/// Ordered rule list applied to every `Fetch.requestPaused` event. See the
+/// module doc comment for how this composes with [`crate::utils::NetworkOverrides`].
+#[derive(Default)]
+pub struct RequestInterceptor {
+    rules: Mutex<Vec<InterceptRule>>,
+}
+
+impl RequestInterceptor {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace the active rule set.
+    pub async fn set_rules(&self, rules: Vec<InterceptRule>) {
+        *self.rules.lock().await = rules;
+    }
+
+    /// Drop all rules, letting every request pass through unmodified.
+    pub async fn clear(&self) {
+        self.rules.lock().await.clear();
+    }
+
+    /// Whether any rules are configured - skips the extra CDP round-trip
+    /// `Fetch.enable` adds on pages that don't need interception at all.
+    pub async fn has_rules(&self) -> bool {
+        !self.rules.lock().await.is_empty()
+    }
+
+    /// First rule whose glob (and resource type, if the rule names one)
+    /// matches `url`/`resource_type`, if any.
+    pub async fn rule_for(&self, url: &str, resource_type: &str) -> Option<InterceptRule> {
+        self.rules.lock().await.iter().find_map(|rule| {
+            let type_matches = rule
+                .resource_type
+                .as_ref()
+                .is_none_or(|wanted| wanted.eq_ignore_ascii_case(resource_type));
+            (type_matches && glob_match(&rule.url_glob, url)).then(|| rule.clone())
+        })
+    }
+}
+
+/// Rules for a named resource-blocking profile, handed to
+/// [`RequestInterceptor::set_rules`] so a spawned agent can boot with images,
+/// fonts, and media already blocked (see `AgentConfig::block_resource_types`).
+#[must_use]
+pub fn block_resource_types(resource_types: &[String]) -> Vec<InterceptRule> {
+    resource_types
+        .iter()
+        .map(|resource_type| InterceptRule {
+            url_glob: "*".to_string(),
+            resource_type: Some(resource_type.clone()),
+            action: InterceptAction::Block,
+        })
+        .collect()
+}
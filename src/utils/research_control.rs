@@ -0,0 +1,51 @@
+//! Shared abort+deadline handle for cancelling in-flight research work.
+//!
+//! Before this, research cancellation went through `ResearchSession::kill`
+//! aborting its background `tokio` task, and `wait_for_element` only ever
+//! gave up on its own local `timeout`. Neither propagates into the other:
+//! a `KILL` mid-navigation left any in-progress `wait_for_element` polling
+//! until its own timeout, and nothing capped a session's *total* runtime
+//! across many per-navigation timeouts. `ResearchControl` is cloned into the
+//! background crawl task, each page navigation, and `wait_for_element` so
+//! all three observe the same abort flag and session-wide deadline.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+/// Abort flag plus a session-wide deadline, shared by every clone.
+#[derive(Clone)]
+pub struct ResearchControl {
+    aborted: Arc<AtomicBool>,
+    deadline: Instant,
+}
+
+impl ResearchControl {
+    /// `budget` bounds total runtime from now, independent of whatever
+    /// per-navigation or per-wait timeout each caller also enforces.
+    pub fn new(budget: Duration) -> Self {
+        Self {
+            aborted: Arc::new(AtomicBool::new(false)),
+            deadline: Instant::now() + budget,
+        }
+    }
+
+    /// Trip the abort flag; every clone observes this immediately.
+    pub fn abort(&self) {
+        self.aborted.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_aborted(&self) -> bool {
+        self.aborted.load(Ordering::SeqCst)
+    }
+
+    pub fn deadline_passed(&self) -> bool {
+        Instant::now() >= self.deadline
+    }
+
+    /// `true` once either the flag is tripped or the shared deadline has
+    /// passed - the single condition callers should poll for early exit.
+    pub fn should_stop(&self) -> bool {
+        self.is_aborted() || self.deadline_passed()
+    }
+}
@@ -0,0 +1,312 @@
+//! Crawler etiquette: robots.txt parsing, per-origin caching, and
+//! `X-Robots-Tag`/`<meta name="robots">` directive parsing for
+//! `DeepResearch`.
+//!
+//! [`RobotsCache`] fetches and parses each origin's `/robots.txt` at most
+//! once per `DeepResearch` instance, keyed by [`crate::utils::url_utils::origin_of`].
+//! [`RobotsRules::is_allowed`] applies the longest-matching-prefix rule
+//! standard robots.txt parsers use, preferring a group addressed to
+//! [`CRAWLER_USER_AGENT_TOKEN`] over the wildcard `*` group when both are
+//! present. [`parse_robots_directives`] handles the separate `noindex`/
+//! `nofollow` mini-language shared by the `<meta name="robots">` tag and
+//! the `X-Robots-Tag` header.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use tokio::sync::Mutex;
+use tracing::{debug, warn};
+
+/// User-agent token this crawler identifies itself by when matching
+/// robots.txt `User-agent` groups. Distinct from the browser's navigation
+/// `User-Agent` header (see `user_agents::DEFAULT_USER_AGENTS`), which
+/// exists to blend in with real traffic - robots.txt matching is about
+/// honoring site-operator intent, not fingerprinting, so it always uses
+/// this fixed token regardless of `ResearchOptions::rotate_user_agent`.
+pub const CRAWLER_USER_AGENT_TOKEN: &str = "KodegenResearchBot";
+
+/// One origin's parsed, already-group-selected robots.txt rules.
+#[derive(Debug, Clone, Default)]
+pub struct RobotsRules {
+    /// `(is_allow, path_prefix)` pairs from the selected `User-agent`
+    /// group(s), in document order. [`Self::is_allowed`] picks the
+    /// longest matching prefix, same as a standard robots.txt parser.
+    rules: Vec<(bool, String)>,
+    pub crawl_delay: Option<Duration>,
+}
+
+impl RobotsRules {
+    /// No rules at all (e.g. robots.txt fetch failed or returned 404) -
+    /// everything is allowed, no crawl delay.
+    fn allow_all() -> Self {
+        Self::default()
+    }
+
+    /// Whether `path` is allowed by the longest matching `Disallow`/`Allow`
+    /// prefix. A path matched by no rule is allowed, per spec.
+    pub fn is_allowed(&self, path: &str) -> bool {
+        let mut best: Option<(usize, bool)> = None;
+        for (is_allow, prefix) in &self.rules {
+            if path.starts_with(prefix.as_str())
+                && best.is_none_or(|(best_len, _)| prefix.len() > best_len)
+            {
+                best = Some((prefix.len(), *is_allow));
+            }
+        }
+        best.is_none_or(|(_, allow)| allow)
+    }
+}
+
+/// One `User-agent:` block plus the `Disallow`/`Allow`/`Crawl-delay` lines
+/// that follow it, before parsing decides which group(s) apply to us.
+#[derive(Default)]
+struct Group {
+    agents: Vec<String>,
+    rules: Vec<(bool, String)>,
+    crawl_delay: Option<Duration>,
+}
+
+/// Parse a robots.txt body into the rules that apply to
+/// [`CRAWLER_USER_AGENT_TOKEN`], falling back to the wildcard `*` group
+/// when no group names us specifically - the standard robots.txt
+/// specificity rule (a named group always wins over `*`, never merged).
+pub fn parse_robots_txt(body: &str) -> RobotsRules {
+    let mut groups: Vec<Group> = Vec::new();
+    let mut current = Group::default();
+    let mut started_rules = false;
+
+    for raw_line in body.lines() {
+        let line = raw_line.split('#').next().unwrap_or("").trim();
+        if line.is_empty() {
+            continue;
+        }
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let key = key.trim().to_ascii_lowercase();
+        let value = value.trim();
+
+        match key.as_str() {
+            "user-agent" => {
+                // A User-agent line after this group has already collected
+                // rules starts a new group; consecutive User-agent lines
+                // (no rules yet) extend the same group.
+                if started_rules {
+                    groups.push(std::mem::take(&mut current));
+                    started_rules = false;
+                }
+                current.agents.push(value.to_ascii_lowercase());
+            }
+            "disallow" => {
+                started_rules = true;
+                if !value.is_empty() {
+                    current.rules.push((false, value.to_string()));
+                }
+                // An empty Disallow value means "disallow nothing" - no
+                // rule needed, since an unmatched path is already allowed.
+            }
+            "allow" => {
+                started_rules = true;
+                current.rules.push((true, value.to_string()));
+            }
+            "crawl-delay" => {
+                started_rules = true;
+                current.crawl_delay = value.parse::<f64>().ok().map(Duration::from_secs_f64);
+            }
+            _ => {}
+        }
+    }
+    if !current.agents.is_empty() || started_rules {
+        groups.push(current);
+    }
+
+    let bot_token = CRAWLER_USER_AGENT_TOKEN.to_ascii_lowercase();
+    let specific: Vec<&Group> = groups
+        .iter()
+        .filter(|g| g.agents.iter().any(|a| *a == bot_token))
+        .collect();
+    let selected: Vec<&Group> = if specific.is_empty() {
+        groups
+            .iter()
+            .filter(|g| g.agents.iter().any(|a| a == "*"))
+            .collect()
+    } else {
+        specific
+    };
+
+    let mut rules = RobotsRules::allow_all();
+    for group in selected {
+        rules.rules.extend(group.rules.iter().cloned());
+        if rules.crawl_delay.is_none() {
+            rules.crawl_delay = group.crawl_delay;
+        }
+    }
+    rules
+}
+
+/// Fetches and caches one origin's robots.txt rules for the lifetime of a
+/// `DeepResearch` instance - robots.txt rarely changes mid-crawl, and a
+/// research run's own frontier is bounded, so there's no need for a TTL
+/// beyond "don't refetch within this run" (contrast `web_search::SearchCache`,
+/// which serves a long-lived process and does need one).
+pub struct RobotsCache {
+    client: reqwest::Client,
+    entries: Mutex<HashMap<String, std::sync::Arc<RobotsRules>>>,
+}
+
+impl Default for RobotsCache {
+    fn default() -> Self {
+        Self::new(crate::TlsTrustStore::default())
+    }
+}
+
+impl RobotsCache {
+    /// `tls_trust_store` should be `BrowserManager::tls_trust_store()` so
+    /// this cache's own robots.txt/`X-Robots-Tag` fetches honor the same
+    /// root-certificate policy as the browser itself, rather than whatever
+    /// `reqwest::Client::default()` trusts. Falls back to `reqwest`'s
+    /// default client (equivalent to [`crate::TlsTrustStore::BundledOnly`])
+    /// if the builder call fails, which only happens on a broken TLS
+    /// backend - not a case worth surfacing as an error from a cache
+    /// constructor.
+    #[must_use]
+    pub fn new(tls_trust_store: crate::TlsTrustStore) -> Self {
+        let builder = reqwest::Client::builder();
+        let builder = match tls_trust_store {
+            crate::TlsTrustStore::BundledOnly => builder,
+            crate::TlsTrustStore::NativeOnly => builder
+                .tls_built_in_root_certs(false)
+                .tls_built_in_native_certs(true),
+            crate::TlsTrustStore::Merged => builder.tls_built_in_native_certs(true),
+        };
+        let client = builder.build().unwrap_or_default();
+        Self {
+            client,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Fetch (or return the cached) rules for `url`'s origin.
+    ///
+    /// A fetch failure (network error, non-2xx status, no robots.txt at
+    /// all) is treated the same as an explicit "allow everything" robots.txt
+    /// - the common, spec-sanctioned default - and is cached too, so a
+    /// missing robots.txt doesn't get refetched on every URL from that origin.
+    pub async fn rules_for(&self, url: &str) -> std::sync::Arc<RobotsRules> {
+        let Some(origin) = crate::utils::url_utils::origin_of(url) else {
+            return std::sync::Arc::new(RobotsRules::allow_all());
+        };
+
+        if let Some(cached) = self.entries.lock().await.get(&origin).cloned() {
+            return cached;
+        }
+
+        let rules = match self.client.get(format!("{origin}/robots.txt")).send().await {
+            Ok(resp) if resp.status().is_success() => match resp.text().await {
+                Ok(body) => parse_robots_txt(&body),
+                Err(e) => {
+                    warn!("Failed to read robots.txt body for {}: {}", origin, e);
+                    RobotsRules::allow_all()
+                }
+            },
+            Ok(resp) => {
+                debug!(
+                    "No robots.txt at {} (status {}) - allowing everything",
+                    origin,
+                    resp.status()
+                );
+                RobotsRules::allow_all()
+            }
+            Err(e) => {
+                debug!(
+                    "Failed to fetch robots.txt for {}: {} - allowing everything",
+                    origin, e
+                );
+                RobotsRules::allow_all()
+            }
+        };
+
+        let rules = std::sync::Arc::new(rules);
+        self.entries
+            .lock()
+            .await
+            .insert(origin, std::sync::Arc::clone(&rules));
+        rules
+    }
+
+    /// Best-effort `X-Robots-Tag` lookup via a lightweight `HEAD` request,
+    /// independent of the browser navigation that follows - a page's
+    /// `X-Robots-Tag` is a response header on the top-level document, which
+    /// the shared `BrowserNavigateTool` doesn't expose (its CDP response
+    /// listener would need to attach before the `goto` it already performs
+    /// internally). Returns `None` on any network error so a directive we
+    /// couldn't check never blocks a crawl.
+    pub async fn fetch_x_robots_tag(&self, url: &str) -> Option<String> {
+        let response = self.client.head(url).send().await.ok()?;
+        response
+            .headers()
+            .get("x-robots-tag")
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string)
+    }
+}
+
+/// Parse the comma-separated directive list shared by `<meta name="robots">`
+/// content and the `X-Robots-Tag` header value (e.g. `"noindex, nofollow"`),
+/// returning `(noindex, nofollow)`. An `X-Robots-Tag` value scoped to a
+/// specific user-agent (`"googlebot: noindex"`) is treated the same as an
+/// unscoped one - good enough for a crawl-policy layer that isn't trying to
+/// impersonate a specific named bot.
+pub fn parse_robots_directives(value: &str) -> (bool, bool) {
+    let lower = value.to_ascii_lowercase();
+    let noindex = lower.split(',').any(|d| d.trim().ends_with("noindex"));
+    let nofollow = lower.split(',').any(|d| d.trim().ends_with("nofollow"));
+    (noindex, nofollow)
+}
+
+/// Enforces per-origin `Crawl-delay` by tracking each origin's last
+/// navigation time and sleeping out any remaining delay before the next
+/// one. Shared across every concurrent task in a crawl (see
+/// `DeepResearch::run_crawl`'s local `Semaphore`), so a delay is honored
+/// even when several permits are admitting URLs from the same origin at once.
+#[derive(Default)]
+pub struct CrawlDelayScheduler {
+    last_access: Mutex<HashMap<String, tokio::time::Instant>>,
+}
+
+impl CrawlDelayScheduler {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sleep until `delay` has elapsed since this origin's last navigation
+    /// (a no-op if `delay` is zero or this is the origin's first visit),
+    /// then record the current time as that origin's last access.
+    pub async fn wait_if_needed(&self, origin: &str, delay: Duration) {
+        if delay.is_zero() {
+            return;
+        }
+
+        let now = tokio::time::Instant::now();
+        let sleep_for = {
+            let last_access = self.last_access.lock().await;
+            last_access
+                .get(origin)
+                .and_then(|last| delay.checked_sub(now.saturating_duration_since(*last)))
+        };
+
+        if let Some(sleep_for) = sleep_for {
+            debug!(
+                "Crawl-delay: sleeping {:?} before next request to {}",
+                sleep_for, origin
+            );
+            tokio::time::sleep(sleep_for).await;
+        }
+
+        self.last_access
+            .lock()
+            .await
+            .insert(origin.to_string(), tokio::time::Instant::now());
+    }
+}
@@ -0,0 +1,774 @@
+//! Pluggable search-engine backends for `DeepResearch`
+//!
+//! Each backend knows how to submit a query to one search engine and parse
+//! its own results page. `DeepResearch::search_query` dispatches across one
+//! or more of these based on `ResearchOptions::search_engine`.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chromiumoxide::Page;
+use chromiumoxide::cdp::browser_protocol::network::EventResponseReceived;
+use futures::StreamExt;
+use tracing::warn;
+
+use crate::manager::BrowserManager;
+use crate::utils::errors::UtilsError;
+
+/// Default retry-after when a throttled response doesn't carry a usable
+/// `Retry-After` header.
+const DEFAULT_RATE_LIMIT_BACKOFF: Duration = Duration::from_secs(5);
+
+/// Navigate `page` to `url`, capturing the top-level document response's
+/// HTTP status and `Retry-After` header (if any) as it comes in over CDP.
+///
+/// Returns `Err(UtilsError::RateLimited)` immediately when the engine
+/// responds with 429/503, so callers can back off before even attempting
+/// to scrape a throttled page.
+async fn goto_checking_throttle(page: &Page, url: &str) -> Result<(), UtilsError> {
+    let mut responses = page
+        .event_listener::<EventResponseReceived>()
+        .await
+        .map_err(|e| UtilsError::BrowserError(e.to_string()))?;
+
+    page.goto(url)
+        .await
+        .map_err(|e| UtilsError::BrowserError(e.to_string()))?;
+
+    // The document-level response is the first one to arrive; sub-resource
+    // responses (images, scripts) that follow are irrelevant to throttling.
+    if let Ok(Some(event)) = tokio::time::timeout(Duration::from_secs(3), responses.next()).await {
+        let status = event.response.status;
+        if status == 429 || status == 503 {
+            let retry_after = event
+                .response
+                .headers
+                .inner()
+                .get("retry-after")
+                .and_then(|v| v.as_str())
+                .map(parse_retry_after)
+                .unwrap_or(DEFAULT_RATE_LIMIT_BACKOFF);
+            return Err(UtilsError::RateLimited { retry_after });
+        }
+    }
+
+    page.wait_for_navigation()
+        .await
+        .map_err(|e| UtilsError::BrowserError(e.to_string()))?;
+
+    Ok(())
+}
+
+/// Parse a `Retry-After` header value in either delta-seconds or HTTP-date form.
+///
+/// Falls back to `DEFAULT_RATE_LIMIT_BACKOFF` when the value is neither.
+fn parse_retry_after(value: &str) -> Duration {
+    let value = value.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Duration::from_secs(secs);
+    }
+    if let Ok(when) = chrono::DateTime::parse_from_rfc2822(value) {
+        let now = chrono::Utc::now();
+        let delta = when.with_timezone(&chrono::Utc) - now;
+        if let Ok(std_delta) = delta.to_std() {
+            return std_delta;
+        }
+    }
+    DEFAULT_RATE_LIMIT_BACKOFF
+}
+
+/// Retry `attempt` with Retry-After-aware backoff on throttling, falling
+/// back to exponential backoff for other transient errors, up to
+/// `max_retries` additional attempts beyond the first.
+pub async fn retry_search<F, Fut>(
+    mut attempt: F,
+    max_retries: u32,
+) -> Result<Vec<SearchHit>, UtilsError>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<Vec<SearchHit>, UtilsError>>,
+{
+    let mut tries = 0;
+    loop {
+        match attempt().await {
+            Ok(hits) => return Ok(hits),
+            Err(UtilsError::RateLimited { retry_after }) if tries < max_retries => {
+                tries += 1;
+                warn!(
+                    "Search engine throttled, retrying in {:?} (attempt {}/{})",
+                    retry_after, tries, max_retries
+                );
+                tokio::time::sleep(retry_after).await;
+            }
+            Err(e) if tries < max_retries => {
+                tries += 1;
+                let backoff = Duration::from_millis(200 * 2u64.pow(tries));
+                warn!(
+                    "Search engine attempt failed ({}), retrying in {:?} (attempt {}/{})",
+                    e, backoff, tries, max_retries
+                );
+                tokio::time::sleep(backoff).await;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// A single search result returned by a `SearchEngine` backend
+#[derive(Debug, Clone)]
+pub struct SearchHit {
+    pub url: String,
+    pub title: String,
+}
+
+/// A pluggable web search-engine backend
+#[async_trait]
+pub trait SearchEngine: Send + Sync {
+    /// Engine name as accepted in `ResearchOptions::search_engine` (lowercase)
+    fn name(&self) -> &'static str;
+
+    /// Run `query` against this engine and return parsed result hits.
+    ///
+    /// `user_agent`, when set, overrides the browser's default UA for this
+    /// request (stealth rotation - see `utils::user_agents`). `offset` skips
+    /// the engine's first `offset` results (its own pagination parameter -
+    /// see each impl), for paging beyond the first page; `0` is the normal
+    /// first-page search.
+    async fn search(
+        &self,
+        manager: &BrowserManager,
+        query: &str,
+        user_agent: Option<&str>,
+        offset: usize,
+    ) -> Result<Vec<SearchHit>, UtilsError>;
+}
+
+/// DuckDuckGo backend - navigates to the no-JS HTML SERP directly and
+/// decodes the `duckduckgo.com/l/?uddg=<target>` redirect form used by
+/// organic result anchors, same approach as [`GoogleEngine`].
+pub struct DuckDuckGoEngine;
+
+#[async_trait]
+impl SearchEngine for DuckDuckGoEngine {
+    fn name(&self) -> &'static str {
+        "duckduckgo"
+    }
+
+    async fn search(
+        &self,
+        manager: &BrowserManager,
+        query: &str,
+        user_agent: Option<&str>,
+        offset: usize,
+    ) -> Result<Vec<SearchHit>, UtilsError> {
+        let browser_arc = manager
+            .get_or_launch()
+            .await
+            .map_err(|e| UtilsError::BrowserError(e.to_string()))?;
+        let browser_guard = browser_arc.lock().await;
+        let wrapper = browser_guard
+            .as_ref()
+            .ok_or_else(|| UtilsError::BrowserError("Browser not available".into()))?;
+        let page = crate::browser::create_blank_page(wrapper)
+            .await
+            .map_err(|e| UtilsError::BrowserError(e.to_string()))?;
+        drop(browser_guard);
+
+        if let Some(ua) = user_agent
+            && let Err(e) = crate::utils::user_agents::apply_user_agent(&page, ua).await
+        {
+            warn!(
+                "Failed to apply rotated user-agent for DuckDuckGo search: {}",
+                e
+            );
+        }
+
+        // The no-JS HTML SERP's "More results" form submits `s` (the
+        // 0-based result offset) as a query param - passing it directly
+        // sidesteps having to click the form each time.
+        let search_url = format!(
+            "https://html.duckduckgo.com/html/?q={}&s={offset}",
+            percent_encode(query)
+        );
+        goto_checking_throttle(&page, &search_url).await?;
+
+        let eval_result = page
+            .evaluate(
+                "Array.from(document.querySelectorAll('a.result__a')).map(a => ({href: a.href, text: a.innerText}))",
+            )
+            .await
+            .map_err(|e| UtilsError::BrowserError(format!("Failed to read DuckDuckGo results: {}", e)))?;
+
+        let anchors = eval_result
+            .into_value::<Vec<serde_json::Value>>()
+            .map_err(|e| {
+                UtilsError::BrowserError(format!("Failed to parse DuckDuckGo results: {}", e))
+            })?;
+
+        let mut hits = Vec::new();
+        for anchor in anchors {
+            let href = anchor
+                .get("href")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default();
+            let text = anchor
+                .get("text")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default();
+            if let Some(target) = decode_duckduckgo_href(href) {
+                hits.push(SearchHit {
+                    url: target,
+                    title: text.to_string(),
+                });
+            }
+        }
+
+        if let Err(e) = page.close().await {
+            warn!("Failed to close DuckDuckGo search page: {}", e);
+        }
+
+        Ok(hits)
+    }
+}
+
+/// Decode a DuckDuckGo HTML-SERP anchor href into its target URL.
+///
+/// Organic results are emitted as `//duckduckgo.com/l/?uddg=<target>&rut=...`;
+/// `<target>` is itself percent-encoded.
+fn decode_duckduckgo_href(href: &str) -> Option<String> {
+    let target = if let Some(q_start) = href.find("uddg=") {
+        let after = &href[q_start + "uddg=".len()..];
+        let end = after.find('&').unwrap_or(after.len());
+        percent_decode(&after[..end])
+    } else {
+        href.to_string()
+    };
+
+    if target.starts_with("http://") || target.starts_with("https://") {
+        Some(target)
+    } else {
+        None
+    }
+}
+
+/// Google backend - navigates to the SERP directly and decodes the
+/// `/url?q=<target>` redirect form used by organic result anchors.
+pub struct GoogleEngine;
+
+#[async_trait]
+impl SearchEngine for GoogleEngine {
+    fn name(&self) -> &'static str {
+        "google"
+    }
+
+    async fn search(
+        &self,
+        manager: &BrowserManager,
+        query: &str,
+        user_agent: Option<&str>,
+        offset: usize,
+    ) -> Result<Vec<SearchHit>, UtilsError> {
+        let browser_arc = manager
+            .get_or_launch()
+            .await
+            .map_err(|e| UtilsError::BrowserError(e.to_string()))?;
+        let browser_guard = browser_arc.lock().await;
+        let wrapper = browser_guard
+            .as_ref()
+            .ok_or_else(|| UtilsError::BrowserError("Browser not available".into()))?;
+        let page = crate::browser::create_blank_page(wrapper)
+            .await
+            .map_err(|e| UtilsError::BrowserError(e.to_string()))?;
+        drop(browser_guard);
+
+        if let Some(ua) = user_agent
+            && let Err(e) = crate::utils::user_agents::apply_user_agent(&page, ua).await
+        {
+            warn!(
+                "Failed to apply rotated user-agent for Google search: {}",
+                e
+            );
+        }
+
+        // Google's SERP takes `start` as the 0-based result offset.
+        let search_url = format!(
+            "https://www.google.com/search?q={}&start={offset}",
+            percent_encode(query)
+        );
+        goto_checking_throttle(&page, &search_url).await?;
+
+        let eval_result = page
+            .evaluate(
+                "Array.from(document.querySelectorAll('a')).map(a => ({href: a.href, text: a.innerText}))",
+            )
+            .await
+            .map_err(|e| UtilsError::BrowserError(format!("Failed to read Google results: {}", e)))?;
+
+        let anchors = eval_result
+            .into_value::<Vec<serde_json::Value>>()
+            .map_err(|e| {
+                UtilsError::BrowserError(format!("Failed to parse Google results: {}", e))
+            })?;
+
+        let mut hits = Vec::new();
+        for anchor in anchors {
+            let href = anchor
+                .get("href")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default();
+            let text = anchor
+                .get("text")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default();
+            if let Some(target) = decode_google_href(href) {
+                hits.push(SearchHit {
+                    url: target,
+                    title: text.to_string(),
+                });
+            }
+        }
+
+        if let Err(e) = page.close().await {
+            warn!("Failed to close Google search page: {}", e);
+        }
+
+        Ok(hits)
+    }
+}
+
+/// Decode a Google SERP anchor href into its target URL, if it is a result link.
+///
+/// Google emits organic results either as a bare absolute URL or wrapped as
+/// `/url?q=<target>&sa=...`. Chrome-level links (Google's own nav, images,
+/// account menu, etc.) are filtered out by requiring an `http(s)` target that
+/// does not point back at a `google.*` host.
+fn decode_google_href(href: &str) -> Option<String> {
+    let target = if let Some(q_start) = href.find("url?q=") {
+        let after = &href[q_start + "url?q=".len()..];
+        let end = after.find('&').unwrap_or(after.len());
+        percent_decode(&after[..end])
+    } else {
+        href.to_string()
+    };
+
+    if (target.starts_with("http://") || target.starts_with("https://"))
+        && !target.contains("google.com/")
+        && !target.contains("google.")
+    {
+        Some(target)
+    } else {
+        None
+    }
+}
+
+/// Brave Search backend - unlike DuckDuckGo/Google, Brave emits organic
+/// result anchors as bare absolute URLs with no redirect wrapper, so hits
+/// only need the same own-domain exclusion Google's unwrapped hrefs use.
+pub struct BraveEngine;
+
+#[async_trait]
+impl SearchEngine for BraveEngine {
+    fn name(&self) -> &'static str {
+        "brave"
+    }
+
+    async fn search(
+        &self,
+        manager: &BrowserManager,
+        query: &str,
+        user_agent: Option<&str>,
+        offset: usize,
+    ) -> Result<Vec<SearchHit>, UtilsError> {
+        let browser_arc = manager
+            .get_or_launch()
+            .await
+            .map_err(|e| UtilsError::BrowserError(e.to_string()))?;
+        let browser_guard = browser_arc.lock().await;
+        let wrapper = browser_guard
+            .as_ref()
+            .ok_or_else(|| UtilsError::BrowserError("Browser not available".into()))?;
+        let page = crate::browser::create_blank_page(wrapper)
+            .await
+            .map_err(|e| UtilsError::BrowserError(e.to_string()))?;
+        drop(browser_guard);
+
+        if let Some(ua) = user_agent
+            && let Err(e) = crate::utils::user_agents::apply_user_agent(&page, ua).await
+        {
+            warn!("Failed to apply rotated user-agent for Brave search: {}", e);
+        }
+
+        // Brave's SERP takes `offset` as the 0-based *page* number (10
+        // results per page), not a result count.
+        let page_number = offset / 10;
+        let search_url = format!(
+            "https://search.brave.com/search?q={}&offset={page_number}",
+            percent_encode(query)
+        );
+        goto_checking_throttle(&page, &search_url).await?;
+
+        let eval_result = page
+            .evaluate(
+                "Array.from(document.querySelectorAll('a')).map(a => ({href: a.href, text: a.innerText}))",
+            )
+            .await
+            .map_err(|e| UtilsError::BrowserError(format!("Failed to read Brave results: {}", e)))?;
+
+        let anchors = eval_result
+            .into_value::<Vec<serde_json::Value>>()
+            .map_err(|e| {
+                UtilsError::BrowserError(format!("Failed to parse Brave results: {}", e))
+            })?;
+
+        let mut hits = Vec::new();
+        for anchor in anchors {
+            let href = anchor
+                .get("href")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default();
+            let text = anchor
+                .get("text")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default();
+            if is_external_result_link(href, "brave.com") && !text.trim().is_empty() {
+                hits.push(SearchHit {
+                    url: href.to_string(),
+                    title: text.to_string(),
+                });
+            }
+        }
+
+        if let Err(e) = page.close().await {
+            warn!("Failed to close Brave search page: {}", e);
+        }
+
+        Ok(hits)
+    }
+}
+
+/// Startpage backend - proxies results from Google but, like Brave, emits
+/// organic anchors as bare absolute URLs.
+pub struct StartpageEngine;
+
+#[async_trait]
+impl SearchEngine for StartpageEngine {
+    fn name(&self) -> &'static str {
+        "startpage"
+    }
+
+    async fn search(
+        &self,
+        manager: &BrowserManager,
+        query: &str,
+        user_agent: Option<&str>,
+        offset: usize,
+    ) -> Result<Vec<SearchHit>, UtilsError> {
+        let browser_arc = manager
+            .get_or_launch()
+            .await
+            .map_err(|e| UtilsError::BrowserError(e.to_string()))?;
+        let browser_guard = browser_arc.lock().await;
+        let wrapper = browser_guard
+            .as_ref()
+            .ok_or_else(|| UtilsError::BrowserError("Browser not available".into()))?;
+        let page = crate::browser::create_blank_page(wrapper)
+            .await
+            .map_err(|e| UtilsError::BrowserError(e.to_string()))?;
+        drop(browser_guard);
+
+        if let Some(ua) = user_agent
+            && let Err(e) = crate::utils::user_agents::apply_user_agent(&page, ua).await
+        {
+            warn!(
+                "Failed to apply rotated user-agent for Startpage search: {}",
+                e
+            );
+        }
+
+        // Startpage's SERP takes `startat` as the 0-based result offset.
+        let search_url = format!(
+            "https://www.startpage.com/sp/search?query={}&startat={offset}",
+            percent_encode(query)
+        );
+        goto_checking_throttle(&page, &search_url).await?;
+
+        let eval_result = page
+            .evaluate(
+                "Array.from(document.querySelectorAll('a')).map(a => ({href: a.href, text: a.innerText}))",
+            )
+            .await
+            .map_err(|e| {
+                UtilsError::BrowserError(format!("Failed to read Startpage results: {}", e))
+            })?;
+
+        let anchors = eval_result
+            .into_value::<Vec<serde_json::Value>>()
+            .map_err(|e| {
+                UtilsError::BrowserError(format!("Failed to parse Startpage results: {}", e))
+            })?;
+
+        let mut hits = Vec::new();
+        for anchor in anchors {
+            let href = anchor
+                .get("href")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default();
+            let text = anchor
+                .get("text")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default();
+            if is_external_result_link(href, "startpage.com") && !text.trim().is_empty() {
+                hits.push(SearchHit {
+                    url: href.to_string(),
+                    title: text.to_string(),
+                });
+            }
+        }
+
+        if let Err(e) = page.close().await {
+            warn!("Failed to close Startpage search page: {}", e);
+        }
+
+        Ok(hits)
+    }
+}
+
+/// Whether `href` is an absolute `http(s)` organic result link rather than
+/// one of the search engine's own nav/logo/account links (identified by
+/// `own_domain`, e.g. `"brave.com"`).
+fn is_external_result_link(href: &str, own_domain: &str) -> bool {
+    (href.starts_with("http://") || href.starts_with("https://")) && !href.contains(own_domain)
+}
+
+/// Resolve a `search_engine` option string into the engine backends to query.
+///
+/// Accepts a single engine name (`"google"`) or a comma-separated list
+/// (`"google,duckduckgo,brave,startpage"`) for concurrent multi-engine
+/// aggregation. Unknown names fall back to DuckDuckGo rather than failing
+/// the whole search.
+pub fn resolve_engines(search_engine: &str) -> Vec<Box<dyn SearchEngine>> {
+    let names: Vec<&str> = search_engine
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .collect();
+
+    let names = if names.is_empty() {
+        vec!["duckduckgo"]
+    } else {
+        names
+    };
+
+    names
+        .into_iter()
+        .map(|name| -> Box<dyn SearchEngine> {
+            match name {
+                "google" => Box::new(GoogleEngine),
+                "brave" => Box::new(BraveEngine),
+                "startpage" => Box::new(StartpageEngine),
+                _ => Box::new(DuckDuckGoEngine),
+            }
+        })
+        .collect()
+}
+
+/// Normalize a URL for deduplication across engines: lowercase the scheme
+/// and host, strip a trailing slash and any fragment, and drop tracking
+/// query params (`utm_*`, `ref`) that vary between copies of the same link
+/// without changing what it points to.
+pub fn normalize_url(url: &str) -> String {
+    let without_fragment = url.split('#').next().unwrap_or(url);
+    let (before_query, query) = match without_fragment.split_once('?') {
+        Some((base, q)) => (base, Some(q)),
+        None => (without_fragment, None),
+    };
+
+    let (scheme, authority_and_path) = match before_query.find("://") {
+        Some(idx) => (&before_query[..idx], &before_query[idx + 3..]),
+        None => ("", before_query),
+    };
+    let (host, path) = match authority_and_path.find('/') {
+        Some(idx) => (&authority_and_path[..idx], &authority_and_path[idx..]),
+        None => (authority_and_path, ""),
+    };
+    let path = path.trim_end_matches('/');
+
+    let mut normalized = if scheme.is_empty() {
+        format!("{}{}", host.to_lowercase(), path)
+    } else {
+        format!(
+            "{}://{}{}",
+            scheme.to_lowercase(),
+            host.to_lowercase(),
+            path
+        )
+    };
+
+    if let Some(query) = query {
+        let kept: Vec<&str> = query
+            .split('&')
+            .filter(|param| {
+                let key = param.split('=').next().unwrap_or(param);
+                !key.starts_with("utm_") && key != "ref"
+            })
+            .collect();
+        if !kept.is_empty() {
+            normalized.push('?');
+            normalized.push_str(&kept.join("&"));
+        }
+    }
+
+    normalized
+}
+
+/// Reciprocal Rank Fusion damping constant. Higher `k` flattens the
+/// influence of top ranks; 60 is the value used by the original RRF paper
+/// and most aggregator implementations.
+const RRF_K: f64 = 60.0;
+
+/// Merge several engines' ranked hit lists into one consensus ranking via
+/// Reciprocal Rank Fusion: each hit's score is `Σ_engines 1/(k + rank)`,
+/// summed across every engine list it appears in (matched by
+/// [`normalize_url`]), then sorted descending by score. A result that
+/// several engines agree on outranks one only a single engine found, even
+/// if that engine ranked it first.
+pub fn fuse_rrf(per_engine: Vec<Vec<SearchHit>>) -> Vec<SearchHit> {
+    let mut scores: std::collections::HashMap<String, f64> = std::collections::HashMap::new();
+    let mut hits: std::collections::HashMap<String, SearchHit> = std::collections::HashMap::new();
+
+    for engine_hits in per_engine {
+        for (rank, hit) in engine_hits.into_iter().enumerate() {
+            let key = normalize_url(&hit.url);
+            *scores.entry(key.clone()).or_insert(0.0) += 1.0 / (RRF_K + rank as f64);
+            hits.entry(key).or_insert(hit);
+        }
+    }
+
+    let mut ranked: Vec<(String, f64)> = scores.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+
+    ranked
+        .into_iter()
+        .filter_map(|(key, _)| hits.remove(&key))
+        .collect()
+}
+
+/// Minimal percent-encoding for query strings (no external dependency).
+fn percent_encode(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for byte in input.as_bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(*byte as char);
+            }
+            _ => out.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    out
+}
+
+/// Minimal percent-decoding, tolerant of malformed sequences (passes them through).
+fn percent_decode(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            if let Ok(hex) = std::str::from_utf8(&bytes[i + 1..i + 3])
+                && let Ok(value) = u8::from_str_radix(hex, 16)
+            {
+                out.push(value);
+                i += 3;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_url_lowercases_scheme_and_host() {
+        assert_eq!(
+            normalize_url("HTTPS://Example.COM/Path"),
+            "https://example.com/path"
+        );
+    }
+
+    #[test]
+    fn normalize_url_strips_trailing_slash_and_fragment() {
+        assert_eq!(
+            normalize_url("https://example.com/path/#section"),
+            "https://example.com/path"
+        );
+    }
+
+    #[test]
+    fn normalize_url_drops_tracking_params_but_keeps_others() {
+        assert_eq!(
+            normalize_url("https://example.com/path?utm_source=x&ref=y&id=42"),
+            "https://example.com/path?id=42"
+        );
+    }
+
+    #[test]
+    fn normalize_url_drops_query_entirely_if_all_params_are_tracking() {
+        assert_eq!(
+            normalize_url("https://example.com/path?utm_source=x&ref=y"),
+            "https://example.com/path"
+        );
+    }
+
+    #[test]
+    fn normalize_url_treats_different_casing_and_tracking_params_as_duplicates() {
+        let a = normalize_url("https://example.com/article?utm_source=newsletter");
+        let b = normalize_url("HTTPS://EXAMPLE.com/article/");
+        assert_eq!(a, b);
+    }
+
+    fn hit(url: &str, title: &str) -> SearchHit {
+        SearchHit {
+            url: url.to_string(),
+            title: title.to_string(),
+        }
+    }
+
+    #[test]
+    fn fuse_rrf_deduplicates_by_normalized_url_across_engines() {
+        let engine_a = vec![hit("https://example.com/a", "A (engine a)")];
+        let engine_b = vec![hit("https://example.com/a/?utm_source=x", "A (engine b)")];
+
+        let fused = fuse_rrf(vec![engine_a, engine_b]);
+
+        assert_eq!(fused.len(), 1);
+        // First engine to contribute a given normalized URL wins the stored
+        // `SearchHit` (title/etc.) - only the score accumulates across engines.
+        assert_eq!(fused[0].title, "A (engine a)");
+    }
+
+    #[test]
+    fn fuse_rrf_ranks_results_agreed_on_by_multiple_engines_above_single_engine_top_rank() {
+        // `shared` is ranked 2nd by both engines; `only_a` is ranked 1st but
+        // only appears in one engine's results. Agreement across engines
+        // should still outrank a single engine's top pick.
+        let engine_a = vec![hit("https://example.com/only-a", "Only A"), hit("https://example.com/shared", "Shared")];
+        let engine_b = vec![hit("https://example.com/other", "Other"), hit("https://example.com/shared", "Shared")];
+
+        let fused = fuse_rrf(vec![engine_a, engine_b]);
+        let shared_rank = fused.iter().position(|h| h.url.contains("shared")).unwrap();
+        let only_a_rank = fused.iter().position(|h| h.url.contains("only-a")).unwrap();
+
+        assert!(shared_rank < only_a_rank);
+    }
+
+    #[test]
+    fn fuse_rrf_empty_input_yields_empty_output() {
+        let fused = fuse_rrf(vec![]);
+        assert!(fused.is_empty());
+    }
+}
@@ -0,0 +1,181 @@
+//! Global, bounded-concurrency queue for research page processing
+//!
+//! A per-call `Semaphore` is local to each `research()` invocation, so N
+//! concurrent research calls can fan out to 3N page loads with no global
+//! cap. `SearchQueue` is shared (stored on `BrowserManager`) and bounds
+//! *all* in-flight page processing across every research call.
+//!
+//! When the in-flight cap is saturated, new callers queue behind a bounded
+//! waiting buffer rather than blocking FIFO-forever: if the buffer is also
+//! full, a *randomly chosen* pending waiter is evicted rather than the
+//! oldest (which gives everyone worst-case latency) or the newest (which is
+//! trivially DoS-able by a single flood of requests). Admission for callers
+//! that do make it into the buffer remains FIFO via `tokio::sync::Semaphore`.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use rand::Rng;
+use tokio::sync::{oneshot, Mutex, OwnedSemaphorePermit, Semaphore};
+
+use crate::utils::errors::UtilsError;
+
+struct Waiter {
+    id: u64,
+    cancel: oneshot::Sender<()>,
+}
+
+/// Shared admission-controlled queue bounding in-flight page processing.
+pub struct SearchQueue {
+    semaphore: Arc<Semaphore>,
+    waiting: Arc<Mutex<Vec<Waiter>>>,
+    waiting_capacity: usize,
+    next_id: AtomicU64,
+}
+
+impl SearchQueue {
+    /// Create a queue allowing `in_flight` concurrent permits and at most
+    /// `waiting_capacity` queued callers before random eviction kicks in.
+    pub fn new(in_flight: usize, waiting_capacity: usize) -> Self {
+        Self {
+            semaphore: Arc::new(Semaphore::new(in_flight.max(1))),
+            waiting: Arc::new(Mutex::new(Vec::new())),
+            waiting_capacity: waiting_capacity.max(1),
+            next_id: AtomicU64::new(0),
+        }
+    }
+
+    /// Create a queue sized to the host's available parallelism, with a
+    /// waiting buffer of the same size (a reasonable default: no more
+    /// queued work than could plausibly be serviced this "generation").
+    pub fn with_default_capacity() -> Self {
+        let parallelism = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(4);
+        Self::new(parallelism, parallelism)
+    }
+
+    /// Acquire a permit, queueing behind the bounded waiting buffer if the
+    /// in-flight cap is currently saturated.
+    ///
+    /// Returns `Err(UtilsError::QueueOverflow)` if this caller is randomly
+    /// evicted while waiting (only possible when the waiting buffer is full).
+    pub async fn acquire(&self) -> Result<OwnedSemaphorePermit, UtilsError> {
+        // Fast path: capacity available, no need to register a waiter at all.
+        if let Ok(permit) = Arc::clone(&self.semaphore).try_acquire_owned() {
+            return Ok(permit);
+        }
+
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let (cancel_tx, cancel_rx) = oneshot::channel();
+
+        {
+            let mut waiting = self.waiting.lock().await;
+            if waiting.len() >= self.waiting_capacity {
+                let evict_idx = rand::thread_rng().gen_range(0..waiting.len());
+                let evicted = waiting.swap_remove(evict_idx);
+                // Best-effort: the evicted waiter may have already been granted
+                // a permit and removed itself between our check and this send.
+                let _ = evicted.cancel.send(());
+            }
+            waiting.push(Waiter { id, cancel: cancel_tx });
+        }
+
+        let semaphore = Arc::clone(&self.semaphore);
+        let result = tokio::select! {
+            permit = semaphore.acquire_owned() => {
+                permit.map_err(|e| UtilsError::UnexpectedError(format!("Semaphore closed: {}", e)))
+            }
+            _ = cancel_rx => {
+                Err(UtilsError::QueueOverflow { retry_after: Duration::from_secs(2) })
+            }
+        };
+
+        let mut waiting = self.waiting.lock().await;
+        waiting.retain(|w| w.id != id);
+        drop(waiting);
+
+        result
+    }
+
+    /// Number of callers currently queued behind the in-flight cap.
+    pub async fn queue_depth(&self) -> usize {
+        self.waiting.lock().await.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn acquire_succeeds_immediately_when_capacity_available() {
+        let queue = SearchQueue::new(1, 1);
+        let permit = queue.acquire().await.expect("should acquire immediately");
+        assert_eq!(queue.queue_depth().await, 0);
+        drop(permit);
+    }
+
+    #[tokio::test]
+    async fn acquire_queues_behind_saturated_in_flight_cap() {
+        let queue = Arc::new(SearchQueue::new(1, 1));
+        let held = queue.acquire().await.expect("first caller gets the only permit");
+
+        let waiter_queue = Arc::clone(&queue);
+        let waiter = tokio::spawn(async move { waiter_queue.acquire().await });
+
+        for _ in 0..100 {
+            if queue.queue_depth().await == 1 {
+                break;
+            }
+            tokio::task::yield_now().await;
+        }
+        assert_eq!(queue.queue_depth().await, 1);
+
+        drop(held);
+        let permit = waiter
+            .await
+            .expect("task panicked")
+            .expect("should acquire once the held permit is released");
+        assert_eq!(queue.queue_depth().await, 0);
+        drop(permit);
+    }
+
+    #[tokio::test]
+    async fn acquire_evicts_a_waiter_with_queue_overflow_when_waiting_buffer_is_full() {
+        let queue = Arc::new(SearchQueue::new(1, 1));
+        let held = queue.acquire().await.expect("first caller gets the only permit");
+
+        let first_queue = Arc::clone(&queue);
+        let first_waiter = tokio::spawn(async move { first_queue.acquire().await });
+        for _ in 0..100 {
+            if queue.queue_depth().await == 1 {
+                break;
+            }
+            tokio::task::yield_now().await;
+        }
+        assert_eq!(queue.queue_depth().await, 1);
+
+        // The waiting buffer (capacity 1) is now full, so this second waiter
+        // evicts the first rather than queueing behind it.
+        let second_queue = Arc::clone(&queue);
+        let second_waiter = tokio::spawn(async move { second_queue.acquire().await });
+        for _ in 0..100 {
+            if queue.queue_depth().await == 1 {
+                break;
+            }
+            tokio::task::yield_now().await;
+        }
+
+        let first_result = first_waiter.await.expect("task panicked");
+        assert!(matches!(
+            first_result,
+            Err(UtilsError::QueueOverflow { .. })
+        ));
+
+        drop(held);
+        let second_result = second_waiter.await.expect("task panicked");
+        assert!(second_result.is_ok());
+    }
+}
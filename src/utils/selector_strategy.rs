@@ -0,0 +1,176 @@
+//! Multi-strategy selector resolution: CSS (the default, and the only
+//! strategy every existing caller already speaks), XPath, exact text match,
+//! and regex text match - chosen by a `prefix=` convention so a single
+//! `String` selector can keep flowing through CSS-only plumbing unchanged
+//! for the common case.
+//!
+//! Real pages frequently have no stable `id`/`class` on the element an
+//! agent actually wants (a "Sign in" link styled purely by its parent's
+//! classes, say), so a CSS-only selector language leaves those elements
+//! unreachable. `text=`/`re=` give an agent something to target based on
+//! what's rendered instead of how the DOM happens to be structured.
+
+use std::time::Duration;
+
+use chromiumoxide::Page;
+use chromiumoxide::element::Element;
+use kodegen_mcp_schema::McpError;
+
+use super::ResearchControl;
+use super::wait_for_element::{WaitCondition, wait_for_element};
+
+/// Temporary marker attribute [`resolve_selector`] tags a `Text`/`Regex`
+/// match with so it can hand the match back to `find_element` instead of
+/// trying to convert a JS `RemoteObject` into an [`Element`] directly - the
+/// same technique `action_registry::resolve_selector` uses for numeric
+/// `index` targeting (`[data-mcp-index="N"]`).
+const MATCH_ATTR: &str = "data-kodegen-match";
+
+/// How a selector string should be resolved to a DOM element.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SelectorStrategy {
+    /// A plain CSS selector - resolved via the existing
+    /// [`wait_for_element`] polling machinery.
+    Css(String),
+    /// An XPath expression, e.g. `//button[contains(., "Submit")]`.
+    XPath(String),
+    /// Exact (trimmed) visible text match, e.g. `Sign in`.
+    Text(String),
+    /// A regular expression matched against trimmed visible text.
+    Regex(String),
+}
+
+impl SelectorStrategy {
+    /// Parse `raw` using a prefix convention: `xpath=//button`,
+    /// `text=Sign in`, `re=^Add to.*cart$`. Anything without a recognized
+    /// prefix is a plain CSS selector, so every existing caller passing a
+    /// bare CSS string keeps working unchanged.
+    pub fn parse(raw: &str) -> Self {
+        if let Some(rest) = raw.strip_prefix("xpath=") {
+            SelectorStrategy::XPath(rest.to_string())
+        } else if let Some(rest) = raw.strip_prefix("text=") {
+            SelectorStrategy::Text(rest.to_string())
+        } else if let Some(rest) = raw.strip_prefix("re=") {
+            SelectorStrategy::Regex(rest.to_string())
+        } else {
+            SelectorStrategy::Css(raw.to_string())
+        }
+    }
+
+    /// Render back to the `prefix=value` syntax [`Self::parse`] accepts, so
+    /// a hint generator can emit a selector an agent can copy verbatim
+    /// instead of degrading a text/XPath match to a CSS guess.
+    pub fn to_selector_string(&self) -> String {
+        match self {
+            SelectorStrategy::Css(s) => s.clone(),
+            SelectorStrategy::XPath(s) => format!("xpath={s}"),
+            SelectorStrategy::Text(s) => format!("text={s}"),
+            SelectorStrategy::Regex(s) => format!("re={s}"),
+        }
+    }
+}
+
+/// Resolve `raw` (see [`SelectorStrategy::parse`]) to an [`Element`].
+///
+/// `Css` waits up to `timeout` for `condition` via [`wait_for_element`],
+/// same as every caller today. `XPath`/`Text`/`Regex` resolve immediately
+/// against the page's current state - they don't poll, so a caller
+/// targeting a not-yet-rendered element with one of these should wait on a
+/// `Css` condition first (or retry resolution itself).
+pub async fn resolve_selector(
+    page: &Page,
+    raw: &str,
+    timeout: Duration,
+    control: Option<&ResearchControl>,
+    condition: WaitCondition,
+) -> Result<Element, McpError> {
+    match SelectorStrategy::parse(raw) {
+        SelectorStrategy::Css(selector) => {
+            wait_for_element(page, &selector, timeout, control, condition).await
+        }
+        SelectorStrategy::XPath(xpath) => page.find_xpath(xpath).await.map_err(|e| {
+            McpError::Other(anyhow::anyhow!(
+                "XPath selector '{}' did not match any element: {}",
+                raw,
+                e
+            ))
+        }),
+        SelectorStrategy::Text(text) => resolve_via_text_scan(page, raw, &text, false).await,
+        SelectorStrategy::Regex(pattern) => resolve_via_text_scan(page, raw, &pattern, true).await,
+    }
+}
+
+/// Shared implementation for `Text`/`Regex`: scan the page for the
+/// innermost element whose trimmed `textContent` satisfies `needle` (exact
+/// equality, or a regex test when `is_regex`), tag it with [`MATCH_ATTR`],
+/// then hand off to `find_element` to get a real [`Element`] handle back.
+async fn resolve_via_text_scan(
+    page: &Page,
+    raw: &str,
+    needle: &str,
+    is_regex: bool,
+) -> Result<Element, McpError> {
+    let needle_json = serde_json::to_string(needle)
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to encode match text: {}", e)))?;
+
+    let matches_expr = if is_regex {
+        format!("new RegExp({needle_json}).test(text)")
+    } else {
+        format!("text === {needle_json}")
+    };
+
+    let script = format!(
+        r#"(() => {{
+            document.querySelectorAll('[{attr}]').forEach(el => el.removeAttribute('{attr}'));
+            const matches = (text) => {matches_expr};
+            const candidates = Array.from(document.querySelectorAll('*'));
+            for (const el of candidates) {{
+                const text = (el.textContent || '').trim();
+                if (!text || !matches(text)) continue;
+                const hasMatchingChild = Array.from(el.children)
+                    .some(c => matches((c.textContent || '').trim()));
+                if (hasMatchingChild) continue;
+                el.setAttribute('{attr}', '1');
+                return true;
+            }}
+            return false;
+        }})()"#,
+        attr = MATCH_ATTR,
+        matches_expr = matches_expr,
+    );
+
+    let found = page
+        .evaluate(script.as_str())
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to evaluate text-match script: {}", e)))?
+        .into_value::<bool>()
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to read text-match result: {}", e)))?;
+
+    if !found {
+        return Err(McpError::Other(anyhow::anyhow!(
+            "No element's visible text matched '{}'",
+            raw
+        )));
+    }
+
+    let selector = format!("[{MATCH_ATTR}=\"1\"]");
+    let element = page.find_element(selector.as_str()).await.map_err(|e| {
+        McpError::Other(anyhow::anyhow!(
+            "Text match for '{}' was tagged but could not be re-found: {}",
+            raw,
+            e
+        ))
+    })?;
+
+    // Clean up the marker now that we hold a handle - it's an
+    // implementation detail, not something that should leak into the
+    // page's DOM for scripts or other tooling to trip over.
+    let _ = element
+        .call_js_fn(
+            &format!("function() {{ this.removeAttribute('{MATCH_ATTR}'); }}"),
+            false,
+        )
+        .await;
+
+    Ok(element)
+}
@@ -0,0 +1,241 @@
+//! Breadth-first site crawl - structured link discovery, not summarization
+//!
+//! Analogous to [`crate::utils::DeepResearch`] but simpler: no search-engine
+//! seeding, no LLM summarization, just a BFS walk from a single seed URL
+//! that records each page's title/status/outlinks. Meant for sitemap-style
+//! discovery or to feed a URL list into `browser_extract_text`.
+
+use std::collections::{HashSet, VecDeque};
+use std::sync::Arc;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::page_extractor::extract_page_info;
+use crate::tools::BrowserNavigateTool;
+use crate::utils::errors::UtilsError;
+use kodegen_mcp_schema::browser::BrowserNavigateArgs;
+
+/// One crawled page: what it was, where it sits in the BFS tree, and what
+/// it links to. The flat `Vec<CrawlPageResult>` this module returns can be
+/// reassembled into a tree via `depth` plus each page's `outlinks`, without
+/// needing a dedicated tree type.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrawlPageResult {
+    pub url: String,
+    pub title: String,
+    /// HTTP status of the top-level response, if observed (see
+    /// `NavigationResult::status`). `None` means the status wasn't
+    /// captured in time, not that the page failed to load.
+    pub status: Option<u16>,
+    /// BFS depth from the seed URL (seed itself is depth 0).
+    pub depth: usize,
+    /// Same-page-filtered, deduplicated links discovered on this page.
+    pub outlinks: Vec<String>,
+}
+
+/// Options controlling crawl scope. Unlike `ResearchOptions`, there's no
+/// `ignore_robots` here - add it if a caller needs the crawler to also
+/// respect robots.txt like `DeepResearch` does.
+#[derive(Debug, Clone)]
+pub struct CrawlOptions {
+    pub max_depth: usize,
+    pub max_pages: usize,
+    /// Restrict link discovery to the seed URL's origin (scheme + host +
+    /// port). When `false`, any registrable-domain match is followed (see
+    /// `crate::utils::url_utils::same_registrable_domain`).
+    pub same_origin_only: bool,
+    /// Only follow links whose URL matches this pattern, if set.
+    pub include_pattern: Option<Regex>,
+    /// Never follow links whose URL matches this pattern, if set. Checked
+    /// after `include_pattern`.
+    pub exclude_pattern: Option<Regex>,
+    pub timeout_seconds: u64,
+}
+
+impl Default for CrawlOptions {
+    fn default() -> Self {
+        Self {
+            max_depth: 2,
+            max_pages: 20,
+            same_origin_only: true,
+            include_pattern: None,
+            exclude_pattern: None,
+            timeout_seconds: 30,
+        }
+    }
+}
+
+/// Breadth-first crawler driven by direct library integration, the same
+/// pattern `DeepResearch` uses: `browser_navigate` is called in-process via
+/// `BrowserNavigateTool::navigate_and_capture_page` rather than over MCP.
+#[derive(Clone)]
+pub struct SiteCrawler {
+    browser_manager: Arc<crate::BrowserManager>,
+}
+
+impl SiteCrawler {
+    #[must_use]
+    pub fn new(browser_manager: Arc<crate::BrowserManager>) -> Self {
+        Self { browser_manager }
+    }
+
+    /// Crawl from `start_url`, returning one `CrawlPageResult` per page
+    /// actually visited, in visitation order. Stops when `max_pages` is
+    /// reached or the frontier is exhausted; per-page errors are logged and
+    /// skipped rather than failing the whole crawl, matching
+    /// `DeepResearch::run_crawl`'s tolerance for individual bad URLs.
+    pub async fn crawl(
+        &self,
+        start_url: &str,
+        options: &CrawlOptions,
+    ) -> Result<Vec<CrawlPageResult>, UtilsError> {
+        let mut visited: HashSet<String> = HashSet::new();
+        let mut frontier: VecDeque<(String, usize)> = VecDeque::new();
+        visited.insert(start_url.to_string());
+        frontier.push_back((start_url.to_string(), 0));
+
+        let mut pages = Vec::new();
+
+        while let Some((url, depth)) = frontier.pop_front() {
+            if pages.len() >= options.max_pages {
+                break;
+            }
+
+            let page_result = match self.crawl_one(&url, depth, start_url, options).await {
+                Ok(result) => result,
+                Err(e) => {
+                    warn!("Error crawling {}: {}", url, e);
+                    continue;
+                }
+            };
+
+            if depth < options.max_depth {
+                for link in &page_result.outlinks {
+                    if visited.insert(link.clone()) {
+                        frontier.push_back((link.clone(), depth + 1));
+                    }
+                }
+            }
+
+            pages.push(page_result);
+        }
+
+        Ok(pages)
+    }
+
+    /// Navigate to `url`, extract its title/outlinks, and hold a
+    /// process-wide `SearchQueue` permit while doing so - the same
+    /// admission control every other browser-driven page load goes
+    /// through, so a crawl doesn't starve concurrent research/search calls.
+    async fn crawl_one(
+        &self,
+        url: &str,
+        depth: usize,
+        start_url: &str,
+        options: &CrawlOptions,
+    ) -> Result<CrawlPageResult, UtilsError> {
+        let _permit = self.browser_manager.search_queue().acquire().await?;
+
+        let nav_tool = BrowserNavigateTool::new(self.browser_manager.clone());
+        let nav_args = BrowserNavigateArgs {
+            url: url.to_string(),
+            wait_for_selector: None,
+            timeout_ms: Some(options.timeout_seconds * 1000),
+        };
+
+        let (page, nav_result) = nav_tool
+            .navigate_and_capture_page(nav_args)
+            .await
+            .map_err(|e| UtilsError::BrowserError(e.to_string()))?;
+
+        let final_url = nav_result.url;
+        let status = nav_result.status;
+
+        let page_info = extract_page_info(page.clone())
+            .await
+            .map_err(|e| UtilsError::BrowserError(e.to_string()))?;
+
+        let outlinks = self
+            .discover_links(&page, &final_url, start_url, options)
+            .await;
+
+        Ok(CrawlPageResult {
+            url: final_url,
+            title: page_info.title,
+            status,
+            depth,
+            outlinks,
+        })
+    }
+
+    /// Parse anchor hrefs from the captured page, resolve them against
+    /// `final_url`, and keep only those passing the origin/include/exclude
+    /// rules in `options`. `start_url`, not `final_url`, anchors the
+    /// origin check, so a redirect on the seed page doesn't silently widen
+    /// (or narrow) the crawl's scope mid-walk.
+    async fn discover_links(
+        &self,
+        page: &chromiumoxide::Page,
+        final_url: &str,
+        start_url: &str,
+        options: &CrawlOptions,
+    ) -> Vec<String> {
+        let eval_result = match page
+            .evaluate(
+                "Array.from(document.querySelectorAll('a[href]')).map(a => a.getAttribute('href'))",
+            )
+            .await
+        {
+            Ok(r) => r,
+            Err(e) => {
+                warn!("Failed to read links from {}: {}", final_url, e);
+                return Vec::new();
+            }
+        };
+
+        let hrefs: Vec<Option<String>> = match eval_result.into_value() {
+            Ok(v) => v,
+            Err(e) => {
+                warn!("Failed to parse links from {}: {}", final_url, e);
+                return Vec::new();
+            }
+        };
+
+        let mut seen = HashSet::new();
+        let mut links = Vec::new();
+        for href in hrefs.into_iter().flatten() {
+            let Some(resolved) = crate::utils::url_utils::resolve_relative(final_url, &href) else {
+                continue;
+            };
+
+            if options.same_origin_only
+                && crate::utils::url_utils::origin_of(start_url)
+                    != crate::utils::url_utils::origin_of(&resolved)
+            {
+                continue;
+            }
+            if !options.same_origin_only
+                && !crate::utils::url_utils::same_registrable_domain(start_url, &resolved)
+            {
+                continue;
+            }
+            if let Some(include) = &options.include_pattern
+                && !include.is_match(&resolved)
+            {
+                continue;
+            }
+            if let Some(exclude) = &options.exclude_pattern
+                && exclude.is_match(&resolved)
+            {
+                continue;
+            }
+            if seen.insert(resolved.clone()) {
+                links.push(resolved);
+            }
+        }
+
+        links
+    }
+}
@@ -0,0 +1,95 @@
+//! Generic cancel-then-bounded-join primitive for background task shutdown.
+//!
+//! A handful of places each hand-roll the same shape: cancel a token, abort
+//! a [`JoinHandle`], `tokio::time::timeout` an await on it, and warn if it
+//! didn't finish in time - with the timeout duration copy-pasted as a bare
+//! `Duration::from_secs(5)` at every call site. [`TaskController`] centralizes
+//! that pattern behind a registry of tracked handles and one configurable
+//! timeout.
+
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+use tracing::warn;
+
+/// Outcome of waiting on one tracked task during [`TaskController::terminate_all_async`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskOutcome {
+    /// Finished on its own (or panicked) before the timeout elapsed.
+    FinishedCleanly,
+    /// Still running once the timeout elapsed and was forcibly aborted.
+    ForceAborted,
+}
+
+/// Per-task result from [`TaskController::terminate_all_async`], labeled so a
+/// caller tracking several tasks can tell which one needed forcing.
+#[derive(Debug, Clone)]
+pub struct TaskReport {
+    pub label: String,
+    pub outcome: TaskOutcome,
+}
+
+/// Owns a [`CancellationToken`] shared with the tasks it tracks, plus the
+/// [`JoinHandle`]s needed to bound how long shutdown waits for them.
+pub struct TaskController {
+    token: CancellationToken,
+    tasks: Vec<(String, JoinHandle<()>)>,
+}
+
+impl TaskController {
+    /// Create a controller with a fresh [`CancellationToken`] and no tracked tasks.
+    pub fn new() -> Self {
+        Self {
+            token: CancellationToken::new(),
+            tasks: Vec::new(),
+        }
+    }
+
+    /// The token tracked tasks should select against (or poll `is_cancelled()`)
+    /// to shut down cooperatively, rather than relying solely on `abort()`.
+    pub fn token(&self) -> CancellationToken {
+        self.token.clone()
+    }
+
+    /// Register `handle` under `label` so [`Self::terminate_all_async`] waits
+    /// for it (bounded) and reports its outcome.
+    pub fn track(&mut self, label: impl Into<String>, handle: JoinHandle<()>) {
+        self.tasks.push((label.into(), handle));
+    }
+
+    /// Cancel the shared token, then await every tracked handle in turn,
+    /// bounded by `timeout`. A handle still running once its timeout elapses
+    /// is forcibly aborted. Returns a per-task report, in registration order;
+    /// tracked tasks are drained, so a controller can be reused afterward.
+    pub async fn terminate_all_async(&mut self, timeout: Duration) -> Vec<TaskReport> {
+        self.token.cancel();
+
+        let mut reports = Vec::with_capacity(self.tasks.len());
+        for (label, mut handle) in self.tasks.drain(..) {
+            let outcome = tokio::select! {
+                result = &mut handle => {
+                    if let Err(join_error) = result {
+                        warn!("Task '{}' panicked during shutdown: {:?}", label, join_error);
+                    }
+                    TaskOutcome::FinishedCleanly
+                }
+                () = tokio::time::sleep(timeout) => {
+                    warn!(
+                        "Task '{}' did not finish within {:?} of cancellation, forcing abort",
+                        label, timeout
+                    );
+                    handle.abort();
+                    TaskOutcome::ForceAborted
+                }
+            };
+            reports.push(TaskReport { label, outcome });
+        }
+        reports
+    }
+}
+
+impl Default for TaskController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
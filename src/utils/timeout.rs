@@ -13,12 +13,18 @@ pub const MAX_INTERACTION_TIMEOUT_MS: u64 = 30_000; // 30 seconds
 
 /// Validate timeout for navigation operations (navigate, wait_for_selector)
 ///
+/// `timeout_ms: Some(0)` means "wait indefinitely" - represented as `Ok(None)`
+/// rather than some sentinel `Duration`, so callers can't accidentally await
+/// it with `tokio::time::timeout` and get an instant timeout instead of the
+/// intended fire-and-forget behavior. See `BrowserNavigateTool::finish_navigation`.
+///
 /// # Arguments
 /// * `timeout_ms` - Optional timeout in milliseconds
 /// * `default_ms` - Default timeout if None provided
 ///
 /// # Returns
-/// * `Ok(Duration)` - Validated Duration object
+/// * `Ok(Some(Duration))` - Validated, finite timeout
+/// * `Ok(None)` - `timeout_ms` was `0`: wait indefinitely
 /// * `Err(McpError)` - If timeout exceeds MAX_NAVIGATION_TIMEOUT_MS
 ///
 /// # Example
@@ -28,9 +34,13 @@ pub const MAX_INTERACTION_TIMEOUT_MS: u64 = 30_000; // 30 seconds
 pub fn validate_navigation_timeout(
     timeout_ms: Option<u64>,
     default_ms: u64,
-) -> Result<Duration, McpError> {
+) -> Result<Option<Duration>, McpError> {
     let ms = timeout_ms.unwrap_or(default_ms);
 
+    if ms == 0 {
+        return Ok(None);
+    }
+
     if ms > MAX_NAVIGATION_TIMEOUT_MS {
         return Err(McpError::invalid_arguments(format!(
             "Timeout cannot exceed {}ms ({} minutes). Received: {}ms ({:.1} minutes)",
@@ -41,7 +51,7 @@ pub fn validate_navigation_timeout(
         )));
     }
 
-    Ok(Duration::from_millis(ms))
+    Ok(Some(Duration::from_millis(ms)))
 }
 
 /// Validate timeout for element interaction operations (click, type_text)
@@ -0,0 +1,104 @@
+//! Minimal URL helpers shared by the crawler, rate limiter, and navigation guard
+//!
+//! Deliberately dependency-free (no `url` crate) since only host/scheme
+//! extraction and relative-URL resolution are needed here.
+
+/// Split a URL into `(scheme, host, rest)`. `rest` includes the leading `/`
+/// of the path, if any. Returns `None` if `url` has no recognizable scheme.
+fn split_url(url: &str) -> Option<(&str, &str, &str)> {
+    let (scheme, after_scheme) = url.split_once("://")?;
+    let authority_end = after_scheme
+        .find(['/', '?', '#'])
+        .unwrap_or(after_scheme.len());
+    let (authority, rest) = after_scheme.split_at(authority_end);
+    // Strip userinfo and port for host comparison purposes
+    let host = authority.rsplit('@').next().unwrap_or(authority);
+    let host = host.split(':').next().unwrap_or(host);
+    Some((scheme, host, rest))
+}
+
+/// Extract the lowercased host from a URL, if any.
+pub fn host_of(url: &str) -> Option<String> {
+    split_url(url).map(|(_, host, _)| host.to_lowercase())
+}
+
+/// Extract `scheme://authority` (host plus port, if any - unlike `host_of`,
+/// which strips the port for comparison purposes) from a URL. This is the
+/// key a robots.txt cache groups rules by, since the file lives at
+/// `{origin}/robots.txt`.
+pub fn origin_of(url: &str) -> Option<String> {
+    let (scheme, after_scheme) = url.split_once("://")?;
+    let authority_end = after_scheme
+        .find(['/', '?', '#'])
+        .unwrap_or(after_scheme.len());
+    Some(format!("{}://{}", scheme, &after_scheme[..authority_end]))
+}
+
+/// Extract the path (defaulting to `/`) a URL points at, ignoring query and
+/// fragment - the part robots.txt `Disallow`/`Allow` prefixes match against.
+pub fn path_of(url: &str) -> String {
+    let Some((_, _, rest)) = split_url(url) else {
+        return "/".to_string();
+    };
+    let path = rest.split(['?', '#']).next().unwrap_or("/");
+    if path.is_empty() {
+        "/".to_string()
+    } else {
+        path.to_string()
+    }
+}
+
+/// Return the "registrable domain" heuristic used for same-site checks:
+/// the last two dot-separated labels (e.g. `sub.example.co.uk` -> `co.uk`
+/// is a known limitation of this simple heuristic; good enough for crawl
+/// scoping, not for security-sensitive cookie policy).
+pub fn registrable_domain(host: &str) -> String {
+    let labels: Vec<&str> = host.split('.').collect();
+    if labels.len() <= 2 {
+        host.to_string()
+    } else {
+        labels[labels.len() - 2..].join(".")
+    }
+}
+
+/// Whether `candidate` shares a registrable domain with `origin`.
+pub fn same_registrable_domain(origin: &str, candidate: &str) -> bool {
+    match (host_of(origin), host_of(candidate)) {
+        (Some(a), Some(b)) => registrable_domain(&a) == registrable_domain(&b),
+        _ => false,
+    }
+}
+
+/// Resolve `href` against `base_url`, handling absolute URLs, protocol-relative
+/// (`//host/path`), absolute-path (`/path`), and relative (`path`) forms.
+pub fn resolve_relative(base_url: &str, href: &str) -> Option<String> {
+    let href = href.trim();
+    if href.is_empty()
+        || href.starts_with('#')
+        || href.starts_with("javascript:")
+        || href.starts_with("mailto:")
+    {
+        return None;
+    }
+
+    if href.contains("://") {
+        return Some(href.to_string());
+    }
+
+    let (scheme, host, rest) = split_url(base_url)?;
+
+    if let Some(path_and_more) = href.strip_prefix("//") {
+        return Some(format!("{}://{}", scheme, path_and_more));
+    }
+
+    if let Some(abs_path) = href.strip_prefix('/') {
+        return Some(format!("{}://{}/{}", scheme, host, abs_path));
+    }
+
+    // Relative to the current path's directory
+    let base_dir = match rest.rfind('/') {
+        Some(idx) => &rest[..=idx],
+        None => "/",
+    };
+    Some(format!("{}://{}{}{}", scheme, host, base_dir, href))
+}
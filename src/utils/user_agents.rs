@@ -0,0 +1,82 @@
+//! Rotating user-agent pool for search and navigation stealth
+//!
+//! A single static `CHROME_USER_AGENT` (see `constants.rs`) is an easy bot
+//! fingerprint: every request from this process looks identical. This pool
+//! lets callers pick a different realistic desktop/mobile UA per request,
+//! either at random or round-robin, to avoid engines that key off a static
+//! agent string.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use rand::Rng;
+
+/// Curated pool of realistic, current-generation desktop and mobile UA strings.
+pub const DEFAULT_USER_AGENTS: &[&str] = &[
+    "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/132.0.6834.160 Safari/537.36",
+    "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/132.0.6834.160 Safari/537.36",
+    "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/132.0.6834.160 Safari/537.36",
+    "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.3 Safari/605.1.15",
+    "Mozilla/5.0 (Windows NT 10.0; Win64; x64; rv:125.0) Gecko/20100101 Firefox/125.0",
+    "Mozilla/5.0 (iPhone; CPU iPhone OS 17_3 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.3 Mobile/15E148 Safari/604.1",
+    "Mozilla/5.0 (Linux; Android 14; Pixel 8) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/132.0.6834.160 Mobile Safari/537.36",
+];
+
+/// Selection strategy used by `UserAgentPool::pick`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UserAgentSelection {
+    /// Cycle through the pool in order.
+    RoundRobin,
+    /// Pick a uniformly random entry each time.
+    Random,
+}
+
+/// A pool of user-agent strings rotated per-request.
+pub struct UserAgentPool {
+    agents: Vec<String>,
+    selection: UserAgentSelection,
+    cursor: AtomicUsize,
+}
+
+impl UserAgentPool {
+    /// Build a pool from an explicit list of agent strings.
+    pub fn new(agents: Vec<String>, selection: UserAgentSelection) -> Self {
+        let agents = if agents.is_empty() {
+            DEFAULT_USER_AGENTS.iter().map(|s| s.to_string()).collect()
+        } else {
+            agents
+        };
+        Self {
+            agents,
+            selection,
+            cursor: AtomicUsize::new(0),
+        }
+    }
+
+    /// Build a pool from the built-in curated set, picked at random.
+    pub fn built_in() -> Self {
+        Self::new(Vec::new(), UserAgentSelection::Random)
+    }
+
+    /// Pick the next user-agent string per this pool's selection strategy.
+    pub fn pick(&self) -> &str {
+        let idx = match self.selection {
+            UserAgentSelection::RoundRobin => {
+                self.cursor.fetch_add(1, Ordering::Relaxed) % self.agents.len()
+            }
+            UserAgentSelection::Random => rand::thread_rng().gen_range(0..self.agents.len()),
+        };
+        &self.agents[idx]
+    }
+}
+
+/// Apply a user-agent override to `page` via the CDP Network domain.
+///
+/// Must be called before navigation for the override to take effect on the
+/// outgoing request.
+pub async fn apply_user_agent(page: &chromiumoxide::Page, user_agent: &str) -> Result<(), chromiumoxide::error::CdpError> {
+    use chromiumoxide::cdp::browser_protocol::network::SetUserAgentOverrideParams;
+
+    page.execute(SetUserAgentOverrideParams::new(user_agent.to_string()))
+        .await?;
+    Ok(())
+}
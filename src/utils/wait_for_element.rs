@@ -7,50 +7,183 @@
 use std::time::Duration;
 
 use chromiumoxide::Page;
+use chromiumoxide::cdp::browser_protocol::runtime::{AddBindingParams, EventBindingCalled};
 use chromiumoxide::element::Element;
+use futures::StreamExt;
 use kodegen_mcp_schema::McpError;
+use tracing::warn;
 
-/// Wait for an element to appear in the DOM using exponential backoff polling
-///
-/// This function polls for an element with exponential backoff, waiting for SPAs
-/// to render elements after page load. Used by navigate, click, and type_text tools.
-///
-/// # Arguments
-/// * `page` - The chromiumoxide Page to search in
-/// * `selector` - CSS selector for the element
-/// * `timeout` - Maximum time to wait for the element
-///
-/// # Returns
-/// * `Ok(Element)` - The element was found
-/// * `Err(McpError)` - Timeout exceeded or other error
+use super::ResearchControl;
+
+/// How often the polling loops re-check an attached [`ResearchControl`] for
+/// an abort or an elapsed deadline, independent of the element poll interval.
+const CONTROL_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+fn aborted_error(selector: &str) -> McpError {
+    McpError::Other(anyhow::anyhow!(
+        "Wait for element '{}' aborted (session killed or deadline exceeded)",
+        selector
+    ))
+}
+
+/// Resolves once `control` reports `should_stop`, polled every
+/// [`CONTROL_CHECK_INTERVAL`]; never resolves when `control` is `None`, so
+/// racing it in a `select!` is a no-op for callers without one.
+async fn wait_on_control(control: Option<&ResearchControl>) {
+    match control {
+        Some(control) => loop {
+            if control.should_stop() {
+                return;
+            }
+            tokio::time::sleep(CONTROL_CHECK_INTERVAL).await;
+        },
+        None => std::future::pending().await,
+    }
+}
+
+/// Name of the CDP runtime binding installed by `wait_for_element_via_binding`.
+/// Exposed as `window.<NAME>` to the injected MutationObserver script.
+const ELEMENT_READY_BINDING: &str = "__kodegen_element_ready";
+
+/// How strictly [`wait_for_element`] checks an otherwise DOM-present element,
+/// modeled on WebDriver's `IsDisplayed`/`IsEnabled` semantics.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WaitCondition {
+    /// Only `querySelector`/`find_element` needs to succeed.
+    Present,
+    /// Non-zero bounding box and not `display:none`/`visibility:hidden`/`opacity:0`/
+    /// `pointer-events:none`.
+    Visible,
+    /// `Visible`, plus not `disabled` and topmost element at its center point.
+    Clickable,
+    /// `Visible`, plus `textContent` contains the given substring.
+    TextContains(String),
+    /// Succeeds once the selector no longer matches anything - the inverse
+    /// of every other variant, for waiting out a loading spinner or a modal
+    /// that's expected to be removed rather than appear.
+    Detached,
+}
+
+impl WaitCondition {
+    fn label(&self) -> String {
+        match self {
+            WaitCondition::Present => "present".to_string(),
+            WaitCondition::Visible => "visible".to_string(),
+            WaitCondition::Clickable => "clickable".to_string(),
+            WaitCondition::TextContains(text) => format!("containing text '{text}'"),
+            WaitCondition::Detached => "detached".to_string(),
+        }
+    }
+}
+
+/// Evaluates `condition` against `selector` via a single JS round-trip.
+/// `Present` never needs this - callers only reach here once `find_element`
+/// has already succeeded. `Detached` is the only variant that doesn't
+/// require the element to currently exist, so it's checked directly against
+/// the document rather than an already-located [`Element`].
+async fn condition_met(page: &Page, selector: &str, condition: &WaitCondition) -> Result<bool, McpError> {
+    if *condition == WaitCondition::Present {
+        return Ok(true);
+    }
+
+    let selector_json = serde_json::to_string(selector)
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to encode selector: {}", e)))?;
+
+    if *condition == WaitCondition::Detached {
+        let script = format!("document.querySelector({selector_json}) === null");
+        return page
+            .evaluate(script.as_str())
+            .await
+            .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to evaluate condition script: {}", e)))?
+            .into_value::<bool>()
+            .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to read condition result: {}", e)));
+    }
+
+    let check_clickable = matches!(condition, WaitCondition::Clickable);
+    let text_contains_json = match condition {
+        WaitCondition::TextContains(text) => serde_json::to_string(text)
+            .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to encode text: {}", e)))?,
+        _ => "null".to_string(),
+    };
+
+    let script = format!(
+        r#"(() => {{
+            const el = document.querySelector({selector_json});
+            if (!el) return false;
+            const rect = el.getBoundingClientRect();
+            const style = window.getComputedStyle(el);
+            const visible = rect.width > 0 && rect.height > 0
+                && style.visibility !== 'hidden' && style.display !== 'none'
+                && style.opacity !== '0' && style.pointerEvents !== 'none';
+            if (!visible) return false;
+            const textNeedle = {text_contains_json};
+            if (textNeedle !== null && !(el.textContent || '').includes(textNeedle)) return false;
+            if (!{check_clickable}) return true;
+            if (el.disabled) return false;
+            const cx = rect.left + rect.width / 2;
+            const cy = rect.top + rect.height / 2;
+            const topmost = document.elementFromPoint(cx, cy);
+            return topmost !== null && (topmost === el || el.contains(topmost));
+        }})()"#,
+        selector_json = selector_json,
+        check_clickable = check_clickable,
+        text_contains_json = text_contains_json,
+    );
+
+    page.evaluate(script.as_str())
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to evaluate condition script: {}", e)))?
+        .into_value::<bool>()
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to read condition result: {}", e)))
+}
+
+/// Wait for `selector` to satisfy `condition`, using exponential backoff
+/// polling, returning the located element - or `None` for
+/// [`WaitCondition::Detached`], which by definition has none once it
+/// succeeds.
 ///
 /// # Polling Strategy
 /// - Starts at 100ms intervals
 /// - Doubles each retry (exponential backoff)
 /// - Caps at 1 second maximum interval
-/// - Total duration limited by timeout parameter
-pub async fn wait_for_element(
+/// - Total duration limited by timeout parameter, or cut short the moment
+///   `control` (if given) is aborted or its shared deadline passes
+pub async fn wait_for_element_with(
     page: &Page,
     selector: &str,
+    condition: WaitCondition,
     timeout: Duration,
-) -> Result<Element, McpError> {
+    control: Option<&ResearchControl>,
+) -> Result<Option<Element>, McpError> {
     let start = std::time::Instant::now();
     let mut poll_interval = Duration::from_millis(100); // Start with 100ms
     let max_interval = Duration::from_secs(1); // Cap at 1 second
 
     loop {
-        // Try to find element
-        if let Ok(element) = page.find_element(selector).await {
-            return Ok(element);
+        if condition == WaitCondition::Detached {
+            if condition_met(page, selector, &condition).await.unwrap_or(false) {
+                return Ok(None);
+            }
+        } else if let Ok(element) = page.find_element(selector).await
+            && condition_met(page, selector, &condition).await.unwrap_or(false)
+        {
+            return Ok(Some(element));
+        }
+
+        if let Some(control) = control
+            && control.should_stop()
+        {
+            return Err(aborted_error(selector));
         }
 
         // Check timeout
         if start.elapsed() >= timeout {
             return Err(McpError::Other(anyhow::anyhow!(
-                "Element not found (timeout after {}ms): '{}'. \
+                "Element not {} (timeout after {}ms): '{}'. \
                  Try: (1) Verify selector is correct using browser dev tools, \
                  (2) Ensure element is visible and loaded, \
                  (3) Increase timeout_ms parameter.",
+                condition.label(),
                 timeout.as_millis(),
                 selector
             )));
@@ -63,3 +196,174 @@ pub async fn wait_for_element(
         poll_interval = (poll_interval * 2).min(max_interval);
     }
 }
+
+/// Wait for an element to appear in the DOM (and, per `condition`, become
+/// visible or clickable) using exponential backoff polling.
+///
+/// This function polls for an element with exponential backoff, waiting for SPAs
+/// to render elements after page load. Used by navigate, click, and type_text tools.
+///
+/// Thin wrapper over [`wait_for_element_with`] for the conditions that
+/// resolve to an actual [`Element`]. [`WaitCondition::Detached`] has no
+/// element to return on success - call [`wait_for_element_with`] directly
+/// for that condition.
+///
+/// # Arguments
+/// * `page` - The chromiumoxide Page to search in
+/// * `selector` - CSS selector for the element
+/// * `timeout` - Maximum time to wait for the element
+/// * `condition` - How strictly to check the located element; see [`WaitCondition`]
+///
+/// # Returns
+/// * `Ok(Element)` - The element was found and met `condition`
+/// * `Err(McpError)` - Timeout exceeded or other error
+pub async fn wait_for_element(
+    page: &Page,
+    selector: &str,
+    timeout: Duration,
+    control: Option<&ResearchControl>,
+    condition: WaitCondition,
+) -> Result<Element, McpError> {
+    match wait_for_element_with(page, selector, condition, timeout, control).await? {
+        Some(element) => Ok(element),
+        None => Err(McpError::Other(anyhow::anyhow!(
+            "WaitCondition::Detached has no element to return for '{}' - call wait_for_element_with directly",
+            selector
+        ))),
+    }
+}
+
+/// Wait for an element using a CDP binding instead of busy-polling
+///
+/// Installs a runtime binding (`AddBindingParams`) and injects a
+/// `MutationObserver` that calls it the instant `selector` matches, then
+/// awaits the resulting `EventBindingCalled` rather than repeatedly calling
+/// `find_element`. This is both faster (no polling latency) and cheaper (no
+/// wasted DOM queries) on SPAs that render well after page load.
+///
+/// Falls back to [`wait_for_element`]'s polling implementation if binding
+/// installation or script injection fails for any reason, so callers can
+/// always use this as a drop-in upgrade.
+pub async fn wait_for_element_via_binding(
+    page: &Page,
+    selector: &str,
+    timeout: Duration,
+    control: Option<&ResearchControl>,
+    condition: WaitCondition,
+) -> Result<Element, McpError> {
+    match wait_for_element_via_binding_inner(page, selector, timeout, control, condition.clone()).await {
+        Ok(element) => Ok(element),
+        Err(e) => {
+            warn!(
+                "Binding-driven wait for '{}' failed ({}), falling back to polling",
+                selector, e
+            );
+            wait_for_element(page, selector, timeout, control, condition).await
+        }
+    }
+}
+
+async fn wait_for_element_via_binding_inner(
+    page: &Page,
+    selector: &str,
+    timeout: Duration,
+    control: Option<&ResearchControl>,
+    condition: WaitCondition,
+) -> Result<Element, McpError> {
+    // The installed binding only ever reports `querySelector` matching, so it
+    // can't observe an element's *absence*, and there's no `Element` to
+    // return for `Detached` regardless (see `wait_for_element`) - callers
+    // waiting on `Detached` should poll via `wait_for_element_with` instead.
+    if condition == WaitCondition::Detached {
+        return Err(McpError::Other(anyhow::anyhow!(
+            "WaitCondition::Detached is not supported by wait_for_element_via_binding for '{}' - use wait_for_element_with",
+            selector
+        )));
+    }
+
+    let start = std::time::Instant::now();
+    let mut binding_events = page
+        .event_listener::<EventBindingCalled>()
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to subscribe to binding events: {}", e)))?;
+
+    page.execute(AddBindingParams::new(ELEMENT_READY_BINDING))
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to add CDP binding: {}", e)))?;
+
+    let selector_json = serde_json::to_string(selector)
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to encode selector: {}", e)))?;
+
+    // Reports immediately if the selector already matches, then observes
+    // the whole document for further mutations until it does.
+    let script = format!(
+        r#"(() => {{
+            const selector = {selector_json};
+            const report = () => {{
+                if (document.querySelector(selector)) {{
+                    window.{binding}(selector);
+                    return true;
+                }}
+                return false;
+            }};
+            if (report()) return;
+            const observer = new MutationObserver(() => {{
+                if (report()) observer.disconnect();
+            }});
+            observer.observe(document.documentElement, {{
+                childList: true,
+                subtree: true,
+                attributes: true,
+            }});
+        }})()"#,
+        selector_json = selector_json,
+        binding = ELEMENT_READY_BINDING,
+    );
+
+    page.evaluate(script.as_str())
+        .await
+        .map_err(|e| McpError::Other(anyhow::anyhow!("Failed to install MutationObserver: {}", e)))?;
+
+    let wait_for_binding = async {
+        while let Some(event) = binding_events.next().await {
+            if event.name == ELEMENT_READY_BINDING && event.payload == selector {
+                return;
+            }
+        }
+    };
+
+    tokio::select! {
+        biased;
+        result = tokio::time::timeout(timeout, wait_for_binding) => {
+            result.map_err(|_| {
+                McpError::Other(anyhow::anyhow!(
+                    "Timed out waiting for binding-driven element ready (timeout after {}ms): '{}'",
+                    timeout.as_millis(),
+                    selector
+                ))
+            })?;
+        }
+        () = wait_on_control(control) => {
+            return Err(aborted_error(selector));
+        }
+    }
+
+    let element = page.find_element(selector).await.map_err(|e| {
+        McpError::Other(anyhow::anyhow!(
+            "Element reported ready but could not be found: '{}': {}",
+            selector,
+            e
+        ))
+    })?;
+
+    if condition == WaitCondition::Present {
+        return Ok(element);
+    }
+
+    // The binding only confirms DOM presence; hand off to the polling loop
+    // for any remaining `Visible`/`Clickable` check with whatever timeout is
+    // left. `find_element` succeeds immediately from here, so this is just
+    // the condition-checking half of `wait_for_element`'s loop.
+    let remaining = timeout.saturating_sub(start.elapsed());
+    wait_for_element(page, selector, remaining, control, condition).await
+}
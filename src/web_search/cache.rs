@@ -0,0 +1,97 @@
+//! Bounded, TTL'd cache of fused `search_with_manager` results
+//!
+//! Keyed on the normalized query text plus the set of engines that produced
+//! it (the same query against a different engine selection is a different
+//! cache entry). Entries older than the configured TTL are treated as
+//! misses and evicted lazily; once `capacity` is reached, the
+//! least-recently-used entry is evicted to make room, so the cache never
+//! grows unbounded regardless of query churn.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use tokio::sync::Mutex;
+
+use super::SearchResults;
+
+/// `(normalized_query, sorted comma-joined engine names)`
+pub type CacheKey = (String, String);
+
+struct Entry {
+    results: SearchResults,
+    inserted_at: Instant,
+    last_used: Instant,
+}
+
+/// In-memory cache of fused, ranked search results.
+pub struct SearchCache {
+    entries: Mutex<HashMap<CacheKey, Entry>>,
+    capacity: usize,
+    ttl: Duration,
+}
+
+impl SearchCache {
+    #[must_use]
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+            capacity: capacity.max(1),
+            ttl,
+        }
+    }
+
+    /// Build the cache key for `query` against `engines` (resolved engine
+    /// names), normalizing case/order so equivalent requests share an entry.
+    #[must_use]
+    pub fn key(query: &str, engines: &[&str]) -> CacheKey {
+        let mut names: Vec<String> = engines.iter().map(|e| e.to_lowercase()).collect();
+        names.sort();
+        (query.trim().to_lowercase(), names.join(","))
+    }
+
+    /// Return a cached result for `key`, or `None` on a miss or expired entry.
+    pub async fn get(&self, key: &CacheKey) -> Option<SearchResults> {
+        let mut entries = self.entries.lock().await;
+
+        match entries.get(key) {
+            Some(entry) if entry.inserted_at.elapsed() > self.ttl => {
+                entries.remove(key);
+                None
+            }
+            Some(_) => {
+                let entry = entries.get_mut(key)?;
+                entry.last_used = Instant::now();
+                Some(entry.results.clone())
+            }
+            None => None,
+        }
+    }
+
+    /// Insert `results` under `key`, evicting expired entries first and
+    /// then, if still at capacity, the least-recently-used survivor.
+    pub async fn put(&self, key: CacheKey, results: SearchResults) {
+        let mut entries = self.entries.lock().await;
+
+        entries.retain(|_, entry| entry.inserted_at.elapsed() <= self.ttl);
+
+        if entries.len() >= self.capacity && !entries.contains_key(&key) {
+            let stalest = entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(k, _)| k.clone());
+            if let Some(stalest) = stalest {
+                entries.remove(&stalest);
+            }
+        }
+
+        let now = Instant::now();
+        entries.insert(
+            key,
+            Entry {
+                results,
+                inserted_at: now,
+                last_used: now,
+            },
+        );
+    }
+}
@@ -0,0 +1,16 @@
+//! Typed errors for `web_search`
+
+use std::time::Duration;
+
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum WebSearchError {
+    /// `connection_id` has exhausted its request budget for the current
+    /// rate-limit window; retry after the given duration.
+    #[error("Rate limit exceeded for connection '{connection_id}'; retry after {retry_after:?}")]
+    RateLimited {
+        connection_id: String,
+        retry_after: Duration,
+    },
+}
@@ -6,8 +6,11 @@
 //!
 //! # Architecture
 //! - `types` - Data structures and constants
-//! - `browser` - Browser lifecycle management
-//! - `search` - Search execution and result extraction
+//! - Engine dispatch is shared with `DeepResearch` via
+//!   [`crate::utils::SearchEngine`] / `resolve_engines`: `Config::search_engine`
+//!   names one engine (`"google"`) or a comma-separated list
+//!   (`"google,duckduckgo"`) to query concurrently, merged with Reciprocal
+//!   Rank Fusion (see [`crate::utils::fuse_rrf`]).
 //!
 //! # Usage Patterns
 //!
@@ -37,69 +40,120 @@
 //! }
 //! ```
 
-mod search;
+mod cache;
+mod errors;
+mod pagination;
+mod rate_limit;
 mod types;
 
 // Re-export public types
+pub use cache::{CacheKey, SearchCache};
+pub use errors::WebSearchError;
+pub use pagination::{PagedSearchResults, SearchCursor, search_paginated};
+pub use rate_limit::RateLimiter;
 pub use types::{MAX_RESULTS, MAX_RETRIES, SearchResult, SearchResults};
 
 use anyhow::Result;
-use tracing::info;
+use futures::StreamExt as _;
+use futures::stream::FuturesUnordered;
+use tracing::{info, warn};
 
-/// Perform web search using provided `BrowserManager`
+/// Perform web search using provided `BrowserManager`, scoped to a caller
+/// connection for rate-limiting purposes.
 ///
 /// This is the function used by MCP tools. The manager is passed in from
 /// the server's tool registry, allowing proper lifecycle management.
 ///
+/// `connection_id` identifies the caller for [`RateLimiter::check`]
+/// (see `BrowserManager::search_rate_limiter`); requests beyond
+/// `Config::requests_per_window` within `Config::window_secs` are rejected
+/// with [`WebSearchError::RateLimited`]. A fused result is served from
+/// `BrowserManager::search_cache` when available, keyed on the query and
+/// resolved engine set, before falling back to a live search.
+///
+/// Dispatches to every engine named by `Config::search_engine` concurrently,
+/// collecting each one's hits as it finishes (via `FuturesUnordered`) rather
+/// than waiting on them in order, then merges the per-engine rankings with
+/// Reciprocal Rank Fusion. An engine that errors out (including after
+/// exhausting its own retries) is dropped rather than failing the whole
+/// search; only if every engine fails is the last error surfaced.
+///
+/// # Pagination
+/// This function always returns the first `MAX_RESULTS` fused hits. To page
+/// further, drive a [`SearchCursor`] (or call [`search_paginated`]) instead -
+/// same per-engine fan-out and fusion, advancing each engine's own offset
+/// param per batch. As with engine selection below, there's no
+/// `limit`/`offset`/`page` field on the `web_search` tool's arguments yet
+/// for the same reason.
+///
+/// # Engine selection
+/// `duckduckgo`, `google`, `brave`, and `startpage` are all available (see
+/// [`crate::utils::resolve_engines`]); which ones run is controlled process-
+/// wide by `Config::search_engine` (e.g. `"google,brave"`). Per-call engine
+/// selection - an `engines` field on the `web_search` tool's arguments -
+/// isn't wired up yet: `WebSearchArgs` is defined in the external
+/// `kodegen_mcp_schema` crate, which isn't part of this tree, so adding a
+/// field to it has to happen there first.
+///
 /// # Arguments
 /// * `browser_manager` - Shared browser manager from tool registry
+/// * `connection_id` - Caller identity used for per-connection rate limiting
 /// * `query` - Search query string
-///
-/// # Implementation
-/// Uses manager instead of global static for browser access.
 pub async fn search_with_manager(
     browser_manager: &crate::BrowserManager,
+    connection_id: &str,
     query: impl Into<String>,
 ) -> Result<SearchResults> {
     let query = query.into();
     info!("Starting web search for query: {}", query);
 
-    // Get browser from manager (NOT global static)
-    let browser_arc = browser_manager.get_or_launch().await?;
-    let browser_lock = browser_arc.lock().await;
-
-    let browser_wrapper = browser_lock
-        .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("Browser not available"))?;
-
-    // Create fresh page for this search
-    let page = crate::browser::create_blank_page(browser_wrapper).await?;
-
-    // Release lock before performing search
-    drop(browser_lock);
-
-    // Perform search with retry logic (unchanged from current implementation)
-    let results = search::retry_with_backoff(
-        || async {
-            search::perform_search(&page, &query).await?;
-            search::wait_for_results(&page).await?;
-            search::extract_results(&page).await
-        },
-        MAX_RETRIES,
-    )
-    .await?;
-
-    info!(
-        "Search completed successfully with {} results",
-        results.len()
-    );
-    
-    // Close page before returning to prevent memory leak
-    if let Err(e) = page.close().await {
-        tracing::warn!("Failed to close search page: {}", e);
+    if let Err(retry_after) = browser_manager
+        .search_rate_limiter()
+        .check(connection_id)
+        .await
+    {
+        return Err(WebSearchError::RateLimited {
+            connection_id: connection_id.to_string(),
+            retry_after,
+        }
+        .into());
+    }
+
+    let config = crate::load_yaml_config().unwrap_or_default();
+    let engines = crate::utils::resolve_engines(&config.search_engine);
+    let engine_names: Vec<&str> = engines.iter().map(|e| e.name()).collect();
+    let cache_key = SearchCache::key(&query, &engine_names);
+
+    if let Some(cached) = browser_manager.search_cache().get(&cache_key).await {
+        info!("Serving cached web search results for query: {}", query);
+        return Ok(cached);
     }
-    
-    Ok(SearchResults::new(query, results))
+
+    let fused = fetch_fused_hits(browser_manager, engines, &query, 0).await?;
+
+    let results = fused
+        .into_iter()
+        .take(MAX_RESULTS)
+        .enumerate()
+        .map(|(i, hit)| SearchResult {
+            rank: i + 1,
+            title: hit.title,
+            url: hit.url,
+            // Engine backends only surface a title, not the SERP snippet
+            // text, for this fused multi-engine path.
+            snippet: String::new(),
+        })
+        .collect();
+
+    info!("Search completed successfully for query: {}", query);
+
+    let results = SearchResults::new(query, results);
+    browser_manager
+        .search_cache()
+        .put(cache_key, results.clone())
+        .await;
+
+    Ok(results)
 }
 
 /// Perform web search (convenience function for standalone scripts)
@@ -119,5 +173,63 @@ pub async fn search_with_manager(
 /// ```
 pub async fn search(query: impl Into<String>) -> Result<SearchResults> {
     let manager = crate::BrowserManager::global();
-    search_with_manager(&manager, query).await
+    search_with_manager(&manager, "standalone", query).await
+}
+
+/// Fan out `query` to every engine in `engines` at `offset`, collecting
+/// each one's hits as it finishes, then fuse them with Reciprocal Rank
+/// Fusion. Shared by [`search_with_manager`] (`offset = 0`) and
+/// [`SearchCursor`] (`offset` advancing per batch).
+///
+/// An engine that errors out (including after exhausting its own retries)
+/// is dropped rather than failing the whole search; only if every engine
+/// fails is the last error surfaced.
+async fn fetch_fused_hits(
+    browser_manager: &crate::BrowserManager,
+    engines: Vec<Box<dyn crate::utils::SearchEngine>>,
+    query: &str,
+    offset: usize,
+) -> Result<Vec<crate::utils::SearchHit>> {
+    let mut in_flight = FuturesUnordered::new();
+    for engine in engines {
+        let query = query.to_string();
+        in_flight.push(async move {
+            let name = engine.name();
+            let result = crate::utils::retry_search(
+                || engine.search(browser_manager, &query, None, offset),
+                MAX_RETRIES,
+            )
+            .await;
+            (name, result)
+        });
+    }
+
+    let mut per_engine = Vec::new();
+    let mut last_error = None;
+    while let Some((name, result)) = in_flight.next().await {
+        match result {
+            Ok(hits) => {
+                info!(
+                    "{} engine found {} hits for query: {} (offset {})",
+                    name,
+                    hits.len(),
+                    query,
+                    offset
+                );
+                per_engine.push(hits);
+            }
+            Err(e) => {
+                warn!("{} engine failed for query '{}': {}", name, query, e);
+                last_error = Some(e);
+            }
+        }
+    }
+
+    if per_engine.is_empty()
+        && let Some(e) = last_error
+    {
+        return Err(anyhow::anyhow!(e.to_string()));
+    }
+
+    Ok(crate::utils::fuse_rrf(per_engine))
 }
@@ -0,0 +1,147 @@
+//! Paging through fused multi-engine search results beyond the first page
+//!
+//! [`super::search_with_manager`] always returns the first
+//! [`super::MAX_RESULTS`] fused hits. [`SearchCursor`] wraps the same
+//! per-engine fan-out/fuse machinery behind a stateful scroll iterator:
+//! each [`SearchCursor::next_batch`] call advances an internal offset and
+//! refills its batch from every configured engine, so callers can collect
+//! dozens of results by driving the cursor forward instead of re-issuing
+//! the same query.
+
+use anyhow::Result;
+
+use super::{SearchResult, fetch_fused_hits};
+
+/// A page of [`super::search`] results plus enough state to fetch the next
+/// one. `has_more` is a lower bound: it's `true` whenever the most recent
+/// batch was full, even though the next batch might turn out empty.
+#[derive(Debug, Clone)]
+pub struct PagedSearchResults {
+    pub query: String,
+    pub results: Vec<SearchResult>,
+    /// Offset to resume from - pass as `offset` on the next call.
+    pub next_offset: usize,
+    pub has_more: bool,
+}
+
+/// Stateful cursor over one query's fused results, paging each configured
+/// engine forward by its own offset parameter as batches are exhausted.
+///
+/// Each `next_batch` call is a fresh fan-out/fuse round at the cursor's
+/// current offset - there's no cross-call engine session to keep alive, so
+/// the "continuation token" is just that offset.
+pub struct SearchCursor {
+    query: String,
+    offset: usize,
+    exhausted: bool,
+}
+
+impl SearchCursor {
+    #[must_use]
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            query: query.into(),
+            offset: 0,
+            exhausted: false,
+        }
+    }
+
+    /// Whether a previous batch came back short, meaning every engine has
+    /// run out of results for this query.
+    #[must_use]
+    pub fn is_exhausted(&self) -> bool {
+        self.exhausted
+    }
+
+    /// Fetch up to `batch_size` more results starting at the cursor's
+    /// current offset, fanning the same `engines` out concurrently and
+    /// fusing them as [`super::search_with_manager`] does for the first
+    /// page. Advances the cursor's offset by the number of results
+    /// returned; once a batch comes back short, the cursor is marked
+    /// exhausted and subsequent calls return an empty batch immediately.
+    pub async fn next_batch(
+        &mut self,
+        browser_manager: &crate::BrowserManager,
+        engines: Vec<Box<dyn crate::utils::SearchEngine>>,
+        batch_size: usize,
+    ) -> Result<Vec<SearchResult>> {
+        if self.exhausted {
+            return Ok(Vec::new());
+        }
+
+        let fused = fetch_fused_hits(browser_manager, engines, &self.query, self.offset).await?;
+        let batch: Vec<SearchResult> = fused
+            .into_iter()
+            .take(batch_size)
+            .enumerate()
+            .map(|(i, hit)| SearchResult {
+                rank: self.offset + i + 1,
+                title: hit.title,
+                url: hit.url,
+                snippet: String::new(),
+            })
+            .collect();
+
+        if batch.len() < batch_size {
+            self.exhausted = true;
+        }
+        self.offset += batch.len();
+
+        Ok(batch)
+    }
+}
+
+/// Collect up to `limit` fused results starting at `offset`, paging the
+/// cursor forward in `batch_size`-sized rounds until `limit` is reached or
+/// every engine is exhausted.
+///
+/// This is the paginated counterpart to [`super::search_with_manager`] -
+/// unlike it, there's no tool-facing `limit`/`offset`/`page` entry point
+/// yet (see the module doc comment on why), so for now this is reached
+/// only by calling it directly as a library function.
+pub async fn search_paginated(
+    browser_manager: &crate::BrowserManager,
+    query: impl Into<String>,
+    engines: Vec<Box<dyn crate::utils::SearchEngine>>,
+    limit: usize,
+    offset: usize,
+) -> Result<PagedSearchResults> {
+    const BATCH_SIZE: usize = super::MAX_RESULTS;
+
+    let query = query.into();
+    let mut cursor = SearchCursor::new(query.clone());
+    cursor.offset = offset;
+
+    let mut collected = Vec::new();
+    while collected.len() < limit && !cursor.is_exhausted() {
+        let remaining = limit - collected.len();
+        let batch = cursor
+            .next_batch(
+                browser_manager,
+                clone_engines(&engines),
+                remaining.min(BATCH_SIZE),
+            )
+            .await?;
+        if batch.is_empty() {
+            break;
+        }
+        collected.extend(batch);
+    }
+
+    Ok(PagedSearchResults {
+        query,
+        next_offset: cursor.offset,
+        has_more: !cursor.is_exhausted(),
+        results: collected,
+    })
+}
+
+/// `Box<dyn SearchEngine>` isn't `Clone` (trait objects can't derive it),
+/// so re-resolve a fresh set of engine backends per batch from their names
+/// rather than threading the original boxes through each loop iteration.
+fn clone_engines(
+    engines: &[Box<dyn crate::utils::SearchEngine>],
+) -> Vec<Box<dyn crate::utils::SearchEngine>> {
+    let names: Vec<&str> = engines.iter().map(|e| e.name()).collect();
+    crate::utils::resolve_engines(&names.join(","))
+}
@@ -0,0 +1,61 @@
+//! Per-connection token-bucket rate limiting for `web_search`
+//!
+//! Each connection (see `research::ResearchRegistry`'s `connection_id`) gets
+//! its own bucket so one noisy connection can't starve another sharing the
+//! same process. Refill is continuous rather than reset-per-window, so a
+//! caller can't burst right at a window boundary.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use tokio::sync::Mutex;
+
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+/// Token-bucket rate limiter, one bucket per `connection_id`.
+pub struct RateLimiter {
+    buckets: Mutex<HashMap<String, Bucket>>,
+    capacity: f64,
+    refill_per_sec: f64,
+}
+
+impl RateLimiter {
+    /// `capacity` requests are allowed per `window`, refilling continuously.
+    #[must_use]
+    pub fn new(capacity: u32, window: Duration) -> Self {
+        let capacity = f64::from(capacity.max(1));
+        Self {
+            buckets: Mutex::new(HashMap::new()),
+            capacity,
+            refill_per_sec: capacity / window.as_secs_f64().max(0.001),
+        }
+    }
+
+    /// Try to consume one token for `connection_id`.
+    ///
+    /// Returns `Ok(())` if a token was available, or `Err(retry_after)` with
+    /// the wait until the next token would be available.
+    pub async fn check(&self, connection_id: &str) -> Result<(), Duration> {
+        let mut buckets = self.buckets.lock().await;
+        let now = Instant::now();
+        let bucket = buckets.entry(connection_id.to_string()).or_insert(Bucket {
+            tokens: self.capacity,
+            last_refill: now,
+        });
+
+        let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+        bucket.last_refill = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            let deficit = 1.0 - bucket.tokens;
+            Err(Duration::from_secs_f64(deficit / self.refill_per_sec))
+        }
+    }
+}
@@ -0,0 +1,38 @@
+//! Result types returned by `web_search::search` / `search_with_manager`
+
+use serde::{Deserialize, Serialize};
+
+/// Maximum number of search results returned to callers after fusion across
+/// engines and truncation.
+pub const MAX_RESULTS: usize = 10;
+
+/// Maximum additional attempts per engine when a search hits a transient
+/// error (throttling, navigation failure, etc.), beyond the first attempt.
+pub const MAX_RETRIES: u32 = 2;
+
+/// A single ranked web search result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchResult {
+    /// 1-based position in the fused, ranked result list
+    pub rank: usize,
+    pub title: String,
+    pub url: String,
+    pub snippet: String,
+}
+
+/// Results of a `web_search::search` / `search_with_manager` call
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchResults {
+    pub query: String,
+    pub results: Vec<SearchResult>,
+}
+
+impl SearchResults {
+    #[must_use]
+    pub fn new(query: impl Into<String>, results: Vec<SearchResult>) -> Self {
+        Self {
+            query: query.into(),
+            results,
+        }
+    }
+}